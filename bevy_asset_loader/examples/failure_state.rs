@@ -1,5 +1,6 @@
 use bevy::app::AppExit;
 use bevy::prelude::*;
+use bevy_asset_loader::loading_state::FailedAssets;
 use bevy_asset_loader::prelude::*;
 
 fn main() {
@@ -32,8 +33,9 @@ fn fail() {
     panic!("The library should have switched to the failure state!");
 }
 
-fn ok(mut quit: EventWriter<AppExit>) {
+fn ok(failed: Res<FailedAssets>, mut quit: EventWriter<AppExit>) {
     info!("As expected, bevy_asset_loader switched to the failure state");
+    info!("Failed to load {:?}", failed.0);
     info!("Quitting the application...");
     quit.send(AppExit);
 }
@@ -0,0 +1,167 @@
+use bevy::asset::io::{AssetReader, AssetReaderError, AssetSource, PathStream, Reader, VecReader};
+use bevy::prelude::*;
+use bevy::utils::BoxedFuture;
+use bevy_asset_loader::prelude::*;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Component, Path};
+use std::time::Duration;
+
+/// Bevy's asset paths cannot contain a port, so the example reader uses this one for all hosts
+const SERVER_PORT: u16 = 8765;
+
+/// This example shows how to load assets referenced by URL into a collection.
+///
+/// Paths containing `://` are passed through to the asset source named by the URL scheme.
+/// In a real game that would be a web asset source for `http` and `https` (e.g. from `bevy_web_asset`).
+/// To keep this example self-contained, it registers a minimal `http` source and serves
+/// the `assets` directory over a deliberately slow local HTTP server.
+///
+/// Loading states do not time out, so the collection is ready as soon as the remote image arrived.
+fn main() {
+    serve_assets_slowly();
+    App::new()
+        // Asset sources have to be registered before the `AssetPlugin` (part of `DefaultPlugins`)
+        .register_asset_source(
+            "http",
+            AssetSource::build().with_reader(|| Box::new(HttpAssetReader)),
+        )
+        .add_plugins(DefaultPlugins)
+        .add_state::<MyStates>()
+        .add_loading_state(
+            LoadingState::new(MyStates::AssetLoading).continue_to_state(MyStates::Next),
+        )
+        .add_collection_to_loading_state::<_, RemoteAssets>(MyStates::AssetLoading)
+        .add_systems(OnEnter(MyStates::AssetLoading), show_loading)
+        .add_systems(OnEnter(MyStates::Next), draw)
+        .run();
+}
+
+#[derive(AssetCollection, Resource)]
+struct RemoteAssets {
+    #[asset(path = "http://127.0.0.1/images/player.png")]
+    player: Handle<Image>,
+}
+
+fn show_loading() {
+    info!("Loading the player image from the local server on port {SERVER_PORT}...");
+}
+
+fn draw(mut commands: Commands, remote_assets: Res<RemoteAssets>) {
+    info!("Remote assets are loaded");
+    commands.spawn(Camera2dBundle::default());
+    commands.spawn(SpriteBundle {
+        texture: remote_assets.player.clone(),
+        ..default()
+    });
+}
+
+/// Reads assets over plain HTTP/1.1
+///
+/// This reader blocks an IO thread per request and only understands the happy path of HTTP.
+struct HttpAssetReader;
+
+impl AssetReader for HttpAssetReader {
+    fn read<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        Box::pin(async move {
+            let bytes = get(path)?;
+            let reader: Box<Reader> = Box::new(VecReader::new(bytes));
+            Ok(reader)
+        })
+    }
+
+    fn read_meta<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        Box::pin(async move { Err(AssetReaderError::NotFound(path.to_path_buf())) })
+    }
+
+    fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<PathStream>, AssetReaderError>> {
+        Box::pin(async move { Err(AssetReaderError::NotFound(path.to_path_buf())) })
+    }
+
+    fn is_directory<'a>(
+        &'a self,
+        _path: &'a Path,
+    ) -> BoxedFuture<'a, Result<bool, AssetReaderError>> {
+        Box::pin(async move { Ok(false) })
+    }
+}
+
+fn get(path: &Path) -> Result<Vec<u8>, AssetReaderError> {
+    let url = path.to_string_lossy().replace('\\', "/");
+    let (host, resource) = url.split_once('/').unwrap_or((&url, ""));
+    let mut stream = TcpStream::connect((host, SERVER_PORT))?;
+    write!(
+        stream,
+        "GET /{resource} HTTP/1.1\r\nHost: {host}\r\nConnection: close\r\n\r\n"
+    )?;
+    let mut response = vec![];
+    stream.read_to_end(&mut response)?;
+
+    let header_end = response
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .ok_or_else(|| AssetReaderError::NotFound(path.to_path_buf()))?;
+    if !response.starts_with(b"HTTP/1.1 200") {
+        return Err(AssetReaderError::NotFound(path.to_path_buf()));
+    }
+
+    Ok(response.split_off(header_end + 4))
+}
+
+/// Serves the `assets` directory and answers every request after a delay
+fn serve_assets_slowly() {
+    let listener =
+        TcpListener::bind(("127.0.0.1", SERVER_PORT)).expect("Failed to start the asset server");
+    std::thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            let mut request_line = String::new();
+            if BufReader::new(&stream)
+                .read_line(&mut request_line)
+                .is_err()
+            {
+                continue;
+            }
+            let resource = request_line.split_whitespace().nth(1).unwrap_or("/");
+            let resource = Path::new(resource.trim_start_matches('/'));
+            // Only serve files inside the assets folder
+            let file = resource
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)))
+                .then(|| {
+                    Path::new(env!("CARGO_MANIFEST_DIR"))
+                        .join("assets")
+                        .join(resource)
+                });
+
+            std::thread::sleep(Duration::from_secs(2));
+            let _ = match file.and_then(|file| std::fs::read(file).ok()) {
+                Some(bytes) => write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    bytes.len()
+                )
+                .and_then(|_| stream.write_all(&bytes)),
+                None => write!(
+                    stream,
+                    "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                ),
+            };
+        }
+    });
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    AssetLoading,
+    Next,
+}
@@ -0,0 +1,484 @@
+use bevy::app::App;
+use bevy::asset::io::{
+    AssetReader, AssetReaderError, AssetSource, AssetSourceId, PathStream, Reader, VecReader,
+};
+use bevy::asset::{AssetApp, AsyncReadExt};
+use bevy::utils::{BoxedFuture, HashMap, HashSet};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Extension trait to register archives as asset sources
+///
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy_asset_loader::archive::ArchiveAssetSourceApp;
+/// # use bevy::prelude::*;
+/// # use bevy::asset::AssetPlugin;
+/// # fn main() {
+///     App::new()
+///         .register_archive_asset_source("pack", "pack.zip")
+///         .add_plugins((MinimalPlugins, AssetPlugin::default()))
+///         // ...
+/// #       .set_runner(|mut app| app.update())
+/// #       .run();
+/// # }
+/// ```
+pub trait ArchiveAssetSourceApp {
+    /// Register the given archive as an asset source with the given name
+    ///
+    /// The path of the archive is relative to the `assets` folder. Archives ending in `.zip` (stored or
+    /// deflated entries) and `.tar` (uncompressed, including GNU and PAX long names) are supported. Assets in the archive are loaded with
+    /// paths like `pack://images/player.png`, or with `#[asset_collection(source = "pack")]` on a collection.
+    ///
+    /// The archive is not streamed: it is read into memory completely when the first asset is loaded from it.
+    /// Entries are extracted one at a time when they are read, nothing is written to disk. Zip entries
+    /// are checked against their CRC-32 and tar headers against their checksums; reading a corrupted
+    /// entry fails its asset.
+    ///
+    /// Like all asset sources, this has to be registered before the [`AssetPlugin`](bevy::asset::AssetPlugin).
+    fn register_archive_asset_source(
+        &mut self,
+        source: &'static str,
+        archive: impl Into<PathBuf>,
+    ) -> &mut Self;
+}
+
+impl ArchiveAssetSourceApp for App {
+    fn register_archive_asset_source(
+        &mut self,
+        source: &'static str,
+        archive: impl Into<PathBuf>,
+    ) -> &mut Self {
+        let archive = archive.into();
+        let mut default_reader = AssetSource::get_default_reader("assets".to_owned());
+        self.register_asset_source(
+            AssetSourceId::Name(source.into()),
+            AssetSource::build().with_reader(move || {
+                Box::new(ArchiveAssetReader {
+                    reader: default_reader(),
+                    path: archive.clone(),
+                    archive: Mutex::default(),
+                })
+            }),
+        )
+    }
+}
+
+/// Reads assets from an archive that is itself read with another [`AssetReader`]
+struct ArchiveAssetReader {
+    reader: Box<dyn AssetReader>,
+    path: PathBuf,
+    archive: Mutex<Option<Arc<Archive>>>,
+}
+
+impl ArchiveAssetReader {
+    async fn archive(&self) -> Result<Arc<Archive>, AssetReaderError> {
+        if let Some(archive) = self.archive.lock().unwrap().clone() {
+            return Ok(archive);
+        }
+        let mut reader = self.reader.read(&self.path).await?;
+        let mut bytes = vec![];
+        reader.read_to_end(&mut bytes).await?;
+        let archive = Arc::new(Archive::parse(&self.path, bytes)?);
+
+        Ok(self.archive.lock().unwrap().get_or_insert(archive).clone())
+    }
+
+    async fn read_entry(&self, path: &Path) -> Result<Box<Reader<'static>>, AssetReaderError> {
+        let archive = self.archive().await?;
+        let bytes = archive.extract(path)?;
+
+        Ok(Box::new(VecReader::new(bytes)))
+    }
+}
+
+impl AssetReader for ArchiveAssetReader {
+    fn read<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        Box::pin(async move { self.read_entry(path).await })
+    }
+
+    fn read_meta<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        Box::pin(async move {
+            let mut meta_path = path.as_os_str().to_owned();
+            meta_path.push(".meta");
+            self.read_entry(Path::new(&meta_path)).await
+        })
+    }
+
+    fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<PathStream>, AssetReaderError>> {
+        Box::pin(async move {
+            let archive = self.archive().await?;
+            if !archive.directories.contains(path) {
+                return Err(AssetReaderError::NotFound(path.to_owned()));
+            }
+            let children: Vec<PathBuf> = archive
+                .entries
+                .keys()
+                .chain(archive.directories.iter())
+                .filter(|child| child.parent() == Some(path))
+                .cloned()
+                .collect();
+            let stream: Box<PathStream> = Box::new(futures_lite::stream::iter(children));
+
+            Ok(stream)
+        })
+    }
+
+    fn is_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<bool, AssetReaderError>> {
+        Box::pin(async move { Ok(self.archive().await?.directories.contains(path)) })
+    }
+}
+
+/// An archive read into memory with the locations of its files
+struct Archive {
+    bytes: Vec<u8>,
+    entries: HashMap<PathBuf, ArchiveEntry>,
+    directories: HashSet<PathBuf>,
+}
+
+struct ArchiveEntry {
+    offset: usize,
+    size: usize,
+    compression: Compression,
+    /// CRC-32 of the extracted file; tar archives only have checksums for their headers
+    crc32: Option<u32>,
+}
+
+enum Compression {
+    Stored,
+    Deflate { uncompressed_size: usize },
+}
+
+impl Archive {
+    fn parse(path: &Path, bytes: Vec<u8>) -> Result<Archive, AssetReaderError> {
+        let extension = path
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_lowercase());
+        let entries = match extension.as_deref() {
+            Some("zip") => parse_zip(&bytes),
+            Some("tar") => parse_tar(&bytes),
+            _ => Err(invalid_archive(
+                "only archives ending in '.zip' or '.tar' are supported",
+            )),
+        }
+        .map_err(|error| {
+            AssetReaderError::Io(io::Error::new(
+                error.kind(),
+                format!("failed to read archive '{}': {error}", path.display()),
+            ))
+        })?;
+        let mut directories = HashSet::default();
+        directories.insert(PathBuf::new());
+        for path in entries.keys() {
+            directories.extend(path.ancestors().skip(1).map(Path::to_path_buf));
+        }
+
+        Ok(Archive {
+            bytes,
+            entries,
+            directories,
+        })
+    }
+
+    fn extract(&self, path: &Path) -> Result<Vec<u8>, AssetReaderError> {
+        let entry = self
+            .entries
+            .get(path)
+            .ok_or_else(|| AssetReaderError::NotFound(path.to_owned()))?;
+        let data = &self.bytes[entry.offset..entry.offset + entry.size];
+        let bytes = match entry.compression {
+            Compression::Stored => data.to_vec(),
+            Compression::Deflate { uncompressed_size } => {
+                miniz_oxide::inflate::decompress_to_vec_with_limit(data, uncompressed_size)
+                    .map_err(|error| {
+                        AssetReaderError::Io(invalid_archive(format!(
+                            "failed to inflate '{}': {error}",
+                            path.display()
+                        )))
+                    })?
+            }
+        };
+        if entry
+            .crc32
+            .is_some_and(|expected| crc32(&bytes) != expected)
+        {
+            return Err(AssetReaderError::Io(invalid_archive(format!(
+                "'{}' does not match its CRC-32, the archive is corrupted",
+                path.display()
+            ))));
+        }
+
+        Ok(bytes)
+    }
+}
+
+/// CRC-32 (IEEE) as used by zip archives
+fn crc32(bytes: &[u8]) -> u32 {
+    const TABLE: [u32; 256] = {
+        let mut table = [0; 256];
+        let mut n = 0;
+        while n < 256 {
+            let mut crc = n as u32;
+            let mut bit = 0;
+            while bit < 8 {
+                crc = if crc & 1 == 1 {
+                    0xedb88320 ^ (crc >> 1)
+                } else {
+                    crc >> 1
+                };
+                bit += 1;
+            }
+            table[n] = crc;
+            n += 1;
+        }
+        table
+    };
+
+    !bytes.iter().fold(!0, |crc, &byte| {
+        TABLE[((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+fn invalid_archive(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+/// Normalize a path stored in an archive; returns `None` for directories
+fn entry_path(name: &str) -> Option<PathBuf> {
+    let name = name.trim_start_matches("./").trim_start_matches('/');
+    if name.is_empty() || name.ends_with('/') {
+        return None;
+    }
+
+    Some(PathBuf::from(name))
+}
+
+fn read_u16(bytes: &[u8], at: usize) -> io::Result<usize> {
+    bytes
+        .get(at..at + 2)
+        .map(|le| u16::from_le_bytes([le[0], le[1]]) as usize)
+        .ok_or_else(|| invalid_archive("unexpected end of zip archive"))
+}
+
+fn read_u32(bytes: &[u8], at: usize) -> io::Result<usize> {
+    bytes
+        .get(at..at + 4)
+        .map(|le| u32::from_le_bytes([le[0], le[1], le[2], le[3]]) as usize)
+        .ok_or_else(|| invalid_archive("unexpected end of zip archive"))
+}
+
+const ZIP_END_OF_CENTRAL_DIRECTORY: usize = 0x06054b50;
+const ZIP_CENTRAL_DIRECTORY_HEADER: usize = 0x02014b50;
+const ZIP_LOCAL_FILE_HEADER: usize = 0x04034b50;
+
+/// Parse the central directory of a zip archive
+///
+/// Zip64 and encrypted archives are not supported.
+fn parse_zip(bytes: &[u8]) -> io::Result<HashMap<PathBuf, ArchiveEntry>> {
+    // the end of central directory record is 22 bytes followed by a comment of up to 65535 bytes
+    let end = bytes
+        .len()
+        .checked_sub(22)
+        .ok_or_else(|| invalid_archive("too small to be a zip archive"))?;
+    let end_of_central_directory = (end.saturating_sub(u16::MAX as usize)..=end)
+        .rev()
+        .find(|&at| read_u32(bytes, at).ok() == Some(ZIP_END_OF_CENTRAL_DIRECTORY))
+        .ok_or_else(|| invalid_archive("no end of central directory found"))?;
+    let count = read_u16(bytes, end_of_central_directory + 10)?;
+    let mut at = read_u32(bytes, end_of_central_directory + 16)?;
+
+    let mut entries = HashMap::default();
+    for _ in 0..count {
+        if read_u32(bytes, at)? != ZIP_CENTRAL_DIRECTORY_HEADER {
+            return Err(invalid_archive("malformed central directory"));
+        }
+        let flags = read_u16(bytes, at + 8)?;
+        let method = read_u16(bytes, at + 10)?;
+        let crc32 = read_u32(bytes, at + 16)? as u32;
+        let size = read_u32(bytes, at + 20)?;
+        let uncompressed_size = read_u32(bytes, at + 24)?;
+        let name_length = read_u16(bytes, at + 28)?;
+        let extra_length = read_u16(bytes, at + 30)?;
+        let comment_length = read_u16(bytes, at + 32)?;
+        let local_header = read_u32(bytes, at + 42)?;
+        let name = bytes
+            .get(at + 46..at + 46 + name_length)
+            .ok_or_else(|| invalid_archive("unexpected end of zip archive"))?;
+        let name = String::from_utf8_lossy(name).into_owned();
+        at += 46 + name_length + extra_length + comment_length;
+
+        let Some(path) = entry_path(&name) else {
+            continue;
+        };
+        if flags & 1 != 0 {
+            return Err(invalid_archive(format!("'{name}' is encrypted")));
+        }
+        if size == u32::MAX as usize || uncompressed_size == u32::MAX as usize {
+            return Err(invalid_archive("zip64 archives are not supported"));
+        }
+        let compression = match method {
+            0 => Compression::Stored,
+            8 => Compression::Deflate { uncompressed_size },
+            _ => {
+                return Err(invalid_archive(format!(
+                    "'{name}' uses the unsupported compression method {method}"
+                )))
+            }
+        };
+        if read_u32(bytes, local_header)? != ZIP_LOCAL_FILE_HEADER {
+            return Err(invalid_archive(format!(
+                "malformed local header of '{name}'"
+            )));
+        }
+        let offset = local_header
+            + 30
+            + read_u16(bytes, local_header + 26)?
+            + read_u16(bytes, local_header + 28)?;
+        if offset + size > bytes.len() {
+            return Err(invalid_archive(format!("'{name}' is truncated")));
+        }
+        entries.insert(
+            path,
+            ArchiveEntry {
+                offset,
+                size,
+                compression,
+                crc32: Some(crc32),
+            },
+        );
+    }
+
+    Ok(entries)
+}
+
+/// Parse the headers of an uncompressed (ustar) tar archive
+///
+/// Only regular files are read; links are ignored. Long names from GNU (`L`) and PAX (`x`) headers
+/// replace the name of the following entry. Headers with a wrong checksum fail the whole archive.
+fn parse_tar(bytes: &[u8]) -> io::Result<HashMap<PathBuf, ArchiveEntry>> {
+    fn text(field: &[u8]) -> String {
+        let end = field
+            .iter()
+            .position(|&byte| byte == 0)
+            .unwrap_or(field.len());
+        String::from_utf8_lossy(&field[..end]).into_owned()
+    }
+
+    let mut entries = HashMap::default();
+    let mut long_name = None;
+    let mut at = 0;
+    while let Some(header) = bytes.get(at..at + 512) {
+        if header.iter().all(|&byte| byte == 0) {
+            break;
+        }
+        verify_tar_checksum(header)?;
+        let size_field = text(&header[124..136]);
+        let size = usize::from_str_radix(size_field.trim(), 8)
+            .map_err(|_| invalid_archive("malformed tar header"))?;
+        let mut name = text(&header[..100]);
+        if &header[257..262] == b"ustar" {
+            let prefix = text(&header[345..500]);
+            if !prefix.is_empty() {
+                name = format!("{prefix}/{name}");
+            }
+        }
+        let offset = at + 512;
+        let data = bytes
+            .get(offset..offset + size)
+            .ok_or_else(|| invalid_archive(format!("'{name}' is truncated")))?;
+        match header[156] {
+            b'0' | 0 => {
+                let name = long_name.take().unwrap_or(name);
+                if let Some(path) = entry_path(&name) {
+                    entries.insert(
+                        path,
+                        ArchiveEntry {
+                            offset,
+                            size,
+                            compression: Compression::Stored,
+                            crc32: None,
+                        },
+                    );
+                }
+            }
+            b'L' => long_name = Some(text(data)),
+            b'x' => long_name = pax_path(data)?.or(long_name),
+            b'g' => {}
+            _ => long_name = None,
+        }
+        at = offset + size.div_ceil(512) * 512;
+    }
+
+    Ok(entries)
+}
+
+/// The checksum of a tar header is the sum of its bytes with the checksum field counted as spaces
+fn verify_tar_checksum(header: &[u8]) -> io::Result<()> {
+    let field = &header[148..156];
+    let end = field
+        .iter()
+        .position(|&byte| byte == 0 || byte == b' ')
+        .unwrap_or(field.len());
+    let expected = std::str::from_utf8(&field[..end])
+        .ok()
+        .and_then(|checksum| u32::from_str_radix(checksum.trim_start(), 8).ok())
+        .ok_or_else(|| invalid_archive("malformed tar header checksum"))?;
+    let sum: u32 = header
+        .iter()
+        .enumerate()
+        .map(|(index, &byte)| {
+            if (148..156).contains(&index) {
+                u32::from(b' ')
+            } else {
+                u32::from(byte)
+            }
+        })
+        .sum();
+    if sum != expected {
+        return Err(invalid_archive(
+            "a tar header does not match its checksum, the archive is corrupted",
+        ));
+    }
+
+    Ok(())
+}
+
+/// The `path` record of a PAX extended header
+///
+/// Records have the form `<length> <key>=<value>\n`, where the length counts the whole record.
+fn pax_path(data: &[u8]) -> io::Result<Option<String>> {
+    let mut path = None;
+    let mut rest = data;
+    while !rest.is_empty() && rest[0] != 0 {
+        let malformed = || invalid_archive("malformed PAX header");
+        let space = rest
+            .iter()
+            .position(|&byte| byte == b' ')
+            .ok_or_else(malformed)?;
+        let length: usize = std::str::from_utf8(&rest[..space])
+            .ok()
+            .and_then(|length| length.parse().ok())
+            .ok_or_else(malformed)?;
+        let record = rest.get(space + 1..length).ok_or_else(malformed)?;
+        let record = record.strip_suffix(b"\n").unwrap_or(record);
+        if let Some(value) = record.strip_prefix(b"path=") {
+            path = Some(String::from_utf8_lossy(value).into_owned());
+        }
+        rest = &rest[length..];
+    }
+
+    Ok(path)
+}
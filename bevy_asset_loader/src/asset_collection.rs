@@ -1,8 +1,54 @@
+use crate::collection_loader::{update_collection_requests, CollectionLoader};
 use crate::dynamic_asset::DynamicAssets;
-use bevy::app::App;
-use bevy::asset::UntypedHandle;
-use bevy::ecs::system::Resource;
-use bevy::ecs::world::World;
+use crate::mapped::{map_folder, MapKey};
+use crate::placeholder::init_placeholders;
+use crate::streamed_folder::{stream_mapped_folders, StreamedFolders};
+use bevy::app::{App, PreStartup, Update};
+#[cfg(not(target_arch = "wasm32"))]
+use bevy::asset::handle_internal_asset_events;
+use bevy::asset::meta::Settings;
+use bevy::asset::{
+    Asset, AssetServer, Handle, LoadState, RecursiveDependencyLoadState, UntypedAssetId,
+    UntypedHandle,
+};
+use bevy::ecs::change_detection::DetectChanges;
+use bevy::ecs::schedule::{OnExit, State, States};
+use bevy::ecs::system::{Commands, Res, ResMut, Resource};
+use bevy::ecs::world::{World, WorldCell};
+use bevy::log::error;
+#[cfg(feature = "reflect")]
+use bevy::reflect::Reflect;
+#[cfg(not(target_arch = "wasm32"))]
+use bevy::tasks::tick_global_task_pools_on_main_thread;
+use bevy::tasks::TaskPool;
+use bevy::time::{Time, Timer, TimerMode};
+use bevy::utils::{HashMap, HashSet};
+use std::any::{type_name, TypeId};
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+
+#[cfg(feature = "2d")]
+use crate::atlas_layout::register_atlas_definition_loader;
+#[cfg(any(feature = "2d", feature = "3d"))]
+use bevy::asset::Assets;
+#[cfg(any(feature = "2d", feature = "3d"))]
+use bevy::log::warn;
+#[cfg(feature = "2d")]
+use bevy::math::Vec2;
+#[cfg(feature = "2d")]
+use bevy::render::render_resource::{TextureDimension, TextureFormat};
+#[cfg(any(feature = "2d", feature = "3d"))]
+use bevy::render::texture::Image;
+#[cfg(feature = "2d")]
+use bevy::render::texture::ImageSampler;
+#[cfg(feature = "2d")]
+use bevy::sprite::TextureAtlas;
+#[cfg(feature = "2d")]
+use bevy::tasks::ComputeTaskPool;
 
 pub use bevy_asset_loader_derive::AssetCollection;
 
@@ -25,6 +71,1085 @@ pub trait AssetCollection: Resource {
     fn create(world: &mut World) -> Self;
     /// Start loading all the assets in the collection
     fn load(world: &mut World) -> Vec<UntypedHandle>;
+
+    /// All asset handles stored in the collection
+    ///
+    /// Folder, file list, and mapped fields contribute every handle they contain.
+    /// This can be used by systems working on all assets of a collection, e.g. to force GPU uploads.
+    /// The derive macro implements this function based on the asset fields of the collection.
+    fn all_handles(&self) -> Vec<UntypedHandle> {
+        vec![]
+    }
+
+    /// Register all asset handles of the collection in the given registry
+    ///
+    /// The registry is called once per handle together with the name of the field holding it.
+    /// The derive macro implements this function based on the asset fields of the collection.
+    fn register_handles(&self, registry: &mut dyn HandleRegistry) {
+        let _ = registry;
+    }
+
+    /// Folder fields of the collection with their asset path and current handles
+    ///
+    /// Loading states with [`LoadingState::with_hot_reload`](crate::loading_state::LoadingState::with_hot_reload)
+    /// watch these folders for added and removed files.
+    /// The derive macro implements this function for `collection` fields loading a folder.
+    fn folders(&self) -> Vec<(&'static str, Vec<UntypedHandle>)> {
+        vec![]
+    }
+
+    /// Replace the handles of all folder fields loading the given folder
+    ///
+    /// Typed fields ignore handles of other asset types. Mapped fields are keyed by their [`MapKey`](crate::mapped::MapKey).
+    /// The derive macro implements this function for `collection` fields loading a folder.
+    fn update_folder(&mut self, folder: &str, handles: &[UntypedHandle]) {
+        let _ = (folder, handles);
+    }
+
+    /// Paths of images that other assets of the collection are created from
+    ///
+    /// Texture atlases, images with a sampler, and standard materials are processed when the collection
+    /// is created. Loading states with [`LoadingState::with_hot_reload`](crate::loading_state::LoadingState::with_hot_reload)
+    /// create the collection again if one of these images is modified.
+    /// The derive macro implements this function for such fields loaded from a path.
+    fn processed_images() -> Vec<&'static str> {
+        vec![]
+    }
+
+    /// Whether the collection is ready to be used in addition to all its assets being loaded
+    ///
+    /// Loading states call this every frame after creating the collection and only insert it
+    /// once this returns `true`. The derive macro forwards to [`CustomReadiness`] for collections
+    /// annotated with `#[asset_collection(custom_ready)]`.
+    fn ready(&self, world: &World) -> bool {
+        let _ = world;
+        true
+    }
+
+    /// Describe how the fields of the collection are bound to assets
+    ///
+    /// The derive macro implements this function if the `reflect` feature is enabled.
+    #[cfg(feature = "reflect")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "reflect")))]
+    fn asset_bindings() -> AssetCollectionBindings {
+        AssetCollectionBindings::default()
+    }
+}
+
+/// Readiness of an asset collection that does not depend on its handles, e.g. a compiled shader pipeline
+///
+/// Annotate the collection with `#[asset_collection(custom_ready)]` to make loading states wait
+/// until [`CustomReadiness::custom_ready`] returns `true`.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy_asset_loader::asset_collection::CustomReadiness;
+/// # use bevy::prelude::*;
+/// #[derive(AssetCollection, Resource)]
+/// #[asset_collection(custom_ready)]
+/// struct WaterAssets {
+///     #[asset(path = "images/water.png")]
+///     water: Handle<Image>,
+/// }
+///
+/// impl CustomReadiness for WaterAssets {
+///     fn custom_ready(&self, world: &World) -> bool {
+///         world.contains_resource::<WaterPipeline>()
+///     }
+/// }
+///
+/// #[derive(Resource)]
+/// struct WaterPipeline;
+/// ```
+pub trait CustomReadiness {
+    /// Whether the collection is ready to be used
+    ///
+    /// Only called once all assets of the collection are loaded.
+    fn custom_ready(&self, world: &World) -> bool;
+}
+
+/// A resource keeping track of asset handles outside of asset collections
+///
+/// Collections added to a loading state with
+/// [`LoadingStateAppExt::add_collection_to_registry`](crate::loading_state::LoadingStateAppExt::add_collection_to_registry)
+/// are merged into a registry instead of being inserted as resources.
+pub trait HandleRegistry: Resource {
+    /// Add a handle loaded for the given field of an asset collection
+    fn register(&mut self, field: &'static str, handle: UntypedHandle);
+}
+
+/// Types of asset collection fields that hold asset handles
+///
+/// Used by the derive macro to implement [`AssetCollection::all_handles`].
+pub trait CollectionHandles {
+    /// Add all handles of this field to the given list
+    fn extend_handles(&self, handles: &mut Vec<UntypedHandle>);
+}
+
+impl<A: Asset> CollectionHandles for Handle<A> {
+    fn extend_handles(&self, handles: &mut Vec<UntypedHandle>) {
+        handles.push(self.clone().untyped());
+    }
+}
+
+impl CollectionHandles for UntypedHandle {
+    fn extend_handles(&self, handles: &mut Vec<UntypedHandle>) {
+        handles.push(self.clone());
+    }
+}
+
+impl<T: CollectionHandles> CollectionHandles for Option<T> {
+    fn extend_handles(&self, handles: &mut Vec<UntypedHandle>) {
+        if let Some(field) = self {
+            field.extend_handles(handles);
+        }
+    }
+}
+
+impl<T: CollectionHandles> CollectionHandles for Vec<T> {
+    fn extend_handles(&self, handles: &mut Vec<UntypedHandle>) {
+        self.iter().for_each(|field| field.extend_handles(handles));
+    }
+}
+
+impl<T: CollectionHandles, const N: usize> CollectionHandles for [T; N] {
+    fn extend_handles(&self, handles: &mut Vec<UntypedHandle>) {
+        self.iter().for_each(|field| field.extend_handles(handles));
+    }
+}
+
+impl<K, T: CollectionHandles> CollectionHandles for HashMap<K, T> {
+    fn extend_handles(&self, handles: &mut Vec<UntypedHandle>) {
+        self.values()
+            .for_each(|field| field.extend_handles(handles));
+    }
+}
+
+impl<T: CollectionHandles> CollectionHandles for Mutex<T> {
+    fn extend_handles(&self, handles: &mut Vec<UntypedHandle>) {
+        self.lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .extend_handles(handles);
+    }
+}
+
+impl<T: CollectionHandles> CollectionHandles for RwLock<T> {
+    fn extend_handles(&self, handles: &mut Vec<UntypedHandle>) {
+        self.read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .extend_handles(handles);
+    }
+}
+
+/// Types of asset collection fields that can hold the contents of a folder
+///
+/// Used by the derive macro to implement [`AssetCollection::update_folder`].
+pub trait FolderHandles {
+    /// Replace the contents of this field with the given handles of a folder
+    ///
+    /// `full_path` is set for mapped fields with `collection(mapped(full_path))`.
+    fn replace_folder_handles(&mut self, folder: &str, full_path: bool, handles: &[UntypedHandle]);
+}
+
+impl FolderHandles for Vec<UntypedHandle> {
+    fn replace_folder_handles(
+        &mut self,
+        _folder: &str,
+        _full_path: bool,
+        handles: &[UntypedHandle],
+    ) {
+        *self = handles.to_vec();
+    }
+}
+
+impl<A: Asset> FolderHandles for Vec<Handle<A>> {
+    fn replace_folder_handles(
+        &mut self,
+        _folder: &str,
+        _full_path: bool,
+        handles: &[UntypedHandle],
+    ) {
+        *self = handles.iter().filter_map(try_typed).collect();
+    }
+}
+
+impl<K: MapKey> FolderHandles for HashMap<K, UntypedHandle> {
+    fn replace_folder_handles(&mut self, folder: &str, full_path: bool, handles: &[UntypedHandle]) {
+        *self = map_folder(folder, full_path, handles, |handle| Some(handle.clone()));
+    }
+}
+
+impl<K: MapKey, A: Asset> FolderHandles for HashMap<K, Handle<A>> {
+    fn replace_folder_handles(&mut self, folder: &str, full_path: bool, handles: &[UntypedHandle]) {
+        *self = map_folder(folder, full_path, handles, try_typed);
+    }
+}
+
+/// The typed handle, or `None` if the handle belongs to an asset of another type
+///
+/// Used by the derive macro to skip files of other types in typed folders.
+#[doc(hidden)]
+pub fn try_typed<A: Asset>(handle: &UntypedHandle) -> Option<Handle<A>> {
+    (handle.type_id() == TypeId::of::<A>()).then(|| handle.clone().typed())
+}
+
+impl<T: FolderHandles> FolderHandles for Mutex<T> {
+    fn replace_folder_handles(&mut self, folder: &str, full_path: bool, handles: &[UntypedHandle]) {
+        self.get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .replace_folder_handles(folder, full_path, handles);
+    }
+}
+
+impl<T: FolderHandles> FolderHandles for RwLock<T> {
+    fn replace_folder_handles(&mut self, folder: &str, full_path: bool, handles: &[UntypedHandle]) {
+        self.get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .replace_folder_handles(folder, full_path, handles);
+    }
+}
+
+/// Reflectable description of the asset bindings of an [`AssetCollection`]
+///
+/// This is meant for tools like editors that want to list or edit the assets of a collection.
+#[cfg(feature = "reflect")]
+#[cfg_attr(docsrs, doc(cfg(feature = "reflect")))]
+#[derive(Reflect, Debug, Clone, Default, PartialEq)]
+pub struct AssetCollectionBindings {
+    /// Name of the asset collection type
+    pub collection: String,
+    /// Bindings of all fields in the asset collection
+    pub bindings: Vec<AssetBinding>,
+}
+
+/// Reflectable description of a single field in an [`AssetCollection`]
+#[cfg(feature = "reflect")]
+#[cfg_attr(docsrs, doc(cfg(feature = "reflect")))]
+#[derive(Reflect, Debug, Clone, PartialEq)]
+pub struct AssetBinding {
+    /// Variant of an enum collection that the field belongs to
+    ///
+    /// This is `None` for struct collections.
+    pub variant: Option<String>,
+    /// Name of the field
+    pub field: String,
+    /// The kind of asset field
+    pub kind: AssetBindingKind,
+    /// Asset file paths of the field
+    ///
+    /// This is empty for dynamic assets and fields initialized from the world.
+    pub paths: Vec<String>,
+    /// Key of a dynamic asset
+    pub key: Option<String>,
+}
+
+/// The different kinds of asset fields supported by the derive macro
+#[cfg(feature = "reflect")]
+#[cfg_attr(docsrs, doc(cfg(feature = "reflect")))]
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetBindingKind {
+    /// A single asset file
+    File,
+    /// A list of asset files
+    Files,
+    /// A folder of asset files
+    Folder,
+    /// An image with a configured sampler
+    Image,
+    /// A standard material created from an image file
+    StandardMaterial,
+    /// A texture atlas created from a sprite sheet or packed from individual image files
+    TextureAtlas,
+    /// A texture atlas created from TexturePacker JSON metadata and a sprite sheet
+    ///
+    /// The paths of this binding are the JSON file followed by the image.
+    SpriteSheet,
+    /// A dynamic asset resolved by its key
+    Dynamic,
+    /// An optional dynamic asset resolved by its key
+    OptionalDynamic,
+    /// The first registered of several dynamic assets, or a fallback file
+    ///
+    /// The key of this binding lists the keys in the order they are tried, separated by `, `.
+    /// The paths contain the fallback file, if the field has one.
+    FirstDynamic,
+    /// A dynamic asset resolving to a collection of handles
+    DynamicCollection,
+    /// An optional dynamic asset resolving to a collection of handles
+    OptionalDynamicCollection,
+    /// A handle copied from a field of another resource
+    Alias,
+    /// A single asset file whose path is resolved by its key in [`RuntimePaths`]
+    RuntimePath,
+    /// A single asset file whose path is resolved by its key in an [`AssetManifest`] at compile time
+    ManifestKey,
+    /// A map of all dynamic assets whose keys start with a prefix
+    ///
+    /// The key of this binding is the prefix.
+    KeysIntoMap,
+    /// A field without asset attributes, initialized with [`FromWorld`](::bevy::ecs::world::FromWorld)
+    FromWorld,
+}
+
+/// Start loading the asset of a field with custom loader settings
+///
+/// Used by the derive macro for fields annotated with `settings`. The asset type is taken from the
+/// field, so the settings function only has to name the settings type of the loader. For labeled
+/// paths like `models/model.glb#Mesh0/Primitive0`, the settings are passed to the loader of the file.
+#[doc(hidden)]
+pub fn load_field_with_settings<C, A: Asset, S: Settings>(
+    asset_server: &AssetServer,
+    path: &str,
+    settings: impl Fn(&mut S) + Send + Sync + 'static,
+    _field: fn(&C) -> &Handle<A>,
+) -> UntypedHandle {
+    asset_server
+        .load_with_settings::<A, S>(path.to_owned(), settings)
+        .untyped()
+}
+
+/// Settings used while building asset collections
+///
+/// A [`LoadingState`](crate::loading_state::LoadingState) inserts this resource while creating its collections
+/// (see [`LoadingState::build_in_parallel`](crate::loading_state::LoadingState::build_in_parallel)).
+/// When creating collections without a loading state, the resource can be inserted manually.
+///
+/// Currently, the texture atlases of a collection are built in parallel if there are at least
+/// `parallel_threshold` of them. The atlases are added to their [`Assets`](::bevy::asset::Assets) serially afterwards.
+#[derive(Resource, Clone)]
+pub struct CollectionBuildSettings {
+    /// Minimum number of derived assets in a collection to build them in parallel
+    pub parallel_threshold: usize,
+    /// Task pool to build derived assets in
+    ///
+    /// The [`ComputeTaskPool`](::bevy::tasks::ComputeTaskPool) is used if this is `None`.
+    pub task_pool: Option<Arc<TaskPool>>,
+}
+
+impl Default for CollectionBuildSettings {
+    fn default() -> Self {
+        CollectionBuildSettings {
+            parallel_threshold: usize::MAX,
+            task_pool: None,
+        }
+    }
+}
+
+/// Byte budget for loading asset collections
+///
+/// While this resource exists, loading a collection only starts loading assets with a plain `path`
+/// as long as the sum of their estimated sizes stays within the budget. Assets that would exceed
+/// the budget are deferred: they are not awaited while loading and only start loading when the
+/// collection is created. Assets without an estimate count as zero bytes. Folders, file lists, dynamic
+/// assets and assets that need processing (images with samplers, texture atlases, standard materials)
+/// are always loaded.
+///
+/// Admitted assets stay resident and keep using the budget until all of their handles are dropped.
+/// [`AssetByteBudget::recount`] releases the bytes of assets that were unloaded since.
+///
+/// A [`LoadingState`](crate::loading_state::LoadingState) configured with
+/// [`LoadingState::with_byte_budget`](crate::loading_state::LoadingState::with_byte_budget)
+/// inserts this resource while starting to load its collections and reports deferred assets in
+/// [`DeferredAssets`](crate::loading_state::DeferredAssets).
+/// When loading collections without a loading state, the resource can be inserted manually.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct AssetByteBudget {
+    /// Maximum number of bytes to load
+    pub budget: u64,
+    /// Estimated number of bytes of all admitted assets that are still loaded
+    pub used: u64,
+    /// Estimated sizes in bytes per asset path
+    pub estimates: HashMap<String, u64>,
+    /// Paths of all assets that were deferred because they exceeded the budget
+    pub deferred: Vec<String>,
+    /// Paths of all admitted assets that are still loaded
+    ///
+    /// Collections sharing an asset path only use the budget once for it.
+    pub admitted: HashSet<String>,
+}
+
+impl AssetByteBudget {
+    /// Create a new budget of the given number of bytes
+    pub fn new(budget: u64) -> Self {
+        AssetByteBudget {
+            budget,
+            ..Default::default()
+        }
+    }
+
+    /// Set the estimated size of the asset at the given path
+    #[must_use]
+    pub fn with_estimate(mut self, path: impl Into<String>, bytes: u64) -> Self {
+        self.estimates.insert(path.into(), bytes);
+
+        self
+    }
+
+    /// Decide if the asset at the given path fits into the remaining budget
+    ///
+    /// Used by the derive macro. Admitted assets are added to the used bytes,
+    /// all others are recorded as deferred.
+    #[doc(hidden)]
+    pub fn admit(&mut self, path: &str) -> bool {
+        if self.admitted.contains(path) {
+            return true;
+        }
+        let estimate = self.estimates.get(path).copied().unwrap_or(0);
+        if self.used.saturating_add(estimate) > self.budget {
+            self.deferred.push(path.to_owned());
+            return false;
+        }
+        self.used += estimate;
+        self.admitted.insert(path.to_owned());
+
+        true
+    }
+
+    /// Count the used bytes again from the estimated assets that are still loaded
+    ///
+    /// An asset is loaded as long as any handle to it exists. This includes deferred assets
+    /// that started loading when their collection was created, so they use the budget from now on.
+    pub fn recount(&mut self, asset_server: &AssetServer) {
+        self.admitted = self
+            .estimates
+            .keys()
+            .filter(|path| asset_server.get_handle_untyped((*path).clone()).is_some())
+            .cloned()
+            .collect();
+        self.used = self
+            .admitted
+            .iter()
+            .map(|path| self.estimates[path])
+            .fold(0, u64::saturating_add);
+    }
+}
+
+/// Limit for the number of assets a loading state loads at the same time or starts per frame
+///
+/// While this resource exists, loading a collection queues assets with a plain `path` instead of
+/// loading them. The loading state starts queued assets whenever fewer than `max_concurrent` of
+/// its assets are still loading and fewer than `max_per_frame` loads were started this frame. See
+/// [`LoadingState::max_concurrent_loads`](crate::loading_state::LoadingState::max_concurrent_loads)
+/// and [`LoadingState::with_max_loads_per_frame`](crate::loading_state::LoadingState::with_max_loads_per_frame).
+///
+/// Loading handles are tracked by path. A queued path that is already loading for another collection
+/// shares the handle of that load and does not take another slot.
+#[derive(Resource, Clone, Debug)]
+#[doc(hidden)]
+pub struct AssetLoadThrottle {
+    pub(crate) max_concurrent: usize,
+    pub(crate) max_per_frame: usize,
+    pub(crate) started_this_frame: usize,
+    pub(crate) queued: Vec<String>,
+    pub(crate) in_flight: HashMap<String, UntypedHandle>,
+}
+
+impl AssetLoadThrottle {
+    pub(crate) fn new(max_concurrent: usize, max_per_frame: usize) -> Self {
+        AssetLoadThrottle {
+            max_concurrent: max_concurrent.max(1),
+            max_per_frame: max_per_frame.max(1),
+            started_this_frame: 0,
+            queued: vec![],
+            in_flight: HashMap::default(),
+        }
+    }
+
+    /// Queue the asset at the given path instead of loading it
+    ///
+    /// Used by the derive macro.
+    #[doc(hidden)]
+    pub fn queue(&mut self, path: &str) {
+        self.queued.push(path.to_owned());
+    }
+
+    /// Forget all handles that finished loading and return how many more loads can be started
+    pub(crate) fn free_slots(&mut self, asset_server: &AssetServer) -> usize {
+        self.in_flight.retain(|_, handle| {
+            !matches!(
+                asset_server.get_load_state(handle.id()),
+                Some(LoadState::Loaded | LoadState::Failed)
+            )
+        });
+
+        self.max_concurrent
+            .saturating_sub(self.in_flight.len())
+            .min(self.max_per_frame.saturating_sub(self.started_this_frame))
+    }
+
+    /// Allow starting `max_per_frame` more loads
+    pub(crate) fn next_frame(&mut self) {
+        self.started_this_frame = 0;
+    }
+}
+
+/// Assets of optional fields like `Option<Handle<T>>` that do not fail their collection
+///
+/// Used by the derive macro. Optional assets are awaited while loading a collection like any
+/// other asset, but count as done if they fail to load. Their fields are `None` in that case.
+///
+/// Entries are kept per collection, so an optional field does not make the same file optional for
+/// other collections loading it.
+///
+/// Assets of fields with `required_if` only count once the asset of the field they depend on finished
+/// loading. If it failed, they count as done and their fields are `None`. Otherwise they are required.
+#[derive(Resource, Debug, Default)]
+#[doc(hidden)]
+pub struct OptionalAssets {
+    handles: HashMap<(TypeId, &'static str), UntypedHandle>,
+    /// Handles of `required_if` fields with the handle of the asset deciding whether they are required
+    conditional: HashMap<(TypeId, &'static str, &'static str), (UntypedHandle, UntypedHandle)>,
+}
+
+impl OptionalAssets {
+    /// Start loading the asset of an optional field
+    #[doc(hidden)]
+    pub fn load<C: AssetCollection>(
+        &mut self,
+        asset_server: &AssetServer,
+        path: &'static str,
+    ) -> UntypedHandle {
+        let handle = asset_server.load_untyped(path).untyped();
+        self.handles
+            .insert((TypeId::of::<C>(), path), handle.clone());
+
+        handle
+    }
+
+    /// The handle of the asset at the given path, or `None` if it failed to load
+    #[doc(hidden)]
+    pub fn get<C: AssetCollection, A: Asset>(
+        world: &mut World,
+        path: &'static str,
+    ) -> Option<Handle<A>> {
+        let loading = world
+            .get_resource_mut::<OptionalAssets>()
+            .and_then(|mut optional| optional.handles.remove(&(TypeId::of::<C>(), path)));
+        let asset_server = world.resource::<AssetServer>();
+        if let Some(loading) = loading {
+            if asset_server.get_load_state(loading.id()) == Some(LoadState::Failed) {
+                return None;
+            }
+        }
+
+        Some(asset_server.load(path))
+    }
+
+    /// Start loading the asset of a field that is only required if the asset at `required_if` loads
+    #[doc(hidden)]
+    pub fn load_required_if<C: AssetCollection>(
+        &mut self,
+        asset_server: &AssetServer,
+        path: &'static str,
+        required_if: &'static str,
+    ) -> UntypedHandle {
+        let handle = asset_server.load_untyped(path).untyped();
+        let condition = asset_server.load_untyped(required_if).untyped();
+        self.conditional.insert(
+            (TypeId::of::<C>(), path, required_if),
+            (handle.clone(), condition),
+        );
+
+        handle
+    }
+
+    /// The handle of the asset at the given path, or `None` if the asset at `required_if` failed to load
+    #[doc(hidden)]
+    pub fn get_required_if<C: AssetCollection, A: Asset>(
+        world: &mut World,
+        path: &'static str,
+        required_if: &'static str,
+    ) -> Option<Handle<A>> {
+        let loading = world
+            .get_resource_mut::<OptionalAssets>()
+            .and_then(|mut optional| {
+                optional
+                    .conditional
+                    .remove(&(TypeId::of::<C>(), path, required_if))
+            });
+        let asset_server = world.resource::<AssetServer>();
+        if let Some((_, condition)) = loading {
+            if asset_server.get_load_state(condition.id()) == Some(LoadState::Failed) {
+                return None;
+            }
+        }
+
+        Some(asset_server.load(path))
+    }
+
+    /// Whether the given asset belongs to an optional field of the collection
+    pub(crate) fn contains(&self, collection: TypeId, id: UntypedAssetId) -> bool {
+        self.handles
+            .iter()
+            .any(|((owner, _), handle)| *owner == collection && handle.id() == id)
+    }
+
+    /// The asset deciding whether the given asset of a `required_if` field of the collection is required
+    fn condition(&self, collection: TypeId, id: UntypedAssetId) -> Option<&UntypedHandle> {
+        self.conditional
+            .iter()
+            .find(|((owner, _, _), (handle, _))| *owner == collection && handle.id() == id)
+            .map(|(_, (_, condition))| condition)
+    }
+}
+
+/// The load state of an asset of a collection, counting failed optional assets as loaded
+///
+/// With `track_dependencies`, assets are only loaded once their dependencies finished loading. Loaders of any
+/// asset type (e.g. from third-party plugins) can load dependencies, see [`LoadingState::track_dependencies`](crate::loading_state::LoadingState::track_dependencies).
+/// Assets of `required_if` fields are loading until the asset they depend on finished loading, and
+/// count as loaded if that asset failed.
+pub(crate) fn collection_load_state<C: AssetCollection>(
+    asset_server: &AssetServer,
+    optional: Option<&OptionalAssets>,
+    id: UntypedAssetId,
+    track_dependencies: bool,
+) -> Option<LoadState> {
+    if let Some(condition) = optional.and_then(|optional| optional.condition(TypeId::of::<C>(), id))
+    {
+        match asset_server.get_load_state(condition.id()) {
+            Some(LoadState::Failed) => return Some(LoadState::Loaded),
+            Some(LoadState::Loaded) => {}
+            _ => return Some(LoadState::Loading),
+        }
+    }
+    // an asset with a failed dependency failed to load as a whole
+    let state = match asset_server.get_load_state(id) {
+        Some(LoadState::Loaded) if track_dependencies => {
+            match asset_server.get_recursive_dependency_load_state(id) {
+                Some(RecursiveDependencyLoadState::Loaded) => Some(LoadState::Loaded),
+                Some(RecursiveDependencyLoadState::Failed) => Some(LoadState::Failed),
+                _ => Some(LoadState::Loading),
+            }
+        }
+        state => state,
+    };
+    match state {
+        Some(LoadState::Failed)
+            if optional.is_some_and(|optional| optional.contains(TypeId::of::<C>(), id)) =>
+        {
+            Some(LoadState::Loaded)
+        }
+        state => state,
+    }
+}
+
+/// Asset paths only known at run time, e.g. file arguments of a command line tool
+///
+/// Fields annotated with `#[asset(runtime_path = "input_model")]` load the path stored under their key.
+/// The resource has to be inserted before such a collection starts loading. In a loading state, a collection
+/// with a key that has no path fails to load and the key is reported in [`MissingRuntimePaths`].
+/// Initializing such a collection outside of a loading state panics.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy_asset_loader::asset_collection::RuntimePaths;
+/// # use bevy::prelude::*;
+/// # fn main() {
+///     let music = std::env::args().nth(1).unwrap_or("audio/background.ogg".to_owned());
+///     App::new().insert_resource(RuntimePaths::new().with_path("music", music));
+/// # }
+/// #[derive(AssetCollection, Resource)]
+/// struct Input {
+///     #[asset(runtime_path = "music")]
+///     music: Handle<AudioSource>,
+/// }
+/// ```
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct RuntimePaths {
+    paths: HashMap<String, String>,
+}
+
+impl RuntimePaths {
+    /// Create an empty set of runtime paths
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store the asset path for the given key
+    #[must_use]
+    pub fn with_path(mut self, key: impl Into<String>, path: impl Into<String>) -> Self {
+        self.insert(key, path);
+
+        self
+    }
+
+    /// Store the asset path for the given key and return the previous path, if any
+    pub fn insert(&mut self, key: impl Into<String>, path: impl Into<String>) -> Option<String> {
+        self.paths.insert(key.into(), path.into())
+    }
+
+    /// The asset path stored for the given key
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.paths.get(key).map(String::as_str)
+    }
+
+    /// The path of a field annotated with `runtime_path`
+    ///
+    /// Used by the derive macro. Returns `None` if the resource or the key is missing.
+    #[doc(hidden)]
+    pub fn resolve(runtime_paths: Option<&RuntimePaths>, key: &str) -> Option<String> {
+        runtime_paths?.get(key).map(str::to_owned)
+    }
+}
+
+/// A key of a field annotated with `runtime_path` that has no path in [`RuntimePaths`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingRuntimePath {
+    /// Type name of the asset collection
+    pub collection: &'static str,
+    /// Name of the field loading the runtime path
+    pub field: &'static str,
+    /// The key without a path
+    pub key: String,
+}
+
+/// Runtime paths that the asset collections of a loading state require, but that are not in [`RuntimePaths`]
+///
+/// A collection with a missing runtime path fails to load, and the loading state continues to its
+/// failure state (see [`LoadingState::on_failure_continue_to_state`](crate::loading_state::LoadingState::on_failure_continue_to_state)).
+/// The resource is cleared when a loading state is entered and keeps its content in the failure state.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct MissingRuntimePaths(pub Vec<MissingRuntimePath>);
+
+/// Report a key of a `runtime_path` field that has no path in [`RuntimePaths`]
+///
+/// Used by the derive macro while loading a collection. Without a [`MissingRuntimePaths`] resource,
+/// e.g. when initializing a collection outside a loading state, this panics.
+#[doc(hidden)]
+pub fn report_missing_runtime_path(
+    cell: &WorldCell,
+    collection: &'static str,
+    field: &'static str,
+    key: &str,
+) {
+    let Some(mut missing) = cell.get_resource_mut::<MissingRuntimePaths>() else {
+        panic!("The field '{field}' of the asset collection {collection} loads the runtime path '{key}', but the RuntimePaths resource has no path for it");
+    };
+    error!("The field '{field}' of the asset collection {collection} loads the runtime path '{key}', but the RuntimePaths resource has no path for it");
+    missing.0.push(MissingRuntimePath {
+        collection,
+        field,
+        key: key.to_owned(),
+    });
+}
+
+/// Asset paths generated at compile time, e.g. by a build script
+///
+/// A manifest is a list of `(key, path)` pairs. Fields annotated with `#[asset(manifest_key = "player")]`
+/// load the path stored under their key in a constant named `ASSET_MANIFEST`, which has to be in scope
+/// where the collection is defined. The key is resolved at compile time and a missing key is a compile error.
+///
+/// A build script can write the manifest to a file in `OUT_DIR`:
+/// ```rust ignore
+/// // build.rs
+/// let out_dir = std::env::var("OUT_DIR").unwrap();
+/// std::fs::write(
+///     std::path::Path::new(&out_dir).join("assets.rs"),
+///     r#"pub const ASSET_MANIFEST: AssetManifest = &[("player", "images/player.png")];"#,
+/// ).unwrap();
+/// ```
+/// The file is then included next to the collection:
+/// ```rust ignore
+/// use bevy_asset_loader::asset_collection::AssetManifest;
+///
+/// include!(concat!(env!("OUT_DIR"), "/assets.rs"));
+/// ```
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy_asset_loader::asset_collection::AssetManifest;
+/// # use bevy::prelude::*;
+/// const ASSET_MANIFEST: AssetManifest = &[("player", "images/player.png")];
+///
+/// #[derive(AssetCollection, Resource)]
+/// struct ImageAssets {
+///     #[asset(manifest_key = "player")]
+///     player: Handle<Image>,
+/// }
+/// ```
+pub type AssetManifest = &'static [(&'static str, &'static str)];
+
+/// The path of a field annotated with `manifest_key`
+///
+/// Used by the derive macro in a constant, so that a missing key fails compilation.
+#[doc(hidden)]
+pub const fn manifest_path(manifest: AssetManifest, key: &str) -> &'static str {
+    let mut index = 0;
+    while index < manifest.len() {
+        let (entry_key, path) = manifest[index];
+        if const_str_eq(entry_key, key) {
+            return path;
+        }
+        index += 1;
+    }
+    panic!("The asset manifest has no path for the key of a field annotated with `manifest_key`")
+}
+
+const fn const_str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < a.len() {
+        if a[index] != b[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// Selects the variant of an enum [`AssetCollection`] that is loaded
+///
+/// Deriving [`AssetCollection`] for an enum turns every variant into its own set of assets. The fields of
+/// a variant take the same attributes as the fields of a struct collection. Loading or creating the collection
+/// loads the variant selected by this resource and panics if the resource does not exist.
+///
+/// The derive macro generates a fieldless enum `<Collection>Variant` with the same visibility and variant names
+/// as the collection to select a variant.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy_asset_loader::asset_collection::CollectionVariant;
+/// # use bevy::prelude::*;
+/// #[derive(AssetCollection, Resource)]
+/// enum LevelAssets {
+///     Forest {
+///         #[asset(path = "audio/background.ogg")]
+///         music: Handle<AudioSource>,
+///     },
+///     Cave {
+///         #[asset(path = "audio/plop.ogg")]
+///         music: Handle<AudioSource>,
+///     },
+/// }
+///
+/// fn enter_cave(mut commands: Commands) {
+///     commands.insert_resource(CollectionVariant::<LevelAssets>::new(LevelAssetsVariant::Cave));
+/// }
+/// ```
+#[derive(Resource)]
+pub struct CollectionVariant<A: EnumCollection> {
+    variant: A::Variant,
+}
+
+impl<A: EnumCollection> CollectionVariant<A> {
+    /// Select the given variant
+    pub fn new(variant: A::Variant) -> Self {
+        CollectionVariant { variant }
+    }
+
+    /// The selected variant
+    pub fn variant(&self) -> A::Variant {
+        self.variant
+    }
+}
+
+/// An enum [`AssetCollection`] whose loaded variant is selected by a [`CollectionVariant`]
+///
+/// Implemented by the derive macro.
+pub trait EnumCollection: AssetCollection {
+    /// Fieldless enum with one variant per variant of the collection
+    type Variant: Copy + Eq + Debug + Send + Sync + 'static;
+}
+
+/// The variant of an enum collection selected by its [`CollectionVariant`]
+///
+/// Used by the derive macro.
+#[doc(hidden)]
+pub fn collection_variant<A: EnumCollection>(world: &World) -> A::Variant {
+    let Some(selected) = world.get_resource::<CollectionVariant<A>>() else {
+        panic!(
+            "Cannot load the asset collection {}, because no variant was selected. Insert the resource CollectionVariant::<{0}>",
+            type_name::<A>()
+        );
+    };
+    selected.variant
+}
+
+/// Grid layout of a texture atlas in an asset collection
+#[cfg(feature = "2d")]
+#[doc(hidden)]
+pub struct TextureAtlasGrid {
+    pub image: Handle<Image>,
+    pub tile_size: Vec2,
+    pub columns: usize,
+    pub rows: usize,
+    pub padding: Vec2,
+    pub offset: Vec2,
+    pub format: Option<TextureFormat>,
+    pub sampler: Option<ImageSampler>,
+}
+
+#[cfg(feature = "2d")]
+impl TextureAtlasGrid {
+    fn build(self) -> TextureAtlas {
+        TextureAtlas::from_grid(
+            self.image,
+            self.tile_size,
+            self.columns,
+            self.rows,
+            Some(self.padding),
+            Some(self.offset),
+        )
+    }
+}
+
+/// Build texture atlases for an asset collection respecting [`CollectionBuildSettings`]
+///
+/// Used by the derive macro. The returned handles are in the same order as the given grids.
+#[cfg(feature = "2d")]
+#[doc(hidden)]
+pub fn build_texture_atlases(
+    world: &mut World,
+    grids: Vec<TextureAtlasGrid>,
+) -> Vec<Handle<TextureAtlas>> {
+    let grids = prepare_atlas_images(world, grids);
+    let settings = world
+        .get_resource::<CollectionBuildSettings>()
+        .cloned()
+        .unwrap_or_default();
+    let atlases: Vec<TextureAtlas> = if grids.len() >= settings.parallel_threshold {
+        let task_pool = match settings.task_pool.as_deref() {
+            Some(task_pool) => task_pool,
+            None => ComputeTaskPool::get_or_init(TaskPool::default),
+        };
+        task_pool.scope(|scope| {
+            for grid in grids {
+                scope.spawn(async move { grid.build() });
+            }
+        })
+    } else {
+        grids.into_iter().map(TextureAtlasGrid::build).collect()
+    };
+
+    let mut assets = world
+        .get_resource_mut::<Assets<TextureAtlas>>()
+        .expect("Cannot get resource Assets<TextureAtlas>");
+    atlases.into_iter().map(|atlas| assets.add(atlas)).collect()
+}
+
+/// Replace the images of grids with a configured format by converted copies and apply configured samplers
+#[cfg(feature = "2d")]
+fn prepare_atlas_images(world: &mut World, grids: Vec<TextureAtlasGrid>) -> Vec<TextureAtlasGrid> {
+    let mut images = world
+        .get_resource_mut::<Assets<Image>>()
+        .expect("Cannot get resource Assets<Image>");
+    grids
+        .into_iter()
+        .map(|mut grid| {
+            if let Some(sampler) = grid.sampler.take() {
+                grid.image = apply_sampler(&mut images, grid.image, sampler);
+            }
+            let Some(format) = grid.format else {
+                return grid;
+            };
+            let image = images
+                .get(&grid.image)
+                .expect("Texture atlas image should be loaded");
+            if image.texture_descriptor.format != format {
+                let converted = convert_image(image, format).unwrap_or_else(|| {
+                    panic!(
+                        "Failed to convert texture atlas image {:?} from {:?} to {:?}",
+                        grid.image.path(),
+                        image.texture_descriptor.format,
+                        format
+                    )
+                });
+                grid.image = images.add(converted);
+            }
+            grid
+        })
+        .collect()
+}
+
+/// Set the sampler of an image
+///
+/// If the image already has a different custom sampler, a copy with the new sampler is added instead.
+#[cfg(feature = "2d")]
+fn apply_sampler(
+    images: &mut Assets<Image>,
+    handle: Handle<Image>,
+    sampler: ImageSampler,
+) -> Handle<Image> {
+    let image = images
+        .get_mut(&handle)
+        .expect("Texture atlas image should be loaded");
+    let is_different_sampler = match (&image.sampler, &sampler) {
+        (ImageSampler::Descriptor(current), ImageSampler::Descriptor(new)) => {
+            current.as_wgpu() != new.as_wgpu()
+        }
+        (ImageSampler::Descriptor(_), ImageSampler::Default) => true,
+        (ImageSampler::Default, _) => false,
+    };
+    if is_different_sampler {
+        let mut cloned_image = image.clone();
+        cloned_image.sampler = sampler;
+        images.add(cloned_image)
+    } else {
+        image.sampler = sampler;
+        handle
+    }
+}
+
+/// Convert an image to one of the uncompressed 8 bit formats
+///
+/// [`Image::convert`] cannot be used, since it always creates RGBA images.
+#[cfg(feature = "2d")]
+fn convert_image(image: &Image, format: TextureFormat) -> Option<Image> {
+    let dynamic_image = image.clone().try_into_dynamic().ok()?;
+    let data = match format {
+        TextureFormat::R8Unorm => dynamic_image.into_luma8().into_raw(),
+        TextureFormat::Rg8Unorm => dynamic_image.into_luma_alpha8().into_raw(),
+        TextureFormat::Rgba8Unorm | TextureFormat::Rgba8UnormSrgb => {
+            dynamic_image.into_rgba8().into_raw()
+        }
+        _ => return None,
+    };
+    let mut converted = Image::new(
+        image.texture_descriptor.size,
+        TextureDimension::D2,
+        data,
+        format,
+    );
+    converted.sampler = image.sampler.clone();
+
+    Some(converted)
+}
+
+/// Replace an image larger than `max_size` in width or height by a downscaled copy
+///
+/// Used by the derive macro. The aspect ratio is kept and the longer side is scaled to `max_size`.
+/// Pixels are averaged over the covered area. Images in formats that cannot be converted
+/// (e.g. compressed textures) are kept as they are.
+#[cfg(any(feature = "2d", feature = "3d"))]
+#[doc(hidden)]
+pub fn limit_image_size(
+    images: &mut Assets<Image>,
+    handle: Handle<Image>,
+    max_size: u32,
+) -> Handle<Image> {
+    let image = images.get(&handle).expect(
+        "Only asset collection fields holding an `Image` handle can be annotated with `image`",
+    );
+    if image.width() <= max_size && image.height() <= max_size {
+        return handle;
+    }
+    let format = image.texture_descriptor.format;
+    let Ok(dynamic_image) = image.clone().try_into_dynamic() else {
+        warn!(
+            "Cannot downscale the image {:?} with the format {:?}",
+            handle.path(),
+            format
+        );
+        return handle;
+    };
+    let mut downscaled = Image::from_dynamic(
+        dynamic_image.thumbnail(max_size, max_size),
+        format.is_srgb(),
+    );
+    downscaled.sampler = image.sampler.clone();
+
+    images.add(downscaled)
 }
 
 /// Extension trait for [`App`](::bevy::app::App) enabling initialisation of [asset collections](crate::asset_collection::AssetCollection)
@@ -34,7 +1159,139 @@ pub trait AssetCollectionApp {
     /// This function does not give any guaranties about the loading status of the asset handles.
     /// If you want to use a loading state, you do not need this function! Instead use an [`LoadingState`](crate::loading_state::LoadingState)
     /// and add collections to it to be prepared during the loading state.
+    ///
+    /// With the `checksums` feature, files with a `sha256` checksum are verified in the background.
+    /// Mismatches are logged as errors and sent as [`AssetLoadFailed`](crate::loading_state::AssetLoadFailed)
+    /// events if the app has them. The collection resource is inserted either way.
     fn init_collection<A: AssetCollection>(&mut self) -> &mut Self;
+
+    /// Load an [`AssetCollection`](crate::asset_collection::AssetCollection) before the first frame
+    ///
+    /// The collection is loaded in [`PreStartup`](::bevy::app::PreStartup). The app blocks until all assets
+    /// of the collection are loaded and the collection is inserted as a resource. This guarantees
+    /// that the assets (e.g. a font for a loading screen) are available in the very first frame.
+    ///
+    /// Nothing else happens while the collection is loading, not even rendering a window.
+    /// Only use this for a small number of small, critical assets and load everything else
+    /// in a [`LoadingState`](crate::loading_state::LoadingState). Dynamic assets used by
+    /// the collection have to be registered before startup.
+    /// Loading fails with a panic if any asset of the collection fails to load or does not match its
+    /// `sha256` checksum, or if the collection is not loaded within 30 seconds. Use [`AssetCollectionApp::init_collection_blocking_with_timeout`]
+    /// to configure the timeout.
+    ///
+    /// This function is not available on wasm, since blocking the main thread would keep assets from loading.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn init_collection_blocking<A: AssetCollection>(&mut self) -> &mut Self;
+
+    /// Load an [`AssetCollection`](crate::asset_collection::AssetCollection) before the first frame
+    /// and panic if it is not loaded within the given time
+    ///
+    /// See [`AssetCollectionApp::init_collection_blocking`].
+    #[cfg(not(target_arch = "wasm32"))]
+    fn init_collection_blocking_with_timeout<A: AssetCollection>(
+        &mut self,
+        timeout: Duration,
+    ) -> &mut Self;
+
+    /// Remove an [`AssetCollection`](crate::asset_collection::AssetCollection) resource the given duration after it was inserted
+    ///
+    /// Removing the resource drops all handles of the collection. Assets without any other strong
+    /// handles are then unloaded. This is meant for short-lived assets like a one-shot cutscene.
+    /// If the collection is inserted again (e.g. by re-entering a loading state), the timer restarts.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # use std::time::Duration;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Cutscene)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, CutsceneAssets>(GameState::Loading)
+    ///         .release_collection_after::<CutsceneAssets>(Duration::from_secs(30))
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Cutscene
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct CutsceneAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    fn release_collection_after<A: AssetCollection>(&mut self, duration: Duration) -> &mut Self;
+
+    /// Remove an [`AssetCollection`](crate::asset_collection::AssetCollection) resource when leaving the given state
+    ///
+    /// Removing the resource drops all handles of the collection. Assets without any other strong
+    /// handles are then unloaded. Handles cloned from the collection, e.g. in components, keep their assets alive.
+    /// A collection can be unloaded on exit of multiple states. It is kept when transitioning between
+    /// any of these states, so a collection shared by e.g. `InGame` and `Paused` stays loaded while switching.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::InGame)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, LevelAssets>(GameState::Loading)
+    ///         .unload_on_exit::<LevelAssets>(GameState::InGame)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     InGame
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct LevelAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    fn unload_on_exit<A: AssetCollection>(&mut self, state: impl States) -> &mut Self;
+
+    /// Add a [`CollectionLoader`](crate::collection_loader::CollectionLoader) resource to load collections from async code
+    ///
+    /// The loader resolves its futures in the [`Update`](::bevy::app::Update) schedule.
+    fn add_collection_loader(&mut self) -> &mut Self;
+
+    /// Load a folder and insert its handles into a map over several frames
+    ///
+    /// Building the map of a huge folder at once can stall a frame. Instead, the map in
+    /// [`StreamedFolders`](crate::streamed_folder::StreamedFolders) receives at most `entries_per_frame`
+    /// entries per frame once the folder is loaded. Entries are keyed by their full asset path like
+    /// in mapped folder fields with `collection(mapped(full_path))` and can be used before the map is complete.
+    fn stream_mapped_folder(&mut self, path: &str, entries_per_frame: usize) -> &mut Self;
+
+    /// Register an [`AssetCollection`](crate::asset_collection::AssetCollection) that is expected to be loaded
+    ///
+    /// On startup, a warning is logged for every registered collection that is neither added to an
+    /// existing loading state nor initialized. This helps to catch collections that silently never load.
+    /// Collections added to a loading state are registered automatically, so this is only needed for
+    /// collections that are meant to be initialized.
+    /// See [`CollectionRegistry`](crate::collection_registry::CollectionRegistry).
+    #[cfg_attr(docsrs, doc(cfg(feature = "collection_diagnostics")))]
+    #[cfg(feature = "collection_diagnostics")]
+    fn register_collection<A: AssetCollection>(&mut self) -> &mut Self;
 }
 
 impl AssetCollectionApp for App {
@@ -42,18 +1299,289 @@ impl AssetCollectionApp for App {
     where
         Collection: AssetCollection,
     {
+        #[cfg(feature = "collection_diagnostics")]
+        crate::collection_registry::mark_collection_loaded::<Collection>(self);
+        init_placeholders(self);
+        #[cfg(feature = "2d")]
+        register_atlas_definition_loader(self);
+        #[cfg(feature = "texture_packer")]
+        crate::texture_packer::register_texture_packer_loader(self);
+        #[cfg(feature = "audio")]
+        crate::audio::register_audio_durations(self);
         if !self.world.contains_resource::<Collection>() {
             // This resource is required for loading a collection
             // Since bevy_asset_loader does not have a "real" Plugin,
             // we need to make sure the resource exists here
             self.init_resource::<DynamicAssets>();
+            #[cfg(feature = "checksums")]
+            self.init_resource::<crate::checksum::PendingChecksums>();
             // make sure the assets start to load
-            let _ = Collection::load(&mut self.world);
+            let _handles = Collection::load(&mut self.world);
+            #[cfg(feature = "checksums")]
+            if let Some(pending) = self
+                .world
+                .remove_resource::<crate::checksum::PendingChecksums>()
+            {
+                crate::checksum::verify_initialized_collection::<Collection>(self, pending);
+            }
+            #[cfg(feature = "collection_diagnostics")]
+            crate::collection_registry::record_load_requests::<Collection>(
+                &mut self.world,
+                &_handles,
+            );
             let resource = Collection::create(&mut self.world);
             self.insert_resource(resource);
         }
         self
     }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn init_collection_blocking<Collection>(&mut self) -> &mut Self
+    where
+        Collection: AssetCollection,
+    {
+        self.init_collection_blocking_with_timeout::<Collection>(BLOCKING_LOAD_TIMEOUT)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn init_collection_blocking_with_timeout<Collection>(&mut self, timeout: Duration) -> &mut Self
+    where
+        Collection: AssetCollection,
+    {
+        #[cfg(feature = "collection_diagnostics")]
+        crate::collection_registry::mark_collection_loaded::<Collection>(self);
+        init_placeholders(self);
+        #[cfg(feature = "2d")]
+        register_atlas_definition_loader(self);
+        #[cfg(feature = "texture_packer")]
+        crate::texture_packer::register_texture_packer_loader(self);
+        #[cfg(feature = "audio")]
+        crate::audio::register_audio_durations(self);
+        self.add_systems(PreStartup, move |world: &mut World| {
+            load_collection_blocking::<Collection>(world, timeout)
+        })
+    }
+
+    fn release_collection_after<Collection>(&mut self, duration: Duration) -> &mut Self
+    where
+        Collection: AssetCollection,
+    {
+        self.insert_resource(ReleaseCollection::<Collection> {
+            duration,
+            timer: None,
+            _marker: PhantomData,
+        })
+        .add_systems(Update, release_collection::<Collection>)
+    }
+
+    fn unload_on_exit<Collection>(&mut self, state: impl States) -> &mut Self
+    where
+        Collection: AssetCollection,
+    {
+        add_unload_on_exit::<Collection, _>(self, state);
+        self
+    }
+
+    fn add_collection_loader(&mut self) -> &mut Self {
+        if self.world.contains_resource::<CollectionLoader>() {
+            return self;
+        }
+        init_placeholders(self);
+        self.init_resource::<CollectionLoader>()
+            .add_systems(Update, update_collection_requests)
+    }
+
+    fn stream_mapped_folder(&mut self, path: &str, entries_per_frame: usize) -> &mut Self {
+        if !self.world.contains_resource::<StreamedFolders>() {
+            self.init_resource::<StreamedFolders>()
+                .add_systems(Update, stream_mapped_folders);
+        }
+        self.world
+            .resource_mut::<StreamedFolders>()
+            .add(path, entries_per_frame);
+        self
+    }
+
+    #[cfg(feature = "collection_diagnostics")]
+    fn register_collection<Collection>(&mut self) -> &mut Self
+    where
+        Collection: AssetCollection,
+    {
+        crate::collection_registry::register_collection::<Collection>(self);
+        self
+    }
+}
+
+#[derive(Resource)]
+struct ReleaseCollection<Collection> {
+    duration: Duration,
+    timer: Option<Timer>,
+    _marker: PhantomData<Collection>,
+}
+
+fn release_collection<Collection: AssetCollection>(
+    mut commands: Commands,
+    time: Res<Time>,
+    collection: Option<Res<Collection>>,
+    mut release: ResMut<ReleaseCollection<Collection>>,
+) {
+    let Some(collection) = collection else {
+        release.timer = None;
+        return;
+    };
+    if collection.is_added() || release.timer.is_none() {
+        release.timer = Some(Timer::new(release.duration, TimerMode::Once));
+    }
+    let timer = release.timer.as_mut().unwrap();
+    if timer.tick(time.delta()).finished() {
+        commands.remove_resource::<Collection>();
+        release.timer = None;
+    }
+}
+
+/// The states a collection is unloaded on exit of, see [`AssetCollectionApp::unload_on_exit`]
+#[derive(Resource)]
+struct UnloadOnExit<S: States, Collection> {
+    states: HashSet<S>,
+    _marker: PhantomData<Collection>,
+}
+
+fn add_unload_on_exit<Collection: AssetCollection, S: States>(app: &mut App, state: S) {
+    let mut unload = app
+        .world
+        .get_resource_or_insert_with(|| UnloadOnExit::<S, Collection> {
+            states: HashSet::default(),
+            _marker: PhantomData,
+        });
+    // registering the same state again would only add a second system
+    if unload.states.insert(state.clone()) {
+        app.add_systems(OnExit(state), unload_collection::<S, Collection>);
+    }
+}
+
+fn unload_collection<S: States, Collection: AssetCollection>(
+    mut commands: Commands,
+    state: Res<State<S>>,
+    unload: Res<UnloadOnExit<S, Collection>>,
+) {
+    // the state is already set to the entered state while running `OnExit`
+    if unload.states.contains(state.get()) {
+        return;
+    }
+    commands.remove_resource::<Collection>();
+}
+
+/// Time [`AssetCollectionApp::init_collection_blocking`] waits for a collection to load
+#[cfg(not(target_arch = "wasm32"))]
+const BLOCKING_LOAD_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Time [`AssetCollectionApp::init_collection_blocking`] sleeps between polling pending assets
+///
+/// Keeps the main thread from spinning on a core the asset loading tasks need.
+#[cfg(not(target_arch = "wasm32"))]
+const BLOCKING_POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+#[cfg(not(target_arch = "wasm32"))]
+fn load_collection_blocking<Collection: AssetCollection>(world: &mut World, timeout: Duration) {
+    if world.contains_resource::<Collection>() {
+        return;
+    }
+    world.init_resource::<DynamicAssets>();
+    #[cfg(feature = "checksums")]
+    world.init_resource::<crate::checksum::PendingChecksums>();
+    let handles = Collection::load(world);
+    #[cfg(feature = "collection_diagnostics")]
+    crate::collection_registry::record_load_requests::<Collection>(world, &handles);
+    #[cfg(feature = "checksums")]
+    let mut checksums = crate::checksum::LoadingChecksums::<Collection>::new(
+        world
+            .remove_resource::<crate::checksum::PendingChecksums>()
+            .unwrap_or_default(),
+    );
+    let start = Instant::now();
+    loop {
+        // Without bevy's multi-threaded feature, tasks (like loading assets) only make progress
+        // if the task pools are ticked on the main thread
+        tick_global_task_pools_on_main_thread();
+        // Loaded assets are only added to their `Assets` resources by this system
+        handle_internal_asset_events(world);
+        let asset_server = world.resource::<AssetServer>();
+        let optional = world.get_resource::<OptionalAssets>();
+        let load_states: Vec<Option<LoadState>> = handles
+            .iter()
+            .map(|handle| {
+                collection_load_state::<Collection>(asset_server, optional, handle.id(), false)
+            })
+            .collect();
+        if load_states.contains(&Some(LoadState::Failed)) {
+            panic!(
+                "Failed to load the asset collection {} before startup",
+                type_name::<Collection>()
+            );
+        }
+        #[cfg(feature = "checksums")]
+        if let Some(path) = checksums.poll_mismatches().first() {
+            panic!(
+                "The checksum of '{path}' in the asset collection {} does not match the expected SHA-256 digest",
+                type_name::<Collection>()
+            );
+        }
+        #[cfg(feature = "checksums")]
+        let verified = checksums.pending().next().is_none();
+        #[cfg(not(feature = "checksums"))]
+        let verified = true;
+        if verified
+            && load_states
+                .iter()
+                .all(|state| state == &Some(LoadState::Loaded))
+        {
+            break;
+        }
+        if start.elapsed() > timeout {
+            #[allow(unused_mut)]
+            let mut pending: Vec<_> = handles
+                .iter()
+                .zip(&load_states)
+                .filter(|(_, state)| *state != &Some(LoadState::Loaded))
+                .map(|(handle, _)| {
+                    handle
+                        .path()
+                        .map_or_else(|| format!("{:?}", handle.id()), ToString::to_string)
+                })
+                .collect();
+            #[cfg(feature = "checksums")]
+            pending.extend(
+                checksums
+                    .pending()
+                    .map(|path| format!("checksum of {path}")),
+            );
+            panic!(
+                "The asset collection {} did not finish loading within {:?} before startup. Pending assets: {}",
+                type_name::<Collection>(),
+                timeout,
+                pending.join(", ")
+            );
+        }
+        std::thread::sleep(BLOCKING_POLL_INTERVAL);
+    }
+    let collection = Collection::create(world);
+    world.insert_resource(collection);
+    #[cfg(feature = "audio")]
+    while world
+        .get_resource::<crate::audio::AudioDurations>()
+        .is_some_and(|durations| !durations.is_done())
+    {
+        if start.elapsed() > timeout {
+            panic!(
+                "The audio durations of the asset collection {} were not measured within {:?} before startup",
+                type_name::<Collection>(),
+                timeout
+            );
+        }
+        tick_global_task_pools_on_main_thread();
+        crate::audio::measure_audio_durations(world);
+        std::thread::sleep(BLOCKING_POLL_INTERVAL);
+    }
 }
 
 /// Extension trait for [`World`](::bevy::ecs::world::World) enabling initialisation of [asset collections](AssetCollection)
@@ -62,6 +1590,7 @@ pub trait AssetCollectionWorld {
     ///
     /// This function does not give any guaranties about the loading status of the asset handles.
     /// If you want such guaranties, use a [`LoadingState`](crate::loading_state::LoadingState).
+    /// The collection is created without loading it first, so `sha256` checksums are not verified.
     fn init_collection<A: AssetCollection>(&mut self);
 }
 
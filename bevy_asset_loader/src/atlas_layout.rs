@@ -0,0 +1,199 @@
+use bevy::app::{App, Plugin};
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetApp, AssetLoader, AsyncReadExt, BoxedFuture, Handle, LoadContext};
+use bevy::math::{Rect, Vec2};
+use bevy::render::texture::Image;
+use bevy::sprite::TextureAtlas;
+use serde::Deserialize;
+
+/// Texture atlas defined in a RON file with the extension `.atlas.ron`
+///
+/// Asset collection fields of type `Handle<TextureAtlas>` can load these files with a plain path attribute,
+/// e.g. `#[asset(path = "hero.atlas.ron")]`. The image of the atlas is loaded from its own path as a dependency
+/// of the definition, so it is shared with other users of the image. Loading states wait for the image
+/// together with the definition.
+/// ```ron
+/// (
+///     image: "images/female_adventurer_sheet.png",
+///     layout: Grid(
+///         tile_size: (96., 99.),
+///         columns: 8,
+///         rows: 1,
+///         // optional
+///         padding: Some((0., 0.)),
+///         // optional
+///         offset: None,
+///     ),
+/// )
+/// ```
+/// Instead of a grid, the layout can list the rectangles of all sprites in the image:
+/// ```ron
+/// (
+///     image: "images/female_adventurer_sheet.png",
+///     layout: Rects(
+///         size: (768., 99.),
+///         rects: [(min: (0., 0.), max: (96., 99.)), (min: (96., 0.), max: (192., 99.))],
+///     ),
+/// )
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextureAtlasDefinition {
+    /// Asset path of the sprite sheet image
+    pub image: String,
+    /// Position of the sprites in the image
+    pub layout: AtlasLayout,
+}
+
+/// Position of the sprites in the image of a [`TextureAtlasDefinition`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum AtlasLayout {
+    /// Sprites in a grid of equally sized tiles
+    ///
+    /// See [`TextureAtlas::from_grid`].
+    Grid {
+        /// Size of a single tile in pixels
+        tile_size: Vec2,
+        /// Number of columns in the grid
+        columns: usize,
+        /// Number of rows in the grid
+        rows: usize,
+        /// Padding between tiles in pixels
+        padding: Option<Vec2>,
+        /// Offset of the grid from the top left corner of the image in pixels
+        offset: Option<Vec2>,
+    },
+    /// Sprites at explicit areas of the image
+    Rects {
+        /// Size of the image in pixels
+        size: Vec2,
+        /// Area of every sprite in the image
+        rects: Vec<Rect>,
+    },
+}
+
+impl TextureAtlasDefinition {
+    /// Parse an atlas definition from RON
+    pub fn from_ron(bytes: &[u8]) -> Result<Self, ron::error::SpannedError> {
+        let ron: TextureAtlasRon = ron::de::from_bytes(bytes)?;
+        let layout = match ron.layout {
+            AtlasLayoutRon::Grid {
+                tile_size,
+                columns,
+                rows,
+                padding,
+                offset,
+            } => AtlasLayout::Grid {
+                tile_size: tile_size.into(),
+                columns,
+                rows,
+                padding: padding.map(Vec2::from),
+                offset: offset.map(Vec2::from),
+            },
+            AtlasLayoutRon::Rects { size, rects } => AtlasLayout::Rects {
+                size: size.into(),
+                rects: rects
+                    .into_iter()
+                    .map(|rect| Rect::from_corners(rect.min.into(), rect.max.into()))
+                    .collect(),
+            },
+        };
+
+        Ok(TextureAtlasDefinition {
+            image: ron.image,
+            layout,
+        })
+    }
+
+    /// Build the texture atlas for the given image
+    pub fn texture_atlas(&self, image: Handle<Image>) -> TextureAtlas {
+        match &self.layout {
+            AtlasLayout::Grid {
+                tile_size,
+                columns,
+                rows,
+                padding,
+                offset,
+            } => TextureAtlas::from_grid(image, *tile_size, *columns, *rows, *padding, *offset),
+            AtlasLayout::Rects { size, rects } => {
+                let mut atlas = TextureAtlas::new_empty(image, *size);
+                for rect in rects {
+                    atlas.add_texture(*rect);
+                }
+
+                atlas
+            }
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct TextureAtlasRon {
+    image: String,
+    layout: AtlasLayoutRon,
+}
+
+#[derive(Deserialize)]
+enum AtlasLayoutRon {
+    Grid {
+        tile_size: [f32; 2],
+        columns: usize,
+        rows: usize,
+        #[serde(default)]
+        padding: Option<[f32; 2]>,
+        #[serde(default)]
+        offset: Option<[f32; 2]>,
+    },
+    Rects {
+        size: [f32; 2],
+        rects: Vec<RectRon>,
+    },
+}
+
+#[derive(Deserialize)]
+struct RectRon {
+    min: [f32; 2],
+    max: [f32; 2],
+}
+
+#[derive(Default)]
+struct TextureAtlasDefinitionLoader;
+
+impl AssetLoader for TextureAtlasDefinitionLoader {
+    type Asset = TextureAtlas;
+    type Settings = ();
+    type Error = anyhow::Error;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let definition = TextureAtlasDefinition::from_ron(&bytes)?;
+            let image: Handle<Image> = load_context.load(definition.image.clone());
+            Ok(definition.texture_atlas(image))
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["atlas.ron"]
+    }
+}
+
+struct TextureAtlasDefinitionPlugin;
+
+impl Plugin for TextureAtlasDefinitionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset_loader::<TextureAtlasDefinitionLoader>();
+    }
+}
+
+pub(crate) fn register_atlas_definition_loader(app: &mut App) {
+    if app.is_plugin_added::<TextureAtlasDefinitionPlugin>() {
+        return;
+    }
+    app.add_plugins(TextureAtlasDefinitionPlugin);
+}
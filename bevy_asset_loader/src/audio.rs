@@ -0,0 +1,151 @@
+use crate::asset_collection::CollectionHandles;
+use crate::io_task::IoTask;
+use bevy::app::{App, Update};
+use bevy::asset::{AssetServer, Assets, Handle, LoadState, UntypedHandle};
+use bevy::audio::AudioSource;
+use bevy::ecs::system::Resource;
+use bevy::ecs::world::World;
+use bevy::tasks::AsyncComputeTaskPool;
+use rodio::{Decoder, Source};
+use std::io::Cursor;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+/// An audio asset together with its duration
+///
+/// Asset collection fields annotated with `duration` hold this type.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy_asset_loader::audio::AudioWithDuration;
+/// # use bevy::prelude::*;
+/// #[derive(AssetCollection, Resource)]
+/// struct MusicAssets {
+///     #[asset(path = "audio/background.ogg", duration)]
+///     background: AudioWithDuration,
+/// }
+/// ```
+/// The duration is measured in a task on the [`AsyncComputeTaskPool`] after the collection is created.
+/// If the format does not report the length of the audio, the whole source is decoded once to count its samples.
+/// Loading states and [`AssetCollectionApp::init_collection_blocking`](crate::asset_collection::AssetCollectionApp::init_collection_blocking)
+/// wait for the measurement, so the duration is known once they are done.
+#[derive(Clone, Debug, Default)]
+pub struct AudioWithDuration {
+    /// Handle of the audio asset
+    pub handle: Handle<AudioSource>,
+    duration: Arc<OnceLock<Option<Duration>>>,
+}
+
+impl AudioWithDuration {
+    /// Start measuring the duration of the given audio
+    ///
+    /// Used by the derive macro.
+    #[doc(hidden)]
+    pub fn new(world: &mut World, handle: Handle<AudioSource>) -> Self {
+        let duration: Arc<OnceLock<Option<Duration>>> = Arc::default();
+        if let Some(mut durations) = world.get_resource_mut::<AudioDurations>() {
+            durations.pending.push(PendingDuration {
+                handle: handle.clone(),
+                duration: duration.clone(),
+                task: None,
+            });
+        }
+
+        AudioWithDuration { handle, duration }
+    }
+
+    /// Duration of the audio
+    ///
+    /// `None` if the source could not be decoded or the duration is not measured yet.
+    pub fn duration(&self) -> Option<Duration> {
+        self.duration.get().copied().flatten()
+    }
+
+    /// Whether measuring the duration finished
+    ///
+    /// Collections initialised through [`AssetCollectionWorld::init_collection`](crate::asset_collection::AssetCollectionWorld::init_collection)
+    /// never measure their durations.
+    pub fn is_measured(&self) -> bool {
+        self.duration.get().is_some()
+    }
+}
+
+impl CollectionHandles for AudioWithDuration {
+    fn extend_handles(&self, handles: &mut Vec<UntypedHandle>) {
+        handles.push(self.handle.clone().untyped());
+    }
+}
+
+/// Durations of [`AudioWithDuration`] fields that are still being measured
+#[derive(Resource, Default)]
+pub(crate) struct AudioDurations {
+    pending: Vec<PendingDuration>,
+}
+
+struct PendingDuration {
+    handle: Handle<AudioSource>,
+    duration: Arc<OnceLock<Option<Duration>>>,
+    task: Option<IoTask<Option<Duration>>>,
+}
+
+impl AudioDurations {
+    pub(crate) fn is_done(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+pub(crate) fn register_audio_durations(app: &mut App) {
+    if app.world.contains_resource::<AudioDurations>() {
+        return;
+    }
+    app.init_resource::<AudioDurations>()
+        .add_systems(Update, measure_audio_durations);
+}
+
+/// Start measuring loaded audio sources and store finished measurements
+pub(crate) fn measure_audio_durations(world: &mut World) {
+    // apps without Bevy's `AudioPlugin` load no audio to measure
+    if !world.contains_resource::<Assets<AudioSource>>() {
+        return;
+    }
+    let Some(mut durations) = world.remove_resource::<AudioDurations>() else {
+        return;
+    };
+    let sources = world.resource::<Assets<AudioSource>>();
+    let asset_server = world.resource::<AssetServer>();
+    durations.pending.retain_mut(|pending| {
+        if let Some(task) = &pending.task {
+            let Some(duration) = task.poll() else {
+                return true;
+            };
+            let _ = pending.duration.set(duration);
+            return false;
+        }
+        if let Some(source) = sources.get(&pending.handle) {
+            let bytes = source.bytes.clone();
+            pending.task = Some(IoTask::spawn_on(AsyncComputeTaskPool::get(), async move {
+                audio_duration(&bytes)
+            }));
+        } else if asset_server.get_load_state(&pending.handle) == Some(LoadState::Failed) {
+            let _ = pending.duration.set(None);
+            return false;
+        }
+
+        true
+    });
+    world.insert_resource(durations);
+}
+
+fn audio_duration(bytes: &Arc<[u8]>) -> Option<Duration> {
+    let decoder = Decoder::new(Cursor::new(bytes.clone())).ok()?;
+    if let Some(duration) = decoder.total_duration() {
+        return Some(duration);
+    }
+    let sample_rate = u64::from(decoder.sample_rate());
+    let channels = u64::from(decoder.channels());
+    if sample_rate == 0 || channels == 0 {
+        return None;
+    }
+    let frames = decoder.count() as u64 / channels;
+
+    Some(Duration::from_secs_f64(frames as f64 / sample_rate as f64))
+}
@@ -0,0 +1,284 @@
+use crate::asset_collection::AssetCollection;
+use crate::io_task::IoTask;
+use crate::loading_state::AssetLoadFailed;
+use bevy::app::{App, Update};
+use bevy::asset::{AssetPath, AssetServer, AsyncReadExt};
+use bevy::ecs::event::Events;
+use bevy::ecs::system::{Commands, ResMut, Resource};
+use bevy::log::error;
+use sha2::{Digest, Sha256};
+use std::any::type_name;
+use std::marker::PhantomData;
+
+/// Checksums requested by a collection that is starting to load
+///
+/// Used by the derive macro for fields annotated with `sha256`. A [`LoadingState`](crate::loading_state::LoadingState),
+/// [`AssetCollectionApp::init_collection`](crate::asset_collection::AssetCollectionApp::init_collection) and
+/// [`AssetCollectionApp::init_collection_blocking`](crate::asset_collection::AssetCollectionApp::init_collection_blocking)
+/// insert this resource while starting to load a collection. Without the resource, checksums are not verified.
+#[doc(hidden)]
+#[derive(Resource, Default)]
+pub struct PendingChecksums {
+    pub(crate) verifications: Vec<ChecksumVerification>,
+}
+
+impl PendingChecksums {
+    /// Start reading the file of the given asset and compare its SHA-256 checksum with the expected hex digest
+    ///
+    /// The file is read from its asset source independently of the asset loader,
+    /// so this works for every asset type at the cost of reading the file a second time.
+    pub fn verify(&mut self, asset_server: &AssetServer, path: &str, sha256: &str) {
+        let asset_server = asset_server.clone();
+        let asset_path = AssetPath::parse(path).into_owned();
+        let expected = sha256.to_ascii_lowercase();
+        let task = IoTask::spawn(async move {
+            let Ok(source) = asset_server.get_source(asset_path.source()) else {
+                return false;
+            };
+            let Ok(mut reader) = source.reader().read(asset_path.path()).await else {
+                return false;
+            };
+            let mut bytes = vec![];
+            if reader.read_to_end(&mut bytes).await.is_err() {
+                return false;
+            }
+
+            format!("{:x}", Sha256::digest(&bytes)) == expected
+        });
+        self.verifications.push(ChecksumVerification {
+            path: path.to_owned(),
+            task,
+            matches: None,
+        });
+    }
+}
+
+pub(crate) struct ChecksumVerification {
+    pub(crate) path: String,
+    task: IoTask<bool>,
+    matches: Option<bool>,
+}
+
+impl ChecksumVerification {
+    /// Returns the result of the verification once, when it finishes
+    fn poll(&mut self) -> Option<bool> {
+        let matches = self.task.poll()?;
+        self.matches = Some(matches);
+
+        Some(matches)
+    }
+}
+
+/// Checksum verifications of a loading collection
+#[derive(Resource)]
+pub(crate) struct LoadingChecksums<T> {
+    verifications: Vec<ChecksumVerification>,
+    marker: PhantomData<T>,
+}
+
+impl<T> LoadingChecksums<T> {
+    pub(crate) fn new(pending: PendingChecksums) -> Self {
+        LoadingChecksums {
+            verifications: pending.verifications,
+            marker: PhantomData,
+        }
+    }
+
+    /// Poll all running verifications and return the paths of assets that just failed theirs
+    pub(crate) fn poll_mismatches(&mut self) -> Vec<String> {
+        self.verifications
+            .iter_mut()
+            .filter_map(|verification| match verification.poll() {
+                Some(false) => Some(verification.path.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub(crate) fn total(&self) -> usize {
+        self.verifications.len()
+    }
+
+    pub(crate) fn matched(&self) -> usize {
+        self.verifications
+            .iter()
+            .filter(|verification| verification.matches == Some(true))
+            .count()
+    }
+
+    pub(crate) fn any_mismatch(&self) -> bool {
+        self.verifications
+            .iter()
+            .any(|verification| verification.matches == Some(false))
+    }
+
+    /// Paths of all assets whose verification did not finish yet
+    pub(crate) fn pending(&self) -> impl Iterator<Item = &str> {
+        self.verifications
+            .iter()
+            .filter(|verification| verification.matches.is_none())
+            .map(|verification| verification.path.as_str())
+    }
+}
+
+/// Checksum verifications of a collection initialized outside of a loading state
+#[derive(Resource)]
+struct InitializedChecksums<T>(LoadingChecksums<T>);
+
+/// Keep verifying the checksums requested while loading a collection with
+/// [`AssetCollectionApp::init_collection`](crate::asset_collection::AssetCollectionApp::init_collection)
+///
+/// Mismatches are logged as errors and sent as [`AssetLoadFailed`] events if the app has them.
+pub(crate) fn verify_initialized_collection<T: AssetCollection>(
+    app: &mut App,
+    pending: PendingChecksums,
+) {
+    if pending.verifications.is_empty() {
+        return;
+    }
+    app.insert_resource(InitializedChecksums(LoadingChecksums::<T>::new(pending)))
+        .add_systems(Update, report_initialized_checksum_mismatches::<T>);
+}
+
+fn report_initialized_checksum_mismatches<T: AssetCollection>(
+    mut commands: Commands,
+    checksums: Option<ResMut<InitializedChecksums<T>>>,
+    failed_events: Option<ResMut<Events<AssetLoadFailed>>>,
+) {
+    let Some(mut checksums) = checksums else {
+        return;
+    };
+    let mismatches = checksums.0.poll_mismatches();
+    for path in &mismatches {
+        error!(
+            "The checksum of '{path}' in the collection {} does not match the expected SHA-256 digest",
+            type_name::<T>()
+        );
+    }
+    if let Some(mut failed_events) = failed_events {
+        failed_events.extend(mismatches.into_iter().map(|path| AssetLoadFailed {
+            collection: type_name::<T>(),
+            path,
+        }));
+    }
+    if checksums.0.pending().next().is_none() {
+        commands.remove_resource::<InitializedChecksums<T>>();
+    }
+}
+
+/// SHA-256 hash over the files of a loaded asset collection
+///
+/// Inserted next to the collection by loading states configured with
+/// [`LoadingState::hash_collections`](crate::loading_state::LoadingState::hash_collections).
+/// Files are hashed in alphabetical order of their asset paths. The path and the length of every file
+/// are part of the hash, so moving content between files changes it.
+/// If any of the files cannot be read a second time for hashing, a warning is logged and the collection
+/// is inserted without this resource.
+#[derive(Resource)]
+pub struct CollectionHash<T> {
+    digest: [u8; 32],
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> CollectionHash<T> {
+    /// The raw SHA-256 digest
+    pub fn digest(&self) -> [u8; 32] {
+        self.digest
+    }
+
+    /// The SHA-256 digest as lowercase hex string
+    pub fn to_hex(&self) -> String {
+        self.digest
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+}
+
+impl<T> Clone for CollectionHash<T> {
+    fn clone(&self) -> Self {
+        CollectionHash {
+            digest: self.digest,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> PartialEq for CollectionHash<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.digest == other.digest
+    }
+}
+
+impl<T> Eq for CollectionHash<T> {}
+
+impl<T> std::fmt::Debug for CollectionHash<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CollectionHash")
+            .field(&self.to_hex())
+            .finish()
+    }
+}
+
+/// Running hash of the files of a loaded collection
+#[derive(Resource)]
+pub(crate) struct HashingCollection<T> {
+    task: IoTask<Result<[u8; 32], String>>,
+    failed: bool,
+    marker: PhantomData<T>,
+}
+
+impl<T> HashingCollection<T> {
+    /// Start hashing the files at the given asset paths
+    ///
+    /// Paths are sorted and deduplicated first, so the hash does not depend on the order of the fields.
+    /// The hash fails if any of the files cannot be read from its asset source.
+    pub(crate) fn spawn(asset_server: &AssetServer, mut paths: Vec<AssetPath<'static>>) -> Self {
+        paths.sort_by_key(|path| path.to_string());
+        paths.dedup();
+        let asset_server = asset_server.clone();
+        let task = IoTask::spawn(async move {
+            let mut hasher = Sha256::new();
+            for path in paths {
+                hasher.update(path.to_string().as_bytes());
+                hasher.update([0]);
+                let Ok(source) = asset_server.get_source(path.source()) else {
+                    return Err(path.to_string());
+                };
+                let Ok(mut reader) = source.reader().read(path.path()).await else {
+                    return Err(path.to_string());
+                };
+                let mut bytes = vec![];
+                if reader.read_to_end(&mut bytes).await.is_err() {
+                    return Err(path.to_string());
+                }
+                hasher.update((bytes.len() as u64).to_le_bytes());
+                hasher.update(&bytes);
+            }
+
+            Ok(hasher.finalize().into())
+        });
+
+        HashingCollection {
+            task,
+            failed: false,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the hash once it is done, or the path of a file that could not be read
+    pub(crate) fn poll(&mut self) -> Option<Result<CollectionHash<T>, String>> {
+        let result = self.task.poll()?;
+        self.failed = result.is_err();
+
+        Some(result.map(|digest| CollectionHash {
+            digest,
+            marker: PhantomData,
+        }))
+    }
+
+    /// Whether hashing stopped at a file that could not be read
+    pub(crate) fn failed(&self) -> bool {
+        self.failed
+    }
+}
@@ -0,0 +1,166 @@
+use crate::asset_collection::{collection_load_state, AssetCollection, OptionalAssets};
+use crate::dynamic_asset::DynamicAssets;
+use crate::loading_state::{requested_asset_path, AssetLoadFailed};
+use bevy::asset::{AssetServer, LoadState, UntypedHandle};
+use bevy::ecs::system::Resource;
+use bevy::ecs::world::World;
+use std::any::type_name;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// Load asset collections from async code
+///
+/// The loader is a cheap to clone resource added by
+/// [`AssetCollectionApp::add_collection_loader`](crate::asset_collection::AssetCollectionApp::add_collection_loader).
+/// It can be moved out of the ECS, e.g. into a task of another async runtime.
+///
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy_asset_loader::collection_loader::CollectionLoader;
+/// # use bevy::prelude::*;
+/// async fn load_assets(loader: CollectionLoader) {
+///     let assets = loader
+///         .load::<AudioAssets>()
+///         .await
+///         .expect("Failed to load audio assets");
+///     // use assets.background
+/// }
+/// # #[derive(AssetCollection, Resource, Clone)]
+/// # pub struct AudioAssets {
+/// #     #[asset(path = "audio/background.ogg")]
+/// #     pub background: Handle<AudioSource>,
+/// # }
+/// ```
+///
+/// The returned futures do not depend on any specific executor. They are resolved by a system in
+/// the [`Update`](::bevy::app::Update) schedule, so the app needs to keep updating while a future
+/// is awaited. Never block on a future on the thread running the app (e.g. inside a system),
+/// since that would keep the future from ever resolving.
+#[derive(Resource, Clone, Default)]
+pub struct CollectionLoader {
+    requests: Arc<Mutex<Vec<Box<dyn CollectionRequest>>>>,
+}
+
+impl CollectionLoader {
+    /// Start loading an [`AssetCollection`] and get a future resolving to it
+    ///
+    /// The future resolves once all assets of the collection are loaded. The collection is
+    /// also inserted as a resource and the future resolves to a clone of it.
+    /// If any asset fails to load, the future resolves to the first failure.
+    pub fn load<A: AssetCollection + Clone>(&self) -> CollectionFuture<A> {
+        let shared = Arc::new(Mutex::new(SharedState {
+            result: None,
+            waker: None,
+        }));
+        self.requests
+            .lock()
+            .unwrap()
+            .push(Box::new(TypedCollectionRequest::<A> {
+                handles: None,
+                shared: shared.clone(),
+                _marker: PhantomData,
+            }));
+
+        CollectionFuture { shared }
+    }
+}
+
+/// Future resolving to a loaded [`AssetCollection`]
+///
+/// Created by [`CollectionLoader::load`].
+pub struct CollectionFuture<A> {
+    shared: Arc<Mutex<SharedState<A>>>,
+}
+
+impl<A> Future for CollectionFuture<A> {
+    type Output = Result<A, AssetLoadFailed>;
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut shared = self.shared.lock().unwrap();
+        if let Some(result) = shared.result.take() {
+            return Poll::Ready(result);
+        }
+        shared.waker = Some(context.waker().clone());
+
+        Poll::Pending
+    }
+}
+
+struct SharedState<A> {
+    result: Option<Result<A, AssetLoadFailed>>,
+    waker: Option<Waker>,
+}
+
+trait CollectionRequest: Send {
+    /// Returns `true` once the request is done
+    fn update(&mut self, world: &mut World) -> bool;
+}
+
+struct TypedCollectionRequest<A> {
+    handles: Option<Vec<UntypedHandle>>,
+    shared: Arc<Mutex<SharedState<A>>>,
+    _marker: PhantomData<A>,
+}
+
+impl<A: AssetCollection + Clone> CollectionRequest for TypedCollectionRequest<A> {
+    fn update(&mut self, world: &mut World) -> bool {
+        let handles = self.handles.get_or_insert_with(|| {
+            let handles = A::load(world);
+            #[cfg(feature = "collection_diagnostics")]
+            crate::collection_registry::record_load_requests::<A>(world, &handles);
+            handles
+        });
+        let asset_server = world.resource::<AssetServer>();
+        let optional = world.get_resource::<OptionalAssets>();
+        let mut loaded = true;
+        for handle in handles.iter() {
+            match collection_load_state::<A>(asset_server, optional, handle.id(), false) {
+                Some(LoadState::Loaded) => {}
+                Some(LoadState::Failed) => {
+                    let path = handle
+                        .path()
+                        .map(|path| requested_asset_path(path.clone()).to_string())
+                        .unwrap_or_default();
+                    self.resolve(Err(AssetLoadFailed {
+                        collection: type_name::<A>(),
+                        path,
+                    }));
+                    return true;
+                }
+                _ => loaded = false,
+            }
+        }
+        if !loaded {
+            return false;
+        }
+        let collection = A::create(world);
+        world.insert_resource(collection.clone());
+        self.resolve(Ok(collection));
+
+        true
+    }
+}
+
+impl<A> TypedCollectionRequest<A> {
+    fn resolve(&self, result: Result<A, AssetLoadFailed>) {
+        let mut shared = self.shared.lock().unwrap();
+        shared.result = Some(result);
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+pub(crate) fn update_collection_requests(world: &mut World) {
+    let loader = world.resource::<CollectionLoader>().clone();
+    let mut requests = std::mem::take(&mut *loader.requests.lock().unwrap());
+    if requests.is_empty() {
+        return;
+    }
+    world.init_resource::<DynamicAssets>();
+    requests.retain_mut(|request| !request.update(world));
+    loader.requests.lock().unwrap().append(&mut requests);
+}
@@ -0,0 +1,200 @@
+use crate::asset_collection::AssetCollection;
+use crate::loading_state::{requested_asset_path, LoadingStateSchedules};
+use bevy::app::{App, Startup};
+use bevy::asset::UntypedHandle;
+use bevy::ecs::schedule::{IntoSystemConfigs, States};
+use bevy::ecs::system::{Res, ResMut, Resource};
+use bevy::ecs::world::World;
+use bevy::log::warn;
+use bevy::utils::{HashMap, HashSet};
+use std::any::{type_name, TypeId};
+
+/// Registry of asset collections and the collections that are loaded by the app
+///
+/// Collections added to a loading state are registered automatically. Other collections can be registered
+/// with [`AssetCollectionApp::register_collection`](crate::asset_collection::AssetCollectionApp::register_collection).
+/// Initializing a collection marks it as loaded. A collection of a loading state counts as loaded if the
+/// loading state is added to the app with
+/// [`add_loading_state`](crate::loading_state::LoadingStateAppExt::add_loading_state).
+/// On startup, a warning is logged for every registered collection that is never loaded.
+#[derive(Resource, Default, Debug)]
+pub struct CollectionRegistry {
+    registered: HashMap<TypeId, &'static str>,
+    loaded: HashSet<TypeId>,
+}
+
+impl CollectionRegistry {
+    /// Type names of all registered collections that are neither loaded by a loading state nor initialized
+    ///
+    /// Collections of loading states are only checked on startup.
+    pub fn unloaded_collections(&self) -> Vec<&'static str> {
+        let mut unloaded: Vec<&'static str> = self
+            .registered
+            .iter()
+            .filter(|(type_id, _)| !self.loaded.contains(*type_id))
+            .map(|(_, name)| *name)
+            .collect();
+        unloaded.sort_unstable();
+
+        unloaded
+    }
+}
+
+/// Asset paths requested by loaded asset collections
+///
+/// Every collection started by a loading state, [`init_collection`](crate::asset_collection::AssetCollectionApp::init_collection)
+/// or a [`CollectionLoader`](crate::collection_loader::CollectionLoader) records the paths of its handles.
+/// A path requested by more than one collection is a duplicate load, unless it was marked as intentionally
+/// shared with [`LoadRequests::share`]. Fields of a single collection using the same path count as one request.
+/// ```edition2021
+/// # use bevy_asset_loader::collection_registry::LoadRequests;
+/// # use bevy::prelude::*;
+/// fn check_loads(requests: Res<LoadRequests>) {
+///     requests.assert_no_duplicates();
+/// }
+/// ```
+#[derive(Resource, Default, Debug)]
+pub struct LoadRequests {
+    requests: HashMap<String, Vec<&'static str>>,
+    shared: HashSet<String>,
+}
+
+/// An asset path that was requested by multiple asset collections
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateLoad {
+    /// The requested asset path
+    pub path: String,
+    /// Type names of the collections requesting the path
+    pub collections: Vec<&'static str>,
+}
+
+impl LoadRequests {
+    /// Number of collections that requested the given asset path
+    pub fn count(&self, path: &str) -> usize {
+        self.requests.get(path).map_or(0, Vec::len)
+    }
+
+    /// Mark an asset path as intentionally shared between collections
+    ///
+    /// Shared paths are never reported as duplicate loads.
+    pub fn share(&mut self, path: impl Into<String>) -> &mut Self {
+        self.shared.insert(path.into());
+
+        self
+    }
+
+    /// All asset paths that were requested by more than one collection and are not shared
+    ///
+    /// Sorted by path and collection name.
+    pub fn duplicates(&self) -> Vec<DuplicateLoad> {
+        let mut duplicates: Vec<DuplicateLoad> = self
+            .requests
+            .iter()
+            .filter(|(path, collections)| collections.len() > 1 && !self.shared.contains(*path))
+            .map(|(path, collections)| {
+                let mut collections = collections.clone();
+                collections.sort_unstable();
+                DuplicateLoad {
+                    path: path.clone(),
+                    collections,
+                }
+            })
+            .collect();
+        duplicates.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+
+        duplicates
+    }
+
+    /// Panic with a list of all duplicate loads, if there are any
+    ///
+    /// Meant for tests auditing the asset loads of an app.
+    pub fn assert_no_duplicates(&self) {
+        let duplicates = self.duplicates();
+        if duplicates.is_empty() {
+            return;
+        }
+        let list: Vec<String> = duplicates
+            .iter()
+            .map(|duplicate| {
+                format!(
+                    "'{}' by {}",
+                    duplicate.path,
+                    duplicate.collections.join(", ")
+                )
+            })
+            .collect();
+        panic!(
+            "Asset paths were loaded by multiple collections: {}",
+            list.join("; ")
+        );
+    }
+
+    fn record(&mut self, collection: &'static str, handles: &[UntypedHandle]) {
+        for path in handles.iter().filter_map(UntypedHandle::path) {
+            let path = requested_asset_path(path.clone()).to_string();
+            let collections = self.requests.entry(path).or_default();
+            if !collections.contains(&collection) {
+                collections.push(collection);
+            }
+        }
+    }
+}
+
+pub(crate) fn record_load_requests<A: AssetCollection>(
+    world: &mut World,
+    handles: &[UntypedHandle],
+) {
+    if let Some(mut requests) = world.get_resource_mut::<LoadRequests>() {
+        requests.record(type_name::<A>(), handles);
+    }
+}
+
+pub(crate) fn register_collection<A: AssetCollection>(app: &mut App) {
+    registry(app)
+        .registered
+        .insert(TypeId::of::<A>(), type_name::<A>());
+}
+
+/// Register a collection of a loading state and mark it as loaded on startup if the loading state exists
+pub(crate) fn register_loading_state_collection<S: States, A: AssetCollection>(
+    app: &mut App,
+    loading_state: S,
+) {
+    register_collection::<A>(app);
+    app.add_systems(
+        Startup,
+        (move |schedules: Option<Res<LoadingStateSchedules<S>>>,
+               mut registry: ResMut<CollectionRegistry>| {
+            if schedules.is_some_and(|schedules| schedules.schedules.contains_key(&loading_state)) {
+                registry.loaded.insert(TypeId::of::<A>());
+            }
+        })
+        .before(warn_about_unloaded_collections),
+    );
+}
+
+pub(crate) fn mark_collection_loaded<A: AssetCollection>(app: &mut App) {
+    registry(app).loaded.insert(TypeId::of::<A>());
+}
+
+fn registry(app: &mut App) -> &mut CollectionRegistry {
+    if !app.world.contains_resource::<CollectionRegistry>() {
+        app.init_resource::<CollectionRegistry>()
+            .init_resource::<LoadRequests>()
+            .add_systems(Startup, warn_about_unloaded_collections);
+    }
+
+    app.world
+        .get_resource_mut::<CollectionRegistry>()
+        .unwrap()
+        .into_inner()
+}
+
+fn warn_about_unloaded_collections(registry: Res<CollectionRegistry>) {
+    for collection in registry.unloaded_collections() {
+        warn!(
+            "The asset collection '{}' is registered, but neither added to an existing loading state nor initialized. It will not be loaded.",
+            collection
+        );
+    }
+}
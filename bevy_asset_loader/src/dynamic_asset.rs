@@ -1,12 +1,17 @@
-use bevy::utils::HashMap;
+use bevy::utils::{default, HashMap, HashSet};
 use std::any::TypeId;
 use std::fmt::Debug;
 
 use bevy::asset::{Asset, AssetServer, UntypedHandle};
 use bevy::ecs::schedule::States;
 use bevy::ecs::system::Resource;
-use bevy::ecs::world::World;
+use bevy::ecs::world::{World, WorldCell};
+use bevy::log::{debug, error};
 use std::marker::PhantomData;
+use std::sync::{Mutex, PoisonError};
+
+#[cfg(feature = "standard_dynamic_assets")]
+use crate::standard_dynamic_asset::{StandardDynamicAsset, StandardDynamicAssetCollection};
 
 /// Different typed that can generate the asset field value of a dynamic asset
 #[derive(Debug)]
@@ -25,6 +30,33 @@ pub trait DynamicAsset: Debug + Send + Sync {
 
     /// Return the handle(s) defining this asset
     fn build(&self, world: &mut World) -> Result<DynamicAssetType, anyhow::Error>;
+
+    /// Return this asset as a [`StandardDynamicAsset`] if it is one
+    ///
+    /// Only standard dynamic assets are part of a snapshot of the [`DynamicAssets`] resource.
+    #[cfg(feature = "standard_dynamic_assets")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "standard_dynamic_assets")))]
+    fn as_standard_dynamic_asset(&self) -> Option<&StandardDynamicAsset> {
+        None
+    }
+}
+
+/// Separates the namespace from the key in namespaced keys like `mod_a:boss_theme`
+pub const NAMESPACE_SEPARATOR: char = ':';
+
+/// Split a dynamic asset key into its namespace and the key inside the namespace
+///
+/// Keys without a namespace have no namespace. Only the first separator counts,
+/// so `mod_a:music:boss` is the key `music:boss` in the namespace `mod_a`.
+pub fn split_namespace(key: &str) -> (Option<&str>, &str) {
+    match key.split_once(NAMESPACE_SEPARATOR) {
+        Some((namespace, key)) => (Some(namespace), key),
+        None => (None, key),
+    }
+}
+
+fn namespaced_key(namespace: &str, key: &str) -> String {
+    format!("{namespace}{NAMESPACE_SEPARATOR}{key}")
 }
 
 /// Resource to dynamically resolve keys to assets.
@@ -32,33 +64,250 @@ pub trait DynamicAsset: Debug + Send + Sync {
 /// This resource is set by a [`LoadingState`](crate::loading_state::LoadingState) and is read when entering the corresponding Bevy [`State`](State).
 /// If you want to manage your dynamic assets manually, they should be configured in a previous [`State`](State).
 ///
+/// Keys can be namespaced like `mod_a:boss_theme` (see [`NAMESPACE_SEPARATOR`]). The same key in different
+/// namespaces resolves to different assets. Dynamic asset collection files can be registered in a namespace with
+/// [`LoadingStateAppExt::add_namespaced_dynamic_collection_to_loading_state`](crate::loading_state::LoadingStateAppExt::add_namespaced_dynamic_collection_to_loading_state).
+///
+/// Keys registered in code take precedence over keys from dynamic asset collection files: a file loaded by a
+/// loading state does not overwrite a key that was registered with [`DynamicAssets::register_asset`] before.
+/// Registering a key in code after the file was loaded overwrites the value from the file.
+///
 /// See the `manual_dynamic_asset` example.
 #[derive(Resource, Default)]
 pub struct DynamicAssets {
     key_asset_map: HashMap<String, Box<dyn DynamicAsset>>,
+    /// Keys whose current value was registered from a dynamic asset collection file
+    file_keys: HashSet<String>,
+    /// Keys looked up since [`DynamicAssets::record_used_keys`] was called
+    used_keys: Mutex<Option<UsedKeys>>,
 }
 
 impl DynamicAssets {
     /// Get the asset corresponding to the given key.
     pub fn get_asset(&self, key: &str) -> Option<&dyn DynamicAsset> {
+        if let Some(used_keys) = self
+            .used_keys
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_mut()
+        {
+            used_keys.keys.insert(key.to_owned());
+        }
         self.key_asset_map.get(key).map(|boxed| boxed.as_ref())
     }
 
     /// Iterate over all the known key→asset mappings
     pub fn iter_assets(&self) -> impl Iterator<Item = (&str, &dyn DynamicAsset)> {
+        if let Some(used_keys) = self
+            .used_keys
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_mut()
+        {
+            used_keys.all = true;
+        }
         self.key_asset_map
             .iter()
             .map(|(k, v)| (k.as_str(), v.as_ref()))
     }
 
+    /// Start recording the keys that are looked up, e.g. while loading an asset collection
+    pub(crate) fn record_used_keys(&self) {
+        *self
+            .used_keys
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(default());
+    }
+
+    /// Stop recording and return the keys looked up since [`DynamicAssets::record_used_keys`]
+    pub(crate) fn take_used_keys(&self) -> UsedKeys {
+        self.used_keys
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take()
+            .unwrap_or_default()
+    }
+
     /// Set the corresponding dynamic asset for the given key.
     ///
     /// In case the key is already known, its value will be overwritten.
     pub fn register_asset<K: Into<String>>(&mut self, key: K, asset: Box<dyn DynamicAsset>) {
-        self.key_asset_map.insert(key.into(), asset);
+        let key = key.into();
+        self.file_keys.remove(&key);
+        self.key_asset_map.insert(key, asset);
+    }
+
+    /// Register the assets of a dynamic asset collection file, optionally in a namespace
+    ///
+    /// Keys that were registered in code are kept. Keys from files, including earlier versions of the same file, are overwritten.
+    /// Returns the registered keys.
+    pub(crate) fn register_file_collection<C: DynamicAssetCollection + ?Sized>(
+        &mut self,
+        namespace: Option<&str>,
+        collection: &C,
+    ) -> HashSet<String> {
+        let mut registered = HashSet::default();
+        let mut assets = DynamicAssets::default();
+        match namespace {
+            Some(namespace) => assets.register_collection_in_namespace(namespace, collection),
+            None => collection.register(&mut assets),
+        }
+        for (key, asset) in assets.key_asset_map.drain() {
+            if self.key_asset_map.contains_key(&key) && !self.file_keys.contains(&key) {
+                debug!("Keeping the dynamic asset '{key}' registered in code instead of the one from a dynamic asset collection file");
+                continue;
+            }
+            self.key_asset_map.insert(key.clone(), asset);
+            self.file_keys.insert(key.clone());
+            registered.insert(key);
+        }
+
+        registered
+    }
+
+    /// Get the asset corresponding to the given key in the given namespace.
+    pub fn get_namespaced_asset(&self, namespace: &str, key: &str) -> Option<&dyn DynamicAsset> {
+        self.get_asset(&namespaced_key(namespace, key))
+    }
+
+    /// Set the corresponding dynamic asset for the given key in the given namespace.
+    ///
+    /// In case the key is already known in the namespace, its value will be overwritten.
+    pub fn register_namespaced_asset(
+        &mut self,
+        namespace: &str,
+        key: &str,
+        asset: Box<dyn DynamicAsset>,
+    ) {
+        self.register_asset(namespaced_key(namespace, key), asset);
+    }
+
+    /// Iterate over the key→asset mappings of the given namespace
+    ///
+    /// The keys are returned without their namespace.
+    pub fn iter_namespace<'a>(
+        &'a self,
+        namespace: &'a str,
+    ) -> impl Iterator<Item = (&'a str, &'a dyn DynamicAsset)> {
+        self.iter_assets()
+            .filter_map(move |(key, asset)| match split_namespace(key) {
+                (Some(key_namespace), key) if key_namespace == namespace => Some((key, asset)),
+                _ => None,
+            })
+    }
+
+    /// Register all assets of a dynamic asset collection in the given namespace
+    ///
+    /// Keys already containing a namespace are nested, e.g. `music:boss` becomes `mod_a:music:boss`.
+    pub fn register_collection_in_namespace<C: DynamicAssetCollection + ?Sized>(
+        &mut self,
+        namespace: &str,
+        collection: &C,
+    ) {
+        let mut assets = DynamicAssets::default();
+        collection.register(&mut assets);
+        for (key, asset) in assets.key_asset_map.drain() {
+            self.register_namespaced_asset(namespace, &key, asset);
+        }
+    }
+
+    /// Take a snapshot of all registered [`StandardDynamicAsset`]s
+    ///
+    /// Custom dynamic assets are not part of the snapshot. The returned collection
+    /// can be serialized and later be passed to [`DynamicAssets::restore`].
+    #[cfg(feature = "standard_dynamic_assets")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "standard_dynamic_assets")))]
+    pub fn snapshot(&self) -> StandardDynamicAssetCollection {
+        StandardDynamicAssetCollection(
+            self.key_asset_map
+                .iter()
+                .filter_map(|(key, asset)| {
+                    asset
+                        .as_standard_dynamic_asset()
+                        .map(|asset| (key.clone(), asset.clone()))
+                })
+                .collect(),
+        )
+    }
+
+    /// Restore the registered [`StandardDynamicAsset`]s from a snapshot
+    ///
+    /// All currently registered standard dynamic assets are replaced by the content of the snapshot.
+    /// Custom dynamic assets are kept, unless the snapshot contains their key.
+    #[cfg(feature = "standard_dynamic_assets")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "standard_dynamic_assets")))]
+    pub fn restore(&mut self, snapshot: &StandardDynamicAssetCollection) {
+        self.key_asset_map
+            .retain(|_, asset| asset.as_standard_dynamic_asset().is_none());
+        snapshot.register(self);
     }
 }
 
+/// Keys of [`DynamicAssets`] that were looked up while recording
+#[derive(Default)]
+pub(crate) struct UsedKeys {
+    keys: HashSet<String>,
+    /// Whether all keys were iterated, e.g. by a field with `keys_into_map`
+    all: bool,
+}
+
+impl UsedKeys {
+    /// Whether any of the given keys was looked up
+    pub(crate) fn contains_any(&self, keys: &HashSet<String>) -> bool {
+        (self.all && !keys.is_empty()) || keys.iter().any(|key| self.keys.contains(key))
+    }
+}
+
+/// A dynamic asset required by a field of an asset collection, but not registered in [`DynamicAssets`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingDynamicAsset {
+    /// Type name of the asset collection
+    pub collection: &'static str,
+    /// Name of the field requiring the dynamic asset
+    pub field: &'static str,
+    /// The key without a registered dynamic asset
+    pub key: String,
+}
+
+/// Dynamic assets that the asset collections of a loading state require, but that are not registered
+///
+/// A collection with a missing dynamic asset fails to load, and the loading state continues to its
+/// failure state (see [`LoadingState::on_failure_continue_to_state`](crate::loading_state::LoadingState::on_failure_continue_to_state)).
+/// The resource is cleared when a loading state is entered and keeps its content in the failure state.
+/// ```edition2021
+/// # use bevy_asset_loader::dynamic_asset::MissingDynamicAssets;
+/// # use bevy::prelude::*;
+/// fn show_errors(missing: Res<MissingDynamicAssets>) {
+///     for asset in &missing.0 {
+///         error!("'{}' of {} needs the dynamic asset '{}'", asset.field, asset.collection, asset.key);
+///     }
+/// }
+/// ```
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct MissingDynamicAssets(pub Vec<MissingDynamicAsset>);
+
+/// Report a key of a dynamic asset field that is not registered in [`DynamicAssets`]
+///
+/// Used by the derive macro while loading a collection. Without a [`MissingDynamicAssets`] resource,
+/// e.g. when initializing a collection outside a loading state, this panics.
+#[doc(hidden)]
+pub fn report_missing_dynamic_asset(
+    cell: &WorldCell,
+    collection: &'static str,
+    field: &'static str,
+    key: &str,
+) {
+    let Some(mut missing) = cell.get_resource_mut::<MissingDynamicAssets>() else {
+        panic!("The field '{field}' of the asset collection {collection} requires the dynamic asset '{key}', but no dynamic asset is registered for this key");
+    };
+    error!("The field '{field}' of the asset collection {collection} requires the dynamic asset '{key}', but no dynamic asset is registered for this key");
+    missing.0.push(MissingDynamicAsset {
+        collection,
+        field,
+        key: key.to_owned(),
+    });
+}
+
 /// This traits describes types that contain asset configurations and can
 /// register them in the [`DynamicAssets`] resource.
 pub trait DynamicAssetCollection {
@@ -70,6 +319,7 @@ pub trait DynamicAssetCollection {
 #[derive(Resource, Debug)]
 pub struct DynamicAssetCollections<State: States> {
     files: HashMap<State, HashMap<TypeId, Vec<String>>>,
+    namespaces: HashMap<State, HashMap<String, String>>,
     _marker: PhantomData<State>,
 }
 
@@ -93,6 +343,31 @@ impl<State: States> DynamicAssetCollections<State> {
             .insert(loading_state, dynamic_collections_for_state);
     }
 
+    /// Register a file containing dynamic asset definitions to be registered in the given namespace
+    ///
+    /// All keys of the file are prefixed with the namespace, so files of different namespaces can use the same keys.
+    /// A file can only belong to one namespace per loading state.
+    pub fn register_namespaced_file<C: DynamicAssetCollection + Asset>(
+        &mut self,
+        loading_state: State,
+        namespace: &str,
+        file: &str,
+    ) {
+        self.namespaces
+            .entry(loading_state.clone())
+            .or_default()
+            .insert(file.to_owned(), namespace.to_owned());
+        self.register_file::<C>(loading_state, file);
+    }
+
+    /// Get the namespace a file was registered in for the given loading state
+    pub fn get_namespace(&self, loading_state: &State, file: &str) -> Option<&str> {
+        self.namespaces
+            .get(loading_state)?
+            .get(file)
+            .map(String::as_str)
+    }
+
     /// Get all currently registered files to be loaded for the given loading state and dynamic asset collection type.
     pub fn get_files<C: DynamicAssetCollection + Asset>(
         &self,
@@ -107,6 +382,7 @@ impl<State: States> Default for DynamicAssetCollections<State> {
     fn default() -> Self {
         DynamicAssetCollections {
             files: HashMap::default(),
+            namespaces: HashMap::default(),
             _marker: PhantomData,
         }
     }
@@ -0,0 +1,44 @@
+use crate::loading_state::LoadingProgress;
+use bevy::ecs::system::Res;
+use bevy_egui::{egui, EguiContexts};
+
+/// Draw a window with the progress of the active loading state
+///
+/// This system is opt-in and meant for quick debug UIs. Add it to the `Update` schedule of an app
+/// with bevy_egui's `EguiPlugin`, usually only while the loading state is active. The window shows
+/// the [`LoadingProgress`] resource and disappears once the loading state is done.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy_asset_loader::egui::egui_loading_progress;
+/// # use bevy::prelude::*;
+/// # fn main() {
+///     App::new()
+/// #       .add_state::<GameState>()
+///         .add_loading_state(
+///           LoadingState::new(GameState::Loading)
+///             .continue_to_state(GameState::Menu)
+///         )
+///         .add_systems(Update, egui_loading_progress.run_if(in_state(GameState::Loading)));
+/// # }
+/// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+/// # enum GameState {
+/// #     #[default]
+/// #     Loading,
+/// #     Menu
+/// # }
+/// ```
+pub fn egui_loading_progress(mut contexts: EguiContexts, progress: Res<LoadingProgress>) {
+    if progress.is_finished() {
+        return;
+    }
+    egui::Window::new("Loading")
+        .collapsible(false)
+        .resizable(false)
+        .show(contexts.ctx_mut(), |ui| {
+            ui.add(egui::ProgressBar::new(progress.fraction()).show_percentage());
+            ui.label(format!(
+                "Loaded {} of {} assets",
+                progress.loaded, progress.total
+            ));
+        });
+}
@@ -0,0 +1,38 @@
+use bevy::tasks::{IoTaskPool, TaskPool};
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+/// A future running on the [`IoTaskPool`] (or another task pool) whose result can be polled from systems
+///
+/// The single threaded task pool (e.g. on wasm or without bevy's `multi-threaded` feature) does not
+/// return the results of spawned tasks. The future is detached and stores its result in a shared slot instead.
+pub(crate) struct IoTask<T> {
+    result: Arc<Mutex<Option<T>>>,
+}
+
+impl<T: Send + 'static> IoTask<T> {
+    pub(crate) fn spawn(future: impl Future<Output = T> + Send + 'static) -> Self {
+        Self::spawn_on(IoTaskPool::get(), future)
+    }
+
+    /// Run the future on another task pool, e.g. the `AsyncComputeTaskPool` for CPU heavy work
+    pub(crate) fn spawn_on(
+        pool: &TaskPool,
+        future: impl Future<Output = T> + Send + 'static,
+    ) -> Self {
+        let result = Arc::new(Mutex::new(None));
+        let slot = result.clone();
+        pool.spawn(async move {
+            let value = future.await;
+            *slot.lock().unwrap() = Some(value);
+        })
+        .detach();
+
+        IoTask { result }
+    }
+
+    /// Take the result if the future finished
+    pub(crate) fn poll(&self) -> Option<T> {
+        self.result.lock().unwrap().take()
+    }
+}
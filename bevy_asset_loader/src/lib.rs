@@ -65,18 +65,68 @@
 #![warn(unused_imports, missing_docs)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+/// Asset sources reading from zip and tar archives
+#[cfg_attr(docsrs, doc(cfg(feature = "archive")))]
+#[cfg(feature = "archive")]
+pub mod archive;
 /// Trait definition for types that represent a collection of assets
 ///
 /// And extension traits to insert said collections into your Bevy app or world
 pub mod asset_collection;
+/// Texture atlases defined in RON files
+#[cfg_attr(docsrs, doc(cfg(feature = "2d")))]
+#[cfg(feature = "2d")]
+pub mod atlas_layout;
+/// Audio assets with additional information about their content
+#[cfg_attr(docsrs, doc(cfg(feature = "audio")))]
+#[cfg(feature = "audio")]
+pub mod audio;
+/// Verification of asset files against expected checksums and hashes of collections
+#[cfg_attr(docsrs, doc(cfg(feature = "checksums")))]
+#[cfg(feature = "checksums")]
+pub mod checksum;
+/// Awaitable loading of asset collections from async code
+pub mod collection_loader;
+/// Diagnostics for asset collections that are never loaded or load the same assets
+#[cfg_attr(docsrs, doc(cfg(feature = "collection_diagnostics")))]
+#[cfg(feature = "collection_diagnostics")]
+pub mod collection_registry;
 /// Types and infrastructure to load and use dynamic assets
 pub mod dynamic_asset;
+/// A system drawing the progress of loading states with bevy_egui
+#[cfg_attr(docsrs, doc(cfg(feature = "egui")))]
+#[cfg(feature = "egui")]
+pub mod egui;
+
+mod io_task;
+/// Timing of asset loads as a Chrome trace
+#[cfg_attr(docsrs, doc(cfg(feature = "load_trace")))]
+#[cfg(feature = "load_trace")]
+pub mod load_trace;
 /// A game state responsible for loading assets
 pub mod loading_state;
+/// Key types for maps of loaded folders
+pub mod mapped;
+/// Placeholder handles for assets of a collection that are still loading
+pub mod placeholder;
+/// Asset sources reading from folders of a quality setting
+pub mod quality;
+/// Asset collections declared at run time without the derive macro
+pub mod runtime_collection;
 /// Dynamic assets for common Bevy asset types
 #[cfg_attr(docsrs, doc(cfg(feature = "standard_dynamic_assets")))]
 #[cfg(feature = "standard_dynamic_assets")]
 pub mod standard_dynamic_asset;
+/// Mapped folders populated over several frames
+pub mod streamed_folder;
+/// Asset sources to load assets one at a time in tests
+#[cfg_attr(docsrs, doc(cfg(feature = "test_support")))]
+#[cfg(feature = "test_support")]
+pub mod test_support;
+/// Texture atlases from TexturePacker sprite sheet metadata
+#[cfg_attr(docsrs, doc(cfg(feature = "texture_packer")))]
+#[cfg(feature = "texture_packer")]
+pub mod texture_packer;
 
 /// Most commonly used types
 pub mod prelude {
@@ -92,7 +142,10 @@ pub mod prelude {
             DynamicAsset, DynamicAssetCollection, DynamicAssetCollections, DynamicAssetType,
             DynamicAssets,
         },
-        loading_state::{LoadingState, LoadingStateAppExt, LoadingStateSet},
+        loading_state::{
+            LoadingProgress, LoadingState, LoadingStateAppExt, LoadingStateCommandsExt,
+            LoadingStateSet,
+        },
     };
 }
 
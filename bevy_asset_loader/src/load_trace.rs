@@ -0,0 +1,186 @@
+use crate::loading_state::requested_asset_path;
+use bevy::asset::{AssetServer, LoadState, UntypedAssetId, UntypedHandle};
+use bevy::ecs::system::Resource;
+use bevy::utils::{HashMap, Instant};
+use serde::Serialize;
+
+/// Timing of the asset loads of all loading states in the [Chrome trace event format](https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU)
+///
+/// Every asset of a collection gets an async begin event (`"ph": "b"`) when its loading state issues the load
+/// and a matching end event (`"ph": "e"`) once it is loaded or failed. Both events share the `id` of the asset.
+/// Assets loaded again in a later visit of a loading state get a new pair of events.
+/// The `name` is the asset path, the category `cat` is the type name of the collection, and the timestamp `ts`
+/// is in microseconds since the first recorded event. Times are measured once per frame, so they are only as
+/// precise as the frame time.
+///
+/// The trace keeps growing over all loading states. Write it to a file with [`LoadTrace::to_json`] and open it in
+/// `chrome://tracing` or [Perfetto](https://ui.perfetto.dev):
+/// ```edition2021
+/// # use bevy_asset_loader::load_trace::LoadTrace;
+/// # use bevy::prelude::*;
+/// fn write_trace(trace: Res<LoadTrace>) {
+///     std::fs::write("load_trace.json", trace.to_json()).expect("Failed to write the trace");
+/// }
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct LoadTrace {
+    start: Option<Instant>,
+    events: Vec<TraceEvent>,
+    /// Trace ids of assets with a begin event, and whether they ended
+    assets: HashMap<UntypedAssetId, (u64, bool)>,
+    next_id: u64,
+}
+
+/// A single event of a [`LoadTrace`]
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct TraceEvent {
+    /// Path of the asset
+    pub name: String,
+    /// Type name of the asset collection
+    pub cat: &'static str,
+    /// Whether the asset started or finished loading
+    pub ph: TracePhase,
+    /// Microseconds since the first event of the trace
+    pub ts: u64,
+    /// Process id, always `1`
+    pub pid: u32,
+    /// Thread id, always `1`
+    pub tid: u32,
+    /// Id shared by the begin and end event of an asset
+    pub id: u64,
+}
+
+/// Phase of a [`TraceEvent`]
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TracePhase {
+    /// An asset started loading
+    #[serde(rename = "b")]
+    Begin,
+    /// An asset finished loading or failed
+    #[serde(rename = "e")]
+    End,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TraceFile<'a> {
+    trace_events: &'a [TraceEvent],
+    display_time_unit: &'static str,
+}
+
+impl LoadTrace {
+    /// All recorded events in the order they happened
+    pub fn events(&self) -> &[TraceEvent] {
+        &self.events
+    }
+
+    /// The trace as a JSON object with the events in `traceEvents`
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&TraceFile {
+            trace_events: &self.events,
+            display_time_unit: "ms",
+        })
+        .expect("Trace events are always serializable")
+    }
+
+    /// Remove all events
+    pub fn clear(&mut self) {
+        *self = LoadTrace::default();
+    }
+
+    /// Forget assets that finished loading, so loading them again records new events
+    pub(crate) fn enter_loading_state(&mut self) {
+        self.assets.retain(|_, (_, ended)| !*ended);
+    }
+
+    /// Record begin events for the handles of a collection that just started loading
+    pub(crate) fn begin(
+        &mut self,
+        collection: &'static str,
+        asset_server: &AssetServer,
+        handles: &[UntypedHandle],
+    ) {
+        let ts = self.timestamp();
+        for handle in handles {
+            if self.assets.contains_key(&handle.id()) {
+                continue;
+            }
+            let id = self.next_id;
+            self.next_id += 1;
+            self.assets.insert(handle.id(), (id, false));
+            self.push(
+                collection,
+                asset_server,
+                handle.id(),
+                TracePhase::Begin,
+                ts,
+                id,
+            );
+        }
+    }
+
+    /// Record the end events for the handles of a loading collection
+    ///
+    /// Handles without a begin event, like loads started by a throttle, begin now.
+    pub(crate) fn record(
+        &mut self,
+        collection: &'static str,
+        asset_server: &AssetServer,
+        handles: &[UntypedHandle],
+        load_state: impl Fn(UntypedAssetId) -> Option<LoadState>,
+    ) {
+        self.begin(collection, asset_server, handles);
+        let ts = self.timestamp();
+        for handle in handles {
+            let done = matches!(
+                load_state(handle.id()),
+                Some(LoadState::Loaded | LoadState::Failed)
+            );
+            let Some((id, ended)) = self.assets.get_mut(&handle.id()) else {
+                continue;
+            };
+            if !done || *ended {
+                continue;
+            }
+            *ended = true;
+            let id = *id;
+            self.push(
+                collection,
+                asset_server,
+                handle.id(),
+                TracePhase::End,
+                ts,
+                id,
+            );
+        }
+    }
+
+    fn timestamp(&mut self) -> u64 {
+        let start = *self.start.get_or_insert_with(Instant::now);
+        start.elapsed().as_micros() as u64
+    }
+
+    fn push(
+        &mut self,
+        collection: &'static str,
+        asset_server: &AssetServer,
+        asset: UntypedAssetId,
+        phase: TracePhase,
+        ts: u64,
+        id: u64,
+    ) {
+        let name = asset_server.get_path(asset).map_or_else(
+            || format!("{asset:?}"),
+            |path| requested_asset_path(path).to_string(),
+        );
+        self.events.push(TraceEvent {
+            name,
+            cat: collection,
+            ph: phase,
+            ts,
+            pid: 1,
+            tid: 1,
+            id,
+        });
+    }
+}
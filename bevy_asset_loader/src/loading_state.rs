@@ -1,32 +1,79 @@
+mod collection_order;
+mod config;
 mod dynamic_asset_systems;
+mod folder_watch;
+#[cfg(any(feature = "2d", feature = "3d"))]
+mod gpu_prewarm;
+mod late_collections;
+mod pipeline;
+mod rendered_frames;
+#[cfg(any(feature = "2d", feature = "3d"))]
+mod reprocess;
+mod skip_on_input;
 mod systems;
+mod unused_assets;
 
 use bevy::app::{App, Plugin};
-use bevy::asset::{Asset, UntypedHandle};
+use bevy::asset::{Asset, AssetPath, AssetServer, UntypedAssetId, UntypedHandle};
 use bevy::ecs::{
+    component::Component,
+    entity::Entity,
+    event::Event,
     schedule::{
         common_conditions::in_state, InternedScheduleLabel, IntoSystemConfigs,
         IntoSystemSetConfigs, NextState, OnEnter, ScheduleLabel, State, States, SystemSet,
     },
     system::Resource,
-    world::FromWorld,
+    world::{FromWorld, World},
 };
-use bevy::prelude::{StateTransition, Update};
+use bevy::prelude::{Last, Res, Startup, StateTransition, Update};
+use bevy::tasks::TaskPool;
 use bevy::utils::{default, HashMap, HashSet};
 use std::any::TypeId;
 use std::marker::PhantomData;
+use std::sync::Arc;
+use std::time::Duration;
 
-use crate::asset_collection::AssetCollection;
+use crate::asset_collection::{
+    AssetByteBudget, AssetCollection, AssetLoadThrottle, CollectionBuildSettings, HandleRegistry,
+    MissingRuntimePaths,
+};
 use crate::dynamic_asset::{DynamicAssetCollection, DynamicAssetCollections};
+use crate::placeholder::init_placeholders;
+use crate::runtime_collection::{
+    insert_runtime_collection_builder, AssetCollectionBuilder, RuntimeAssetCollection,
+    RuntimeCollectionBuilders,
+};
+
+pub use config::{LoadingStateConfig, LoadingStateRegistry};
+pub use folder_watch::FolderContentsChanged;
+#[cfg(any(feature = "2d", feature = "3d"))]
+pub use gpu_prewarm::GpuPrewarm;
+pub use late_collections::LoadingStateCommandsExt;
+pub use pipeline::LoadingStatePipeline;
+pub use unused_assets::UnusedAssets;
 
 use systems::{
-    check_loading_collection, finish_loading_state, init_resource, initialize_loading_state,
-    reset_loading_state, resume_to_finalize, start_loading_collection,
+    apply_pending_transition, check_loading_collection, finish_loading_state, init_resource,
+    initialize_loading_state, insert_collection_as_components, receive_continue_loading,
+    register_collection_handles, reset_loading_state, resume_to_finalize, start_loading_collection,
 };
 
+use collection_order::CollectionOrder;
+use folder_watch::watch_collection_folders;
+#[cfg(any(feature = "2d", feature = "3d"))]
+use gpu_prewarm::{init_gpu_prewarm, reset_gpu_prewarm};
+use late_collections::{check_late_collections, start_late_collections, LateCollections};
+use rendered_frames::init_rendered_frames;
+#[cfg(any(feature = "2d", feature = "3d"))]
+use reprocess::reprocess_modified_images;
+use skip_on_input::{init_input_events, skip_on_input};
+use unused_assets::report_unused_assets;
+
 use dynamic_asset_systems::{
     check_dynamic_asset_collections, load_dynamic_asset_collections,
-    resume_to_loading_asset_collections,
+    reload_collection_with_dynamic_assets, reload_dynamic_asset_collections,
+    resume_to_loading_asset_collections, DynamicAssetsReloads, WatchedDynamicCollections,
 };
 
 #[cfg(feature = "standard_dynamic_assets")]
@@ -38,8 +85,18 @@ use crate::standard_dynamic_asset::{StandardDynamicAsset, StandardDynamicAssetCo
 #[cfg(feature = "progress_tracking")]
 use iyes_progress::TrackedProgressSet;
 
-use crate::dynamic_asset::{DynamicAsset, DynamicAssets};
-use crate::loading_state::systems::{apply_internal_state_transition, run_loading_state};
+#[cfg(feature = "texture_packer")]
+use crate::texture_packer::register_texture_packer_loader;
+
+#[cfg(feature = "2d")]
+use crate::atlas_layout::register_atlas_definition_loader;
+
+use crate::dynamic_asset::{DynamicAsset, DynamicAssets, MissingDynamicAssets};
+pub(crate) use crate::loading_state::systems::requested_asset_path;
+use crate::loading_state::systems::{
+    apply_internal_state_transition, loading_state_is_active, run_loading_state,
+    warn_if_not_watching_for_changes,
+};
 
 /// A Bevy plugin to configure automatic asset loading
 ///
@@ -94,11 +151,37 @@ use crate::loading_state::systems::{apply_internal_state_transition, run_loading
 pub struct LoadingState<State> {
     next_state: Option<State>,
     failure_state: Option<State>,
+    fail_fast: bool,
+    cleanup_after: bool,
+    wait_for_continue: bool,
+    skip_on_input: bool,
+    transition_at_end_of_frame: bool,
+    after_first_render: bool,
+    byte_budget: Option<u64>,
+    asset_size_estimates: HashMap<String, u64>,
+    max_concurrent_loads: Option<usize>,
+    max_loads_per_frame: Option<usize>,
+    retries: u32,
+    retry_delay: Option<Duration>,
+    #[cfg(feature = "progress_tracking")]
+    weight_by_asset_count: bool,
+    #[cfg(feature = "checksums")]
+    hash_collections: bool,
+    hot_reload: bool,
+    report_unused_assets: bool,
+    track_dependencies: bool,
+    #[cfg(any(feature = "2d", feature = "3d"))]
+    prewarm_gpu: bool,
+    parallel_build_threshold: Option<usize>,
+    build_task_pool: Option<Arc<TaskPool>>,
     loading_state: State,
     dynamic_assets: HashMap<String, Box<dyn DynamicAsset>>,
+    metadata: HashMap<String, String>,
 
     #[cfg(feature = "standard_dynamic_assets")]
     standard_dynamic_asset_collection_file_endings: Vec<&'static str>,
+    #[cfg(feature = "standard_dynamic_assets")]
+    dynamic_asset_files: Vec<String>,
 }
 
 impl<S> LoadingState<S>
@@ -151,10 +234,36 @@ where
         Self {
             next_state: None,
             failure_state: None,
+            fail_fast: false,
+            cleanup_after: false,
+            wait_for_continue: false,
+            skip_on_input: false,
+            transition_at_end_of_frame: false,
+            after_first_render: false,
+            byte_budget: None,
+            asset_size_estimates: HashMap::default(),
+            max_concurrent_loads: None,
+            max_loads_per_frame: None,
+            retries: 0,
+            retry_delay: None,
+            #[cfg(feature = "progress_tracking")]
+            weight_by_asset_count: false,
+            #[cfg(feature = "checksums")]
+            hash_collections: false,
+            hot_reload: false,
+            report_unused_assets: false,
+            track_dependencies: false,
+            #[cfg(any(feature = "2d", feature = "3d"))]
+            prewarm_gpu: false,
+            parallel_build_threshold: None,
+            build_task_pool: None,
             loading_state: load,
             dynamic_assets: HashMap::default(),
+            metadata: HashMap::default(),
             #[cfg(feature = "standard_dynamic_assets")]
             standard_dynamic_asset_collection_file_endings: vec!["assets.ron"],
+            #[cfg(feature = "standard_dynamic_assets")]
+            dynamic_asset_files: vec![],
         }
     }
 
@@ -197,6 +306,11 @@ where
     /// #     pub tree: Handle<Image>,
     /// # }
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Adding the loading state panics if `next` is the loading state itself.
+    /// Such a loading state would reload its collections forever.
     #[must_use]
     pub fn continue_to_state(mut self, next: S) -> Self {
         self.next_state = Some(next);
@@ -243,43 +357,100 @@ where
         self
     }
 
-    /// Insert a map of asset keys with corresponding standard dynamic assets
+    /// Stop loading at the first asset that fails and set the given failure state
+    ///
+    /// Like [`on_failure_continue_to_state`](Self::on_failure_continue_to_state), but the loading state
+    /// gives up as soon as an asset fails. It does not start queued loads and stops checking its other
+    /// collections, so no further failures are reported. [`FailedAssets`] holds only the first failed path.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .fail_fast(GameState::Error)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Error,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
     #[must_use]
-    #[cfg(feature = "standard_dynamic_assets")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "standard_dynamic_assets")))]
-    pub fn add_standard_dynamic_assets(
-        mut self,
-        mut dynamic_assets: HashMap<String, StandardDynamicAsset>,
-    ) -> Self {
-        dynamic_assets.drain().for_each(|(key, value)| {
-            self.dynamic_assets.insert(key, Box::new(value));
-        });
+    pub fn fail_fast(mut self, failure: S) -> Self {
+        self.failure_state = Some(failure);
+        self.fail_fast = true;
 
         self
     }
 
-    /// Set all file endings that should be loaded as [`StandardDynamicAssetCollection`].
+    /// Stop running the [`LoadingState`] once all asset collections are loaded
     ///
-    /// The default file ending is `.assets`
+    /// Without a next state, the loading state keeps checking its (finished) internal state
+    /// every frame. With this option, the system running the loading state is disabled after
+    /// completion. Re-entering the loading state will enable it again.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .cleanup_after()
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
     #[must_use]
-    #[cfg_attr(docsrs, doc(cfg(feature = "standard_dynamic_assets")))]
-    #[cfg(feature = "standard_dynamic_assets")]
-    pub fn set_standard_dynamic_asset_collection_file_endings(
-        mut self,
-        endings: Vec<&'static str>,
-    ) -> Self {
-        self.standard_dynamic_asset_collection_file_endings = endings;
+    pub fn cleanup_after(mut self) -> Self {
+        self.cleanup_after = true;
 
         self
     }
 
-    /// Finish configuring the [`LoadingState`]
+    /// Wait for a [`ContinueLoading`] event before leaving the [`LoadingState`]
     ///
-    /// Calling this function is required to set up the asset loading.
-    /// Most of the time you do not want to call this method directly though, but complete the setup
-    /// using [`LoadingStateAppExt::add_loading_state`].
+    /// This is meant for "Press any key to continue" loading screens. All collections are loaded and
+    /// inserted as usual, but the loading state only continues to the next state after receiving the event.
+    /// An event sent while the loading state is still loading is remembered and the state continues
+    /// as soon as loading is done.
     /// ```edition2021
     /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy_asset_loader::loading_state::ContinueLoading;
     /// # use bevy::prelude::*;
     /// # use bevy::asset::AssetPlugin;
     /// # fn main() {
@@ -287,15 +458,22 @@ where
     /// #       .add_state::<GameState>()
     /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
     /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    /// #       .init_resource::<Input<KeyCode>>()
     ///         .add_loading_state(
     ///           LoadingState::new(GameState::Loading)
     ///             .continue_to_state(GameState::Menu)
+    ///             .wait_for_continue_event()
     ///         )
-    ///         .add_collection_to_loading_state::<_, AudioAssets>(GameState::Loading)
-    ///         .add_collection_to_loading_state::<_, ImageAssets>(GameState::Loading)
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    ///         .add_systems(Update, press_any_key.run_if(in_state(GameState::Loading)))
     /// #       .set_runner(|mut app| app.update())
     /// #       .run();
     /// # }
+    /// fn press_any_key(keys: Res<Input<KeyCode>>, mut continue_loading: EventWriter<ContinueLoading>) {
+    ///     if keys.get_just_pressed().next().is_some() {
+    ///         continue_loading.send(ContinueLoading);
+    ///     }
+    /// }
     /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
     /// # enum GameState {
     /// #     #[default]
@@ -303,88 +481,998 @@ where
     /// #     Menu
     /// # }
     /// # #[derive(AssetCollection, Resource)]
-    /// # pub struct AudioAssets {
+    /// # pub struct MyAssets {
     /// #     #[asset(path = "audio/background.ogg")]
     /// #     pub background: Handle<AudioSource>,
     /// # }
+    /// ```
+    #[must_use]
+    pub fn wait_for_continue_event(mut self) -> Self {
+        self.wait_for_continue = true;
+
+        self
+    }
+
+    /// Leave the [`LoadingState`] early when a key, mouse button, gamepad button, or touch is pressed
+    ///
+    /// This is meant as a manual override, e.g. for kiosk demos. After the input, collections that are
+    /// completely loaded are still inserted as usual, but collections that are still loading are skipped and
+    /// their resources are not inserted. Dynamic asset collection files are awaited before any collection
+    /// starts loading, since collections might depend on them. A skipped loading state does not wait for
+    /// [`LoadingState::wait_for_continue_event`].
+    ///
+    /// Systems in the next state should use `Option<Res<MyAssets>>` for collections that might be skipped.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .skip_on_input()
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
     /// # #[derive(AssetCollection, Resource)]
-    /// # pub struct ImageAssets {
-    /// #     #[asset(path = "images/player.png")]
-    /// #     pub player: Handle<Image>,
-    /// #     #[asset(path = "images/tree.png")]
-    /// #     pub tree: Handle<Image>,
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
     /// # }
     /// ```
-    #[allow(unused_mut)]
-    pub fn build(mut self, app: &mut App) {
-        app.init_resource::<AssetLoaderConfiguration<S>>();
-        {
-            let mut asset_loader_configuration = app
-                .world
-                .get_resource_mut::<AssetLoaderConfiguration<S>>()
-                .unwrap();
-            let mut loading_config = asset_loader_configuration
-                .state_configurations
-                .remove(&self.loading_state)
-                .unwrap_or_default();
-            if self.next_state.is_some() {
-                loading_config.next = self.next_state;
-            }
-            if self.failure_state.is_some() {
-                loading_config.failure = self.failure_state;
-            }
-            asset_loader_configuration
-                .state_configurations
-                .insert(self.loading_state.clone(), loading_config);
-        }
-        app.init_resource::<State<InternalLoadingState<S>>>();
-        app.init_resource::<NextState<InternalLoadingState<S>>>();
+    #[must_use]
+    pub fn skip_on_input(mut self) -> Self {
+        self.skip_on_input = true;
 
-        app.init_resource::<DynamicAssetCollections<S>>();
-        #[cfg(feature = "standard_dynamic_assets")]
-        if !app.is_plugin_added::<RonAssetPlugin<StandardDynamicAssetCollection>>() {
-            app.add_plugins(RonAssetPlugin::<StandardDynamicAssetCollection>::new(
-                &self.standard_dynamic_asset_collection_file_endings,
-            ));
-        }
+        self
+    }
 
-        if !app.is_plugin_added::<InternalAssetLoaderPlugin<S>>() {
-            app.add_plugins(InternalAssetLoaderPlugin::<S>::new());
-        }
+    /// Set the next state at the end of the frame in which the [`LoadingState`] finishes
+    ///
+    /// By default, the next state is set while the loading state runs in [`Update`] (see [`LoadingStateSet`]).
+    /// With this option, finishing the loading state only records the transition and [`NextState`] is set in the
+    /// [`Last`] schedule in [`LoadingStateTransitionSet`]. All systems in [`Update`] and [`PostUpdate`](bevy::app::PostUpdate) of that frame
+    /// observe the final progress while [`NextState`] is still unchanged. Either way, the state changes
+    /// in the [`StateTransition`] schedule of the following frame.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy_asset_loader::loading_state::LoadingStateTransitionSet;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .transition_at_end_of_frame()
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    ///         .add_systems(Last, log_progress.before(LoadingStateTransitionSet(GameState::Loading)))
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// fn log_progress(my_assets: Option<Res<MyAssets>>) {
+    ///     info!("Assets loaded: {}", my_assets.is_some());
+    /// }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn transition_at_end_of_frame(mut self) -> Self {
+        self.transition_at_end_of_frame = true;
 
-        app.init_resource::<LoadingStateSchedules<S>>();
+        self
+    }
 
-        let loading_state_schedule = LoadingStateSchedule(self.loading_state.clone());
-        let configure_loading_state = app.get_schedule(loading_state_schedule.clone()).is_none();
-        app.init_schedule(loading_state_schedule.clone())
-            .init_schedule(OnEnterInternalLoadingState(
-                self.loading_state.clone(),
-                InternalLoadingState::LoadingAssets,
-            ))
-            .init_schedule(OnEnterInternalLoadingState(
-                self.loading_state.clone(),
-                InternalLoadingState::LoadingDynamicAssetCollections,
-            ))
-            .init_schedule(OnEnterInternalLoadingState(
-                self.loading_state.clone(),
-                InternalLoadingState::Finalize,
-            ));
+    /// Only continue to the next state after a frame with all collections loaded has been rendered
+    ///
+    /// This is useful for screenshots or setup that depends on the first rendered frame of the loaded assets.
+    /// The loading state waits for the frame after it is otherwise done, including [`LoadingState::wait_for_continue_event`].
+    /// A frame counts as rendered once the render app reaches the cleanup of its `Render` schedule for it. Without
+    /// a render app (e.g. headless or without the `2d` and `3d` features), a frame counts as rendered at the end of the
+    /// [`Last`] schedule. This delays the transition by at least one frame; with pipelined rendering it can be two.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .after_first_render()
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn after_first_render(mut self) -> Self {
+        self.after_first_render = true;
 
-        if configure_loading_state {
+        self
+    }
+
+    /// Declare that the assets of this [`LoadingState`] are meant to be hot reloaded
+    ///
+    /// The [`AssetServer`](bevy::asset::AssetServer) is global, so this cannot enable watching for changes.
+    /// Instead, a warning is logged on startup if the asset server is not watching the default asset
+    /// source for changes. Enable Bevy's `file_watcher` feature to watch for changes.
+    ///
+    /// Folders loaded into `collection` fields are listed about once per second while the collection exists.
+    /// Added files are loaded and then added to the fields, removed files are removed from them. Every change
+    /// is reported with a [`FolderContentsChanged`] event. Listing folders does not depend on the `file_watcher` feature.
+    ///
+    /// Dynamic asset files of the loading state are kept alive. If one of them changes, its keys are registered again
+    /// and all existing collections of the loading state are loaded and created again.
+    ///
+    /// With the feature `2d` or `3d`, the [processed images](crate::asset_collection::AssetCollection::processed_images)
+    /// of existing collections are kept loaded. If one of them is modified, the collection is created again, which
+    /// rebuilds its texture atlases, images with a sampler, and standard materials.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .with_hot_reload()
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_hot_reload(mut self) -> Self {
+        self.hot_reload = true;
+
+        self
+    }
+
+    /// Report files next to the assets of this [`LoadingState`]'s collections that no field uses
+    ///
+    /// After a collection is inserted, the folders loaded by its `collection` fields (including sub folders)
+    /// and the folders containing its other assets are listed once all collections of the loading state are loaded.
+    /// Files that no loaded asset uses are logged as a warning and stored in the [`UnusedAssets`] resource. This helps finding dead content
+    /// and should not be used in release builds, since listing folders is not free.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .report_unused_assets()
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn report_unused_assets(mut self) -> Self {
+        self.report_unused_assets = true;
+
+        self
+    }
+
+    /// Wait for the dependencies of loaded assets before counting them as loaded
+    ///
+    /// Asset loaders can load other assets as dependencies, e.g. the textures of a material loaded by a
+    /// third-party plugin. By default, an asset of a collection counts as loaded as soon as its own loader
+    /// finished. With this option, it only counts as loaded (and as progress) once all its dependencies loaded,
+    /// and it counts as failed if one of them failed to load.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .track_dependencies()
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn track_dependencies(mut self) -> Self {
+        self.track_dependencies = true;
+
+        self
+    }
+
+    /// Wait for the images of all collections to be prepared on the GPU before leaving this [`LoadingState`]
+    ///
+    /// Bevy uploads images to the GPU in the render app after they are loaded. Without prewarming, this can
+    /// happen in the first frames of the next state and cause a hitch. With prewarming, the loading state only
+    /// finishes once the render app reports all images as prepared. Progress is reported in the [`GpuPrewarm`] resource.
+    ///
+    /// This is best effort: without a render app, the loading state does not wait, and images that are not prepared
+    /// after 120 frames are skipped with a warning.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .prewarm_gpu()
+    ///         )
+    ///         .add_collection_to_loading_state::<_, ImageAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct ImageAssets {
+    /// #     #[asset(path = "images/player.png")]
+    /// #     pub player: Handle<Image>,
+    /// # }
+    /// ```
+    #[must_use]
+    #[cfg(any(feature = "2d", feature = "3d"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "2d", feature = "3d"))))]
+    pub fn prewarm_gpu(mut self) -> Self {
+        self.prewarm_gpu = true;
+
+        self
+    }
+
+    /// Limit the estimated number of bytes loaded by the collections of this [`LoadingState`]
+    ///
+    /// Assets are admitted in the order they are requested until their estimated sizes
+    /// (see [`LoadingState::estimate_asset_size`]) would exceed the budget. Assets past the budget are
+    /// deferred: the loading state does not wait for them and they only start loading when their collection
+    /// is created. The paths of deferred assets are reported in the [`DeferredAssets`] resource.
+    /// Assets with an estimate keep using the budget as long as they are loaded, also across entries of the
+    /// loading state. See [`AssetByteBudget`] for the kinds of assets that are subject to the budget.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .with_byte_budget(64 * 1024 * 1024)
+    ///             .estimate_asset_size("audio/background.ogg", 48 * 1024 * 1024)
+    ///             .estimate_asset_size("audio/ambience.ogg", 32 * 1024 * 1024)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_byte_budget(mut self, bytes: u64) -> Self {
+        self.byte_budget = Some(bytes);
+
+        self
+    }
+
+    /// Estimate the size of the asset at the given path in bytes
+    ///
+    /// Estimates are only used together with [`LoadingState::with_byte_budget`].
+    /// Assets without an estimate count as zero bytes.
+    #[must_use]
+    pub fn estimate_asset_size(mut self, path: impl Into<String>, bytes: u64) -> Self {
+        self.asset_size_estimates.insert(path.into(), bytes);
+
+        self
+    }
+
+    /// Limit the number of assets of this [`LoadingState`] that load at the same time
+    ///
+    /// Assets are queued in the order they are requested and only start loading while fewer than
+    /// `max` assets of the loading state are still loading. This keeps slow sources (e.g. a web server)
+    /// from being flooded with requests. Like the byte budget, the limit applies to assets with a plain `path`.
+    /// Folders, file lists, dynamic assets and assets that need processing are always loaded right away.
+    /// A limit of `0` is treated as `1`.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .max_concurrent_loads(4)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn max_concurrent_loads(mut self, max: usize) -> Self {
+        self.max_concurrent_loads = Some(max);
+
+        self
+    }
+
+    /// Limit the number of assets of this [`LoadingState`] that start loading in a single frame
+    ///
+    /// Starting hundreds of loads at once can cause a visible hitch on disk-backed IO. With this limit,
+    /// assets are queued in the order they are requested and at most `max` of them start loading per frame.
+    /// Queued assets count towards the total progress of the loading state. It can be combined with
+    /// [`LoadingState::max_concurrent_loads`]. Like that limit, it applies to assets with a plain `path`.
+    /// A limit of `0` is treated as `1`.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .with_max_loads_per_frame(32)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_max_loads_per_frame(mut self, max: usize) -> Self {
+        self.max_loads_per_frame = Some(max);
+
+        self
+    }
+
+    /// Load assets of this [`LoadingState`] again if they fail to load
+    ///
+    /// A failed asset is requested from the asset server again until it was retried `attempts` times.
+    /// Only then the failure counts for [`LoadingState::on_failure_continue_to_state`] and [`LoadingState::fail_fast`].
+    /// This helps with asset sources that fail now and then, e.g. a web server.
+    /// Attempts are counted per asset path. The delay before a retry doubles with every attempt,
+    /// starting at 250 milliseconds (see [`LoadingState::with_retry_delay`]).
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .on_failure_continue_to_state(GameState::Error)
+    ///             .with_retries(3)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Error,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_retries(mut self, attempts: u32) -> Self {
+        self.retries = attempts;
+
+        self
+    }
+
+    /// Set the delay before the first retry of a failed asset, see [`LoadingState::with_retries`]
+    ///
+    /// The delay doubles with every further attempt for the same asset path.
+    /// A delay of zero retries failed assets right away.
+    #[must_use]
+    pub fn with_retry_delay(mut self, delay: Duration) -> Self {
+        self.retry_delay = Some(delay);
+
+        self
+    }
+
+    /// Attach a metadata entry to this [`LoadingState`], e.g. a display name for tools like editors
+    ///
+    /// The loading state itself ignores metadata. All entries can be read with
+    /// [`LoadingStateRegistry::metadata`]. Setting an existing key replaces its value.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .with_metadata("display_name", "Main menu assets")
+    ///             .with_metadata("description", "Music and images of the main menu")
+    ///         )
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+
+        self
+    }
+
+    /// Count the files of loaded folders in the progress of this [`LoadingState`]
+    ///
+    /// By default, every handle of a collection counts as one unit of progress. A folder is a single handle,
+    /// so a collection with a folder of a hundred files weighs the same as a collection with a single asset.
+    /// With this option, a folder counts as the number of files in it. The files of a folder are only known
+    /// once the folder is loaded, so until then the folder counts as one unit and the total grows afterwards.
+    #[must_use]
+    #[cfg(feature = "progress_tracking")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "progress_tracking")))]
+    pub fn weight_by_asset_count(mut self) -> Self {
+        self.weight_by_asset_count = true;
+
+        self
+    }
+
+    /// Hash the files of every collection loaded in this [`LoadingState`]
+    ///
+    /// Once all assets of a collection are loaded, their files are read from the asset sources and
+    /// hashed together with SHA-256. The collection is inserted when the hash is done, together with
+    /// a [`CollectionHash`](crate::checksum::CollectionHash) resource holding it.
+    /// Identical files result in the same hash, independent of the order of the fields in the collection.
+    /// This is meant for cache invalidation and costs reading every file a second time.
+    #[must_use]
+    #[cfg(feature = "checksums")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "checksums")))]
+    pub fn hash_collections(mut self) -> Self {
+        self.hash_collections = true;
+
+        self
+    }
+
+    /// Build the derived assets of a collection in parallel if it has at least `threshold` of them
+    ///
+    /// Currently, this applies to texture atlases. They are built in the [`ComputeTaskPool`](bevy::tasks::ComputeTaskPool)
+    /// unless another task pool is configured with [`LoadingState::build_in_task_pool`].
+    /// The built assets are inserted serially afterwards.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .build_in_parallel(16)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn build_in_parallel(mut self, threshold: usize) -> Self {
+        self.parallel_build_threshold = Some(threshold);
+
+        self
+    }
+
+    /// Use the given task pool to build derived assets in parallel
+    ///
+    /// See [`LoadingState::build_in_parallel`].
+    #[must_use]
+    pub fn build_in_task_pool(mut self, task_pool: Arc<TaskPool>) -> Self {
+        self.build_task_pool = Some(task_pool);
+
+        self
+    }
+
+    /// Insert a map of asset keys with corresponding standard dynamic assets
+    #[must_use]
+    #[cfg(feature = "standard_dynamic_assets")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "standard_dynamic_assets")))]
+    pub fn add_standard_dynamic_assets(
+        mut self,
+        mut dynamic_assets: HashMap<String, StandardDynamicAsset>,
+    ) -> Self {
+        dynamic_assets.drain().for_each(|(key, value)| {
+            self.dynamic_assets.insert(key, Box::new(value));
+        });
+
+        self
+    }
+
+    /// Load a file of standard dynamic assets every time the loading state is entered
+    ///
+    /// The keys of the file are registered in [`DynamicAssets`] before any asset collection of the
+    /// loading state is loaded, so fields like `#[asset(key = "player")]` resolve to the paths in the file.
+    /// Changing the file changes the loaded assets without recompiling.
+    /// This is a shortcut for [`LoadingStateAppExt::add_dynamic_collection_to_loading_state`]
+    /// with a [`StandardDynamicAssetCollection`].
+    ///
+    /// The file needs one of the configured file endings (see
+    /// [`set_standard_dynamic_asset_collection_file_endings`](Self::set_standard_dynamic_asset_collection_file_endings)).
+    /// If a required key is missing from the file, the field and key are reported in [`MissingDynamicAssets`]
+    /// and the loading state continues to its failure state.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///             .on_failure_continue_to_state(GameState::Error)
+    ///             .with_dynamic_assets_file("dynamic_asset.assets.ron")
+    ///         )
+    ///         .add_collection_to_loading_state::<_, MyAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Error,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct MyAssets {
+    /// #     #[asset(key = "sounds.background")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    #[must_use]
+    #[cfg(feature = "standard_dynamic_assets")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "standard_dynamic_assets")))]
+    pub fn with_dynamic_assets_file(mut self, file: impl Into<String>) -> Self {
+        self.dynamic_asset_files.push(file.into());
+
+        self
+    }
+
+    /// Set all file endings that should be loaded as [`StandardDynamicAssetCollection`].
+    ///
+    /// The default file ending is `.assets`
+    #[must_use]
+    #[cfg_attr(docsrs, doc(cfg(feature = "standard_dynamic_assets")))]
+    #[cfg(feature = "standard_dynamic_assets")]
+    pub fn set_standard_dynamic_asset_collection_file_endings(
+        mut self,
+        endings: Vec<&'static str>,
+    ) -> Self {
+        self.standard_dynamic_asset_collection_file_endings = endings;
+
+        self
+    }
+
+    /// Finish configuring the [`LoadingState`]
+    ///
+    /// Calling this function is required to set up the asset loading.
+    /// Most of the time you do not want to call this method directly though, but complete the setup
+    /// using [`LoadingStateAppExt::add_loading_state`].
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, AudioAssets>(GameState::Loading)
+    ///         .add_collection_to_loading_state::<_, ImageAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct AudioAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct ImageAssets {
+    /// #     #[asset(path = "images/player.png")]
+    /// #     pub player: Handle<Image>,
+    /// #     #[asset(path = "images/tree.png")]
+    /// #     pub tree: Handle<Image>,
+    /// # }
+    /// ```
+    #[allow(unused_mut)]
+    pub fn build(mut self, app: &mut App) {
+        if self.next_state.as_ref() == Some(&self.loading_state) {
+            panic!(
+                "The loading state {:?} is configured to continue to itself, which would reload its collections forever. Use a different state in `continue_to_state`.",
+                self.loading_state
+            );
+        }
+        app.init_resource::<AssetLoaderConfiguration<S>>();
+        {
+            let mut asset_loader_configuration = app
+                .world
+                .get_resource_mut::<AssetLoaderConfiguration<S>>()
+                .unwrap();
+            let mut loading_config = asset_loader_configuration
+                .state_configurations
+                .remove(&self.loading_state)
+                .unwrap_or_default();
+            if self.next_state.is_some() {
+                loading_config.next = self.next_state;
+            }
+            if self.failure_state.is_some() {
+                loading_config.failure = self.failure_state;
+            }
+            if self.fail_fast {
+                loading_config.fail_fast = true;
+            }
+            if self.cleanup_after {
+                loading_config.cleanup_after = true;
+            }
+            if self.wait_for_continue {
+                loading_config.wait_for_continue = true;
+            }
+            if self.skip_on_input && !loading_config.skip_on_input {
+                loading_config.skip_on_input = true;
+            } else {
+                // the input system was already added for this state
+                self.skip_on_input = false;
+            }
+            if self.transition_at_end_of_frame {
+                loading_config.transition_at_end_of_frame = true;
+            }
+            if self.after_first_render {
+                loading_config.after_first_render = true;
+            }
+            if self.hot_reload {
+                loading_config.hot_reload = true;
+            }
+            if self.report_unused_assets {
+                loading_config.report_unused_assets = true;
+            }
+            if self.track_dependencies {
+                loading_config.track_dependencies = true;
+            }
+            #[cfg(any(feature = "2d", feature = "3d"))]
+            if self.prewarm_gpu {
+                loading_config.prewarm_gpu = true;
+            }
+            #[cfg(feature = "progress_tracking")]
+            if self.weight_by_asset_count {
+                loading_config.weight_by_asset_count = true;
+            }
+            #[cfg(feature = "checksums")]
+            if self.hash_collections {
+                loading_config.hash_collections = true;
+            }
+            if let Some(bytes) = self.byte_budget {
+                loading_config
+                    .byte_budget
+                    .get_or_insert_with(default)
+                    .budget = bytes;
+            }
+            if !self.asset_size_estimates.is_empty() {
+                loading_config
+                    .byte_budget
+                    .get_or_insert_with(|| AssetByteBudget::new(u64::MAX))
+                    .estimates
+                    .extend(self.asset_size_estimates.drain());
+            }
+            if self.max_concurrent_loads.is_some() || self.max_loads_per_frame.is_some() {
+                loading_config.load_throttle = Some(AssetLoadThrottle::new(
+                    self.max_concurrent_loads.unwrap_or(usize::MAX),
+                    self.max_loads_per_frame.unwrap_or(usize::MAX),
+                ));
+            }
+            if self.retries > 0 {
+                loading_config.retries = self.retries;
+            }
+            if let Some(delay) = self.retry_delay {
+                loading_config.retry_delay = delay;
+            }
+            loading_config.metadata.extend(self.metadata.drain());
+            if let Some(threshold) = self.parallel_build_threshold {
+                loading_config.build_settings.parallel_threshold = threshold;
+            }
+            if self.build_task_pool.is_some() {
+                loading_config.build_settings.task_pool = self.build_task_pool;
+            }
+            asset_loader_configuration
+                .state_configurations
+                .insert(self.loading_state.clone(), loading_config);
+        }
+        app.add_event::<AssetLoadFailed>();
+        app.add_event::<AssetCollectionLoaded>();
+        #[cfg(feature = "load_trace")]
+        app.init_resource::<crate::load_trace::LoadTrace>();
+        app.add_event::<ContinueLoading>();
+        app.add_event::<FolderContentsChanged>();
+        app.init_resource::<DeferredAssets>();
+        app.init_resource::<LoadingProgress>();
+        app.init_resource::<FailedAssets>();
+        app.init_resource::<MissingDynamicAssets>();
+        app.init_resource::<MissingRuntimePaths>();
+        app.init_resource::<UnusedAssets>();
+        app.init_resource::<State<InternalLoadingState<S>>>();
+        app.init_resource::<NextState<InternalLoadingState<S>>>();
+
+        app.init_resource::<DynamicAssetCollections<S>>();
+        app.init_resource::<DynamicAssetsReloads<S>>();
+        app.init_resource::<LateCollections<S>>();
+        #[cfg(feature = "standard_dynamic_assets")]
+        if !app.is_plugin_added::<RonAssetPlugin<StandardDynamicAssetCollection>>() {
+            app.add_plugins(RonAssetPlugin::<StandardDynamicAssetCollection>::new(
+                &self.standard_dynamic_asset_collection_file_endings,
+            ));
+        }
+
+        #[cfg(feature = "texture_packer")]
+        register_texture_packer_loader(app);
+        #[cfg(feature = "2d")]
+        register_atlas_definition_loader(app);
+        #[cfg(feature = "audio")]
+        crate::audio::register_audio_durations(app);
+
+        #[cfg(any(feature = "2d", feature = "3d"))]
+        if self.prewarm_gpu {
+            init_gpu_prewarm(app);
+            app.add_systems(OnEnter(self.loading_state.clone()), reset_gpu_prewarm::<S>);
+        }
+
+        if self.after_first_render {
+            init_rendered_frames(app);
+        }
+
+        init_placeholders(app);
+
+        if self.hot_reload {
+            let loading_state = self.loading_state.clone();
+            app.add_systems(Startup, move |asset_server: Res<AssetServer>| {
+                warn_if_not_watching_for_changes(&loading_state, &asset_server)
+            });
+        }
+
+        if !app.is_plugin_added::<InternalAssetLoaderPlugin<S>>() {
+            app.add_plugins(InternalAssetLoaderPlugin::<S>::new());
+        }
+
+        let loading_state_schedule = LoadingStateSchedule(self.loading_state.clone());
+        app.init_resource::<LoadingStateSchedules<S>>();
+        app.world
+            .resource_mut::<LoadingStateSchedules<S>>()
+            .schedules
+            .insert(self.loading_state.clone(), loading_state_schedule.intern());
+        let configure_loading_state = app.get_schedule(loading_state_schedule.clone()).is_none();
+        app.init_schedule(loading_state_schedule.clone())
+            .init_schedule(OnEnterInternalLoadingState(
+                self.loading_state.clone(),
+                InternalLoadingState::LoadingAssets,
+            ))
+            .init_schedule(OnEnterInternalLoadingState(
+                self.loading_state.clone(),
+                InternalLoadingState::LoadingDynamicAssetCollections,
+            ))
+            .init_schedule(OnEnterInternalLoadingState(
+                self.loading_state.clone(),
+                InternalLoadingState::Finalize,
+            ));
+
+        if configure_loading_state {
             app.add_systems(
                 loading_state_schedule.clone(),
                 (
                     resume_to_loading_asset_collections::<S>
                         .in_set(InternalLoadingStateSet::ResumeDynamicAssetCollections),
                     initialize_loading_state::<S>.in_set(InternalLoadingStateSet::Initialize),
+                    check_late_collections::<S>.in_set(InternalLoadingStateSet::CheckAssets),
                     resume_to_finalize::<S>.in_set(InternalLoadingStateSet::CheckAssets),
                     finish_loading_state::<S>.in_set(InternalLoadingStateSet::Finalize),
+                    receive_continue_loading::<S>.before(InternalLoadingStateSet::Finalize),
                 ),
             )
             .add_systems(
                 OnEnter(self.loading_state.clone()),
                 reset_loading_state::<S>,
             )
+            .add_systems(
+                OnEnterInternalLoadingState(
+                    self.loading_state.clone(),
+                    InternalLoadingState::LoadingAssets,
+                ),
+                start_late_collections::<S>,
+            )
+            .add_systems(
+                Last,
+                apply_pending_transition::<S>
+                    .in_set(LoadingStateTransitionSet(self.loading_state.clone()))
+                    .run_if(in_state(self.loading_state.clone())),
+            )
             .configure_sets(Update, LoadingStateSet(self.loading_state.clone()));
             let mut loading_state_schedule = app.get_schedule_mut(loading_state_schedule).unwrap();
             loading_state_schedule
@@ -412,6 +1500,17 @@ where
                     InternalLoadingStateSet::Finalize
                         .run_if(in_state(InternalLoadingState::<S>::Finalize)),
                 );
+            app.configure_sets(
+                OnEnterInternalLoadingState(
+                    self.loading_state.clone(),
+                    InternalLoadingState::Finalize,
+                ),
+                (
+                    FinalizeCollectionSet::RegisterHandles,
+                    FinalizeCollectionSet::InsertComponents,
+                )
+                    .chain(),
+            );
 
             #[cfg(feature = "standard_dynamic_assets")]
             app.register_dynamic_asset_collection::<_, StandardDynamicAssetCollection>(
@@ -424,14 +1523,24 @@ where
                 run_loading_state::<S>
                     .in_set(TrackedProgressSet)
                     .in_set(LoadingStateSet(self.loading_state.clone()))
-                    .run_if(in_state(self.loading_state)),
+                    .run_if(in_state(self.loading_state.clone()))
+                    .run_if(loading_state_is_active::<S>),
             );
             #[cfg(not(feature = "progress_tracking"))]
             app.add_systems(
                 Update,
                 run_loading_state::<S>
                     .in_set(LoadingStateSet(self.loading_state.clone()))
-                    .run_if(in_state(self.loading_state)),
+                    .run_if(in_state(self.loading_state.clone()))
+                    .run_if(loading_state_is_active::<S>),
+            );
+        }
+
+        if self.skip_on_input {
+            init_input_events(app);
+            app.add_systems(
+                LoadingStateSchedule(self.loading_state.clone()),
+                skip_on_input::<S>.before(InternalLoadingStateSet::CheckAssets),
             );
         }
 
@@ -440,13 +1549,44 @@ where
         for (key, asset) in self.dynamic_assets {
             dynamic_assets.register_asset(key, asset);
         }
+        #[cfg(feature = "standard_dynamic_assets")]
+        for file in &self.dynamic_asset_files {
+            app.add_dynamic_collection_to_loading_state::<_, StandardDynamicAssetCollection>(
+                self.loading_state.clone(),
+                file,
+            );
+        }
     }
 }
 
-///  Systems in this set check the loading state of assets and will change the [`InternalLoadingState`] accordingly.
+/// The system running a [`LoadingState`] is in this set
+///
+/// The system checks the assets of all collections and continues to the next state once they are loaded.
+/// It runs in the [`Update`] schedule, with or without the `progress_tracking` feature.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy::prelude::*;
+/// # fn main() {
+///     App::new()
+/// #       .add_state::<GameState>()
+///         .add_systems(Update, count_frames.before(LoadingStateSet(GameState::Loading)));
+/// # }
+/// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+/// # enum GameState {
+/// #     #[default]
+/// #     Loading,
+/// #     Menu
+/// # }
+/// # fn count_frames() {}
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
 pub struct LoadingStateSet<S: States>(pub S);
 
+/// The system in this set sets the next state of a [`LoadingState`] configured with
+/// [`LoadingState::transition_at_end_of_frame`]. It runs in the [`Last`] schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+pub struct LoadingStateTransitionSet<S: States>(pub S);
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
 pub(crate) enum InternalLoadingStateSet {
     Initialize,
@@ -456,6 +1596,16 @@ pub(crate) enum InternalLoadingStateSet {
     Finalize,
 }
 
+/// Order of the systems handing loaded collections to registries and entities when a loading state finalizes
+///
+/// Handles are registered before collections are inserted as components,
+/// so a collection can be added to both.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+pub(crate) enum FinalizeCollectionSet {
+    RegisterHandles,
+    InsertComponents,
+}
+
 #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
 pub(crate) struct OnEnterInternalLoadingState<S: States>(pub S, pub InternalLoadingState<S>);
 #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
@@ -476,11 +1626,226 @@ pub(crate) enum InternalLoadingState<S: States> {
     Done(PhantomData<S>),
 }
 
+/// Sent when an asset collection of a loading state finished loading and was inserted
+///
+/// Collections finish independently of each other, so systems can react to a single collection
+/// (e.g. start the menu music) while other collections of the loading state are still loading.
+/// ```edition2021
+/// # use bevy_asset_loader::loading_state::AssetCollectionLoaded;
+/// # use bevy::prelude::*;
+/// # #[derive(Resource)]
+/// # struct AudioAssets;
+/// fn start_music(mut loaded: EventReader<AssetCollectionLoaded>) {
+///     if loaded.read().any(|loaded| loaded.is::<AudioAssets>()) {
+///         // play the menu music
+///     }
+/// }
+/// ```
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssetCollectionLoaded {
+    /// Type name of the asset collection
+    pub collection: &'static str,
+}
+
+impl AssetCollectionLoaded {
+    /// Whether the loaded collection is of the given type
+    pub fn is<A: Resource>(&self) -> bool {
+        self.collection == std::any::type_name::<A>()
+    }
+}
+
+/// Sent once for every handle of an asset collection that failed to load
+///
+/// With the `checksums` feature, it is also sent for every asset whose file does not match its `sha256` checksum.
+///
+/// The event is sent in addition to moving to the failure state of the loading state (if configured).
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct AssetLoadFailed {
+    /// Type name of the asset collection that failed to load
+    pub collection: &'static str,
+    /// Path of the asset that failed to load
+    pub path: String,
+}
+
+/// Paths of all assets that failed to load in the current loading state
+///
+/// The list is cleared when a loading state is entered and keeps its content after continuing to the
+/// failure state (see [`LoadingState::on_failure_continue_to_state`]), so an error screen can show the failed files.
+/// Every path is also reported by an [`AssetLoadFailed`] event.
+/// ```edition2021
+/// # use bevy_asset_loader::loading_state::FailedAssets;
+/// # use bevy::prelude::*;
+/// fn show_errors(failed: Res<FailedAssets>) {
+///     for path in &failed.0 {
+///         error!("Failed to load '{path}'");
+///     }
+/// }
+/// ```
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct FailedAssets(pub Vec<String>);
+
+/// Check whether the asset at the given path finished loading
+///
+/// This can be used for fine-grained loading screens, e.g. to show text as soon as a font is ready.
+/// The function returns `true` once the asset and all of its dependencies are loaded.
+/// Folders are expanded into their files, so assets of a folder are queried by their own path
+/// (e.g. `images/player.png` for the folder `images`). The folder path itself only reports `true`
+/// while a handle to the folder is alive. Collections do not keep folder handles, so after a
+/// collection is inserted, the folder path reports `false`.
+///
+/// Paths that were never requested, failed to load, or have no strong handle left return `false`.
+pub fn loading_state_asset_loaded<'a>(world: &World, path: impl Into<AssetPath<'a>>) -> bool {
+    let Some(asset_server) = world.get_resource::<AssetServer>() else {
+        return false;
+    };
+    asset_server
+        .get_handle_untyped(path)
+        .is_some_and(|handle| asset_server.is_loaded_with_dependencies(handle.id()))
+}
+
+/// Send this event to leave a loading state configured with [`LoadingState::wait_for_continue_event`]
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ContinueLoading;
+
+/// Assets that were deferred by the byte budget of a loading state
+///
+/// See [`LoadingState::with_byte_budget`]. The list is cleared when a loading state with a byte budget is entered.
+#[derive(Resource, Debug, Default)]
+pub struct DeferredAssets {
+    /// Paths of all deferred assets in the order they were requested
+    pub paths: Vec<String>,
+}
+
+/// Number of loaded assets of the active loading state
+///
+/// The resource is reset when a loading state is entered and updated every frame while its collections load.
+/// Every handle of all collections added with [`LoadingStateAppExt::add_collection_to_loading_state`] is counted,
+/// including the image handles of texture atlases. Loaded folders are counted by their files.
+/// Once the loading state is done, the progress is frozen at 100% until the next loading state is entered.
+/// ```edition2021
+/// # use bevy_asset_loader::loading_state::LoadingProgress;
+/// # use bevy::prelude::*;
+/// fn print_progress(progress: Res<LoadingProgress>) {
+///     info!(
+///         "Loaded {} of {} assets ({:.0}%)",
+///         progress.loaded,
+///         progress.total,
+///         progress.fraction() * 100.
+///     );
+/// }
+/// ```
+#[derive(Resource, Debug, Clone, Default)]
+pub struct LoadingProgress {
+    /// Number of loaded assets
+    pub loaded: usize,
+    /// Number of all assets of the loading state that started loading so far
+    pub total: usize,
+    finished: bool,
+    collections: HashMap<TypeId, (usize, usize)>,
+}
+
+impl LoadingProgress {
+    /// Loaded fraction of all assets between `0.` and `1.`
+    ///
+    /// This is `1.` once the loading state is done, even if it did not load any assets.
+    pub fn fraction(&self) -> f32 {
+        if self.finished {
+            return 1.;
+        }
+        if self.total == 0 {
+            return 0.;
+        }
+        self.loaded as f32 / self.total as f32
+    }
+
+    /// Whether the loading state is done
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    pub(crate) fn update_collection<Assets: 'static>(&mut self, loaded: usize, total: usize) {
+        self.collections
+            .insert(TypeId::of::<Assets>(), (loaded, total));
+        self.sum_collections();
+    }
+
+    pub(crate) fn complete_collection<Assets: 'static>(&mut self) {
+        if let Some(&(_, total)) = self.collections.get(&TypeId::of::<Assets>()) {
+            self.update_collection::<Assets>(total, total);
+        }
+    }
+
+    pub(crate) fn finish(&mut self) {
+        self.loaded = self.total;
+        self.finished = true;
+    }
+
+    pub(crate) fn reopen(&mut self) {
+        self.sum_collections();
+        self.finished = false;
+    }
+
+    fn sum_collections(&mut self) {
+        (self.loaded, self.total) = self
+            .collections
+            .values()
+            .fold((0, 0), |(loaded, total), collection| {
+                (loaded + collection.0, total + collection.1)
+            });
+    }
+}
+
+/// Collections added to loading states, so that the systems of a collection are only added once per loading state
+#[derive(Resource)]
+pub(crate) struct LoadingStateCollections<S: States> {
+    collections: HashSet<(S, TypeId)>,
+}
+
+impl<S: States> Default for LoadingStateCollections<S> {
+    fn default() -> Self {
+        LoadingStateCollections {
+            collections: default(),
+        }
+    }
+}
+
+/// Entities that receive an [`AssetCollection`] as component once a loading state finalizes
+#[derive(Resource)]
+pub(crate) struct CollectionEntities<S: States, A> {
+    entities: HashMap<S, Vec<Entity>>,
+    marker: PhantomData<A>,
+}
+
+impl<S: States, A> Default for CollectionEntities<S, A> {
+    fn default() -> Self {
+        CollectionEntities {
+            entities: default(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<S: States, A> CollectionEntities<S, A> {
+    pub(crate) fn get(&self, state: &S) -> &[Entity] {
+        self.entities.get(state).map_or(&[], Vec::as_slice)
+    }
+}
+
 /// This resource is used for handles from asset collections and loading dynamic asset collection files.
 /// The generic will be the [`AssetCollection`] type for the first and the [`DynamicAssetCollection`] for the second.
 #[derive(Resource)]
 pub(crate) struct LoadingAssetHandles<T> {
     handles: Vec<UntypedHandle>,
+    queued: Vec<String>,
+    /// Dynamic assets of the collection without a registered key and runtime paths without a path
+    missing_assets: usize,
+    reported_failures: HashSet<UntypedAssetId>,
+    /// Failed assets that are waiting to be loaded again
+    retrying: Vec<RetryingAsset>,
+    /// Retries per asset path, see [`LoadingState::with_retries`]
+    attempts: HashMap<String, u32>,
+    /// See [`LoadingState::track_dependencies`]
+    track_dependencies: bool,
     marker: PhantomData<T>,
 }
 
@@ -488,11 +1853,35 @@ impl<T> Default for LoadingAssetHandles<T> {
     fn default() -> Self {
         LoadingAssetHandles {
             handles: Default::default(),
+            queued: Default::default(),
+            missing_assets: 0,
+            reported_failures: Default::default(),
+            retrying: Default::default(),
+            attempts: Default::default(),
+            track_dependencies: false,
             marker: Default::default(),
         }
     }
 }
 
+/// A failed asset of a collection that is loaded again after a delay
+struct RetryingAsset {
+    path: String,
+    folder: bool,
+    /// Elapsed [`Time`](bevy::time::Time) at which the asset is requested again
+    retry_at: Duration,
+}
+
+type ConstructorFn<A> = dyn Fn(Vec<UntypedHandle>, &mut World) -> A + Send + Sync;
+
+/// Constructs an asset collection instead of [`AssetCollection::create`], see [`LoadingStateAppExt::build_with`]
+#[derive(Resource)]
+pub(crate) struct CollectionConstructor<A>(pub(crate) Box<ConstructorFn<A>>);
+
+/// A created asset collection waiting for [`AssetCollection::ready`]
+#[derive(Resource)]
+pub(crate) struct UnreadyCollection<A>(pub(crate) A);
+
 #[derive(Resource)]
 pub(crate) struct AssetLoaderConfiguration<State: States> {
     state_configurations: HashMap<State, LoadingConfiguration<State>>,
@@ -509,9 +1898,38 @@ impl<State: States> Default for AssetLoaderConfiguration<State> {
 struct LoadingConfiguration<State: States> {
     next: Option<State>,
     failure: Option<State>,
+    fail_fast: bool,
+    cleanup_after: bool,
+    wait_for_continue: bool,
+    continue_requested: bool,
+    skip_on_input: bool,
+    /// Input was received in a loading state configured with [`LoadingState::skip_on_input`]
+    skip_requested: bool,
+    transition_at_end_of_frame: bool,
+    after_first_render: bool,
+    /// Rendered frames when the loading state was ready to continue, see [`LoadingState::after_first_render`]
+    finished_at_frame: Option<u64>,
+    hot_reload: bool,
+    report_unused_assets: bool,
+    track_dependencies: bool,
+    #[cfg(any(feature = "2d", feature = "3d"))]
+    prewarm_gpu: bool,
+    pending_transition: Option<State>,
+    byte_budget: Option<AssetByteBudget>,
+    load_throttle: Option<AssetLoadThrottle>,
+    retries: u32,
+    retry_delay: Duration,
+    #[cfg(feature = "progress_tracking")]
+    weight_by_asset_count: bool,
+    #[cfg(feature = "checksums")]
+    hash_collections: bool,
+    build_settings: CollectionBuildSettings,
     loading_failed: bool,
     loading_collections: usize,
+    collections: HashSet<TypeId>,
+    loaded_collections: HashSet<TypeId>,
     loading_dynamic_collections: HashSet<TypeId>,
+    metadata: HashMap<String, String>,
 }
 
 impl<State: States> Default for LoadingConfiguration<State> {
@@ -519,9 +1937,36 @@ impl<State: States> Default for LoadingConfiguration<State> {
         LoadingConfiguration {
             next: None,
             failure: None,
+            fail_fast: false,
+            cleanup_after: false,
+            wait_for_continue: false,
+            continue_requested: false,
+            skip_on_input: false,
+            skip_requested: false,
+            transition_at_end_of_frame: false,
+            after_first_render: false,
+            finished_at_frame: None,
+            hot_reload: false,
+            report_unused_assets: false,
+            track_dependencies: false,
+            #[cfg(any(feature = "2d", feature = "3d"))]
+            prewarm_gpu: false,
+            pending_transition: None,
+            byte_budget: None,
+            load_throttle: None,
+            retries: 0,
+            retry_delay: Duration::from_millis(250),
+            #[cfg(feature = "progress_tracking")]
+            weight_by_asset_count: false,
+            #[cfg(feature = "checksums")]
+            hash_collections: false,
+            build_settings: default(),
             loading_failed: false,
             loading_collections: 0,
+            collections: default(),
+            loaded_collections: default(),
             loading_dynamic_collections: default(),
+            metadata: default(),
         }
     }
 }
@@ -546,9 +1991,16 @@ pub trait LoadingStateAppExt {
     /// Add a loading state to your app
     fn add_loading_state<S: States>(&mut self, loading_state: LoadingState<S>) -> &mut Self;
 
+    /// Add the loading states of a [`LoadingStatePipeline`] to your app
+    fn add_loading_state_pipeline<S: States>(
+        &mut self,
+        pipeline: LoadingStatePipeline<S>,
+    ) -> &mut Self;
+
     /// Add an [`AssetCollection`] to the [`LoadingState`]
     ///
     /// The added collection will be loaded and inserted into your Bevy app as a resource.
+    /// Adding the same collection to a loading state again has no effect.
     /// ```edition2021
     /// # use bevy_asset_loader::prelude::*;
     /// # use bevy::prelude::*;
@@ -591,6 +2043,151 @@ pub trait LoadingStateAppExt {
         loading_state: S,
     ) -> &mut Self;
 
+    /// Add an [`AssetCollection`] to the [`LoadingState`] and merge its handles into a [`HandleRegistry`]
+    ///
+    /// The collection is loaded like any other collection, but instead of being inserted as a resource,
+    /// [`HandleRegistry::register`] is called for every handle of the collection when the loading state finalizes.
+    /// The registry resource has to exist at that point.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy_asset_loader::asset_collection::HandleRegistry;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # use bevy::utils::HashMap;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .init_resource::<SoundRegistry>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///         )
+    ///         .add_collection_to_registry::<_, AudioAssets, SoundRegistry>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// #[derive(Resource, Default)]
+    /// struct SoundRegistry {
+    ///     sounds: HashMap<&'static str, UntypedHandle>,
+    /// }
+    ///
+    /// impl HandleRegistry for SoundRegistry {
+    ///     fn register(&mut self, field: &'static str, handle: UntypedHandle) {
+    ///         self.sounds.insert(field, handle);
+    ///     }
+    /// }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct AudioAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    fn add_collection_to_registry<S: States, A: AssetCollection, R: HandleRegistry>(
+        &mut self,
+        loading_state: S,
+    ) -> &mut Self;
+
+    /// Add an [`AssetCollection`] to the [`LoadingState`] and insert it as component on the given entity
+    ///
+    /// The collection is loaded like any other collection, but instead of being inserted as a resource,
+    /// it is inserted as component on `entity` when the loading state finalizes.
+    /// The collection can be added for multiple entities. Every entity gets its own clone of the collection.
+    /// Entities that do not exist anymore are skipped with a warning. If the collection is also added to a
+    /// [`HandleRegistry`] with [`LoadingStateAppExt::add_collection_to_registry`], its handles are registered
+    /// before it is inserted as components.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     let mut app = App::new();
+    /// #   app
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>();
+    ///     let player_one = app.world.spawn_empty().id();
+    ///     let player_two = app.world.spawn_empty().id();
+    ///     app
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///         )
+    ///         .add_collection_to_loading_state_as_component::<_, PlayerAssets>(GameState::Loading, player_one)
+    ///         .add_collection_to_loading_state_as_component::<_, PlayerAssets>(GameState::Loading, player_two)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// #[derive(AssetCollection, Resource, Component, Clone)]
+    /// pub struct PlayerAssets {
+    ///     #[asset(path = "images/player.png")]
+    ///     pub sprite: Handle<Image>,
+    /// }
+    /// ```
+    fn add_collection_to_loading_state_as_component<
+        S: States,
+        A: AssetCollection + Component + Clone,
+    >(
+        &mut self,
+        loading_state: S,
+        entity: Entity,
+    ) -> &mut Self;
+
+    /// Add a [`RuntimeAssetCollection`] declared at run time to the [`LoadingState`]
+    ///
+    /// The builder is stored for the loading state in [`RuntimeCollectionBuilders`] and the collection
+    /// is loaded like a derived collection. Once the loading state is done, [`RuntimeAssetCollection`]
+    /// is available as a resource. Calling this again for the same loading state merges the new entries
+    /// into its builder. Each loading state loads the entries of its own builder.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy_asset_loader::runtime_collection::AssetCollectionBuilder;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///         )
+    ///         .add_runtime_collection_to_loading_state(
+    ///             GameState::Loading,
+    ///             AssetCollectionBuilder::new()
+    ///                 .with_file("background", "audio/background.ogg")
+    ///                 .with_folder("images", "images"),
+    ///         )
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// ```
+    fn add_runtime_collection_to_loading_state<S: States>(
+        &mut self,
+        loading_state: S,
+        builder: AssetCollectionBuilder,
+    ) -> &mut Self;
+
     /// Register a new [`DynamicAssetCollection`] to be handled in the loading state
     ///
     /// You do not need to call this for [`StandardDynamicAssetCollection`], only if you want to use
@@ -614,6 +2211,21 @@ pub trait LoadingStateAppExt {
         file: &str,
     ) -> &mut Self;
 
+    /// Register a file to be loaded as a certain type of [`DynamicAssetCollection`] in a namespace
+    ///
+    /// Works like [`LoadingStateAppExt::add_dynamic_collection_to_loading_state`], but all keys of the
+    /// file are registered in the given namespace. Asset collections reference them as `namespace:key`,
+    /// e.g. `#[asset(key = "mod_a:boss_theme")]`. Files in different namespaces can use the same keys.
+    fn add_namespaced_dynamic_collection_to_loading_state<
+        S: States,
+        C: DynamicAssetCollection + Asset,
+    >(
+        &mut self,
+        loading_state: S,
+        namespace: &str,
+        file: &str,
+    ) -> &mut Self;
+
     /// Add any [`FromWorld`] resource to be initialized after all asset collections are loaded.
     /// ```edition2021
     /// # use bevy_asset_loader::prelude::*;
@@ -663,6 +2275,113 @@ pub trait LoadingStateAppExt {
         &mut self,
         loading_state: S,
     ) -> &mut Self;
+
+    /// Build an [`AssetCollection`] with the given closure instead of [`AssetCollection::create`]
+    ///
+    /// Once all assets of the collection are loaded, the closure receives their handles and the world.
+    /// Folders are replaced by the handles of their files, and assets loaded without a type by the
+    /// handles of the loaded assets. The handles are in the order the collection started loading them.
+    /// The closure is used in every loading state that loads the collection.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, AudioAssets>(GameState::Loading)
+    ///         .build_with(|handles, _world| AudioAssets {
+    ///             background: handles[0].clone().typed(),
+    ///         })
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct AudioAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    fn build_with<A: AssetCollection>(
+        &mut self,
+        constructor: impl Fn(Vec<UntypedHandle>, &mut World) -> A + Send + Sync + 'static,
+    ) -> &mut Self;
+
+    /// Only start loading the [`AssetCollection`] `A` in the [`LoadingState`] once the collection `Prerequisite` is loaded
+    ///
+    /// By default, all collections of a loading state load in parallel. A collection with prerequisites
+    /// waits until all of them are inserted in the given loading state, e.g. because its `FromWorld` fields
+    /// need their assets. Its assets only count towards the progress once it starts loading.
+    /// Prerequisites that are not part of the loading state are not waited for.
+    ///
+    /// The order only applies to the given loading state. Panics if `Prerequisite` already (indirectly)
+    /// waits for `A` in that loading state, since neither collection could ever start loading.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// # use bevy::asset::AssetPlugin;
+    /// # fn main() {
+    ///     App::new()
+    /// #       .add_state::<GameState>()
+    /// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+    /// #       .init_resource::<iyes_progress::ProgressCounter>()
+    ///         .add_loading_state(
+    ///           LoadingState::new(GameState::Loading)
+    ///             .continue_to_state(GameState::Menu)
+    ///         )
+    ///         .add_collection_to_loading_state::<_, AudioAssets>(GameState::Loading)
+    ///         .add_collection_to_loading_state::<_, Playlist>(GameState::Loading)
+    ///         .after_collection::<_, Playlist, AudioAssets>(GameState::Loading)
+    /// #       .set_runner(|mut app| app.update())
+    /// #       .run();
+    /// # }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct AudioAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct Playlist {
+    /// #     #[asset(path = "audio/plop.ogg")]
+    /// #     pub plop: Handle<AudioSource>,
+    /// # }
+    /// ```
+    fn after_collection<S: States, A: AssetCollection, Prerequisite: AssetCollection>(
+        &mut self,
+        loading_state: S,
+    ) -> &mut Self;
+}
+
+/// Run condition for systems of loading states configured with [`LoadingState::with_hot_reload`]
+fn hot_reload_enabled<S: States>(
+    loading_state: S,
+) -> impl FnMut(Option<Res<AssetLoaderConfiguration<S>>>) -> bool {
+    move |configuration: Option<Res<AssetLoaderConfiguration<S>>>| {
+        configuration.is_some_and(|configuration| {
+            configuration
+                .state_configurations
+                .get(&loading_state)
+                .is_some_and(|config| config.hot_reload)
+        })
+    }
 }
 
 impl LoadingStateAppExt for App {
@@ -672,24 +2391,138 @@ impl LoadingStateAppExt for App {
         self
     }
 
+    fn add_loading_state_pipeline<S: States>(
+        &mut self,
+        pipeline: LoadingStatePipeline<S>,
+    ) -> &mut Self {
+        pipeline.build(self);
+
+        self
+    }
+
     fn add_collection_to_loading_state<S: States, A: AssetCollection>(
         &mut self,
         loading_state: S,
     ) -> &mut Self {
+        // a collection can be added to a registry and as component at the same time
+        if !self
+            .world
+            .get_resource_or_insert_with(LoadingStateCollections::<S>::default)
+            .collections
+            .insert((loading_state.clone(), TypeId::of::<A>()))
+        {
+            return self;
+        }
+        #[cfg(feature = "collection_diagnostics")]
+        crate::collection_registry::register_loading_state_collection::<S, A>(
+            self,
+            loading_state.clone(),
+        );
         self.add_systems(
             OnEnterInternalLoadingState(loading_state.clone(), InternalLoadingState::LoadingAssets),
             start_loading_collection::<S, A>,
         )
         .add_systems(
-            LoadingStateSchedule(loading_state),
+            LoadingStateSchedule(loading_state.clone()),
             check_loading_collection::<S, A>.in_set(InternalLoadingStateSet::CheckAssets),
         )
+        .add_systems(
+            Update,
+            (
+                watch_collection_folders::<A>.run_if(hot_reload_enabled(loading_state.clone())),
+                {
+                    let loading_state = loading_state.clone();
+                    move |world: &mut World| {
+                        reload_collection_with_dynamic_assets::<S, A>(world, &loading_state)
+                    }
+                }
+                .run_if(hot_reload_enabled(loading_state.clone())),
+                #[cfg(any(feature = "2d", feature = "3d"))]
+                reprocess_modified_images::<A>.run_if(hot_reload_enabled(loading_state.clone())),
+                report_unused_assets::<A>.run_if(
+                    move |configuration: Option<Res<AssetLoaderConfiguration<S>>>| {
+                        configuration.is_some_and(|configuration| {
+                            configuration
+                                .state_configurations
+                                .get(&loading_state)
+                                .is_some_and(|config| {
+                                    config.report_unused_assets && config.loading_collections == 0
+                                })
+                        })
+                    },
+                ),
+            ),
+        )
+    }
+
+    fn add_collection_to_registry<S: States, A: AssetCollection, R: HandleRegistry>(
+        &mut self,
+        loading_state: S,
+    ) -> &mut Self {
+        self.add_collection_to_loading_state::<S, A>(loading_state.clone())
+            .add_systems(
+                OnEnterInternalLoadingState(loading_state, InternalLoadingState::Finalize),
+                register_collection_handles::<S, A, R>
+                    .in_set(FinalizeCollectionSet::RegisterHandles),
+            )
+    }
+
+    fn add_collection_to_loading_state_as_component<
+        S: States,
+        A: AssetCollection + Component + Clone,
+    >(
+        &mut self,
+        loading_state: S,
+        entity: Entity,
+    ) -> &mut Self {
+        let mut targets = self
+            .world
+            .get_resource_or_insert_with(CollectionEntities::<S, A>::default);
+        if let Some(entities) = targets.entities.get_mut(&loading_state) {
+            entities.push(entity);
+            return self;
+        }
+        targets.entities.insert(loading_state.clone(), vec![entity]);
+
+        self.add_collection_to_loading_state::<S, A>(loading_state.clone())
+            .add_systems(
+                OnEnterInternalLoadingState(loading_state, InternalLoadingState::Finalize),
+                insert_collection_as_components::<S, A>
+                    .in_set(FinalizeCollectionSet::InsertComponents),
+            )
+    }
+
+    fn add_runtime_collection_to_loading_state<S: States>(
+        &mut self,
+        loading_state: S,
+        builder: AssetCollectionBuilder,
+    ) -> &mut Self {
+        if self
+            .world
+            .get_resource_or_insert_with(RuntimeCollectionBuilders::<S>::default)
+            .merge(loading_state.clone(), builder)
+        {
+            return self;
+        }
+        self.add_systems(
+            OnEnterInternalLoadingState(loading_state.clone(), InternalLoadingState::LoadingAssets),
+            insert_runtime_collection_builder::<S>
+                .before(start_loading_collection::<S, RuntimeAssetCollection>),
+        )
+        .add_collection_to_loading_state::<S, RuntimeAssetCollection>(loading_state)
     }
 
     fn register_dynamic_asset_collection<S: States, C: DynamicAssetCollection + Asset>(
         &mut self,
         loading_state: S,
     ) -> &mut Self {
+        if !self
+            .world
+            .contains_resource::<WatchedDynamicCollections<S, C>>()
+        {
+            self.init_resource::<WatchedDynamicCollections<S, C>>()
+                .add_systems(Update, reload_dynamic_asset_collections::<S, C>);
+        }
         self.add_systems(
             OnEnterInternalLoadingState(
                 loading_state.clone(),
@@ -718,6 +2551,24 @@ impl LoadingStateAppExt for App {
         self
     }
 
+    fn add_namespaced_dynamic_collection_to_loading_state<
+        S: States,
+        C: DynamicAssetCollection + Asset,
+    >(
+        &mut self,
+        loading_state: S,
+        namespace: &str,
+        file: &str,
+    ) -> &mut Self {
+        let mut dynamic_asset_collections = self
+            .world
+            .get_resource_mut::<DynamicAssetCollections<S>>()
+            .unwrap();
+
+        dynamic_asset_collections.register_namespaced_file::<C>(loading_state, namespace, file);
+        self
+    }
+
     fn init_resource_after_loading_state<S: States, A: Resource + FromWorld>(
         &mut self,
         loading_state: S,
@@ -727,6 +2578,24 @@ impl LoadingStateAppExt for App {
             init_resource::<A>,
         )
     }
+
+    fn build_with<A: AssetCollection>(
+        &mut self,
+        constructor: impl Fn(Vec<UntypedHandle>, &mut World) -> A + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.insert_resource(CollectionConstructor(Box::new(constructor)))
+    }
+
+    fn after_collection<S: States, A: AssetCollection, Prerequisite: AssetCollection>(
+        &mut self,
+        loading_state: S,
+    ) -> &mut Self {
+        self.world
+            .get_resource_or_insert_with(CollectionOrder::<S>::default)
+            .add::<A, Prerequisite>(loading_state);
+
+        self
+    }
 }
 
 struct InternalAssetLoaderPlugin<S> {
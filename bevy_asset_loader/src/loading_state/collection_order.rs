@@ -0,0 +1,145 @@
+use bevy::ecs::schedule::{State, States};
+use bevy::ecs::system::{Resource, SystemState};
+use bevy::ecs::world::World;
+use bevy::log::debug;
+use bevy::utils::HashMap;
+use std::any::{type_name, TypeId};
+use std::marker::PhantomData;
+
+use crate::asset_collection::AssetCollection;
+use crate::loading_state::systems::{begin_loading_collection, finish_loading_collection};
+use crate::loading_state::AssetLoaderConfiguration;
+
+/// Collections that only start loading once other collections of their loading state are loaded
+#[derive(Resource)]
+pub(crate) struct CollectionOrder<S: States> {
+    prerequisites: HashMap<(S, TypeId), Vec<TypeId>>,
+    names: HashMap<TypeId, &'static str>,
+}
+
+impl<S: States> Default for CollectionOrder<S> {
+    fn default() -> Self {
+        CollectionOrder {
+            prerequisites: HashMap::default(),
+            names: HashMap::default(),
+        }
+    }
+}
+
+impl<S: States> CollectionOrder<S> {
+    /// Let `A` wait for `Prerequisite` in the given loading state
+    ///
+    /// Panics if `Prerequisite` already waits for `A` in that loading state, because neither collection would ever start loading.
+    pub(crate) fn add<A: AssetCollection, Prerequisite: AssetCollection>(&mut self, state: S) {
+        let collection = TypeId::of::<A>();
+        let prerequisite = TypeId::of::<Prerequisite>();
+        self.names.insert(collection, type_name::<A>());
+        self.names.insert(prerequisite, type_name::<Prerequisite>());
+        if let Some(cycle) = self.path(&state, prerequisite, collection) {
+            let cycle: Vec<_> = cycle.iter().map(|id| self.names[id]).collect();
+            panic!(
+                "The asset collection {} cannot load after {} in the loading state {:?}, because the collections would wait for each other: {} -> {}",
+                type_name::<A>(),
+                type_name::<Prerequisite>(),
+                state,
+                type_name::<A>(),
+                cycle.join(" -> ")
+            );
+        }
+        let prerequisites = self.prerequisites.entry((state, collection)).or_default();
+        if !prerequisites.contains(&prerequisite) {
+            prerequisites.push(prerequisite);
+        }
+    }
+
+    /// Collections from `from` to `to` following the prerequisites of the loading state, if `from` waits for `to`
+    fn path(&self, state: &S, from: TypeId, to: TypeId) -> Option<Vec<TypeId>> {
+        if from == to {
+            return Some(vec![to]);
+        }
+        self.prerequisites
+            .get(&(state.clone(), from))?
+            .iter()
+            .find_map(|&next| self.path(state, next, to))
+            .map(|mut path| {
+                path.insert(0, from);
+                path
+            })
+    }
+}
+
+/// Marks a collection that waits for its prerequisites before it starts loading
+#[derive(Resource)]
+struct WaitingCollection<A>(PhantomData<fn() -> A>);
+
+/// Record a collection of the active loading state and let it wait for its prerequisites
+///
+/// Returns `true` if the collection has prerequisites. It then counts as a loading collection of the
+/// loading state, but only starts loading in [`start_when_prerequisites_loaded`].
+pub(crate) fn wait_for_prerequisites<S: States, A: AssetCollection>(world: &mut World) -> bool {
+    let state = world.resource::<State<S>>().get().clone();
+    let has_prerequisites = world
+        .get_resource::<CollectionOrder<S>>()
+        .is_some_and(|order| {
+            order
+                .prerequisites
+                .contains_key(&(state.clone(), TypeId::of::<A>()))
+        });
+    let mut asset_loader_configuration = world.resource_mut::<AssetLoaderConfiguration<S>>();
+    let Some(config) = asset_loader_configuration
+        .state_configurations
+        .get_mut(&state)
+    else {
+        return false;
+    };
+    config.collections.insert(TypeId::of::<A>());
+    if !has_prerequisites {
+        return false;
+    }
+    debug!(
+        "The collection {} waits for its prerequisites",
+        type_name::<A>()
+    );
+    config.loading_collections += 1;
+    world.insert_resource(WaitingCollection::<A>(PhantomData));
+
+    true
+}
+
+/// Start loading a waiting collection once all its prerequisites are loaded
+///
+/// Prerequisites that are not part of the active loading state are not waited for.
+/// Returns `true` while the collection is waiting.
+pub(crate) fn start_when_prerequisites_loaded<S: States, A: AssetCollection>(
+    world: &mut World,
+) -> bool {
+    if !world.contains_resource::<WaitingCollection<A>>() {
+        return false;
+    }
+    let state = world.resource::<State<S>>().get().clone();
+    let asset_loader_configuration = world.resource::<AssetLoaderConfiguration<S>>();
+    let Some(config) = asset_loader_configuration.state_configurations.get(&state) else {
+        return true;
+    };
+    if config.skip_requested {
+        world.remove_resource::<WaitingCollection<A>>();
+        finish_loading_collection::<S>(world);
+        return true;
+    }
+    let order = world.resource::<CollectionOrder<S>>();
+    let ready = order.prerequisites[&(state.clone(), TypeId::of::<A>())]
+        .iter()
+        .all(|prerequisite| {
+            config.loaded_collections.contains(prerequisite)
+                || !config.collections.contains(prerequisite)
+        });
+    if !ready {
+        return true;
+    }
+    world.remove_resource::<WaitingCollection<A>>();
+    finish_loading_collection::<S>(world);
+    let mut system_state = SystemState::new(world);
+    begin_loading_collection::<S, A>(world, &mut system_state);
+
+    false
+}
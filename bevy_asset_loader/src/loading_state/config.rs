@@ -0,0 +1,241 @@
+use crate::asset_collection::AssetCollection;
+use crate::loading_state::{AssetLoaderConfiguration, LoadingState, LoadingStateAppExt};
+use anyhow::anyhow;
+use bevy::app::App;
+use bevy::ecs::schedule::States;
+use bevy::ecs::world::World;
+use bevy::utils::HashMap;
+
+#[cfg(feature = "standard_dynamic_assets")]
+use crate::standard_dynamic_asset::StandardDynamicAssetCollection;
+
+/// Data describing a [`LoadingState`], e.g. deserialized from a config file
+///
+/// States and asset collections are referenced by name. A [`LoadingStateRegistry`]
+/// resolves the names and adds the configured loading state to an app.
+/// With the feature `standard_dynamic_assets`, the config can be deserialized.
+/// All fields except `state` are optional then.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(
+    feature = "standard_dynamic_assets",
+    derive(serde::Deserialize),
+    serde(default)
+)]
+pub struct LoadingStateConfig {
+    /// Name of the state to load the collections in
+    pub state: String,
+    /// Name of the state to continue to, see [`LoadingState::continue_to_state`]
+    pub next_state: Option<String>,
+    /// Name of the state to continue to on failure, see [`LoadingState::on_failure_continue_to_state`]
+    pub failure_state: Option<String>,
+    /// Give up at the first failed asset, see [`LoadingState::fail_fast`]
+    ///
+    /// Only used together with `failure_state`.
+    pub fail_fast: bool,
+    /// Names of the asset collections to load
+    pub collections: Vec<String>,
+    /// See [`LoadingState::cleanup_after`]
+    pub cleanup_after: bool,
+    /// See [`LoadingState::wait_for_continue_event`]
+    pub wait_for_continue_event: bool,
+    /// See [`LoadingState::skip_on_input`]
+    pub skip_on_input: bool,
+    /// See [`LoadingState::transition_at_end_of_frame`]
+    pub transition_at_end_of_frame: bool,
+    /// See [`LoadingState::with_hot_reload`]
+    pub hot_reload: bool,
+    /// See [`LoadingState::report_unused_assets`]
+    pub report_unused_assets: bool,
+    /// See [`LoadingState::with_byte_budget`]
+    pub byte_budget: Option<u64>,
+    /// See [`LoadingState::max_concurrent_loads`]
+    pub max_concurrent_loads: Option<usize>,
+    /// See [`LoadingState::with_max_loads_per_frame`]
+    pub max_loads_per_frame: Option<usize>,
+    /// See [`LoadingState::with_retries`]
+    pub retries: u32,
+    /// See [`LoadingState::with_metadata`]
+    pub metadata: HashMap<String, String>,
+    /// Files with standard dynamic assets to load before the collections
+    #[cfg(feature = "standard_dynamic_assets")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "standard_dynamic_assets")))]
+    pub dynamic_asset_files: Vec<String>,
+}
+
+/// Maps names used in a [`LoadingStateConfig`] to states and asset collections
+///
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy_asset_loader::loading_state::{LoadingStateConfig, LoadingStateRegistry};
+/// # use bevy::prelude::*;
+/// # use bevy::asset::AssetPlugin;
+/// # fn main() {
+///     let registry = LoadingStateRegistry::new()
+///         .register_state("loading", GameState::Loading)
+///         .register_state("menu", GameState::Menu)
+///         .register_collection::<AudioAssets>("audio");
+///     let config = LoadingStateConfig {
+///         state: "loading".to_owned(),
+///         next_state: Some("menu".to_owned()),
+///         collections: vec!["audio".to_owned()],
+///         ..default()
+///     };
+///
+///     let mut app = App::new();
+/// #   app.add_state::<GameState>()
+/// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+/// #       .init_resource::<iyes_progress::ProgressCounter>();
+///     registry
+///         .add_to_app(&mut app, &config)
+///         .expect("Invalid loading state config");
+/// # }
+/// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+/// # enum GameState {
+/// #     #[default]
+/// #     Loading,
+/// #     Menu
+/// # }
+/// # #[derive(AssetCollection, Resource)]
+/// # pub struct AudioAssets {
+/// #     #[asset(path = "audio/background.ogg")]
+/// #     pub background: Handle<AudioSource>,
+/// # }
+/// ```
+pub struct LoadingStateRegistry<S: States> {
+    states: HashMap<String, S>,
+    collections: HashMap<String, fn(&mut App, S)>,
+}
+
+impl<S: States> Default for LoadingStateRegistry<S> {
+    fn default() -> Self {
+        LoadingStateRegistry {
+            states: HashMap::default(),
+            collections: HashMap::default(),
+        }
+    }
+}
+
+impl<S: States> LoadingStateRegistry<S> {
+    /// Create an empty registry
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Make a state available under the given name
+    #[must_use]
+    pub fn register_state(mut self, name: impl Into<String>, state: S) -> Self {
+        self.states.insert(name.into(), state);
+
+        self
+    }
+
+    /// Make an asset collection available under the given name
+    #[must_use]
+    pub fn register_collection<A: AssetCollection>(mut self, name: impl Into<String>) -> Self {
+        self.collections.insert(name.into(), |app, state| {
+            app.add_collection_to_loading_state::<S, A>(state);
+        });
+
+        self
+    }
+
+    /// Add the loading state described by the config to the app
+    ///
+    /// All names in the config are resolved before the app is changed. An unknown name
+    /// results in an error and leaves the app untouched.
+    pub fn add_to_app(&self, app: &mut App, config: &LoadingStateConfig) -> anyhow::Result<()> {
+        let state = self.state(&config.state)?;
+        let mut loading_state = LoadingState::new(state.clone());
+        if let Some(next) = &config.next_state {
+            loading_state = loading_state.continue_to_state(self.state(next)?);
+        }
+        if let Some(failure) = &config.failure_state {
+            let failure = self.state(failure)?;
+            loading_state = if config.fail_fast {
+                loading_state.fail_fast(failure)
+            } else {
+                loading_state.on_failure_continue_to_state(failure)
+            };
+        }
+        if config.cleanup_after {
+            loading_state = loading_state.cleanup_after();
+        }
+        if config.wait_for_continue_event {
+            loading_state = loading_state.wait_for_continue_event();
+        }
+        if config.skip_on_input {
+            loading_state = loading_state.skip_on_input();
+        }
+        if config.transition_at_end_of_frame {
+            loading_state = loading_state.transition_at_end_of_frame();
+        }
+        if config.hot_reload {
+            loading_state = loading_state.with_hot_reload();
+        }
+        if config.report_unused_assets {
+            loading_state = loading_state.report_unused_assets();
+        }
+        if let Some(bytes) = config.byte_budget {
+            loading_state = loading_state.with_byte_budget(bytes);
+        }
+        if let Some(max) = config.max_concurrent_loads {
+            loading_state = loading_state.max_concurrent_loads(max);
+        }
+        if let Some(max) = config.max_loads_per_frame {
+            loading_state = loading_state.with_max_loads_per_frame(max);
+        }
+        if config.retries > 0 {
+            loading_state = loading_state.with_retries(config.retries);
+        }
+        for (key, value) in &config.metadata {
+            loading_state = loading_state.with_metadata(key, value);
+        }
+        let collections = config
+            .collections
+            .iter()
+            .map(|name| {
+                self.collections.get(name).ok_or_else(|| {
+                    anyhow!("Unknown asset collection '{name}' in loading state config")
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        app.add_loading_state(loading_state);
+        #[cfg(feature = "standard_dynamic_assets")]
+        for file in &config.dynamic_asset_files {
+            app.add_dynamic_collection_to_loading_state::<S, StandardDynamicAssetCollection>(
+                state.clone(),
+                file,
+            );
+        }
+        for add_collection in collections {
+            add_collection(app, state.clone());
+        }
+
+        Ok(())
+    }
+
+    /// The metadata of the loading state with the given name, see [`LoadingState::with_metadata`]
+    ///
+    /// Returns `None` if the name is unknown or no loading state was added for the state.
+    pub fn metadata<'w>(
+        &self,
+        world: &'w World,
+        name: &str,
+    ) -> Option<&'w HashMap<String, String>> {
+        let state = self.states.get(name)?;
+        world
+            .get_resource::<AssetLoaderConfiguration<S>>()?
+            .state_configurations
+            .get(state)
+            .map(|config| &config.metadata)
+    }
+
+    fn state(&self, name: &str) -> anyhow::Result<S> {
+        self.states
+            .get(name)
+            .cloned()
+            .ok_or_else(|| anyhow!("Unknown state '{name}' in loading state config"))
+    }
+}
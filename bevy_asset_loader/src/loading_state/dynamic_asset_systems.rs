@@ -1,12 +1,70 @@
+use crate::asset_collection::AssetCollection;
 use crate::dynamic_asset::{DynamicAssetCollection, DynamicAssetCollections, DynamicAssets};
+use crate::loading_state::systems::load_collection;
 use crate::loading_state::{AssetLoaderConfiguration, InternalLoadingState, LoadingAssetHandles};
-use bevy::asset::{Asset, AssetServer, Assets, LoadState};
+use bevy::asset::{Asset, AssetEvent, AssetServer, Assets, Handle, LoadState, UntypedHandle};
 use bevy::ecs::change_detection::ResMut;
+use bevy::ecs::event::EventReader;
 use bevy::ecs::schedule::{NextState, State, States};
-use bevy::ecs::system::{Res, SystemState};
+use bevy::ecs::system::{Res, Resource, SystemState};
 use bevy::ecs::world::World;
-use bevy::log::debug;
-use std::any::TypeId;
+use bevy::log::{debug, info, warn};
+use bevy::utils::{default, HashMap, HashSet};
+use std::any::{type_name, TypeId};
+use std::marker::PhantomData;
+
+/// Dynamic asset collection files of hot reloaded loading states, kept alive to be notified about changes
+#[derive(Resource)]
+pub(crate) struct WatchedDynamicCollections<S: States, C: Asset> {
+    files: Vec<(S, Handle<C>)>,
+}
+
+impl<S: States, C: Asset> Default for WatchedDynamicCollections<S, C> {
+    fn default() -> Self {
+        WatchedDynamicCollections { files: vec![] }
+    }
+}
+
+/// The keys of dynamic assets that each loading state registered again after a file changed
+#[derive(Resource)]
+pub(crate) struct DynamicAssetsReloads<S: States> {
+    /// Keys of the changed file for every reload
+    reloads: HashMap<S, Vec<HashSet<String>>>,
+}
+
+impl<S: States> Default for DynamicAssetsReloads<S> {
+    fn default() -> Self {
+        DynamicAssetsReloads { reloads: default() }
+    }
+}
+
+impl<S: States> DynamicAssetsReloads<S> {
+    /// How often the dynamic assets of the loading state were registered again
+    fn get(&self, loading_state: &S) -> u64 {
+        self.reloads
+            .get(loading_state)
+            .map_or(0, |reloads| reloads.len() as u64)
+    }
+
+    /// All keys registered again since the given number of reloads
+    fn changed_keys_since(&self, loading_state: &S, reloads: u64) -> HashSet<String> {
+        self.reloads
+            .get(loading_state)
+            .into_iter()
+            .flat_map(|changed| changed.iter().skip(reloads as usize))
+            .flatten()
+            .cloned()
+            .collect()
+    }
+}
+
+/// Progress of rebuilding a collection after the dynamic assets of its loading state changed
+#[derive(Resource)]
+pub(crate) struct ReloadingCollection<A> {
+    reloads: u64,
+    handles: Option<Vec<UntypedHandle>>,
+    marker: PhantomData<A>,
+}
 
 #[allow(clippy::type_complexity)]
 pub(crate) fn load_dynamic_asset_collections<S: States, C: DynamicAssetCollection + Asset>(
@@ -46,16 +104,19 @@ pub(crate) fn check_dynamic_asset_collections<S: States, C: DynamicAssetCollecti
         Option<ResMut<LoadingAssetHandles<(S, C)>>>,
         Res<State<S>>,
         Res<Assets<C>>,
+        Res<DynamicAssetCollections<S>>,
         ResMut<DynamicAssets>,
         ResMut<AssetLoaderConfiguration<S>>,
     )>,
 ) {
-    {
+    let mut watched = vec![];
+    let state = {
         let (
             asset_server,
             mut loading_collections,
             state,
             dynamic_asset_collections,
+            collection_files,
             mut asset_keys,
             mut asset_loader_config,
         ) = system_state.get_mut(world);
@@ -64,6 +125,10 @@ pub(crate) fn check_dynamic_asset_collections<S: States, C: DynamicAssetCollecti
             return;
         }
         let loading_collections = loading_collections.as_mut().unwrap();
+        let hot_reload = asset_loader_config
+            .state_configurations
+            .get(state.get())
+            .is_some_and(|config| config.hot_reload);
         for handle in &loading_collections.handles {
             if let Some(load_state) = asset_server.get_load_state(handle.id()) {
                 if load_state != LoadState::Loaded {
@@ -73,11 +138,15 @@ pub(crate) fn check_dynamic_asset_collections<S: States, C: DynamicAssetCollecti
                 return;
             }
         }
-        for collection in loading_collections.handles.drain(..) {
-            let collection = dynamic_asset_collections
-                .get(collection.typed::<C>())
-                .unwrap();
-            collection.register(&mut asset_keys);
+        for handle in loading_collections.handles.drain(..) {
+            if hot_reload {
+                watched.push(handle.clone().typed::<C>());
+            }
+            let namespace = handle
+                .path()
+                .and_then(|path| collection_files.get_namespace(state.get(), &path.to_string()));
+            let collection = dynamic_asset_collections.get(handle.typed::<C>()).unwrap();
+            asset_keys.register_file_collection(namespace, collection);
         }
         let config = asset_loader_config
             .state_configurations
@@ -86,8 +155,149 @@ pub(crate) fn check_dynamic_asset_collections<S: States, C: DynamicAssetCollecti
         config
             .loading_dynamic_collections
             .remove(&TypeId::of::<C>());
-    }
+        state.get().clone()
+    };
     world.remove_resource::<LoadingAssetHandles<(S, C)>>();
+    if !watched.is_empty() {
+        let mut watched_collections =
+            world.get_resource_or_insert_with(WatchedDynamicCollections::<S, C>::default);
+        for handle in watched {
+            if !watched_collections
+                .files
+                .iter()
+                .any(|(watched_state, watched)| watched_state == &state && watched == &handle)
+            {
+                watched_collections.files.push((state.clone(), handle));
+            }
+        }
+    }
+}
+
+/// Register the keys of modified dynamic asset collection files of a hot reloaded loading state again
+pub(crate) fn reload_dynamic_asset_collections<S: States, C: DynamicAssetCollection + Asset>(
+    mut events: EventReader<AssetEvent<C>>,
+    watched: Res<WatchedDynamicCollections<S, C>>,
+    dynamic_asset_collections: Res<Assets<C>>,
+    collection_files: Res<DynamicAssetCollections<S>>,
+    mut asset_keys: ResMut<DynamicAssets>,
+    mut reloads: ResMut<DynamicAssetsReloads<S>>,
+) {
+    for event in events.read() {
+        let AssetEvent::Modified { id } = event else {
+            continue;
+        };
+        for (loading_state, handle) in watched
+            .files
+            .iter()
+            .filter(|(_, handle)| handle.id() == *id)
+        {
+            let Some(collection) = dynamic_asset_collections.get(handle) else {
+                continue;
+            };
+            let namespace = handle
+                .path()
+                .and_then(|path| collection_files.get_namespace(loading_state, &path.to_string()));
+            let keys = asset_keys.register_file_collection(namespace, collection);
+            info!(
+                "Registered the dynamic assets of {:?} again after it changed",
+                handle.path()
+            );
+            reloads
+                .reloads
+                .entry(loading_state.clone())
+                .or_default()
+                .push(keys);
+        }
+    }
+}
+
+/// Create a collection again once the dynamic assets of its loading state changed and its assets are loaded
+///
+/// Only collections that look up one of the keys of a changed file are created again.
+/// If any of their assets fails to load, the current collection is kept.
+pub(crate) fn reload_collection_with_dynamic_assets<S: States, A: AssetCollection>(
+    world: &mut World,
+    loading_state: &S,
+) {
+    if !world.contains_resource::<A>() {
+        return;
+    }
+    let reloads = world
+        .get_resource::<DynamicAssetsReloads<S>>()
+        .map_or(0, |reloads| reloads.get(loading_state));
+    let Some(mut reloading) = world.get_resource_mut::<ReloadingCollection<A>>() else {
+        world.insert_resource(ReloadingCollection::<A> {
+            reloads,
+            handles: None,
+            marker: PhantomData,
+        });
+        return;
+    };
+    if reloading.reloads != reloads {
+        let seen_reloads = reloading.reloads;
+        reloading.reloads = reloads;
+        let changed_keys = world
+            .resource::<DynamicAssetsReloads<S>>()
+            .changed_keys_since(loading_state, seen_reloads);
+        if let Some(asset_keys) = world.get_resource::<DynamicAssets>() {
+            asset_keys.record_used_keys();
+        }
+        let (handles, missing_assets) = load_collection::<A>(world);
+        let used_keys = world
+            .get_resource::<DynamicAssets>()
+            .map(DynamicAssets::take_used_keys)
+            .unwrap_or_default();
+        if !used_keys.contains_any(&changed_keys) {
+            debug!(
+                "Keeping the asset collection {}, because it does not use any of the changed dynamic assets",
+                type_name::<A>()
+            );
+            return;
+        }
+        if missing_assets > 0 {
+            warn!(
+                "Keeping the asset collection {}, because the changed dynamic assets are missing keys it requires",
+                type_name::<A>()
+            );
+            return;
+        }
+        world.resource_mut::<ReloadingCollection<A>>().handles = Some(handles);
+    }
+    let reloading = world.resource::<ReloadingCollection<A>>();
+    let Some(handles) = &reloading.handles else {
+        return;
+    };
+    let asset_server = world.resource::<AssetServer>();
+    let load_states: Vec<_> = handles
+        .iter()
+        .map(|handle| asset_server.load_state(handle.id()))
+        .collect();
+    if load_states.contains(&LoadState::Failed) {
+        warn!(
+            "Keeping the asset collection {}, because assets of the changed dynamic assets failed to load",
+            type_name::<A>()
+        );
+        world
+            .resource_mut::<ReloadingCollection<A>>()
+            .handles
+            .take();
+        return;
+    }
+    if !load_states.iter().all(|state| state == &LoadState::Loaded) {
+        return;
+    }
+    // the loaded handles have to stay alive until the collection holds its own handles
+    let handles = world
+        .resource_mut::<ReloadingCollection<A>>()
+        .handles
+        .take();
+    let collection = A::create(world);
+    world.insert_resource(collection);
+    drop(handles);
+    info!(
+        "Created the asset collection {} again with the changed dynamic assets",
+        type_name::<A>()
+    );
 }
 
 pub(crate) fn resume_to_loading_asset_collections<S: States>(
@@ -0,0 +1,214 @@
+use crate::asset_collection::AssetCollection;
+use crate::io_task::IoTask;
+use bevy::asset::io::AssetReader;
+use bevy::asset::{
+    AssetPath, AssetServer, Assets, Handle, LoadState, LoadedUntypedAsset, UntypedHandle,
+};
+use bevy::ecs::change_detection::DetectChanges;
+use bevy::ecs::event::{Event, EventWriter};
+use bevy::ecs::system::{Local, Res, ResMut};
+use bevy::log::warn;
+use bevy::time::{Time, Timer, TimerMode};
+use bevy::utils::{HashMap, HashSet};
+use futures_lite::StreamExt;
+use std::any::type_name;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// How often the folders of hot reloaded collections are listed
+const FOLDER_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Sent when files were added to or removed from a folder of a hot reloaded asset collection
+///
+/// See [`LoadingState::with_hot_reload`](crate::loading_state::LoadingState::with_hot_reload).
+/// The event is sent after the folder fields of the collection were updated. Added files are
+/// loaded before they show up in the collection.
+///
+/// The event holds the only strong handles of removed assets that are not used anywhere else.
+/// Removed assets stay alive until the event is dropped, which is after the next frame.
+/// Keep a handle to a removed asset to continue using it.
+#[derive(Event, Debug, Clone)]
+pub struct FolderContentsChanged {
+    /// Type name of the asset collection holding the folder
+    pub collection: &'static str,
+    /// Asset path of the folder
+    pub folder: String,
+    /// Handles of the files added to the folder
+    pub added: Vec<UntypedHandle>,
+    /// Handles of the files removed from the folder
+    pub removed: Vec<UntypedHandle>,
+}
+
+#[derive(Default)]
+pub(crate) struct WatchedFolders {
+    timer: Option<Timer>,
+    folders: HashMap<&'static str, WatchedFolder>,
+}
+
+#[derive(Default)]
+struct WatchedFolder {
+    handles: Vec<UntypedHandle>,
+    listing: Option<IoTask<Option<HashSet<PathBuf>>>>,
+    loading: Vec<Handle<LoadedUntypedAsset>>,
+    removed: Vec<UntypedHandle>,
+}
+
+/// Diff the folders of a hot reloaded collection against their contents on disk and update the collection
+pub(crate) fn watch_collection_folders<A: AssetCollection>(
+    mut watched: Local<WatchedFolders>,
+    collection: Option<ResMut<A>>,
+    asset_server: Res<AssetServer>,
+    loaded_untyped: Res<Assets<LoadedUntypedAsset>>,
+    time: Res<Time>,
+    mut changes: EventWriter<FolderContentsChanged>,
+) {
+    let Some(mut collection) = collection else {
+        watched.folders.clear();
+        return;
+    };
+    if collection.is_added() || watched.folders.is_empty() {
+        watched.folders = collection
+            .folders()
+            .into_iter()
+            .map(|(path, handles)| {
+                (
+                    path,
+                    WatchedFolder {
+                        handles,
+                        ..Default::default()
+                    },
+                )
+            })
+            .collect();
+    }
+    let poll = watched
+        .timer
+        .get_or_insert_with(|| Timer::new(FOLDER_POLL_INTERVAL, TimerMode::Repeating))
+        .tick(time.delta())
+        .just_finished();
+
+    for (path, folder) in watched.folders.iter_mut() {
+        if let Some(listed) = folder.listing.as_ref().and_then(IoTask::poll) {
+            folder.listing = None;
+            if let Some(files) = listed {
+                folder.start_update(path, files, &asset_server);
+            }
+        }
+        if folder.has_pending_update() {
+            if folder.loading.iter().all(|handle| {
+                matches!(
+                    asset_server.load_state(handle.id()),
+                    LoadState::Loaded | LoadState::Failed
+                )
+            }) {
+                let (added, removed) = folder.finish_update(path, &loaded_untyped);
+                collection.update_folder(path, &folder.handles);
+                changes.send(FolderContentsChanged {
+                    collection: type_name::<A>(),
+                    folder: path.to_string(),
+                    added,
+                    removed,
+                });
+            }
+            continue;
+        }
+        if poll && folder.listing.is_none() {
+            let asset_server = asset_server.clone();
+            let folder_path = AssetPath::parse(path).into_owned();
+            folder.listing = Some(IoTask::spawn(async move {
+                let source = asset_server.get_source(folder_path.source()).ok()?;
+                list_files(source.reader(), folder_path.path(), true).await
+            }));
+        }
+    }
+}
+
+impl WatchedFolder {
+    fn has_pending_update(&self) -> bool {
+        !self.loading.is_empty() || !self.removed.is_empty()
+    }
+
+    /// Diff the listed files against the known handles and start loading new files
+    fn start_update(&mut self, path: &str, files: HashSet<PathBuf>, asset_server: &AssetServer) {
+        let known: HashSet<&Path> = self
+            .handles
+            .iter()
+            .filter_map(|handle| handle.path().map(AssetPath::path))
+            .collect();
+        let source = AssetPath::parse(path).source().clone_owned();
+        self.loading = files
+            .iter()
+            .filter(|file| !known.contains(file.as_path()))
+            .map(|file| {
+                asset_server.load_untyped(
+                    AssetPath::from_path(file)
+                        .with_source(source.clone())
+                        .into_owned(),
+                )
+            })
+            .collect();
+        self.removed = self
+            .handles
+            .iter()
+            .filter(|handle| {
+                handle
+                    .path()
+                    .is_some_and(|asset_path| !files.contains(asset_path.path()))
+            })
+            .cloned()
+            .collect();
+    }
+
+    /// Apply the pending update to the known handles and return the added and removed handles
+    fn finish_update(
+        &mut self,
+        path: &str,
+        loaded_untyped: &Assets<LoadedUntypedAsset>,
+    ) -> (Vec<UntypedHandle>, Vec<UntypedHandle>) {
+        let added: Vec<UntypedHandle> = self
+            .loading
+            .drain(..)
+            .filter_map(|handle| {
+                let loaded = loaded_untyped
+                    .get(&handle)
+                    .map(|asset| asset.handle.clone());
+                if loaded.is_none() {
+                    warn!(
+                        "Failed to load {:?}, which was added to the folder '{path}'",
+                        handle.path()
+                    );
+                }
+                loaded
+            })
+            .collect();
+        let removed = std::mem::take(&mut self.removed);
+        self.handles.retain(|handle| !removed.contains(handle));
+        self.handles.extend(added.iter().cloned());
+
+        (added, removed)
+    }
+}
+
+/// All files in the given folder and, if `recursive` is set, its sub folders
+pub(super) async fn list_files(
+    reader: &dyn AssetReader,
+    folder: &Path,
+    recursive: bool,
+) -> Option<HashSet<PathBuf>> {
+    let mut files = HashSet::default();
+    let mut folders = vec![folder.to_path_buf()];
+    while let Some(folder) = folders.pop() {
+        let mut entries = reader.read_directory(&folder).await.ok()?;
+        while let Some(entry) = entries.next().await {
+            if reader.is_directory(&entry).await.ok()? {
+                if recursive {
+                    folders.push(entry);
+                }
+            } else {
+                files.insert(entry);
+            }
+        }
+    }
+
+    Some(files)
+}
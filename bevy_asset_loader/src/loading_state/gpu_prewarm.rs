@@ -0,0 +1,190 @@
+use crate::asset_collection::AssetCollection;
+use crate::loading_state::AssetLoaderConfiguration;
+use bevy::app::App;
+use bevy::asset::AssetId;
+use bevy::ecs::schedule::{IntoSystemConfigs, State, States};
+use bevy::ecs::system::{Res, Resource};
+use bevy::ecs::world::World;
+use bevy::log::{debug, warn};
+use bevy::render::render_asset::RenderAssets;
+use bevy::render::texture::Image;
+use bevy::render::{Render, RenderApp, RenderSet};
+use bevy::utils::HashSet;
+use std::any::{type_name, TypeId};
+use std::sync::{Arc, Mutex};
+
+/// Number of frames a loading state waits for images to be prepared before giving up
+const MAX_PREWARM_FRAMES: u32 = 120;
+
+/// Progress of preparing the images of a loading state configured with
+/// [`LoadingState::prewarm_gpu`](crate::loading_state::LoadingState::prewarm_gpu)
+///
+/// The images of all collections are queued when the collections are created. The render app reports
+/// images once their GPU textures are prepared. Without a render app (e.g. headless), nothing is uploaded
+/// and the queue is cleared right away. If images are not prepared within 120 frames, the loading state
+/// logs a warning and continues without them.
+#[derive(Resource, Debug, Clone)]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "2d", feature = "3d"))))]
+pub struct GpuPrewarm {
+    queue: Arc<Mutex<PrewarmQueue>>,
+    render_app: bool,
+    frames: u32,
+}
+
+#[derive(Debug, Default)]
+struct PrewarmQueue {
+    pending: HashSet<AssetId<Image>>,
+    total: usize,
+    /// Set once the loading state stopped waiting for the pending images
+    timed_out: bool,
+}
+
+/// The render world side of [`GpuPrewarm`]
+#[derive(Resource)]
+struct PrewarmQueueHandle(Arc<Mutex<PrewarmQueue>>);
+
+impl GpuPrewarm {
+    /// Number of images queued for the current loading state
+    pub fn total(&self) -> usize {
+        self.queue.lock().unwrap().total
+    }
+
+    /// Number of queued images that are prepared on the GPU
+    ///
+    /// Without a render app, this is always `0`.
+    pub fn prepared(&self) -> usize {
+        if !self.render_app {
+            return 0;
+        }
+        let queue = self.queue.lock().unwrap();
+
+        queue.total - queue.pending.len()
+    }
+
+    /// Whether no queued image is waiting to be prepared anymore
+    pub fn is_done(&self) -> bool {
+        let queue = self.queue.lock().unwrap();
+
+        queue.timed_out || queue.pending.is_empty()
+    }
+
+    fn reset(&mut self) {
+        *self.queue.lock().unwrap() = PrewarmQueue::default();
+        self.frames = 0;
+    }
+
+    fn queue(&self, ids: impl IntoIterator<Item = AssetId<Image>>) {
+        let mut queue = self.queue.lock().unwrap();
+        for id in ids {
+            queue.total += 1;
+            if self.render_app {
+                queue.pending.insert(id);
+            }
+        }
+    }
+
+    /// Returns `true` once the loading state can continue
+    pub(crate) fn poll(&mut self) -> bool {
+        if self.is_done() {
+            return true;
+        }
+        self.frames += 1;
+        if self.frames < MAX_PREWARM_FRAMES {
+            return false;
+        }
+        let mut queue = self.queue.lock().unwrap();
+        warn!(
+            "{} images were not prepared on the GPU after {MAX_PREWARM_FRAMES} frames. Continuing without them.",
+            queue.pending.len()
+        );
+        queue.timed_out = true;
+
+        true
+    }
+}
+
+pub(crate) fn init_gpu_prewarm(app: &mut App) {
+    if app.world.contains_resource::<GpuPrewarm>() {
+        return;
+    }
+    let queue: Arc<Mutex<PrewarmQueue>> = Arc::default();
+    let render_app = match app.get_sub_app_mut(RenderApp) {
+        Ok(render_app) => {
+            render_app
+                .insert_resource(PrewarmQueueHandle(queue.clone()))
+                .add_systems(Render, mark_prepared_images.in_set(RenderSet::Cleanup));
+            true
+        }
+        Err(_) => {
+            debug!(
+                "No render app to prewarm images in. Loading states will not wait for GPU uploads."
+            );
+            false
+        }
+    };
+    app.insert_resource(GpuPrewarm {
+        queue,
+        render_app,
+        frames: 0,
+    });
+}
+
+fn mark_prepared_images(queue: Res<PrewarmQueueHandle>, images: Option<Res<RenderAssets<Image>>>) {
+    let Some(images) = images else {
+        return;
+    };
+    queue
+        .0
+        .lock()
+        .unwrap()
+        .pending
+        .retain(|id| images.get(*id).is_none());
+}
+
+pub(crate) fn reset_gpu_prewarm<S: States>(world: &mut World) {
+    if !prewarm_gpu::<S>(world) {
+        return;
+    }
+    if let Some(mut prewarm) = world.get_resource_mut::<GpuPrewarm>() {
+        prewarm.reset();
+    }
+}
+
+/// Queue all images of a newly created collection if the current loading state prewarms the GPU
+pub(crate) fn queue_collection_images<S: States, A: AssetCollection>(
+    world: &World,
+    collection: &A,
+) {
+    if !prewarm_gpu::<S>(world) {
+        return;
+    }
+    let Some(prewarm) = world.get_resource::<GpuPrewarm>() else {
+        return;
+    };
+    let images: Vec<AssetId<Image>> = collection
+        .all_handles()
+        .into_iter()
+        .filter(|handle| handle.type_id() == TypeId::of::<Image>())
+        .map(|handle| handle.id().typed())
+        .collect();
+    debug!(
+        "Queueing {} images of {} for GPU prewarming",
+        images.len(),
+        type_name::<A>()
+    );
+    prewarm.queue(images);
+}
+
+fn prewarm_gpu<S: States>(world: &World) -> bool {
+    let (Some(state), Some(configuration)) = (
+        world.get_resource::<State<S>>(),
+        world.get_resource::<AssetLoaderConfiguration<S>>(),
+    ) else {
+        return false;
+    };
+
+    configuration
+        .state_configurations
+        .get(state.get())
+        .is_some_and(|config| config.prewarm_gpu)
+}
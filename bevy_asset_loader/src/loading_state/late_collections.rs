@@ -0,0 +1,205 @@
+use bevy::ecs::schedule::{NextState, State, States};
+use bevy::ecs::system::{Command, Commands, Resource, SystemState};
+use bevy::ecs::world::World;
+use bevy::log::{info, warn};
+use bevy::utils::HashMap;
+use std::any::{type_name, TypeId};
+use std::marker::PhantomData;
+
+use crate::asset_collection::AssetCollection;
+use crate::loading_state::systems::{check_loading_collection, start_loading_collection};
+use crate::loading_state::{AssetLoaderConfiguration, InternalLoadingState, LoadingProgress};
+
+/// Add asset collections to loading states while the app is running
+pub trait LoadingStateCommandsExt {
+    /// Add an [`AssetCollection`] to a [`LoadingState`](crate::loading_state::LoadingState) after the app started
+    ///
+    /// Use this if the collections of a loading state are only known at run time, e.g. after scanning mods.
+    /// The collection is loaded every time the loading state is entered, like collections added with
+    /// [`LoadingStateAppExt::add_collection_to_loading_state`](crate::loading_state::LoadingStateAppExt::add_collection_to_loading_state).
+    ///
+    /// If the loading state is active, the collection starts loading right away. A loading state that is
+    /// already finalizing or done is reopened and only continues to its next state once the new collection
+    /// is loaded as well. Adding a collection after the loading state continued to its next state only
+    /// affects the next time it is entered. Adding the same collection to a loading state again does nothing.
+    ///
+    /// The assets of the new collection are added to the totals of [`LoadingProgress`] and of the
+    /// `ProgressCounter` of the `progress_tracking` feature. The number of loaded assets never decreases,
+    /// but the loaded fraction drops whenever a collection is added mid-flight. A reopened loading state
+    /// is no longer finished.
+    ///
+    /// Folders of collections added with this method are not followed by hot reloading and they are not
+    /// checked for unused assets.
+    /// ```edition2021
+    /// # use bevy_asset_loader::prelude::*;
+    /// # use bevy::prelude::*;
+    /// fn add_mod_assets(mut commands: Commands) {
+    ///     commands.add_collection_to_loading_state::<_, ModAssets>(GameState::Loading);
+    /// }
+    /// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+    /// # enum GameState {
+    /// #     #[default]
+    /// #     Loading,
+    /// #     Menu
+    /// # }
+    /// # #[derive(AssetCollection, Resource)]
+    /// # pub struct ModAssets {
+    /// #     #[asset(path = "audio/background.ogg")]
+    /// #     pub background: Handle<AudioSource>,
+    /// # }
+    /// ```
+    fn add_collection_to_loading_state<S: States, A: AssetCollection>(&mut self, loading_state: S);
+}
+
+impl LoadingStateCommandsExt for Commands<'_, '_> {
+    fn add_collection_to_loading_state<S: States, A: AssetCollection>(&mut self, loading_state: S) {
+        self.add(AddCollection::<S, A> {
+            loading_state,
+            marker: PhantomData,
+        });
+    }
+}
+
+struct AddCollection<S, A> {
+    loading_state: S,
+    marker: PhantomData<A>,
+}
+
+impl<S: States, A: AssetCollection> Command for AddCollection<S, A> {
+    fn apply(self, world: &mut World) {
+        let loading_state = self.loading_state;
+        let is_configured = world
+            .get_resource::<AssetLoaderConfiguration<S>>()
+            .is_some_and(|configuration| {
+                configuration
+                    .state_configurations
+                    .contains_key(&loading_state)
+            });
+        if !is_configured {
+            warn!(
+                "Cannot add the asset collection {} to '{}::{:?}', because it is not a loading state",
+                type_name::<A>(),
+                type_name::<S>(),
+                loading_state
+            );
+            return;
+        }
+        let mut late_collections = world.resource_mut::<LateCollections<S>>();
+        let collections = late_collections
+            .collections
+            .entry(loading_state.clone())
+            .or_default();
+        if collections
+            .iter()
+            .any(|collection| collection.type_id == TypeId::of::<A>())
+        {
+            return;
+        }
+        collections.push(LateCollection {
+            type_id: TypeId::of::<A>(),
+            start: start_late_collection::<S, A>,
+            check: check_loading_collection::<S, A>,
+        });
+
+        if world.resource::<State<S>>().get() != &loading_state {
+            return;
+        }
+        match world.resource::<State<InternalLoadingState<S>>>().get() {
+            // the collection is started with all others once the loading state loads its assets
+            InternalLoadingState::Initialize
+            | InternalLoadingState::LoadingDynamicAssetCollections => {}
+            InternalLoadingState::LoadingAssets => {
+                // all other collections might have finished this frame
+                world.resource_mut::<NextState<InternalLoadingState<S>>>().0 = None;
+                start_late_collection::<S, A>(world);
+            }
+            InternalLoadingState::Finalize | InternalLoadingState::Done(_) => {
+                info!(
+                    "Reopening loading state '{}::{:?}' to load the asset collection {}",
+                    type_name::<S>(),
+                    loading_state,
+                    type_name::<A>()
+                );
+                // entering the internal state again starts all late collections that did not start yet
+                reopen_loading_state(world, &loading_state);
+            }
+        }
+    }
+}
+
+/// Collections added to loading states with [`LoadingStateCommandsExt::add_collection_to_loading_state`]
+#[derive(Resource)]
+pub(crate) struct LateCollections<S: States> {
+    collections: HashMap<S, Vec<LateCollection>>,
+}
+
+impl<S: States> Default for LateCollections<S> {
+    fn default() -> Self {
+        LateCollections {
+            collections: HashMap::default(),
+        }
+    }
+}
+
+impl<S: States> LateCollections<S> {
+    fn get(&self, state: &S) -> Vec<LateCollection> {
+        self.collections.get(state).cloned().unwrap_or_default()
+    }
+}
+
+#[derive(Clone)]
+struct LateCollection {
+    type_id: TypeId,
+    start: fn(&mut World),
+    check: fn(&mut World),
+}
+
+fn start_late_collection<S: States, A: AssetCollection>(world: &mut World) {
+    let mut system_state = SystemState::new(world);
+    start_loading_collection::<S, A>(world, &mut system_state);
+}
+
+/// Go back to loading assets
+///
+/// Collections that already started loading in this visit of the loading state are not started again.
+fn reopen_loading_state<S: States>(world: &mut World, loading_state: &S) {
+    world
+        .resource_mut::<NextState<InternalLoadingState<S>>>()
+        .set(InternalLoadingState::LoadingAssets);
+    world.resource_mut::<LoadingProgress>().reopen();
+    let next = world
+        .resource::<AssetLoaderConfiguration<S>>()
+        .state_configurations
+        .get(loading_state)
+        .and_then(|config| config.next.clone());
+    // the loading state finished this frame and already requested its next state
+    let mut next_state = world.resource_mut::<NextState<S>>();
+    let mut cancelled_transition = next.is_some() && next_state.0 == next;
+    if cancelled_transition {
+        next_state.0 = None;
+    }
+    let mut configuration = world.resource_mut::<AssetLoaderConfiguration<S>>();
+    let Some(config) = configuration.state_configurations.get_mut(loading_state) else {
+        return;
+    };
+    cancelled_transition |= config.pending_transition.take().is_some();
+    config.finished_at_frame = None;
+    // a loading state that was allowed to continue does not wait for another continue event
+    if cancelled_transition {
+        config.continue_requested = true;
+    }
+}
+
+pub(crate) fn start_late_collections<S: States>(world: &mut World) {
+    let state = world.resource::<State<S>>().get().clone();
+    for collection in world.resource::<LateCollections<S>>().get(&state) {
+        (collection.start)(world);
+    }
+}
+
+pub(crate) fn check_late_collections<S: States>(world: &mut World) {
+    let state = world.resource::<State<S>>().get().clone();
+    for collection in world.resource::<LateCollections<S>>().get(&state) {
+        (collection.check)(world);
+    }
+}
@@ -0,0 +1,160 @@
+use crate::asset_collection::AssetCollection;
+use crate::loading_state::{LoadingState, LoadingStateAppExt};
+use bevy::app::App;
+use bevy::ecs::schedule::States;
+
+/// Several [`LoadingState`]s that are entered one after another, e.g. for a multi-phase boot
+///
+/// Every stage continues to the loading state of the next stage once its collections are loaded.
+/// The last stage continues to the state set with [`LoadingStatePipeline::continue_to_state`].
+/// If an asset fails to load, the pipeline is aborted: the failing stage continues to the state set with
+/// [`LoadingStatePipeline::on_failure_continue_to_state`] and the remaining stages are never entered.
+/// Stages with their own failure state keep it.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy_asset_loader::loading_state::LoadingStatePipeline;
+/// # use bevy::prelude::*;
+/// # use bevy::asset::AssetPlugin;
+/// # fn main() {
+///     App::new()
+/// #       .add_state::<GameState>()
+/// #       .add_plugins((MinimalPlugins, AssetPlugin::default()))
+/// #       .init_resource::<iyes_progress::ProgressCounter>()
+///         .add_loading_state_pipeline(
+///           LoadingStatePipeline::new()
+///             .then(LoadingState::new(GameState::LoadingCore))
+///             .load_collection::<CoreAssets>()
+///             .then(LoadingState::new(GameState::LoadingMenu))
+///             .load_collection::<MenuAssets>()
+///             .continue_to_state(GameState::Menu)
+///             .on_failure_continue_to_state(GameState::Error)
+///         )
+/// #       .set_runner(|mut app| app.update())
+/// #       .run();
+/// # }
+/// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+/// # enum GameState {
+/// #     #[default]
+/// #     LoadingCore,
+/// #     LoadingMenu,
+/// #     Menu,
+/// #     Error,
+/// # }
+/// # #[derive(AssetCollection, Resource)]
+/// # pub struct CoreAssets {
+/// #     #[asset(path = "audio/background.ogg")]
+/// #     pub background: Handle<AudioSource>,
+/// # }
+/// # #[derive(AssetCollection, Resource)]
+/// # pub struct MenuAssets {
+/// #     #[asset(path = "audio/plop.ogg")]
+/// #     pub plop: Handle<AudioSource>,
+/// # }
+/// ```
+pub struct LoadingStatePipeline<S: States> {
+    stages: Vec<PipelineStage<S>>,
+    next_state: Option<S>,
+    failure_state: Option<S>,
+}
+
+struct PipelineStage<S: States> {
+    loading_state: LoadingState<S>,
+    collections: Vec<fn(&mut App, S)>,
+}
+
+impl<S: States> Default for LoadingStatePipeline<S> {
+    fn default() -> Self {
+        LoadingStatePipeline {
+            stages: vec![],
+            next_state: None,
+            failure_state: None,
+        }
+    }
+}
+
+impl<S: States> LoadingStatePipeline<S> {
+    /// Create an empty pipeline
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a stage entered after all previous stages finished
+    ///
+    /// The next state of the loading state is replaced by the loading state of the following stage.
+    #[must_use]
+    pub fn then(mut self, loading_state: LoadingState<S>) -> Self {
+        self.stages.push(PipelineStage {
+            loading_state,
+            collections: vec![],
+        });
+
+        self
+    }
+
+    /// Load the given collection in the last added stage
+    ///
+    /// # Panics
+    ///
+    /// Panics if no stage was added with [`LoadingStatePipeline::then`] yet.
+    #[must_use]
+    pub fn load_collection<A: AssetCollection>(mut self) -> Self {
+        let stage = self.stages.last_mut().expect(
+            "Add a stage with `LoadingStatePipeline::then` before loading collections in it",
+        );
+        stage.collections.push(|app, state| {
+            app.add_collection_to_loading_state::<S, A>(state);
+        });
+
+        self
+    }
+
+    /// The state to continue to once the last stage is done
+    #[must_use]
+    pub fn continue_to_state(mut self, next: S) -> Self {
+        self.next_state = Some(next);
+
+        self
+    }
+
+    /// The state to continue to if an asset of any stage fails to load
+    #[must_use]
+    pub fn on_failure_continue_to_state(mut self, failure: S) -> Self {
+        self.failure_state = Some(failure);
+
+        self
+    }
+
+    /// Add the loading states of all stages to the app
+    ///
+    /// # Panics
+    ///
+    /// Panics if the pipeline has no stages or if a loading state would continue to itself.
+    pub fn build(self, app: &mut App) {
+        assert!(
+            !self.stages.is_empty(),
+            "A loading state pipeline needs at least one stage"
+        );
+        let next_states: Vec<_> = self
+            .stages
+            .iter()
+            .skip(1)
+            .map(|stage| Some(stage.loading_state.loading_state.clone()))
+            .chain([self.next_state])
+            .collect();
+        for (stage, next) in self.stages.into_iter().zip(next_states) {
+            let mut loading_state = stage.loading_state;
+            let state = loading_state.loading_state.clone();
+            if let Some(next) = next {
+                loading_state.next_state = Some(next);
+            }
+            if loading_state.failure_state.is_none() {
+                loading_state.failure_state = self.failure_state.clone();
+            }
+            app.add_loading_state(loading_state);
+            for add_collection in stage.collections {
+                add_collection(app, state.clone());
+            }
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use bevy::app::{App, Last};
+#[cfg(any(feature = "2d", feature = "3d"))]
+use bevy::ecs::schedule::IntoSystemConfigs;
+use bevy::ecs::system::{Res, Resource};
+#[cfg(any(feature = "2d", feature = "3d"))]
+use bevy::render::{Render, RenderApp, RenderSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Number of completed render frames, used by
+/// [`LoadingState::after_first_render`](crate::loading_state::LoadingState::after_first_render)
+///
+/// A frame counts as rendered once the render app reaches the cleanup of its `Render` schedule for it.
+/// Without a render app (e.g. headless or without the `2d` and `3d` features), a frame counts as rendered
+/// at the end of the [`Last`] schedule of the main app.
+#[derive(Resource, Debug, Clone)]
+pub(crate) struct RenderedFrames(Arc<AtomicU64>);
+
+impl RenderedFrames {
+    pub(crate) fn get(&self) -> u64 {
+        self.0.load(Ordering::Acquire)
+    }
+
+    fn count(&self) {
+        self.0.fetch_add(1, Ordering::AcqRel);
+    }
+}
+
+pub(crate) fn init_rendered_frames(app: &mut App) {
+    if app.world.contains_resource::<RenderedFrames>() {
+        return;
+    }
+    let frames = RenderedFrames(Arc::default());
+    app.insert_resource(frames.clone());
+    #[cfg(any(feature = "2d", feature = "3d"))]
+    if let Ok(render_app) = app.get_sub_app_mut(RenderApp) {
+        render_app
+            .insert_resource(frames)
+            .add_systems(Render, count_rendered_frames.in_set(RenderSet::Cleanup));
+        return;
+    }
+    app.add_systems(Last, count_rendered_frames);
+}
+
+fn count_rendered_frames(frames: Res<RenderedFrames>) {
+    frames.count();
+}
@@ -0,0 +1,70 @@
+use crate::asset_collection::AssetCollection;
+use bevy::asset::{AssetEvent, AssetServer, Handle};
+use bevy::ecs::event::{Events, ManualEventReader};
+use bevy::ecs::system::Local;
+use bevy::ecs::world::World;
+use bevy::log::info;
+use bevy::render::texture::Image;
+use std::any::type_name;
+
+/// Images of a hot reloaded collection that other assets of the collection are created from
+#[derive(Default)]
+pub(crate) struct ProcessedImages {
+    /// Strong handles keeping the images loaded, so their changes are noticed
+    handles: Vec<Handle<Image>>,
+    events: ManualEventReader<AssetEvent<Image>>,
+    /// Creating the collection can modify its images, e.g. to apply a sampler
+    created: bool,
+}
+
+/// Create a hot reloaded collection again after one of its [processed images](AssetCollection::processed_images) was modified
+///
+/// Handles of the collection stay stable across hot reloads, but texture atlases, images with a sampler,
+/// and standard materials are created from the source images and need to be processed again.
+pub(crate) fn reprocess_modified_images<A: AssetCollection>(
+    world: &mut World,
+    mut processed: Local<ProcessedImages>,
+) {
+    if !world.contains_resource::<A>() {
+        processed.handles.clear();
+        return;
+    }
+    let Some(events) = world.get_resource::<Events<AssetEvent<Image>>>() else {
+        return;
+    };
+    if processed.handles.is_empty() {
+        let paths = A::processed_images();
+        if paths.is_empty() {
+            return;
+        }
+        processed.events.clear(events);
+        let asset_server = world.resource::<AssetServer>();
+        processed.handles = paths
+            .into_iter()
+            .map(|path| asset_server.load(path))
+            .collect();
+        return;
+    }
+    if std::mem::take(&mut processed.created) {
+        processed.events.clear(events);
+        return;
+    }
+    let ProcessedImages {
+        handles,
+        events: reader,
+        ..
+    } = &mut *processed;
+    let modified = reader.read(events).any(|event| {
+        matches!(event, AssetEvent::Modified { id } if handles.iter().any(|handle| handle.id() == *id))
+    });
+    if !modified {
+        return;
+    }
+    let collection = A::create(world);
+    world.insert_resource(collection);
+    processed.created = true;
+    info!(
+        "Created the asset collection {} again, because one of its processed images changed",
+        type_name::<A>()
+    );
+}
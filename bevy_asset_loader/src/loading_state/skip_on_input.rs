@@ -0,0 +1,64 @@
+use bevy::app::App;
+use bevy::ecs::event::EventReader;
+use bevy::ecs::schedule::{State, States};
+use bevy::ecs::system::{Res, ResMut};
+use bevy::input::gamepad::GamepadButtonChangedEvent;
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::mouse::MouseButtonInput;
+use bevy::input::touch::{TouchInput, TouchPhase};
+use bevy::input::ButtonState;
+use bevy::log::info;
+use std::any::type_name;
+
+use crate::loading_state::AssetLoaderConfiguration;
+
+/// Make sure the input events exist, even if the app does not use the `InputPlugin`
+pub(crate) fn init_input_events(app: &mut App) {
+    app.add_event::<KeyboardInput>()
+        .add_event::<MouseButtonInput>()
+        .add_event::<TouchInput>()
+        .add_event::<GamepadButtonChangedEvent>();
+}
+
+/// Request skipping the current loading state if a key, mouse button, gamepad button, or touch was pressed
+pub(crate) fn skip_on_input<S: States>(
+    state: Res<State<S>>,
+    mut keyboard: EventReader<KeyboardInput>,
+    mut mouse: EventReader<MouseButtonInput>,
+    mut touch: EventReader<TouchInput>,
+    mut gamepad: EventReader<GamepadButtonChangedEvent>,
+    mut asset_loader_configuration: ResMut<AssetLoaderConfiguration<S>>,
+) {
+    // read all events, so none of them trigger a skip the next time the loading state runs
+    let keys = keyboard
+        .read()
+        .filter(|input| input.state == ButtonState::Pressed)
+        .count();
+    let buttons = mouse
+        .read()
+        .filter(|input| input.state == ButtonState::Pressed)
+        .count();
+    let touches = touch
+        .read()
+        .filter(|input| input.phase == TouchPhase::Started)
+        .count();
+    let gamepad_buttons = gamepad.read().filter(|input| input.value > 0.).count();
+    if keys + buttons + touches + gamepad_buttons == 0 {
+        return;
+    }
+    let Some(config) = asset_loader_configuration
+        .state_configurations
+        .get_mut(state.get())
+    else {
+        return;
+    };
+    if !config.skip_on_input || config.skip_requested {
+        return;
+    }
+    info!(
+        "Skipping loading state '{}::{:?}' on input",
+        type_name::<S>(),
+        state.get()
+    );
+    config.skip_requested = true;
+}
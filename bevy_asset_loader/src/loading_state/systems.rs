@@ -1,19 +1,41 @@
-use bevy::asset::{AssetServer, LoadState};
+use bevy::asset::io::AssetSourceId;
+use bevy::asset::{AssetPath, AssetServer, LoadState, UntypedHandle};
+use bevy::ecs::component::Component;
 use bevy::ecs::schedule::{State, States};
 use bevy::ecs::system::SystemState;
 use bevy::ecs::world::{FromWorld, World, WorldCell};
 use bevy::log::{debug, info, trace, warn};
-use bevy::prelude::{NextState, Res, ResMut, Resource, Schedules};
+use bevy::prelude::{EventReader, Mut, NextState, Res, ResMut, Resource, Schedules};
+use bevy::time::Time;
+use bevy::utils::default;
 use std::any::{type_name, TypeId};
 use std::marker::PhantomData;
+use std::time::Duration;
 
+use bevy::asset::{LoadedFolder, LoadedUntypedAsset};
+
+#[cfg(feature = "checksums")]
+use crate::checksum::{CollectionHash, HashingCollection, LoadingChecksums, PendingChecksums};
 #[cfg(feature = "progress_tracking")]
 use iyes_progress::{HiddenProgress, Progress, ProgressCounter};
 
-use crate::asset_collection::AssetCollection;
+#[cfg(any(feature = "2d", feature = "3d"))]
+use crate::loading_state::gpu_prewarm::{queue_collection_images, GpuPrewarm};
+
+use crate::asset_collection::{
+    collection_load_state, AssetByteBudget, AssetCollection, AssetLoadThrottle,
+    CollectionBuildSettings, HandleRegistry, MissingRuntimePaths, OptionalAssets,
+};
+use crate::dynamic_asset::MissingDynamicAssets;
+use crate::loading_state::collection_order::{
+    start_when_prerequisites_loaded, wait_for_prerequisites,
+};
+use crate::loading_state::rendered_frames::RenderedFrames;
 use crate::loading_state::{
-    AssetLoaderConfiguration, InternalLoadingState, LoadingAssetHandles, LoadingStateSchedule,
-    OnEnterInternalLoadingState,
+    AssetCollectionLoaded, AssetLoadFailed, AssetLoaderConfiguration, CollectionConstructor,
+    CollectionEntities, ContinueLoading, DeferredAssets, FailedAssets, InternalLoadingState,
+    LoadingAssetHandles, LoadingProgress, LoadingStateSchedule, OnEnterInternalLoadingState,
+    RetryingAsset, UnreadyCollection,
 };
 
 pub(crate) fn init_resource<Asset: Resource + FromWorld>(world: &mut World) {
@@ -21,10 +43,92 @@ pub(crate) fn init_resource<Asset: Resource + FromWorld>(world: &mut World) {
     world.insert_resource(asset);
 }
 
+/// Register the handles of a loaded collection in a [`HandleRegistry`]
+///
+/// The collection is only kept as resource if it is also inserted as components afterwards.
+pub(crate) fn register_collection_handles<
+    S: States,
+    Assets: AssetCollection,
+    Registry: HandleRegistry,
+>(
+    world: &mut World,
+) {
+    let Some(collection) = world.remove_resource::<Assets>() else {
+        return;
+    };
+    let mut registry = world.get_resource_mut::<Registry>().unwrap_or_else(|| {
+        panic!(
+            "Cannot register the handles of the asset collection {} in {}, because the registry resource does not exist",
+            type_name::<Assets>(),
+            type_name::<Registry>()
+        )
+    });
+    collection.register_handles(&mut *registry);
+    let state = world.resource::<State<S>>().get();
+    if world
+        .get_resource::<CollectionEntities<S, Assets>>()
+        .is_some_and(|entities| !entities.get(state).is_empty())
+    {
+        world.insert_resource(collection);
+    }
+}
+
+pub(crate) fn insert_collection_as_components<
+    S: States,
+    Assets: AssetCollection + Component + Clone,
+>(
+    world: &mut World,
+) {
+    let Some(collection) = world.remove_resource::<Assets>() else {
+        return;
+    };
+    let state = world.resource::<State<S>>().get().clone();
+    let entities = world
+        .resource::<CollectionEntities<S, Assets>>()
+        .get(&state)
+        .to_vec();
+    for entity in entities {
+        let Some(mut entity_mut) = world.get_entity_mut(entity) else {
+            warn!(
+                "Cannot insert the asset collection {} on {:?}, because the entity does not exist",
+                type_name::<Assets>(),
+                entity
+            );
+            continue;
+        };
+        entity_mut.insert(collection.clone());
+    }
+}
+
 #[allow(clippy::type_complexity)]
 pub(crate) fn start_loading_collection<S: States, Assets: AssetCollection>(
     world: &mut World,
     system_state: &mut SystemState<(ResMut<AssetLoaderConfiguration<S>>, Res<State<S>>)>,
+) {
+    if collection_started::<S, Assets>(world) {
+        // a reopened loading state enters `LoadingAssets` again
+        return;
+    }
+    if wait_for_prerequisites::<S, Assets>(world) {
+        return;
+    }
+    begin_loading_collection::<S, Assets>(world, system_state);
+}
+
+/// Whether the collection already started loading since the active loading state was entered
+fn collection_started<S: States, Assets: AssetCollection>(world: &World) -> bool {
+    let state = world.resource::<State<S>>();
+    world
+        .resource::<AssetLoaderConfiguration<S>>()
+        .state_configurations
+        .get(state.get())
+        .is_some_and(|config| config.collections.contains(&TypeId::of::<Assets>()))
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn begin_loading_collection<S: States, Assets: AssetCollection>(
+    world: &mut World,
+    system_state: &mut SystemState<(ResMut<AssetLoaderConfiguration<S>>, Res<State<S>>)>,
 ) {
     debug!(
         "Starting to load collection for type id {:?}",
@@ -42,11 +146,71 @@ pub(crate) fn start_loading_collection<S: States, Assets: AssetCollection>(
             )
         });
     config.loading_collections += 1;
-    let handles = LoadingAssetHandles {
-        handles: Assets::load(world),
+    let byte_budget = config.byte_budget.take();
+    let load_throttle = config.load_throttle.take();
+    let track_dependencies = config.track_dependencies;
+    let state = state.get().clone();
+    if let Some(byte_budget) = byte_budget {
+        world.insert_resource(byte_budget);
+    }
+    if let Some(load_throttle) = load_throttle {
+        world.insert_resource(load_throttle);
+    }
+    world.remove_resource::<UnreadyCollection<Assets>>();
+    #[cfg(feature = "checksums")]
+    world.remove_resource::<HashingCollection<Assets>>();
+    #[cfg(feature = "checksums")]
+    world.remove_resource::<CollectionHash<Assets>>();
+    #[cfg(feature = "checksums")]
+    world.init_resource::<PendingChecksums>();
+    let (loaded_handles, missing_assets) = load_collection::<Assets>(world);
+    #[cfg(feature = "load_trace")]
+    trace_started_collection::<Assets>(world, &loaded_handles);
+    let mut handles = LoadingAssetHandles {
+        handles: loaded_handles,
+        queued: default(),
+        missing_assets,
+        reported_failures: default(),
+        retrying: default(),
+        attempts: default(),
+        track_dependencies,
         marker: PhantomData::<Assets>,
     };
+    #[cfg(feature = "collection_diagnostics")]
+    crate::collection_registry::record_load_requests::<Assets>(world, &handles.handles);
+    #[cfg(feature = "checksums")]
+    if let Some(pending) = world.remove_resource::<PendingChecksums>() {
+        world.insert_resource(LoadingChecksums::<Assets>::new(pending));
+    }
+    let mut byte_budget = world.remove_resource::<AssetByteBudget>();
+    if let Some(byte_budget) = byte_budget.as_mut() {
+        let mut deferred_assets = world.resource_mut::<DeferredAssets>();
+        for path in byte_budget.deferred.drain(..) {
+            // collections can share a deferred path
+            if !deferred_assets.paths.contains(&path) {
+                deferred_assets.paths.push(path);
+            }
+        }
+    }
+    let mut load_throttle = world.remove_resource::<AssetLoadThrottle>();
+    if let Some(load_throttle) = load_throttle.as_mut() {
+        handles.queued.append(&mut load_throttle.queued);
+    }
+    #[cfg(feature = "test_support")]
+    crate::test_support::request_stepped_assets(world, &state, &handles.handles, &handles.queued);
     world.insert_resource(handles);
+    if let Some(config) = world
+        .resource_mut::<AssetLoaderConfiguration<S>>()
+        .state_configurations
+        .get_mut(&state)
+    {
+        if byte_budget.is_some() {
+            config.byte_budget = byte_budget;
+        }
+        if load_throttle.is_some() {
+            config.load_throttle = load_throttle;
+        }
+    }
 }
 
 pub(crate) fn check_loading_collection<S: States, Assets: AssetCollection>(world: &mut World) {
@@ -54,43 +218,637 @@ pub(crate) fn check_loading_collection<S: States, Assets: AssetCollection>(world
         "Check loading of collection for type id {:?}",
         TypeId::of::<Assets>()
     );
+    if start_when_prerequisites_loaded::<S, Assets>(world) || failed_fast::<S>(world) {
+        return;
+    }
+    start_queued_loads::<S, Assets>(world);
+    retry_failed_handles::<S, Assets>(world);
+    #[cfg(feature = "load_trace")]
+    trace_loading_collection::<Assets>(world);
+    report_failed_handles::<S, Assets>(world);
+    #[cfg(feature = "checksums")]
+    if !failed_fast::<S>(world) {
+        report_checksum_mismatches::<Assets>(world);
+    }
     if let Some((done, total)) = count_loaded_handles::<S, Assets>(world.cell()) {
+        if let Some((loaded, total)) = count_assets::<Assets>(world) {
+            world
+                .resource_mut::<LoadingProgress>()
+                .update_collection::<Assets>(loaded, total);
+        }
+        #[cfg(feature = "progress_tracking")]
+        let progress = collection_progress::<S, Assets>(world, Progress { done, total });
+        if total != done && skip_requested::<S>(world) {
+            skip_loading_collection::<S, Assets>(world);
+            return;
+        }
+        #[cfg(feature = "checksums")]
+        if total == done && !collection_hashed::<S, Assets>(world) {
+            if skip_requested::<S>(world) {
+                skip_loading_collection::<S, Assets>(world);
+                return;
+            }
+            #[cfg(feature = "progress_tracking")]
+            world
+                .resource::<ProgressCounter>()
+                .manually_track(Progress {
+                    done: progress.done,
+                    total: progress.total + 1,
+                });
+            return;
+        }
         if total == done {
-            let asset_collection = Assets::create(world);
+            let asset_collection = match world.remove_resource::<UnreadyCollection<Assets>>() {
+                Some(UnreadyCollection(asset_collection)) => asset_collection,
+                None => {
+                    let build_settings = current_build_settings::<S>(world);
+                    world.insert_resource(build_settings);
+                    let asset_collection =
+                        match world.remove_resource::<CollectionConstructor<Assets>>() {
+                            Some(constructor) => {
+                                let handles = loaded_handles::<Assets>(world);
+                                let asset_collection = (constructor.0)(handles, world);
+                                world.insert_resource(constructor);
+                                asset_collection
+                            }
+                            None => Assets::create(world),
+                        };
+                    world.remove_resource::<CollectionBuildSettings>();
+                    asset_collection
+                }
+            };
+            if !asset_collection.ready(world) {
+                if skip_requested::<S>(world) {
+                    skip_loading_collection::<S, Assets>(world);
+                    return;
+                }
+                trace!(
+                    "Waiting for the custom readiness of {}",
+                    type_name::<Assets>()
+                );
+                world.insert_resource(UnreadyCollection(asset_collection));
+                #[cfg(feature = "progress_tracking")]
+                world
+                    .resource::<ProgressCounter>()
+                    .manually_track(Progress {
+                        done: progress.done,
+                        total: progress.total + 1,
+                    });
+                return;
+            }
+            #[cfg(any(feature = "2d", feature = "3d"))]
+            queue_collection_images::<S, Assets>(world, &asset_collection);
             world.insert_resource(asset_collection);
+            mark_collection_loaded::<S, Assets>(world);
+            world.send_event(AssetCollectionLoaded {
+                collection: type_name::<Assets>(),
+            });
             world.remove_resource::<LoadingAssetHandles<Assets>>();
+            #[cfg(feature = "checksums")]
+            world.remove_resource::<LoadingChecksums<Assets>>();
+            world
+                .resource_mut::<LoadingProgress>()
+                .complete_collection::<Assets>();
+            finish_loading_collection::<S>(world);
 
             #[cfg(feature = "progress_tracking")]
             world
                 .resource_mut::<ProgressCounter>()
-                .persist_progress(Progress { done, total });
+                .persist_progress(progress);
         } else {
             #[cfg(feature = "progress_tracking")]
-            world
-                .resource::<ProgressCounter>()
-                .manually_track(Progress { done, total });
+            world.resource::<ProgressCounter>().manually_track(progress);
+        }
+    }
+}
+
+/// Record the begin of the loads a collection just issued in the [`LoadTrace`](crate::load_trace::LoadTrace)
+#[cfg(feature = "load_trace")]
+fn trace_started_collection<Assets: AssetCollection>(world: &mut World, handles: &[UntypedHandle]) {
+    let cell = world.cell();
+    let Some(mut trace) = cell.get_resource_mut::<crate::load_trace::LoadTrace>() else {
+        return;
+    };
+    let asset_server = cell
+        .get_resource::<AssetServer>()
+        .expect("Cannot get AssetServer resource");
+    trace.begin(type_name::<Assets>(), &asset_server, handles);
+}
+
+/// Record the loads of a collection in the [`LoadTrace`](crate::load_trace::LoadTrace)
+#[cfg(feature = "load_trace")]
+fn trace_loading_collection<Assets: AssetCollection>(world: &mut World) {
+    let cell = world.cell();
+    let (Some(loading_asset_handles), Some(mut trace)) = (
+        cell.get_resource::<LoadingAssetHandles<Assets>>(),
+        cell.get_resource_mut::<crate::load_trace::LoadTrace>(),
+    ) else {
+        return;
+    };
+    let asset_server = cell
+        .get_resource::<AssetServer>()
+        .expect("Cannot get AssetServer resource");
+    let optional = cell.get_resource::<OptionalAssets>();
+    trace.record(
+        type_name::<Assets>(),
+        &asset_server,
+        &loading_asset_handles.handles,
+        |id| {
+            collection_load_state::<Assets>(
+                &asset_server,
+                optional.as_deref(),
+                id,
+                loading_asset_handles.track_dependencies,
+            )
+        },
+    );
+}
+
+/// Start loading a collection and count the dynamic assets and runtime paths it requires, but that are missing
+pub(crate) fn load_collection<Assets: AssetCollection>(
+    world: &mut World,
+) -> (Vec<UntypedHandle>, usize) {
+    let missing = |world: &World| {
+        world
+            .get_resource::<MissingDynamicAssets>()
+            .map_or(0, |missing| missing.0.len())
+            + world
+                .get_resource::<MissingRuntimePaths>()
+                .map_or(0, |missing| missing.0.len())
+    };
+    let reported = missing(world);
+    let handles = Assets::load(world);
+
+    (handles, missing(world) - reported)
+}
+
+/// The loaded handles of a collection with folders replaced by their files
+///
+/// Handles of assets loaded without a type are replaced by the handles of the loaded assets.
+fn loaded_handles<Assets: AssetCollection>(world: &World) -> Vec<UntypedHandle> {
+    let Some(loading_asset_handles) = world.get_resource::<LoadingAssetHandles<Assets>>() else {
+        return vec![];
+    };
+    let folders = world.get_resource::<bevy::asset::Assets<LoadedFolder>>();
+    let loaded_untyped = world.get_resource::<bevy::asset::Assets<LoadedUntypedAsset>>();
+    let mut handles = vec![];
+    for handle in &loading_asset_handles.handles {
+        if handle.type_id() == TypeId::of::<LoadedFolder>() {
+            if let Some(folder) =
+                folders.and_then(|folders| folders.get(handle.id().typed::<LoadedFolder>()))
+            {
+                handles.extend(folder.handles.iter().cloned());
+                continue;
+            }
+        } else if handle.type_id() == TypeId::of::<LoadedUntypedAsset>() {
+            if let Some(asset) = loaded_untyped.and_then(|loaded_untyped| {
+                loaded_untyped.get(handle.id().typed::<LoadedUntypedAsset>())
+            }) {
+                handles.push(asset.handle.clone());
+                continue;
+            }
+        }
+        handles.push(handle.clone());
+    }
+
+    handles
+}
+
+/// A loading state configured with [`LoadingState::fail_fast`](crate::loading_state::LoadingState::fail_fast)
+/// stops loading after its first failed asset
+fn failed_fast<S: States>(world: &World) -> bool {
+    let state = world.resource::<State<S>>();
+    world
+        .resource::<AssetLoaderConfiguration<S>>()
+        .state_configurations
+        .get(state.get())
+        .is_some_and(|config| config.fail_fast && config.loading_failed)
+}
+
+fn skip_requested<S: States>(world: &World) -> bool {
+    let state = world.resource::<State<S>>();
+    world
+        .resource::<AssetLoaderConfiguration<S>>()
+        .state_configurations
+        .get(state.get())
+        .is_some_and(|config| config.skip_requested)
+}
+
+/// Stop waiting for a collection of a skipped loading state without inserting it
+fn skip_loading_collection<S: States, Assets: AssetCollection>(world: &mut World) {
+    debug!(
+        "Skipping the collection {} that is still loading",
+        type_name::<Assets>()
+    );
+    world.remove_resource::<UnreadyCollection<Assets>>();
+    world.remove_resource::<LoadingAssetHandles<Assets>>();
+    #[cfg(feature = "checksums")]
+    world.remove_resource::<LoadingChecksums<Assets>>();
+    #[cfg(feature = "checksums")]
+    world.remove_resource::<HashingCollection<Assets>>();
+    finish_loading_collection::<S>(world);
+}
+
+/// Whether the hash of a loaded collection is done, if its loading state hashes collections
+///
+/// Starts hashing the files of the collection on the first call and inserts the
+/// [`CollectionHash`] once it is done. If a file cannot be read, the failed hash stays in the world
+/// until the loading state exits, so the collection is not hashed again.
+#[cfg(feature = "checksums")]
+fn collection_hashed<S: States, Assets: AssetCollection>(world: &mut World) -> bool {
+    let state = world.resource::<State<S>>();
+    let hash_collections = world
+        .resource::<AssetLoaderConfiguration<S>>()
+        .state_configurations
+        .get(state.get())
+        .is_some_and(|config| config.hash_collections);
+    if !hash_collections || world.contains_resource::<CollectionHash<Assets>>() {
+        return true;
+    }
+    let Some(mut hashing) = world.get_resource_mut::<HashingCollection<Assets>>() else {
+        let asset_server = world.resource::<AssetServer>();
+        let paths = loaded_handles::<Assets>(world)
+            .iter()
+            .filter_map(|handle| handle.path())
+            .map(|path| requested_asset_path(path.without_label()))
+            .collect();
+        let hashing = HashingCollection::<Assets>::spawn(asset_server, paths);
+        world.insert_resource(hashing);
+        return false;
+    };
+    if hashing.failed() {
+        return true;
+    }
+    match hashing.poll() {
+        None => false,
+        Some(Ok(hash)) => {
+            world.remove_resource::<HashingCollection<Assets>>();
+            world.insert_resource(hash);
+            true
+        }
+        Some(Err(path)) => {
+            warn!(
+                "Failed to read '{path}' while hashing the collection {}. No CollectionHash is inserted",
+                type_name::<Assets>()
+            );
+            true
+        }
+    }
+}
+
+pub(crate) fn finish_loading_collection<S: States>(world: &mut World) {
+    let state = world.resource::<State<S>>().get().clone();
+    if let Some(config) = world
+        .resource_mut::<AssetLoaderConfiguration<S>>()
+        .state_configurations
+        .get_mut(&state)
+    {
+        config.loading_collections -= 1;
+    }
+}
+
+/// Let collections waiting for this one start loading
+fn mark_collection_loaded<S: States, Assets: AssetCollection>(world: &mut World) {
+    let state = world.resource::<State<S>>().get().clone();
+    if let Some(config) = world
+        .resource_mut::<AssetLoaderConfiguration<S>>()
+        .state_configurations
+        .get_mut(&state)
+    {
+        config.loaded_collections.insert(TypeId::of::<Assets>());
+    }
+}
+
+/// Progress of a loading collection, counting the files of loaded folders if configured
+#[cfg(feature = "progress_tracking")]
+fn collection_progress<S: States, Assets: AssetCollection>(
+    world: &World,
+    progress: Progress,
+) -> Progress {
+    let state = world.resource::<State<S>>();
+    let weight_by_asset_count = world
+        .resource::<AssetLoaderConfiguration<S>>()
+        .state_configurations
+        .get(state.get())
+        .map(|config| config.weight_by_asset_count)
+        .unwrap_or(false);
+    if !weight_by_asset_count {
+        return progress;
+    }
+    let Some((done, total)) = count_assets::<Assets>(world) else {
+        return progress;
+    };
+
+    Progress {
+        done: done as u32,
+        total: total as u32,
+    }
+}
+
+/// Number of loaded and all assets of a loading collection, counting the files of loaded folders
+///
+/// Queued loads count as not loaded.
+fn count_assets<Assets: AssetCollection>(world: &World) -> Option<(usize, usize)> {
+    let loading_asset_handles = world.get_resource::<LoadingAssetHandles<Assets>>()?;
+    let asset_server = world.resource::<AssetServer>();
+    let optional = world.get_resource::<OptionalAssets>();
+    let folders = world.get_resource::<bevy::asset::Assets<LoadedFolder>>();
+    let is_loaded = |handle: &UntypedHandle| {
+        collection_load_state::<Assets>(
+            asset_server,
+            optional,
+            handle.id(),
+            loading_asset_handles.track_dependencies,
+        ) == Some(LoadState::Loaded)
+    };
+
+    let mut loaded = 0;
+    let mut total = loading_asset_handles.queued.len();
+    for handle in &loading_asset_handles.handles {
+        let folder = if handle.type_id() == TypeId::of::<LoadedFolder>() {
+            folders.and_then(|folders| folders.get(handle.id().typed::<LoadedFolder>()))
+        } else {
+            None
+        };
+        if let Some(folder) = folder {
+            total += folder.handles.len();
+            loaded += folder.handles.iter().filter(|file| is_loaded(file)).count();
+        } else {
+            total += 1;
+            loaded += is_loaded(handle) as usize;
         }
     }
+
+    Some((loaded, total))
+}
+
+/// Start queued loads of a collection as long as the loading state allows more concurrent loads
+fn start_queued_loads<S: States, Assets: AssetCollection>(world: &mut World) {
+    let Some(mut loading_asset_handles) = world.remove_resource::<LoadingAssetHandles<Assets>>()
+    else {
+        return;
+    };
+    if !loading_asset_handles.queued.is_empty() {
+        world.resource_scope(
+            |world, mut asset_loader_configuration: Mut<AssetLoaderConfiguration<S>>| {
+                let state = world.resource::<State<S>>();
+                let asset_server = world.resource::<AssetServer>();
+                let Some(throttle) = asset_loader_configuration
+                    .state_configurations
+                    .get_mut(state.get())
+                    .and_then(|config| config.load_throttle.as_mut())
+                else {
+                    return;
+                };
+                let mut free_slots = throttle.free_slots(asset_server);
+                let mut started = 0;
+                for path in &loading_asset_handles.queued {
+                    let handle = if let Some(handle) = throttle.in_flight.get(path) {
+                        // another collection of the loading state already loads this path
+                        handle.clone()
+                    } else if free_slots > 0 {
+                        free_slots -= 1;
+                        throttle.started_this_frame += 1;
+                        trace!("Starting queued load of '{path}'");
+                        let handle = asset_server.load_untyped(path).untyped();
+                        throttle.in_flight.insert(path.clone(), handle.clone());
+                        handle
+                    } else {
+                        break;
+                    };
+                    loading_asset_handles.handles.push(handle);
+                    started += 1;
+                }
+                loading_asset_handles.queued.drain(..started);
+            },
+        );
+    }
+    world.insert_resource(loading_asset_handles);
+}
+
+/// Load failed assets of a collection again while the loading state has retries left
+///
+/// A failed asset is requested again after the retry delay, which doubles with every attempt.
+/// The asset server only loads a failed path again after all handles to it were dropped. Until then,
+/// the asset is kept in [`LoadingAssetHandles::retrying`] and requested again in the next frames.
+fn retry_failed_handles<S: States, Assets: AssetCollection>(world: &mut World) {
+    let state = world.resource::<State<S>>().get().clone();
+    let Some((retries, retry_delay)) = world
+        .resource::<AssetLoaderConfiguration<S>>()
+        .state_configurations
+        .get(&state)
+        .map(|config| (config.retries, config.retry_delay))
+        .filter(|(retries, _)| *retries > 0)
+    else {
+        return;
+    };
+    let Some(mut loading_asset_handles) = world.remove_resource::<LoadingAssetHandles<Assets>>()
+    else {
+        return;
+    };
+    let now = world
+        .get_resource::<Time>()
+        .map_or(Duration::ZERO, Time::elapsed);
+    world.resource_scope(
+        |world, mut asset_loader_configuration: Mut<AssetLoaderConfiguration<S>>| {
+            let asset_server = world.resource::<AssetServer>();
+            let optional = world.get_resource::<OptionalAssets>();
+            let LoadingAssetHandles {
+                handles,
+                retrying,
+                attempts,
+                track_dependencies,
+                ..
+            } = &mut loading_asset_handles;
+            let retry_count = retrying.len();
+            handles.retain(|handle| {
+                if collection_load_state::<Assets>(
+                    asset_server,
+                    optional,
+                    handle.id(),
+                    *track_dependencies,
+                ) != Some(LoadState::Failed)
+                {
+                    return true;
+                }
+                let Some(path) = asset_server.get_path(handle.id()) else {
+                    return true;
+                };
+                let path = requested_asset_path(path).to_string();
+                let attempt = attempts.entry(path.clone()).or_default();
+                if *attempt >= retries {
+                    return true;
+                }
+                let delay = retry_delay.saturating_mul(1 << (*attempt).min(16));
+                *attempt += 1;
+                warn!("Failed to load '{path}', retrying in {delay:?} ({attempt}/{retries})");
+                retrying.push(RetryingAsset {
+                    path,
+                    folder: handle.type_id() == TypeId::of::<LoadedFolder>(),
+                    retry_at: now + delay,
+                });
+                false
+            });
+            if retrying.len() > retry_count {
+                // the throttle should not keep the failed handles alive
+                if let Some(throttle) = asset_loader_configuration
+                    .state_configurations
+                    .get_mut(&state)
+                    .and_then(|config| config.load_throttle.as_mut())
+                {
+                    throttle.free_slots(asset_server);
+                }
+            }
+            retrying.retain(|asset| {
+                if asset.retry_at > now {
+                    return true;
+                }
+                let handle = if asset.folder {
+                    asset_server.load_folder(&asset.path).untyped()
+                } else {
+                    asset_server.load_untyped(&asset.path).untyped()
+                };
+                if asset_server.get_load_state(handle.id()) == Some(LoadState::Failed) {
+                    // the failed asset was not dropped yet
+                    return true;
+                }
+                trace!("Retrying load of '{}'", asset.path);
+                handles.push(handle);
+                false
+            });
+        },
+    );
+    world.insert_resource(loading_asset_handles);
+}
+
+fn current_build_settings<S: States>(world: &World) -> CollectionBuildSettings {
+    let state = world.resource::<State<S>>();
+    world
+        .resource::<AssetLoaderConfiguration<S>>()
+        .state_configurations
+        .get(state.get())
+        .map(|config| config.build_settings.clone())
+        .unwrap_or_default()
+}
+
+fn report_failed_handles<S: States, Assets: AssetCollection>(world: &mut World) {
+    world.resource_scope(|world, asset_server: Mut<AssetServer>| {
+        let Some(loading_asset_handles) = world.get_resource::<LoadingAssetHandles<Assets>>()
+        else {
+            return;
+        };
+        // failed optional assets do not fail the collection
+        let optional = world.get_resource::<OptionalAssets>();
+        let failed_handles: Vec<UntypedHandle> = loading_asset_handles
+            .handles
+            .iter()
+            .filter(|handle| {
+                collection_load_state::<Assets>(
+                    &asset_server,
+                    optional,
+                    handle.id(),
+                    loading_asset_handles.track_dependencies,
+                ) == Some(LoadState::Failed)
+            })
+            .cloned()
+            .collect();
+        let state = world.resource::<State<S>>().get().clone();
+        let mut configuration = world.resource_mut::<AssetLoaderConfiguration<S>>();
+        let fail_fast = configuration
+            .state_configurations
+            .get_mut(&state)
+            .filter(|config| config.fail_fast);
+        // only the first failure of a fail fast loading state is reported
+        let failed_handles = match fail_fast {
+            Some(config) if !failed_handles.is_empty() => {
+                config.loading_failed = true;
+                failed_handles.into_iter().take(1).collect()
+            }
+            _ => failed_handles,
+        };
+        let mut loading_asset_handles = world.resource_mut::<LoadingAssetHandles<Assets>>();
+        let failed_paths: Vec<String> = failed_handles
+            .into_iter()
+            .filter(|handle| loading_asset_handles.reported_failures.insert(handle.id()))
+            .map(|handle| {
+                asset_server
+                    .get_path(handle.id())
+                    .map(|path| requested_asset_path(path).to_string())
+                    .unwrap_or_default()
+            })
+            .collect();
+        world
+            .resource_mut::<FailedAssets>()
+            .0
+            .extend(failed_paths.iter().cloned());
+        for path in failed_paths {
+            world.send_event(AssetLoadFailed {
+                collection: type_name::<Assets>(),
+                path,
+            });
+        }
+    });
+}
+
+#[cfg(feature = "checksums")]
+fn report_checksum_mismatches<Assets: AssetCollection>(world: &mut World) {
+    let Some(mut checksums) = world.get_resource_mut::<LoadingChecksums<Assets>>() else {
+        return;
+    };
+    let mismatches = checksums.poll_mismatches();
+    world
+        .resource_mut::<FailedAssets>()
+        .0
+        .extend(mismatches.iter().cloned());
+    for path in mismatches {
+        warn!("The checksum of '{path}' does not match the expected SHA-256 digest");
+        world.send_event(AssetLoadFailed {
+            collection: type_name::<Assets>(),
+            path,
+        });
+    }
 }
 
 fn count_loaded_handles<S: States, Assets: AssetCollection>(cell: WorldCell) -> Option<(u32, u32)> {
     let loading_asset_handles = cell.get_resource::<LoadingAssetHandles<Assets>>()?;
-    let total = loading_asset_handles.handles.len();
+    // a missing dynamic asset counts as an asset that never loads
+    let total = loading_asset_handles.handles.len()
+        + loading_asset_handles.queued.len()
+        + loading_asset_handles.retrying.len()
+        + loading_asset_handles.missing_assets;
 
     let asset_server = cell
         .get_resource::<AssetServer>()
         .expect("Cannot get AssetServer resource");
-    let failure = loading_asset_handles
+    let optional = cell.get_resource::<OptionalAssets>();
+    let load_states: Vec<_> = loading_asset_handles
         .handles
         .iter()
-        .any(|handle| asset_server.get_load_state(handle.id()) == Some(LoadState::Failed));
-    let done = loading_asset_handles
-        .handles
+        .map(|handle| {
+            collection_load_state::<Assets>(
+                &asset_server,
+                optional.as_deref(),
+                handle.id(),
+                loading_asset_handles.track_dependencies,
+            )
+        })
+        .collect();
+    let failure =
+        load_states.contains(&Some(LoadState::Failed)) || loading_asset_handles.missing_assets > 0;
+    let done = load_states
         .iter()
-        .map(|handle| asset_server.get_load_state(handle.id()))
-        .filter(|state| state == &Some(LoadState::Loaded))
+        .filter(|state| state == &&Some(LoadState::Loaded))
         .count();
-    if done < total && !failure {
+    #[cfg(feature = "checksums")]
+    let (done, total, failure) = match cell.get_resource::<LoadingChecksums<Assets>>() {
+        Some(checksums) => (
+            done + checksums.matched(),
+            total + checksums.total(),
+            failure || checksums.any_mismatch(),
+        ),
+        None => (done, total, failure),
+    };
+    if !failure {
         return Some((done as u32, total as u32));
     }
 
@@ -104,11 +862,7 @@ fn count_loaded_handles<S: States, Assets: AssetCollection>(cell: WorldCell) ->
         .state_configurations
         .get_mut(state.get())
     {
-        if failure {
-            config.loading_failed = true;
-        } else {
-            config.loading_collections -= 1;
-        }
+        config.loading_failed = true;
     } else {
         warn!("Failed to read loading state configuration in count_loaded_handles")
     }
@@ -116,6 +870,24 @@ fn count_loaded_handles<S: States, Assets: AssetCollection>(cell: WorldCell) ->
     Some((done as u32, total as u32))
 }
 
+/// Untyped loads (e.g. for `Handle<T>` fields) are tracked by Bevy under a modified asset source.
+/// This returns the path as it was requested by the asset collection.
+pub(crate) fn requested_asset_path(path: AssetPath) -> AssetPath<'static> {
+    let path = path.into_owned();
+    let AssetSourceId::Name(source) = path.source() else {
+        return path;
+    };
+    if &**source == "--untyped" {
+        return path.with_source(AssetSourceId::Default);
+    }
+    if let Some(source) = source.strip_suffix("----untyped") {
+        let source = source.to_owned();
+        return path.with_source(source);
+    }
+
+    path
+}
+
 pub(crate) fn resume_to_finalize<S: States>(
     loader_configuration: Res<AssetLoaderConfiguration<S>>,
     mut internal_state: ResMut<NextState<InternalLoadingState<S>>>,
@@ -140,22 +912,83 @@ pub(crate) fn resume_to_finalize<S: States>(
 
 pub(crate) fn initialize_loading_state<S: States>(
     mut loading_state: ResMut<NextState<InternalLoadingState<S>>>,
+    mut loading_progress: ResMut<LoadingProgress>,
+    mut failed_assets: ResMut<FailedAssets>,
+    mut missing_dynamic_assets: ResMut<MissingDynamicAssets>,
+    mut missing_runtime_paths: ResMut<MissingRuntimePaths>,
     #[cfg(feature = "progress_tracking")] mut progress_counter: ResMut<ProgressCounter>,
+    #[cfg(feature = "load_trace")] mut load_trace: ResMut<crate::load_trace::LoadTrace>,
 ) {
+    *loading_progress = default();
+    #[cfg(feature = "load_trace")]
+    load_trace.enter_loading_state();
+    failed_assets.0.clear();
+    missing_dynamic_assets.0.clear();
+    missing_runtime_paths.0.clear();
     #[cfg(feature = "progress_tracking")]
     progress_counter.persist_progress_hidden(HiddenProgress(Progress { total: 1, done: 0 }));
     loading_state.set(InternalLoadingState::LoadingDynamicAssetCollections);
 }
 
+pub(crate) fn receive_continue_loading<S: States>(
+    mut continue_loading: EventReader<ContinueLoading>,
+    state: Res<State<S>>,
+    mut asset_loader_configuration: ResMut<AssetLoaderConfiguration<S>>,
+) {
+    if continue_loading.read().count() == 0 {
+        return;
+    }
+    if let Some(config) = asset_loader_configuration
+        .state_configurations
+        .get_mut(state.get())
+    {
+        config.continue_requested = true;
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn finish_loading_state<S: States>(
     state: Res<State<S>>,
     mut next_state: ResMut<NextState<S>>,
     #[cfg(feature = "progress_tracking")] mut progress_counter: ResMut<ProgressCounter>,
     mut loading_state: ResMut<NextState<InternalLoadingState<S>>>,
-    asset_loader_configuration: Res<AssetLoaderConfiguration<S>>,
+    mut asset_loader_configuration: ResMut<AssetLoaderConfiguration<S>>,
+    mut loading_progress: ResMut<LoadingProgress>,
+    #[cfg(any(feature = "2d", feature = "3d"))] gpu_prewarm: Option<ResMut<GpuPrewarm>>,
+    #[cfg(feature = "audio")] audio_durations: Option<Res<crate::audio::AudioDurations>>,
+    rendered_frames: Option<Res<RenderedFrames>>,
 ) {
+    if let Some(config) = asset_loader_configuration
+        .state_configurations
+        .get_mut(state.get())
+    {
+        #[cfg(any(feature = "2d", feature = "3d"))]
+        if config.prewarm_gpu
+            && !config.skip_requested
+            && gpu_prewarm.is_some_and(|mut prewarm| !prewarm.poll())
+        {
+            return;
+        }
+        #[cfg(feature = "audio")]
+        if !config.skip_requested && audio_durations.is_some_and(|durations| !durations.is_done()) {
+            return;
+        }
+        if config.wait_for_continue && !config.continue_requested && !config.skip_requested {
+            return;
+        }
+        if let Some(rendered_frames) = rendered_frames.filter(|_| config.after_first_render) {
+            let finished_at_frame = *config
+                .finished_at_frame
+                .get_or_insert_with(|| rendered_frames.get());
+            if rendered_frames.get() <= finished_at_frame {
+                return;
+            }
+        }
+        config.continue_requested = false;
+    }
     #[cfg(feature = "progress_tracking")]
     progress_counter.persist_progress_hidden(HiddenProgress(Progress { total: 0, done: 1 }));
+    loading_progress.finish();
     info!(
         "Loading state '{}::{:?}' is done",
         type_name::<S>(),
@@ -163,10 +996,14 @@ pub(crate) fn finish_loading_state<S: States>(
     );
     if let Some(config) = asset_loader_configuration
         .state_configurations
-        .get(state.get())
+        .get_mut(state.get())
     {
-        if let Some(next) = config.next.as_ref() {
-            next_state.set(next.clone());
+        if let Some(next) = config.next.clone() {
+            if config.transition_at_end_of_frame {
+                config.pending_transition = Some(next);
+            } else {
+                next_state.set(next);
+            }
             return;
         }
     }
@@ -174,13 +1011,95 @@ pub(crate) fn finish_loading_state<S: States>(
     loading_state.set(InternalLoadingState::Done(PhantomData));
 }
 
+pub(crate) fn apply_pending_transition<S: States>(
+    state: Res<State<S>>,
+    mut next_state: ResMut<NextState<S>>,
+    mut asset_loader_configuration: ResMut<AssetLoaderConfiguration<S>>,
+) {
+    let Some(next) = asset_loader_configuration
+        .state_configurations
+        .get_mut(state.get())
+        .and_then(|config| config.pending_transition.take())
+    else {
+        return;
+    };
+    next_state.set(next);
+}
+
 pub(crate) fn reset_loading_state<S: States>(world: &mut World) {
     world.remove_resource::<State<InternalLoadingState<S>>>();
     world.init_resource::<State<InternalLoadingState<S>>>();
+    let state = world.resource::<State<S>>().get().clone();
+    let mut has_byte_budget = false;
+    let asset_server = world.resource::<AssetServer>().clone();
+    if let Some(config) = world
+        .resource_mut::<AssetLoaderConfiguration<S>>()
+        .state_configurations
+        .get_mut(&state)
+    {
+        config.continue_requested = false;
+        config.skip_requested = false;
+        config.pending_transition = None;
+        config.finished_at_frame = None;
+        config.collections.clear();
+        config.loaded_collections.clear();
+        if let Some(byte_budget) = config.byte_budget.as_mut() {
+            byte_budget.recount(&asset_server);
+            has_byte_budget = true;
+        }
+    }
+    if has_byte_budget {
+        world.resource_mut::<DeferredAssets>().paths.clear();
+    }
+}
+
+pub(crate) fn loading_state_is_active<S: States>(
+    state: Res<State<S>>,
+    internal_state: Option<Res<State<InternalLoadingState<S>>>>,
+    asset_loader_configuration: Res<AssetLoaderConfiguration<S>>,
+) -> bool {
+    let Some(internal_state) = internal_state else {
+        return true;
+    };
+    if !matches!(internal_state.get(), InternalLoadingState::Done(_)) {
+        return true;
+    }
+
+    !asset_loader_configuration
+        .state_configurations
+        .get(state.get())
+        .map(|config| config.cleanup_after)
+        .unwrap_or(false)
+}
+
+pub(crate) fn warn_if_not_watching_for_changes<S: States>(
+    loading_state: &S,
+    asset_server: &AssetServer,
+) {
+    let watching_for_changes = asset_server
+        .get_source(AssetSourceId::Default)
+        .map(|source| source.event_receiver().is_some())
+        .unwrap_or(false);
+    if !watching_for_changes {
+        warn!(
+            "Loading state '{}::{:?}' requested hot reloading, but the asset server is not watching for changes. \
+            Enable the Bevy feature 'file_watcher' for assets to be reloaded.",
+            type_name::<S>(),
+            loading_state
+        );
+    }
 }
 
 pub(crate) fn run_loading_state<S: States>(world: &mut World) {
     let state = world.resource::<State<S>>().get().clone();
+    if let Some(throttle) = world
+        .resource_mut::<AssetLoaderConfiguration<S>>()
+        .state_configurations
+        .get_mut(&state)
+        .and_then(|config| config.load_throttle.as_mut())
+    {
+        throttle.next_frame();
+    }
     world.run_schedule(LoadingStateSchedule(state));
 }
 
@@ -0,0 +1,118 @@
+use crate::asset_collection::AssetCollection;
+use crate::io_task::IoTask;
+use crate::loading_state::folder_watch::list_files;
+use bevy::asset::{AssetPath, AssetServer};
+use bevy::ecs::change_detection::DetectChanges;
+use bevy::ecs::system::{Local, Res, ResMut, Resource};
+use bevy::log::warn;
+use bevy::utils::{HashMap, HashSet};
+use std::any::type_name;
+
+/// Files in the folders of asset collections that no loaded asset uses
+///
+/// Filled for loading states with [`LoadingState::report_unused_assets`](crate::loading_state::LoadingState::report_unused_assets).
+/// Folders loaded by `collection` fields are searched including their sub folders. For all other fields,
+/// only the files next to the loaded asset are reported. Files with a live handle in the [`AssetServer`]
+/// are not reported, so collections sharing a folder do not report each other's files.
+#[derive(Resource, Default, Debug)]
+pub struct UnusedAssets {
+    collections: HashMap<&'static str, Vec<String>>,
+}
+
+impl UnusedAssets {
+    /// Asset paths of the unused files next to the assets of the given collection
+    ///
+    /// Returns `None` while the folders of the collection are not listed yet.
+    pub fn get<A: AssetCollection>(&self) -> Option<&[String]> {
+        self.collections.get(type_name::<A>()).map(Vec::as_slice)
+    }
+
+    /// Iterate over the unused asset paths of all reported collections by collection type name
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &[String])> {
+        self.collections
+            .iter()
+            .map(|(collection, paths)| (*collection, paths.as_slice()))
+    }
+}
+
+/// List the folders of a newly inserted collection and report files no loaded asset uses
+///
+/// Only runs once no collection of the loading state is loading anymore, so that the handles of all
+/// collections in the state are alive while listing.
+pub(crate) fn report_unused_assets<A: AssetCollection>(
+    mut listing: Local<Option<IoTask<Vec<String>>>>,
+    collection: Option<Res<A>>,
+    asset_server: Res<AssetServer>,
+    mut unused_assets: ResMut<UnusedAssets>,
+) {
+    if let Some(collection) = collection.filter(DetectChanges::is_added) {
+        *listing = Some(list_unused_files(&*collection, &asset_server));
+    }
+    let Some(unused) = listing.as_ref().and_then(IoTask::poll) else {
+        return;
+    };
+    *listing = None;
+    if !unused.is_empty() {
+        warn!(
+            "The folders of the asset collection {} contain {} unused files: {:?}",
+            type_name::<A>(),
+            unused.len(),
+            unused
+        );
+    }
+    unused_assets.collections.insert(type_name::<A>(), unused);
+}
+
+fn list_unused_files<A: AssetCollection>(
+    collection: &A,
+    asset_server: &AssetServer,
+) -> IoTask<Vec<String>> {
+    let used: HashSet<AssetPath<'static>> = collection
+        .all_handles()
+        .iter()
+        .filter_map(|handle| handle.path())
+        .map(|path| path.without_label().into_owned())
+        .collect();
+    let folder_fields: Vec<AssetPath<'static>> = collection
+        .folders()
+        .into_iter()
+        .map(|(path, _)| AssetPath::parse(path).into_owned())
+        .collect();
+    let mut folders: HashSet<(AssetPath<'static>, bool)> = used
+        .iter()
+        .filter_map(AssetPath::parent)
+        .map(|folder| (folder, false))
+        .collect();
+    folders.retain(|(folder, _)| !folder_fields.contains(folder));
+    folders.extend(folder_fields.into_iter().map(|folder| (folder, true)));
+
+    let asset_server = asset_server.clone();
+    IoTask::spawn(async move {
+        let mut unused = vec![];
+        for (folder, recursive) in folders {
+            let Ok(source) = asset_server.get_source(folder.source()) else {
+                continue;
+            };
+            let Some(files) = list_files(source.reader(), folder.path(), recursive).await else {
+                continue;
+            };
+            unused.extend(
+                files
+                    .into_iter()
+                    .map(|file| {
+                        AssetPath::from_path(&file)
+                            .with_source(folder.source().clone_owned())
+                            .into_owned()
+                    })
+                    .filter(|file| {
+                        !used.contains(file) && asset_server.get_handle_untyped(file).is_none()
+                    })
+                    .map(|file| file.to_string()),
+            );
+        }
+        unused.sort();
+        unused.dedup();
+
+        unused
+    })
+}
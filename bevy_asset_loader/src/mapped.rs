@@ -0,0 +1,206 @@
+use bevy::asset::{AssetPath, UntypedHandle};
+use bevy::log::warn;
+use bevy::utils::hashbrown::hash_map::Entry;
+use bevy::utils::HashMap;
+use std::borrow::Borrow;
+use std::fmt::{Display, Formatter};
+use std::hash::Hash;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+/// Types that can be used as keys of mapped folders
+///
+/// A field `#[asset(path = "sounds", collection(mapped))]` can be a map with any key implementing
+/// this trait. `String` keys are the full asset path of a file (`sounds/ui/click.ogg`).
+/// [`RelativePath`] keys are relative to the loaded folder (`ui/click.ogg`) and [`FileStem`] keys
+/// additionally drop the extension (`ui/click`). [`BareFileStem`] keys only keep the file stem (`click`).
+///
+/// With `collection(mapped(full_path))`, keys are created from the full asset path instead of the path
+/// in the loaded folder, e.g. `sounds/ui/click` for [`FileStem`] keys.
+pub trait MapKey: Eq + Hash {
+    /// Create the key of the file at `path` in the loaded `folder`
+    fn from_asset_path(path: &Path, folder: &Path) -> Self;
+
+    /// Create the key of the file at `path` for a field with `collection(mapped(full_path))`
+    ///
+    /// Defaults to the key of the file in the asset root folder.
+    fn from_full_path(path: &Path) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_asset_path(path, Path::new(""))
+    }
+}
+
+impl MapKey for String {
+    fn from_asset_path(path: &Path, _folder: &Path) -> Self {
+        to_slash(path)
+    }
+}
+
+/// Map key holding the path of a file relative to its loaded folder, e.g. `ui/click.ogg`
+///
+/// Maps with this key can be indexed with `&str`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RelativePath(String);
+
+impl MapKey for RelativePath {
+    fn from_asset_path(path: &Path, folder: &Path) -> Self {
+        RelativePath(to_slash(path.strip_prefix(folder).unwrap_or(path)))
+    }
+}
+
+/// Map key holding the path of a file relative to its loaded folder without extension, e.g. `ui/click`
+///
+/// Files that only differ in their extension share a key. Only the first of them in alphabetical
+/// order is added to the map and a warning is logged for the others.
+/// Maps with this key can be indexed with `&str`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FileStem(String);
+
+impl MapKey for FileStem {
+    fn from_asset_path(path: &Path, folder: &Path) -> Self {
+        let relative = path.strip_prefix(folder).unwrap_or(path);
+        FileStem(to_slash(&relative.with_extension("")))
+    }
+}
+
+/// Map key holding only the file stem of a file, e.g. `click` for `sounds/ui/click.ogg`
+///
+/// Files in different directories or with different extensions can share a key. Only the first of them
+/// in alphabetical order of their paths is added to the map and a warning is logged for the others.
+/// Maps with this key can be indexed with `&str`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BareFileStem(String);
+
+impl MapKey for BareFileStem {
+    fn from_asset_path(path: &Path, _folder: &Path) -> Self {
+        BareFileStem(to_slash(Path::new(
+            path.file_stem().unwrap_or(path.as_os_str()),
+        )))
+    }
+}
+
+macro_rules! impl_string_key {
+    ($key:ident) => {
+        impl Deref for $key {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Borrow<str> for $key {
+            fn borrow(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl AsRef<str> for $key {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Display for $key {
+            fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl From<$key> for String {
+            fn from(key: $key) -> Self {
+                key.0
+            }
+        }
+    };
+}
+
+impl_string_key!(RelativePath);
+impl_string_key!(FileStem);
+impl_string_key!(BareFileStem);
+
+fn to_slash(path: &Path) -> String {
+    path_slash::PathExt::to_slash(path)
+        .expect("Path should be valid UTF-8")
+        .into()
+}
+
+/// Build the map of a mapped folder field
+///
+/// Used by the derive macro. Files are inserted in alphabetical order of their paths and a file is skipped
+/// with a warning if its key is already taken. Files for which `handle` returns `None` are skipped.
+#[doc(hidden)]
+pub fn map_folder<'a, K: MapKey, H>(
+    folder: &str,
+    full_path: bool,
+    handles: impl IntoIterator<Item = &'a UntypedHandle>,
+    handle: impl Fn(&UntypedHandle) -> Option<H>,
+) -> HashMap<K, H> {
+    let folder_path = AssetPath::parse(folder);
+    let files = handles
+        .into_iter()
+        .filter_map(|untyped| {
+            let path = untyped
+                .path()
+                .expect("Handles of a loaded folder should have a path")
+                .path()
+                .to_path_buf();
+            Some((path, handle(untyped)?))
+        })
+        .collect();
+
+    map_files(files, full_path, folder_path.path(), || {
+        format!("the mapped folder '{folder}'")
+    })
+}
+
+/// Build the map of a mapped field loading the given files
+///
+/// Used by the derive macro. Keys are created like for files in the asset root folder.
+/// Colliding keys are handled like in [`map_folder`].
+#[doc(hidden)]
+pub fn map_paths<'a, K: MapKey, H>(
+    full_path: bool,
+    files: impl IntoIterator<Item = (&'a str, H)>,
+) -> HashMap<K, H> {
+    let files = files
+        .into_iter()
+        .map(|(path, handle)| (AssetPath::parse(path).path().to_path_buf(), handle))
+        .collect();
+
+    map_files(files, full_path, Path::new(""), || {
+        "mapped files".to_owned()
+    })
+}
+
+fn map_files<K: MapKey, H>(
+    mut files: Vec<(PathBuf, H)>,
+    full_path: bool,
+    folder: &Path,
+    describe: impl Fn() -> String,
+) -> HashMap<K, H> {
+    files.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut map = HashMap::with_capacity(files.len());
+    for (path, handle) in files {
+        let key = if full_path {
+            K::from_full_path(&path)
+        } else {
+            K::from_asset_path(&path, folder)
+        };
+        match map.entry(key) {
+            Entry::Occupied(_) => warn!(
+                "Skipping '{}' of {}, because another file has the same key",
+                to_slash(&path),
+                describe()
+            ),
+            Entry::Vacant(entry) => {
+                entry.insert(handle);
+            }
+        }
+    }
+
+    map
+}
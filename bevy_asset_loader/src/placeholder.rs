@@ -0,0 +1,165 @@
+use bevy::app::{App, PreUpdate};
+use bevy::asset::{
+    Asset, AssetServer, Handle, RecursiveDependencyLoadState, UntypedAssetId, UntypedHandle,
+};
+use bevy::ecs::event::{Event, Events};
+use bevy::ecs::system::Resource;
+use bevy::ecs::world::World;
+use bevy::log::warn;
+use bevy::utils::HashMap;
+use std::any::type_name;
+use std::sync::{Mutex, PoisonError, RwLock};
+
+/// Sent when the placeholder of an asset collection field was replaced by the loaded asset
+///
+/// Fields with a `placeholder` attribute hold the placeholder handle if their asset is still loading
+/// when the collection is created. Once the asset and all its dependencies are loaded, the field
+/// is set to the real handle in the [`PreUpdate`] schedule and this event is sent in the same frame.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy_asset_loader::placeholder::PlaceholderReplaced;
+/// # use bevy::prelude::*;
+/// fn music_ready(mut replaced: EventReader<PlaceholderReplaced>) {
+///     for event in replaced.read() {
+///         info!("{}.{} is loaded", event.collection, event.field);
+///     }
+/// }
+/// ```
+#[derive(Event, Debug, Clone)]
+pub struct PlaceholderReplaced {
+    /// Type name of the asset collection
+    pub collection: &'static str,
+    /// Name of the field that now holds the loaded asset
+    pub field: &'static str,
+    /// Handle of the loaded asset
+    pub handle: UntypedHandle,
+}
+
+/// Assets of placeholder fields that are still loading
+///
+/// Used by the derive macro.
+#[derive(Resource, Default)]
+#[doc(hidden)]
+pub struct PendingPlaceholders {
+    /// Handles keeping assets alive between loading and creating their collection
+    loading: HashMap<&'static str, UntypedHandle>,
+    replacements: Vec<PendingReplacement>,
+}
+
+/// Sets the loaded handle in its collection, if the collection still exists
+type ReplacePlaceholder = Box<dyn FnOnce(&mut World) -> Option<PlaceholderReplaced> + Send + Sync>;
+
+struct PendingReplacement {
+    id: UntypedAssetId,
+    replace: ReplacePlaceholder,
+}
+
+impl PendingPlaceholders {
+    /// Start loading the asset of a placeholder field without waiting for it
+    #[doc(hidden)]
+    pub fn load(&mut self, asset_server: &AssetServer, path: &'static str) {
+        self.loading
+            .insert(path, asset_server.load_untyped(path).untyped());
+    }
+
+    /// The loaded asset at the given path, or the placeholder while it is still loading
+    ///
+    /// The placeholder is replaced in the field returned by `field` once the asset is loaded.
+    #[doc(hidden)]
+    pub fn loaded_or_placeholder<C: Resource, A: Asset, F: PlaceholderField<A> + 'static>(
+        world: &mut World,
+        path: &'static str,
+        field_name: &'static str,
+        placeholder: impl FnOnce(&mut World) -> Handle<A>,
+        field: fn(&mut C) -> &mut F,
+    ) -> Handle<A> {
+        let asset_server = world.resource::<AssetServer>().clone();
+        let handle: Handle<A> = asset_server.load(path);
+        let mut placeholders = world.get_resource_or_insert_with(PendingPlaceholders::default);
+        placeholders.loading.remove(path);
+        if asset_server.is_loaded_with_dependencies(&handle) {
+            return handle;
+        }
+        let loaded = handle.clone();
+        placeholders.replacements.push(PendingReplacement {
+            id: handle.id().untyped(),
+            replace: Box::new(move |world: &mut World| {
+                let mut collection = world.get_resource_mut::<C>()?;
+                field(&mut collection).replace_placeholder(loaded.clone());
+                Some(PlaceholderReplaced {
+                    collection: type_name::<C>(),
+                    field: field_name,
+                    handle: loaded.untyped(),
+                })
+            }),
+        });
+
+        placeholder(world)
+    }
+}
+
+/// Types of asset collection fields that can hold a placeholder
+///
+/// Used by the derive macro to replace placeholders with the loaded asset.
+pub trait PlaceholderField<A: Asset> {
+    /// Replace the placeholder in this field with the handle of the loaded asset
+    fn replace_placeholder(&mut self, handle: Handle<A>);
+}
+
+impl<A: Asset> PlaceholderField<A> for Handle<A> {
+    fn replace_placeholder(&mut self, handle: Handle<A>) {
+        *self = handle;
+    }
+}
+
+impl<A: Asset, T: PlaceholderField<A>> PlaceholderField<A> for Mutex<T> {
+    fn replace_placeholder(&mut self, handle: Handle<A>) {
+        self.get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .replace_placeholder(handle);
+    }
+}
+
+impl<A: Asset, T: PlaceholderField<A>> PlaceholderField<A> for RwLock<T> {
+    fn replace_placeholder(&mut self, handle: Handle<A>) {
+        self.get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .replace_placeholder(handle);
+    }
+}
+
+pub(crate) fn init_placeholders(app: &mut App) {
+    if app.world.contains_resource::<Events<PlaceholderReplaced>>() {
+        return;
+    }
+    app.init_resource::<PendingPlaceholders>()
+        .add_event::<PlaceholderReplaced>()
+        .add_systems(PreUpdate, replace_placeholders);
+}
+
+fn replace_placeholders(world: &mut World) {
+    let Some(mut placeholders) = world.remove_resource::<PendingPlaceholders>() else {
+        return;
+    };
+    let asset_server = world.resource::<AssetServer>().clone();
+    let mut replaced = vec![];
+    let mut pending = vec![];
+    for replacement in placeholders.replacements.drain(..) {
+        match asset_server.get_recursive_dependency_load_state(replacement.id) {
+            Some(RecursiveDependencyLoadState::Loaded) => {
+                // collections that were removed in the meantime are skipped
+                replaced.extend((replacement.replace)(world));
+            }
+            Some(RecursiveDependencyLoadState::Failed) => {
+                warn!(
+                    "Failed to load the asset {:?} of a placeholder field. The placeholder is kept.",
+                    asset_server.get_path(replacement.id)
+                );
+            }
+            _ => pending.push(replacement),
+        }
+    }
+    placeholders.replacements = pending;
+    world.insert_resource(placeholders);
+    world.send_event_batch(replaced);
+}
@@ -0,0 +1,250 @@
+use crate::asset_collection::AssetCollection;
+use bevy::app::{App, Update};
+use bevy::asset::io::{
+    AssetReader, AssetReaderError, AssetSource, AssetSourceId, PathStream, Reader,
+};
+use bevy::asset::{AssetApp, AssetServer};
+use bevy::ecs::change_detection::DetectChanges;
+use bevy::ecs::system::{Local, Res, Resource};
+use bevy::log::debug;
+use bevy::utils::{BoxedFuture, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// Extension trait to load assets from folders that depend on a quality setting
+///
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy_asset_loader::quality::{QualityAssetSourceApp, QualityLevel};
+/// # use bevy::prelude::*;
+/// # use bevy::asset::AssetPlugin;
+/// # fn main() {
+///     App::new()
+///         // `quality://player.png` loads `textures/high/player.png`
+///         .register_quality_asset_source("quality", "textures", "high")
+///         .add_plugins((MinimalPlugins, AssetPlugin::default()))
+///         .add_state::<GameState>()
+///         .add_loading_state(LoadingState::new(GameState::Loading).continue_to_state(GameState::Menu))
+///         .add_collection_to_loading_state::<_, TextureAssets>(GameState::Loading)
+///         .reload_on_quality_change::<TextureAssets>()
+/// #       .set_runner(|mut app| app.update())
+/// #       .run();
+/// # }
+/// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+/// # enum GameState { #[default] Loading, Menu }
+///
+/// #[derive(AssetCollection, Resource)]
+/// #[asset_collection(source = "quality")]
+/// struct TextureAssets {
+///     #[asset(path = "player.png")]
+///     player: Handle<Image>,
+/// }
+///
+/// // e.g. run from a settings menu
+/// fn lower_quality(mut quality: ResMut<QualityLevel>) {
+///     // `quality://player.png` is reloaded from `textures/low/player.png`
+///     quality.set("low");
+/// }
+/// ```
+pub trait QualityAssetSourceApp {
+    /// Register an asset source with the given name that reads from a folder of the current [`QualityLevel`]
+    ///
+    /// A path like `quality://player.png` is read from `<base_path>/<level>/player.png` in the `assets`
+    /// folder. Load assets from the source with such paths, or with `#[asset_collection(source = "quality")]`
+    /// on a collection. The level is the folder name of the quality, e.g. `"high"`.
+    ///
+    /// This inserts the [`QualityLevel`] resource with the given level. All quality asset sources share this
+    /// resource, so the level is ignored if the resource already exists.
+    ///
+    /// Like all asset sources, this has to be registered before the [`AssetPlugin`](bevy::asset::AssetPlugin).
+    /// Quality asset sources read unprocessed assets. The asset processor does not process their folders, so
+    /// loading from them fails with [`AssetMode::Processed`](bevy::asset::AssetMode::Processed).
+    fn register_quality_asset_source(
+        &mut self,
+        source: &'static str,
+        base_path: impl Into<PathBuf>,
+        level: impl Into<String>,
+    ) -> &mut Self;
+
+    /// Reload the assets of a collection from quality asset sources when the [`QualityLevel`] changes
+    ///
+    /// The handles of the collection stay the same, only their assets are replaced once they are reloaded
+    /// from the folder of the new level. Assets from other asset sources are not reloaded. Since quality
+    /// asset sources are not processed, reloading never runs the asset processor.
+    fn reload_on_quality_change<A: AssetCollection>(&mut self) -> &mut Self;
+}
+
+impl QualityAssetSourceApp for App {
+    fn register_quality_asset_source(
+        &mut self,
+        source: &'static str,
+        base_path: impl Into<PathBuf>,
+        level: impl Into<String>,
+    ) -> &mut Self {
+        let base_path = base_path.into();
+        let level = {
+            let mut quality = self
+                .world
+                .get_resource_or_insert_with(|| QualityLevel::new(level));
+            quality.sources.push(source);
+            quality.shared.clone()
+        };
+        self.register_asset_source(
+            AssetSourceId::Name(source.into()),
+            AssetSource::build().with_reader(move || {
+                Box::new(QualityAssetReader {
+                    base_path: base_path.clone(),
+                    level: level.clone(),
+                    readers: OnceLock::new(),
+                })
+            }),
+        )
+    }
+
+    fn reload_on_quality_change<A: AssetCollection>(&mut self) -> &mut Self {
+        self.add_systems(Update, reload_collection_on_quality_change::<A>)
+    }
+}
+
+/// The quality level read by quality asset sources
+///
+/// Quality asset sources are registered with [`QualityAssetSourceApp::register_quality_asset_source`].
+/// Changing the level only affects assets loaded afterwards. Use
+/// [`QualityAssetSourceApp::reload_on_quality_change`] to reload the assets of a collection.
+#[derive(Resource, Debug)]
+pub struct QualityLevel {
+    level: String,
+    shared: Arc<RwLock<String>>,
+    sources: Vec<&'static str>,
+}
+
+impl QualityLevel {
+    fn new(level: impl Into<String>) -> Self {
+        let level = level.into();
+        QualityLevel {
+            shared: Arc::new(RwLock::new(level.clone())),
+            level,
+            sources: vec![],
+        }
+    }
+
+    /// The current level
+    pub fn get(&self) -> &str {
+        &self.level
+    }
+
+    /// Read assets of quality asset sources from the folder of the given level
+    pub fn set(&mut self, level: impl Into<String>) {
+        self.level = level.into();
+        *self.shared.write().unwrap() = self.level.clone();
+    }
+}
+
+fn reload_collection_on_quality_change<A: AssetCollection>(
+    quality: Option<Res<QualityLevel>>,
+    collection: Option<Res<A>>,
+    asset_server: Res<AssetServer>,
+    mut loaded_level: Local<Option<String>>,
+) {
+    let Some(quality) = quality else {
+        return;
+    };
+    if !quality.is_changed() || loaded_level.as_deref() == Some(quality.get()) {
+        return;
+    }
+    let previous_level = loaded_level.replace(quality.get().to_owned());
+    // without a previous level, the collection was not loaded yet or was loaded with the current level
+    let (Some(collection), Some(_)) = (collection, previous_level) else {
+        return;
+    };
+    debug!(
+        "Reloading the collection {} with quality level '{}'",
+        std::any::type_name::<A>(),
+        quality.get()
+    );
+    let mut reloaded = HashSet::new();
+    for handle in collection.all_handles() {
+        let Some(path) = asset_server.get_path(handle.id()) else {
+            continue;
+        };
+        let from_quality_source = match path.source() {
+            AssetSourceId::Name(source) => quality.sources.iter().any(|name| &**source == *name),
+            AssetSourceId::Default => false,
+        };
+        let path = path.without_label().into_owned();
+        if from_quality_source && reloaded.insert(path.clone()) {
+            asset_server.reload(path);
+        }
+    }
+}
+
+/// Reads assets from the folder of the current quality level
+///
+/// Every level folder is read by its own default [`AssetReader`], so the readers of assets are passed on
+/// without buffering their bytes.
+struct QualityAssetReader {
+    base_path: PathBuf,
+    level: Arc<RwLock<String>>,
+    readers: OnceLock<Box<LevelReader>>,
+}
+
+/// Reader of a level folder, followed by the readers of levels that were read later
+///
+/// The list only grows, so a reader can be borrowed as long as the [`QualityAssetReader`] lives.
+struct LevelReader {
+    level: String,
+    reader: Box<dyn AssetReader>,
+    next: OnceLock<Box<LevelReader>>,
+}
+
+impl QualityAssetReader {
+    fn level_reader(&self) -> &dyn AssetReader {
+        let level = self.level.read().unwrap().clone();
+        let mut next = &self.readers;
+        loop {
+            let level_reader = next.get_or_init(|| {
+                let folder = Path::new("assets").join(&self.base_path).join(&level);
+                Box::new(LevelReader {
+                    reader: AssetSource::get_default_reader(folder.to_string_lossy().into_owned())(
+                    ),
+                    level: level.clone(),
+                    next: OnceLock::new(),
+                })
+            });
+            if level_reader.level == level {
+                return &*level_reader.reader;
+            }
+            next = &level_reader.next;
+        }
+    }
+}
+
+impl AssetReader for QualityAssetReader {
+    fn read<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        self.level_reader().read(path)
+    }
+
+    fn read_meta<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        self.level_reader().read_meta(path)
+    }
+
+    fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<PathStream>, AssetReaderError>> {
+        self.level_reader().read_directory(path)
+    }
+
+    fn is_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<bool, AssetReaderError>> {
+        self.level_reader().is_directory(path)
+    }
+}
@@ -0,0 +1,253 @@
+use crate::asset_collection::AssetCollection;
+use bevy::asset::{Asset, AssetServer, Handle, UntypedHandle};
+use bevy::ecs::schedule::{State, States};
+use bevy::ecs::system::Resource;
+use bevy::ecs::world::World;
+use bevy::utils::HashMap;
+use std::any::TypeId;
+
+/// The kind of asset an entry of an [`AssetCollectionBuilder`] loads
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RuntimeAssetKind {
+    /// A single file loaded with [`AssetServer::load_untyped`]
+    ///
+    /// The loader is chosen based on the file extension.
+    File,
+    /// A folder loaded with [`AssetServer::load_folder`]
+    ///
+    /// The handle in the collection is a `Handle<LoadedFolder>`.
+    Folder,
+}
+
+/// A single named asset of an [`AssetCollectionBuilder`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeAssetEntry {
+    /// Name of the asset in the [`RuntimeAssetCollection`]
+    pub name: String,
+    /// Asset path to load
+    pub path: String,
+    /// How to load the path
+    pub kind: RuntimeAssetKind,
+}
+
+/// Declares the assets of a [`RuntimeAssetCollection`] at run time
+///
+/// This is the run time counterpart to deriving [`AssetCollection`] and is meant for data-driven
+/// collections (e.g. from mods) whose assets are not known at compile time.
+/// Add it to a loading state with
+/// [`LoadingStateAppExt::add_runtime_collection_to_loading_state`](crate::loading_state::LoadingStateAppExt::add_runtime_collection_to_loading_state).
+///
+/// The builders are stored per loading state in [`RuntimeCollectionBuilders`]. Entries can still be added
+/// there until the loading state starts loading collections. While loading, the builder of the active
+/// loading state is inserted as a resource for [`RuntimeAssetCollection`] to read.
+/// ```edition2021
+/// # use bevy_asset_loader::runtime_collection::{AssetCollectionBuilder, RuntimeAssetKind};
+/// let builder = AssetCollectionBuilder::new()
+///     .with_file("background", "audio/background.ogg")
+///     .with_entry("sounds", "audio", RuntimeAssetKind::Folder);
+/// ```
+#[derive(Resource, Debug, Clone, Default)]
+pub struct AssetCollectionBuilder {
+    entries: Vec<RuntimeAssetEntry>,
+}
+
+impl AssetCollectionBuilder {
+    /// Create a builder without any entries
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an asset with the given name, path and kind
+    ///
+    /// An entry with the same name as an earlier one replaces it.
+    #[must_use = "The builder has to be added to a loading state"]
+    pub fn with_entry(
+        mut self,
+        name: impl Into<String>,
+        path: impl Into<String>,
+        kind: RuntimeAssetKind,
+    ) -> Self {
+        self.add_entry(name, path, kind);
+        self
+    }
+
+    /// Add a single file with the given name
+    #[must_use = "The builder has to be added to a loading state"]
+    pub fn with_file(self, name: impl Into<String>, path: impl Into<String>) -> Self {
+        self.with_entry(name, path, RuntimeAssetKind::File)
+    }
+
+    /// Add a folder with the given name
+    #[must_use = "The builder has to be added to a loading state"]
+    pub fn with_folder(self, name: impl Into<String>, path: impl Into<String>) -> Self {
+        self.with_entry(name, path, RuntimeAssetKind::Folder)
+    }
+
+    /// Add an asset to an existing builder, e.g. to the resource before the loading state starts
+    ///
+    /// An entry with the same name as an earlier one replaces it.
+    pub fn add_entry(
+        &mut self,
+        name: impl Into<String>,
+        path: impl Into<String>,
+        kind: RuntimeAssetKind,
+    ) {
+        let entry = RuntimeAssetEntry {
+            name: name.into(),
+            path: path.into(),
+            kind,
+        };
+        if let Some(existing) = self.entries.iter_mut().find(|e| e.name == entry.name) {
+            *existing = entry;
+        } else {
+            self.entries.push(entry);
+        }
+    }
+
+    /// All entries of the builder in the order they were added
+    pub fn entries(&self) -> &[RuntimeAssetEntry] {
+        &self.entries
+    }
+
+    pub(crate) fn merge(&mut self, other: AssetCollectionBuilder) {
+        for entry in other.entries {
+            self.add_entry(entry.name, entry.path, entry.kind);
+        }
+    }
+}
+
+/// The [`AssetCollectionBuilder`]s of all loading states of the state type `S`
+///
+/// See [`LoadingStateAppExt::add_runtime_collection_to_loading_state`](crate::loading_state::LoadingStateAppExt::add_runtime_collection_to_loading_state).
+#[derive(Resource, Debug)]
+pub struct RuntimeCollectionBuilders<S: States> {
+    builders: HashMap<S, AssetCollectionBuilder>,
+}
+
+impl<S: States> Default for RuntimeCollectionBuilders<S> {
+    fn default() -> Self {
+        RuntimeCollectionBuilders {
+            builders: HashMap::default(),
+        }
+    }
+}
+
+impl<S: States> RuntimeCollectionBuilders<S> {
+    /// The builder of the given loading state
+    pub fn get(&self, loading_state: &S) -> Option<&AssetCollectionBuilder> {
+        self.builders.get(loading_state)
+    }
+
+    /// The builder of the given loading state, e.g. to add entries before the loading state starts
+    pub fn get_mut(&mut self, loading_state: &S) -> Option<&mut AssetCollectionBuilder> {
+        self.builders.get_mut(loading_state)
+    }
+
+    /// Merge the builder into the one of the loading state
+    ///
+    /// Returns `false` if the loading state did not have a builder yet.
+    pub(crate) fn merge(&mut self, loading_state: S, builder: AssetCollectionBuilder) -> bool {
+        match self.builders.get_mut(&loading_state) {
+            Some(existing) => {
+                existing.merge(builder);
+                true
+            }
+            None => {
+                self.builders.insert(loading_state, builder);
+                false
+            }
+        }
+    }
+}
+
+/// Insert the builder of the active loading state as resource before its collections start loading
+pub(crate) fn insert_runtime_collection_builder<S: States>(world: &mut World) {
+    let state = world.resource::<State<S>>().get().clone();
+    if let Some(builder) = world
+        .resource::<RuntimeCollectionBuilders<S>>()
+        .get(&state)
+        .cloned()
+    {
+        world.insert_resource(builder);
+    }
+}
+
+/// A type-erased asset collection declared with an [`AssetCollectionBuilder`]
+///
+/// The collection is inserted as a resource like any derived collection and maps
+/// the names of the builder entries to their handles. If more than one loading state has
+/// a builder, the collection holds the entries of all of them that were loaded so far.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct RuntimeAssetCollection {
+    handles: HashMap<String, UntypedHandle>,
+}
+
+impl RuntimeAssetCollection {
+    /// The handle of the asset with the given name
+    pub fn get(&self, name: &str) -> Option<&UntypedHandle> {
+        self.handles.get(name)
+    }
+
+    /// The handle of the asset with the given name, if it has the asset type `A`
+    pub fn get_typed<A: Asset>(&self, name: &str) -> Option<Handle<A>> {
+        self.handles
+            .get(name)
+            .filter(|handle| handle.type_id() == TypeId::of::<A>())
+            .map(|handle| handle.clone().typed::<A>())
+    }
+
+    /// All handles of the collection by name
+    pub fn handles(&self) -> &HashMap<String, UntypedHandle> {
+        &self.handles
+    }
+}
+
+impl AssetCollection for RuntimeAssetCollection {
+    fn create(world: &mut World) -> Self {
+        let cell = world.cell();
+        let asset_server = cell
+            .get_resource::<AssetServer>()
+            .expect("Cannot get AssetServer");
+        let mut handles = cell
+            .get_resource::<RuntimeAssetCollection>()
+            .map(|collection| collection.handles.clone())
+            .unwrap_or_default();
+        let Some(builder) = cell.get_resource::<AssetCollectionBuilder>() else {
+            return RuntimeAssetCollection { handles };
+        };
+        handles.extend(builder.entries.iter().map(|entry| {
+            let handle = match entry.kind {
+                RuntimeAssetKind::File => asset_server
+                    .get_handle_untyped(&entry.path)
+                    .unwrap_or_else(|| panic!("The asset '{}' was not loaded", entry.path)),
+                RuntimeAssetKind::Folder => asset_server.load_folder(&entry.path).untyped(),
+            };
+            (entry.name.clone(), handle)
+        }));
+
+        RuntimeAssetCollection { handles }
+    }
+
+    fn load(world: &mut World) -> Vec<UntypedHandle> {
+        let cell = world.cell();
+        let asset_server = cell
+            .get_resource::<AssetServer>()
+            .expect("Cannot get AssetServer");
+        let Some(builder) = cell.get_resource::<AssetCollectionBuilder>() else {
+            return vec![];
+        };
+        builder
+            .entries
+            .iter()
+            .map(|entry| match entry.kind {
+                RuntimeAssetKind::File => asset_server.load_untyped(&entry.path).untyped(),
+                RuntimeAssetKind::Folder => asset_server.load_folder(&entry.path).untyped(),
+            })
+            .collect()
+    }
+
+    fn all_handles(&self) -> Vec<UntypedHandle> {
+        self.handles.values().cloned().collect()
+    }
+}
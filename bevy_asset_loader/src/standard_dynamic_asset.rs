@@ -5,7 +5,7 @@ use bevy::ecs::system::Command;
 use bevy::ecs::world::World;
 use bevy::reflect::TypePath;
 use bevy::utils::HashMap;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "2d")]
 use bevy::math::Vec2;
@@ -17,11 +17,11 @@ use bevy::sprite::TextureAtlas;
 #[cfg(any(feature = "3d", feature = "2d"))]
 use bevy::render::texture::{Image, ImageSampler, ImageSamplerDescriptor};
 #[cfg(any(feature = "3d", feature = "2d"))]
-use serde::Deserializer;
+use serde::{Deserializer, Serializer};
 
 /// These asset variants can be loaded from configuration files. They will then replace
 /// a dynamic asset based on their keys.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum StandardDynamicAsset {
     /// A dynamic asset directly loaded from a single file
     File {
@@ -48,7 +48,12 @@ pub enum StandardDynamicAsset {
         /// Image file path
         path: String,
         /// Sampler
-        #[serde(deserialize_with = "deserialize_some", default)]
+        #[serde(
+            deserialize_with = "deserialize_some",
+            serialize_with = "serialize_some",
+            skip_serializing_if = "Option::is_none",
+            default
+        )]
         sampler: Option<ImageSamplerType>,
     },
     /// A dynamic standard material asset directly loaded from an image file
@@ -71,16 +76,36 @@ pub enum StandardDynamicAsset {
         /// Rows on the sprite sheet
         rows: usize,
         /// Padding between columns in pixels
-        #[serde(deserialize_with = "deserialize_some", default)]
+        #[serde(
+            deserialize_with = "deserialize_some",
+            serialize_with = "serialize_some",
+            skip_serializing_if = "Option::is_none",
+            default
+        )]
         padding_x: Option<f32>,
         /// Padding between rows in pixels
-        #[serde(deserialize_with = "deserialize_some", default)]
+        #[serde(
+            deserialize_with = "deserialize_some",
+            serialize_with = "serialize_some",
+            skip_serializing_if = "Option::is_none",
+            default
+        )]
         padding_y: Option<f32>,
         /// Number of pixels offset of the first tile
-        #[serde(deserialize_with = "deserialize_some", default)]
+        #[serde(
+            deserialize_with = "deserialize_some",
+            serialize_with = "serialize_some",
+            skip_serializing_if = "Option::is_none",
+            default
+        )]
         offset_x: Option<f32>,
         /// Number of pixels offset of the first tile
-        #[serde(deserialize_with = "deserialize_some", default)]
+        #[serde(
+            deserialize_with = "deserialize_some",
+            serialize_with = "serialize_some",
+            skip_serializing_if = "Option::is_none",
+            default
+        )]
         offset_y: Option<f32>,
     },
 }
@@ -95,10 +120,21 @@ where
     Ok(Some(opt))
 }
 
+#[cfg(any(feature = "3d", feature = "2d"))]
+fn serialize_some<S, G>(value: &Option<G>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    G: Serialize,
+{
+    match value {
+        Some(value) => value.serialize(serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
 /// Define the image sampler to configure for an image asset
 #[cfg(any(feature = "3d", feature = "2d"))]
-#[derive(Debug, Clone, Deserialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub enum ImageSamplerType {
     /// See [`ImageSampler::nearest`]
     Nearest,
@@ -251,6 +287,10 @@ impl DynamicAsset for StandardDynamicAsset {
             )),
         }
     }
+
+    fn as_standard_dynamic_asset(&self) -> Option<&StandardDynamicAsset> {
+        Some(self)
+    }
 }
 
 /// Command to register a standard dynamic asset under the given key
@@ -272,7 +312,7 @@ impl<K: Into<String> + Sync + Send + 'static> Command for RegisterStandardDynami
 ///
 /// These assets are loaded at the beginning of a loading state
 /// and combined in [`DynamicAssets`](DynamicAssets).
-#[derive(serde::Deserialize, Asset, TypePath)]
+#[derive(serde::Deserialize, serde::Serialize, Asset, TypePath)]
 pub struct StandardDynamicAssetCollection(pub HashMap<String, StandardDynamicAsset>);
 
 impl DynamicAssetCollection for StandardDynamicAssetCollection {
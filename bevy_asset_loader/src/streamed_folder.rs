@@ -0,0 +1,128 @@
+use bevy::asset::{AssetServer, Assets, Handle, LoadedFolder, UntypedHandle};
+use bevy::ecs::system::{Res, ResMut, Resource};
+use bevy::utils::HashMap;
+use std::collections::VecDeque;
+
+/// Mapped folders that are populated over several frames
+///
+/// Folders are added with
+/// [`AssetCollectionApp::stream_mapped_folder`](crate::asset_collection::AssetCollectionApp::stream_mapped_folder).
+/// Every folder has a [`StreamedFolder`] from the start. Its map stays empty until the folder is loaded
+/// and then receives a limited number of entries per frame in the [`Update`](::bevy::app::Update) schedule.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy_asset_loader::streamed_folder::StreamedFolders;
+/// # use bevy::prelude::*;
+/// fn show_progress(streamed_folders: Res<StreamedFolders>) {
+///     let levels = streamed_folders.get("levels").unwrap();
+///     if let Some(total) = levels.total() {
+///         info!("{}/{} levels ready", levels.len(), total);
+///     }
+/// }
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct StreamedFolders {
+    folders: HashMap<String, StreamedFolder>,
+}
+
+impl StreamedFolders {
+    /// The streamed folder with the given asset path
+    pub fn get(&self, path: &str) -> Option<&StreamedFolder> {
+        self.folders.get(path)
+    }
+
+    /// Whether all streamed folders are completely populated
+    pub fn is_complete(&self) -> bool {
+        self.folders.values().all(StreamedFolder::is_complete)
+    }
+
+    pub(crate) fn add(&mut self, path: &str, entries_per_frame: usize) {
+        self.folders
+            .entry(path.to_owned())
+            .or_insert_with(|| StreamedFolder {
+                entries_per_frame: entries_per_frame.max(1),
+                ..Default::default()
+            });
+    }
+}
+
+/// A partially populated map of a folder's handles keyed by asset path
+///
+/// Entries that are in the map can be used right away. Entries are inserted in the order of their paths.
+#[derive(Debug, Default)]
+pub struct StreamedFolder {
+    folder: Option<Handle<LoadedFolder>>,
+    entries: HashMap<String, UntypedHandle>,
+    pending: VecDeque<UntypedHandle>,
+    total: Option<usize>,
+    entries_per_frame: usize,
+}
+
+impl StreamedFolder {
+    /// All entries inserted so far
+    pub fn entries(&self) -> &HashMap<String, UntypedHandle> {
+        &self.entries
+    }
+
+    /// The handle for the given asset path, if it was inserted already
+    pub fn get(&self, path: &str) -> Option<&UntypedHandle> {
+        self.entries.get(path)
+    }
+
+    /// Number of entries inserted so far
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no entries were inserted yet
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Number of assets in the folder
+    ///
+    /// This is `None` until the folder is loaded.
+    pub fn total(&self) -> Option<usize> {
+        self.total
+    }
+
+    /// Whether every asset of the folder is in the map
+    pub fn is_complete(&self) -> bool {
+        self.total.is_some() && self.pending.is_empty()
+    }
+}
+
+pub(crate) fn stream_mapped_folders(
+    mut streamed_folders: ResMut<StreamedFolders>,
+    asset_server: Res<AssetServer>,
+    loaded_folders: Res<Assets<LoadedFolder>>,
+) {
+    for (path, streamed) in streamed_folders.folders.iter_mut() {
+        let folder = streamed
+            .folder
+            .get_or_insert_with(|| asset_server.load_folder(path));
+        if streamed.total.is_none() {
+            let Some(loaded) = loaded_folders.get(&*folder) else {
+                continue;
+            };
+            let mut handles = loaded.handles.clone();
+            handles.sort_by(|a, b| {
+                a.path()
+                    .map(|path| path.path())
+                    .cmp(&b.path().map(|path| path.path()))
+            });
+            streamed.total = Some(handles.len());
+            streamed.pending = handles.into();
+        }
+        for _ in 0..streamed.entries_per_frame {
+            let Some(handle) = streamed.pending.pop_front() else {
+                break;
+            };
+            let path = handle.path().unwrap().path();
+            let key: String = path_slash::PathExt::to_slash(path)
+                .expect("Path should be valid UTF-8")
+                .into();
+            streamed.entries.insert(key, handle);
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use bevy::app::App;
+use bevy::asset::io::{
+    AssetReader, AssetReaderError, AssetSource, AssetSourceId, PathStream, Reader,
+};
+use bevy::asset::{AssetApp, AssetServer, UntypedHandle};
+use bevy::ecs::schedule::{State, States};
+use bevy::ecs::system::Resource;
+use bevy::ecs::world::World;
+use bevy::utils::{BoxedFuture, HashMap, HashSet};
+use std::any::Any;
+use std::future::poll_fn;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::task::{Poll, Waker};
+
+/// Extension trait to register an asset source that holds every read until it is released
+///
+/// Assets loaded from such a source only finish loading one at a time with [`advance_one_asset`].
+/// This makes the progress of a loading state deterministic in tests.
+/// ```edition2021
+/// # use bevy_asset_loader::prelude::*;
+/// # use bevy_asset_loader::test_support::{advance_one_asset, SteppedAssetSourceApp};
+/// # use bevy::prelude::*;
+/// # use bevy::asset::AssetPlugin;
+/// # fn main() {
+///     let mut app = App::new();
+///     app.register_stepped_asset_source("stepped")
+///         .add_plugins((MinimalPlugins, AssetPlugin::default()))
+///         .add_state::<GameState>()
+///         .add_loading_state(LoadingState::new(GameState::Loading).continue_to_state(GameState::Next));
+///     app.update();
+///     // release the first asset read from `stepped://` paths
+///     advance_one_asset(&mut app.world, GameState::Loading);
+/// # }
+/// # #[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+/// # enum GameState { #[default] Loading, Next }
+/// ```
+pub trait SteppedAssetSourceApp {
+    /// Register an asset source with the given name that reads from the `assets` folder, but holds every read
+    ///
+    /// Assets are loaded with paths like `stepped://images/player.png`, or with
+    /// `#[asset_collection(source = "stepped")]` on a collection. Reads of `.meta` files and directories are not held.
+    ///
+    /// Like all asset sources, this has to be registered before the [`AssetPlugin`](bevy::asset::AssetPlugin).
+    fn register_stepped_asset_source(&mut self, source: &'static str) -> &mut Self;
+}
+
+impl SteppedAssetSourceApp for App {
+    fn register_stepped_asset_source(&mut self, source: &'static str) -> &mut Self {
+        let reads = self
+            .world
+            .get_resource_or_insert_with(SteppedAssets::default)
+            .reads
+            .clone();
+        let mut default_reader = AssetSource::get_default_reader("assets".to_owned());
+        self.register_asset_source(
+            AssetSourceId::Name(source.into()),
+            AssetSource::build().with_reader(move || {
+                Box::new(SteppedAssetReader {
+                    source,
+                    reader: default_reader(),
+                    reads: reads.clone(),
+                })
+            }),
+        )
+    }
+}
+
+/// Reads of stepped asset sources that are held until they are released
+#[derive(Resource, Default)]
+pub struct SteppedAssets {
+    reads: Arc<Mutex<HeldReads>>,
+}
+
+impl SteppedAssets {
+    /// Paths of the held reads in the order they were started, including their source
+    pub fn pending(&self) -> Vec<String> {
+        let reads = self.reads.lock().unwrap();
+        reads.held.iter().map(|read| read.path.clone()).collect()
+    }
+
+    /// Release the oldest held read and return its path
+    pub fn release_next(&self) -> Option<String> {
+        self.release_first(|_, _| true)
+    }
+
+    fn release_first(&self, mut filter: impl FnMut(&HeldReads, &str) -> bool) -> Option<String> {
+        let mut reads = self.reads.lock().unwrap();
+        let index = reads
+            .held
+            .iter()
+            .position(|read| filter(&reads, &read.path))?;
+        let read = reads.held.remove(index);
+        reads.released.insert(read.id);
+        if let Some(waker) = read.waker {
+            waker.wake();
+        }
+
+        Some(read.path)
+    }
+}
+
+/// Release the next asset read of the given loading state from a stepped asset source
+///
+/// Reads are released in the order the asset server started them, so the next pending asset of the
+/// loading state continues to load. The asset finishes loading within the next app updates. Returns the
+/// path of the released asset, or `None` if the loading state is not active or no read of it is held.
+///
+/// Reads of assets requested by the collections of another loading state are skipped. Reads that no
+/// collection requested directly, like dependencies of assets or dynamic asset files, are released
+/// for any loading state.
+/// Requires an asset source registered with [`SteppedAssetSourceApp::register_stepped_asset_source`].
+pub fn advance_one_asset<S: States>(world: &mut World, state: S) -> Option<String> {
+    if world.get_resource::<State<S>>()?.get() != &state {
+        return None;
+    }
+    world
+        .get_resource::<SteppedAssets>()?
+        .release_first(|reads, path| match reads.requested_by.get(path) {
+            Some(states) => states
+                .iter()
+                .any(|requester| requester.downcast_ref::<S>() == Some(&state)),
+            None => true,
+        })
+}
+
+/// Remember which loading state requested the given assets, so [`advance_one_asset`] only releases their reads for that state
+pub(crate) fn request_stepped_assets<S: States>(
+    world: &World,
+    state: &S,
+    handles: &[UntypedHandle],
+    queued: &[String],
+) {
+    let Some(stepped_assets) = world.get_resource::<SteppedAssets>() else {
+        return;
+    };
+    let asset_server = world.resource::<AssetServer>();
+    let mut reads = stepped_assets.reads.lock().unwrap();
+    let paths = handles
+        .iter()
+        .filter_map(|handle| asset_server.get_path(handle.id()))
+        .map(|path| {
+            // untyped loads are tracked under a separate source of the asset server
+            let path = path.without_label().to_string();
+            match path.split_once("----untyped://") {
+                Some((source, path)) => format!("{source}://{path}"),
+                None => path,
+            }
+        })
+        .chain(queued.iter().cloned());
+    for path in paths {
+        let states = reads.requested_by.entry(path).or_default();
+        if !states
+            .iter()
+            .any(|requester| requester.downcast_ref::<S>() == Some(state))
+        {
+            states.push(Box::new(state.clone()));
+        }
+    }
+}
+
+#[derive(Default)]
+struct HeldReads {
+    next_id: u64,
+    held: Vec<HeldRead>,
+    released: HashSet<u64>,
+    /// Loading states that requested an asset path
+    requested_by: HashMap<String, Vec<Box<dyn Any + Send>>>,
+}
+
+struct HeldRead {
+    id: u64,
+    path: String,
+    waker: Option<Waker>,
+}
+
+/// Reads assets with another [`AssetReader`] once their read was released
+struct SteppedAssetReader {
+    source: &'static str,
+    reader: Box<dyn AssetReader>,
+    reads: Arc<Mutex<HeldReads>>,
+}
+
+impl SteppedAssetReader {
+    async fn wait_for_release(&self, path: &Path) {
+        let id = {
+            let mut reads = self.reads.lock().unwrap();
+            let id = reads.next_id;
+            reads.next_id += 1;
+            reads.held.push(HeldRead {
+                id,
+                path: format!("{}://{}", self.source, path.display()),
+                waker: None,
+            });
+            id
+        };
+        let _cancel = CancelReadOnDrop {
+            reads: &self.reads,
+            id,
+        };
+        poll_fn(|context| {
+            let mut reads = self.reads.lock().unwrap();
+            if reads.released.remove(&id) {
+                return Poll::Ready(());
+            }
+            if let Some(read) = reads.held.iter_mut().find(|read| read.id == id) {
+                read.waker = Some(context.waker().clone());
+            }
+            Poll::Pending
+        })
+        .await;
+    }
+}
+
+/// Forgets a held read if its future is dropped before the read was released
+struct CancelReadOnDrop<'a> {
+    reads: &'a Mutex<HeldReads>,
+    id: u64,
+}
+
+impl Drop for CancelReadOnDrop<'_> {
+    fn drop(&mut self) {
+        let Ok(mut reads) = self.reads.lock() else {
+            return;
+        };
+        let id = self.id;
+        reads.held.retain(|read| read.id != id);
+        reads.released.remove(&id);
+    }
+}
+
+impl AssetReader for SteppedAssetReader {
+    fn read<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        Box::pin(async move {
+            self.wait_for_release(path).await;
+            self.reader.read(path).await
+        })
+    }
+
+    fn read_meta<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        self.reader.read_meta(path)
+    }
+
+    fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<PathStream>, AssetReaderError>> {
+        self.reader.read_directory(path)
+    }
+
+    fn is_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<bool, AssetReaderError>> {
+        self.reader.is_directory(path)
+    }
+}
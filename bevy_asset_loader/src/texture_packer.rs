@@ -0,0 +1,160 @@
+use bevy::app::App;
+use bevy::asset::io::Reader;
+use bevy::asset::{
+    Asset, AssetApp, AssetLoader, Assets, AsyncReadExt, BoxedFuture, Handle, LoadContext,
+};
+use bevy::math::{Rect, Vec2};
+use bevy::reflect::TypePath;
+use bevy::render::texture::Image;
+use bevy::sprite::TextureAtlas;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// Sprite sheet metadata exported by TexturePacker in one of its JSON formats
+///
+/// Files ending in `.tp.json` are loaded as sprite sheets, so other JSON files stay free for other loaders. Both the "JSON (Hash)" and the "JSON (Array)" formats are supported. Frames of the hash format
+/// are ordered by their names, frames of the array format keep the order of the file.
+/// Only the `frame` rectangle of every sprite is used. Rotated frames are not supported.
+#[derive(Asset, TypePath, Debug, Clone, PartialEq)]
+pub struct TexturePackerSheet {
+    /// Size of the sprite sheet image in pixels
+    pub size: Vec2,
+    /// All frames in the sprite sheet
+    pub frames: Vec<TexturePackerFrame>,
+}
+
+/// A single named frame of a [`TexturePackerSheet`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TexturePackerFrame {
+    /// The file name of the sprite
+    pub name: String,
+    /// Area of the sprite in the sprite sheet image
+    pub rect: Rect,
+}
+
+impl TexturePackerSheet {
+    /// Parse TexturePacker JSON metadata
+    pub fn from_json(bytes: &[u8]) -> Result<Self, serde_json::Error> {
+        let json: TexturePackerJson = serde_json::from_slice(bytes)?;
+        let frames = match json.frames {
+            TexturePackerJsonFrames::Hash(frames) => frames
+                .into_iter()
+                .map(|(name, frame)| frame.into_frame(name))
+                .collect(),
+            TexturePackerJsonFrames::Array(frames) => frames
+                .into_iter()
+                .map(|frame| frame.frame.into_frame(frame.filename))
+                .collect(),
+        };
+
+        Ok(TexturePackerSheet {
+            size: Vec2::new(json.meta.size.w, json.meta.size.h),
+            frames,
+        })
+    }
+
+    /// Build a texture atlas for the given sprite sheet image
+    ///
+    /// The atlas indices correspond to the order of [`TexturePackerSheet::frames`].
+    pub fn texture_atlas(&self, image: Handle<Image>) -> TextureAtlas {
+        let mut atlas = TextureAtlas::new_empty(image, self.size);
+        for frame in &self.frames {
+            atlas.add_texture(frame.rect);
+        }
+
+        atlas
+    }
+
+    /// Get the atlas index of the frame with the given name
+    pub fn index_of(&self, name: &str) -> Option<usize> {
+        self.frames.iter().position(|frame| frame.name == name)
+    }
+}
+
+#[derive(Deserialize)]
+struct TexturePackerJson {
+    frames: TexturePackerJsonFrames,
+    meta: TexturePackerJsonMeta,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TexturePackerJsonFrames {
+    Hash(BTreeMap<String, TexturePackerJsonFrame>),
+    Array(Vec<TexturePackerJsonNamedFrame>),
+}
+
+#[derive(Deserialize)]
+struct TexturePackerJsonNamedFrame {
+    filename: String,
+    #[serde(flatten)]
+    frame: TexturePackerJsonFrame,
+}
+
+#[derive(Deserialize)]
+struct TexturePackerJsonFrame {
+    frame: TexturePackerJsonRect,
+}
+
+impl TexturePackerJsonFrame {
+    fn into_frame(self, name: String) -> TexturePackerFrame {
+        let TexturePackerJsonRect { x, y, w, h } = self.frame;
+        TexturePackerFrame {
+            name,
+            rect: Rect::new(x, y, x + w, y + h),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct TexturePackerJsonRect {
+    x: f32,
+    y: f32,
+    w: f32,
+    h: f32,
+}
+
+#[derive(Deserialize)]
+struct TexturePackerJsonMeta {
+    size: TexturePackerJsonSize,
+}
+
+#[derive(Deserialize)]
+struct TexturePackerJsonSize {
+    w: f32,
+    h: f32,
+}
+
+#[derive(Default)]
+struct TexturePackerSheetLoader;
+
+impl AssetLoader for TexturePackerSheetLoader {
+    type Asset = TexturePackerSheet;
+    type Settings = ();
+    type Error = anyhow::Error;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            Ok(TexturePackerSheet::from_json(&bytes)?)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["tp.json"]
+    }
+}
+
+pub(crate) fn register_texture_packer_loader(app: &mut App) {
+    if app.world.contains_resource::<Assets<TexturePackerSheet>>() {
+        return;
+    }
+    app.init_asset::<TexturePackerSheet>()
+        .init_asset_loader::<TexturePackerSheetLoader>();
+}
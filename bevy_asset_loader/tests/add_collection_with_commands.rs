@@ -0,0 +1,118 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::loading_state::ContinueLoading;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loads_collections_added_while_loading() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, Music>(MyStates::Load)
+        .add_systems(OnEnter(MyStates::Load), add_effects)
+        .add_systems(Update, timeout)
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn reopens_finalizing_loading_state() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .wait_for_continue_event(),
+        )
+        .add_collection_to_loading_state::<_, Music>(MyStates::Load)
+        .add_systems(
+            Update,
+            add_effects_once_music_is_loaded.run_if(in_state(MyStates::Load)),
+        )
+        .add_systems(Update, timeout)
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+fn add_effects(mut commands: Commands) {
+    commands.add_collection_to_loading_state::<_, Effects>(MyStates::Load);
+}
+
+fn add_effects_once_music_is_loaded(
+    mut commands: Commands,
+    music: Option<Res<Music>>,
+    mut continue_loading: EventWriter<ContinueLoading>,
+    mut added: Local<bool>,
+) {
+    // the loading state waits for the continue event after the music was loaded
+    if *added || music.is_none() {
+        return;
+    }
+    *added = true;
+    commands.add_collection_to_loading_state::<_, Effects>(MyStates::Load);
+    // adding the same collection again does not load it twice
+    commands.add_collection_to_loading_state::<_, Effects>(MyStates::Load);
+    continue_loading.send(ContinueLoading);
+}
+
+fn expect(
+    music: Option<Res<Music>>,
+    effects: Option<Res<Effects>>,
+    progress: Res<LoadingProgress>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(music.is_some(), "Music should be loaded");
+    assert!(effects.is_some(), "The added collection should be loaded");
+    assert!(progress.is_finished());
+    assert_eq!(progress.total, 2);
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct Music {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct Effects {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,158 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::asset::{AssetPlugin, LoadState};
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+#[cfg(feature = "test_support")]
+use bevy_asset_loader::test_support::{advance_one_asset, SteppedAssetSourceApp, SteppedAssets};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking"),
+    feature = "test_support",
+))]
+#[test]
+fn steps_collection_one_asset_at_a_time() {
+    let mut app = App::new();
+    app.register_stepped_asset_source("stepped")
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error),
+        )
+        .add_collection_to_loading_state::<_, AudioAssets>(MyStates::Load);
+
+    update_until(&mut app, |world| {
+        world.resource::<SteppedAssets>().pending().len() == 2
+    });
+    assert_eq!(
+        advance_one_asset(&mut app.world, MyStates::Next),
+        None,
+        "Only the active loading state should advance"
+    );
+
+    let first = advance_one_asset(&mut app.world, MyStates::Load)
+        .expect("The first asset should be released");
+    update_until(&mut app, |world| loaded(world, &first));
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(
+        app.world.resource::<State<MyStates>>().get(),
+        &MyStates::Load
+    );
+    assert!(!app.world.contains_resource::<AudioAssets>());
+    assert_eq!(app.world.resource::<SteppedAssets>().pending().len(), 1);
+
+    let second = advance_one_asset(&mut app.world, MyStates::Load)
+        .expect("The second asset should be released");
+    assert_ne!(first, second);
+    update_until(&mut app, |world| {
+        world.resource::<State<MyStates>>().get() == &MyStates::Next
+    });
+    assert!(app.world.contains_resource::<AudioAssets>());
+    assert_eq!(advance_one_asset(&mut app.world, MyStates::Load), None);
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking"),
+    feature = "test_support",
+))]
+#[test]
+fn only_releases_assets_of_the_given_loading_state() {
+    let mut app = App::new();
+    app.register_stepped_asset_source("stepped")
+        .add_state::<MyStates>()
+        .add_state::<OtherStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, AudioAssets>(MyStates::Load)
+        .add_loading_state(
+            LoadingState::new(OtherStates::Load).continue_to_state(OtherStates::Next),
+        )
+        .add_collection_to_loading_state::<_, OtherAudioAssets>(OtherStates::Load);
+
+    update_until(&mut app, |world| {
+        world.resource::<SteppedAssets>().pending().len() == 3
+    });
+    assert_eq!(
+        advance_one_asset(&mut app.world, OtherStates::Load),
+        Some("stepped://audio/yipee.ogg".to_owned())
+    );
+    assert_eq!(
+        advance_one_asset(&mut app.world, OtherStates::Load),
+        None,
+        "Reads of other loading states should stay held"
+    );
+    update_until(&mut app, |world| {
+        world.resource::<State<OtherStates>>().get() == &OtherStates::Next
+    });
+    assert_eq!(
+        app.world.resource::<State<MyStates>>().get(),
+        &MyStates::Load
+    );
+    assert_eq!(app.world.resource::<SteppedAssets>().pending().len(), 2);
+}
+
+fn update_until(app: &mut App, condition: impl Fn(&World) -> bool) {
+    for _ in 0..100_000 {
+        if condition(&app.world) {
+            return;
+        }
+        app.update();
+    }
+    panic!("The condition was not met in time");
+}
+
+fn loaded(world: &World, path: &str) -> bool {
+    let asset_server = world.resource::<AssetServer>();
+    asset_server
+        .get_handle_untyped(path.to_owned())
+        .is_some_and(|handle| asset_server.load_state(handle.id()) == LoadState::Loaded)
+}
+
+#[derive(AssetCollection, Resource)]
+#[asset_collection(source = "stepped")]
+struct AudioAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+#[asset_collection(source = "stepped")]
+struct OtherAudioAssets {
+    #[asset(path = "audio/yipee.ogg")]
+    yipee: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum OtherStates {
+    #[default]
+    Load,
+    Next,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Error,
+    Next,
+}
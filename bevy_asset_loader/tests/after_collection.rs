@@ -0,0 +1,121 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loads_collection_after_its_prerequisite() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, Playlist>(MyStates::Load)
+        .add_collection_to_loading_state::<_, AudioAssets>(MyStates::Load)
+        .after_collection::<_, Playlist, AudioAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn orders_collections_per_loading_state() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_loading_state(LoadingState::new(MyStates::Reload).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, Playlist>(MyStates::Load)
+        .add_collection_to_loading_state::<_, AudioAssets>(MyStates::Load)
+        .add_collection_to_loading_state::<_, Playlist>(MyStates::Reload)
+        .add_collection_to_loading_state::<_, AudioAssets>(MyStates::Reload)
+        .after_collection::<_, Playlist, AudioAssets>(MyStates::Load)
+        // the opposite order in another loading state is no cycle
+        .after_collection::<_, AudioAssets, Playlist>(MyStates::Reload)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+#[should_panic(expected = "the collections would wait for each other")]
+fn panics_on_cycles() {
+    App::new()
+        .after_collection::<_, Playlist, AudioAssets>(MyStates::Load)
+        .after_collection::<_, Sounds, Playlist>(MyStates::Load)
+        .after_collection::<_, AudioAssets, Sounds>(MyStates::Load);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collections did not finish loading in 10 seconds");
+    }
+}
+
+fn expect(playlist: Res<Playlist>, audio: Res<AudioAssets>, mut exit: EventWriter<AppExit>) {
+    assert_eq!(playlist.first.0, audio.background);
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct AudioAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct Playlist {
+    first: FirstTrack,
+}
+
+#[derive(AssetCollection, Resource)]
+struct Sounds {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+/// Needs the [`AudioAssets`] collection when the playlist is created
+struct FirstTrack(Handle<AudioSource>);
+
+impl FromWorld for FirstTrack {
+    fn from_world(world: &mut World) -> Self {
+        let audio = world
+            .get_resource::<AudioAssets>()
+            .expect("The audio assets should be loaded before the playlist");
+        FirstTrack(audio.background.clone())
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Reload,
+    Next,
+}
@@ -0,0 +1,105 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{ContinueLoading, LoadingState, LoadingStateAppExt};
+
+const WAIT_FRAMES: u32 = 3;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn delays_transition_by_a_rendered_frame() {
+    // the continue event is handled in the frame it is sent, so the state changes in the following frame
+    run_and_expect_frames_until_transition(false, 1);
+    run_and_expect_frames_until_transition(true, 2);
+}
+
+/// Continue the loading state a few frames after the collection is loaded and assert
+/// the number of frames until the next state is entered
+fn run_and_expect_frames_until_transition(after_first_render: bool, expected: u32) {
+    let mut loading_state = LoadingState::new(MyStates::Load)
+        .continue_to_state(MyStates::Next)
+        .wait_for_continue_event();
+    if after_first_render {
+        loading_state = loading_state.after_first_render();
+    }
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .insert_resource(Frames {
+            expected,
+            ..default()
+        })
+        .add_loading_state(loading_state)
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(First, count_frames)
+        .add_systems(Update, timeout)
+        .add_systems(
+            PreUpdate,
+            continue_after_some_frames
+                .run_if(in_state(MyStates::Load).and_then(resource_exists::<MyAssets>())),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect_transition)
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct Frames {
+    current: u32,
+    after_loading: u32,
+    continued: Option<u32>,
+    expected: u32,
+}
+
+fn count_frames(mut frames: ResMut<Frames>) {
+    frames.current += 1;
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The loading state did not continue in 10 seconds");
+    }
+}
+
+fn continue_after_some_frames(
+    mut frames: ResMut<Frames>,
+    mut continue_loading: EventWriter<ContinueLoading>,
+) {
+    frames.after_loading += 1;
+    if frames.after_loading == WAIT_FRAMES {
+        frames.continued = Some(frames.current);
+        continue_loading.send(ContinueLoading);
+    }
+}
+
+fn expect_transition(frames: Res<Frames>, mut exit: EventWriter<AppExit>) {
+    let continued = frames
+        .continued
+        .expect("The loading state should only continue after the continue event");
+    assert_eq!(frames.current - continued, frames.expected);
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,75 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn alias_handle_from_resource() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .init_resource::<SharedAudio>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+fn expect(collection: Res<MyAssets>, shared: Res<SharedAudio>, mut exit: EventWriter<AppExit>) {
+    assert_eq!(collection.shared, shared.audio.plop);
+    exit.send(AppExit);
+}
+
+#[derive(Resource)]
+struct SharedAudio {
+    audio: SharedHandles,
+}
+
+struct SharedHandles {
+    plop: Handle<AudioSource>,
+}
+
+impl FromWorld for SharedAudio {
+    fn from_world(world: &mut World) -> Self {
+        SharedAudio {
+            audio: SharedHandles {
+                plop: world.resource::<AssetServer>().load("audio/plop.ogg"),
+            },
+        }
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(alias_from = SharedAudio.audio.plop)]
+    shared: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,70 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn lists_all_handles() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+fn expect(collection: Res<MyAssets>, mut exit: EventWriter<AppExit>) {
+    let handles = collection.all_handles();
+    assert_eq!(
+        handles.len(),
+        1 + collection.folder.len() + collection.files.len(),
+        "Folder and file fields should contribute all their handles"
+    );
+    assert!(handles.contains(&collection.background.clone().untyped()));
+    for handle in collection.folder.iter().chain(collection.files.values()) {
+        assert!(handles.contains(handle));
+    }
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(path = "audio", collection)]
+    folder: Vec<UntypedHandle>,
+    #[asset(paths("audio/plop.ogg", "audio/yipee.ogg"), collection(mapped))]
+    files: bevy::utils::HashMap<String, UntypedHandle>,
+    not_an_asset: NotAnAsset,
+}
+
+#[derive(Default)]
+struct NotAnAsset;
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,180 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::{AssetPlugin, LoadState};
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+
+#[cfg(feature = "archive")]
+use bevy_asset_loader::archive::ArchiveAssetSourceApp;
+
+#[cfg(all(
+    feature = "archive",
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loads_collection_from_zip_archive() {
+    App::new()
+        .add_state::<MyStates>()
+        .register_archive_asset_source("pack", "pack.zip")
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, ZipAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_zip_assets)
+        .run();
+}
+
+#[cfg(all(
+    feature = "archive",
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loads_asset_from_tar_archive() {
+    App::new()
+        .add_state::<MyStates>()
+        .register_archive_asset_source("tar-pack", "pack.tar")
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, TarAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_tar_assets)
+        .run();
+}
+
+#[cfg(all(
+    feature = "archive",
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loads_long_names_from_tar_archives() {
+    let path = format!(
+        "sounds/{0}/{0}/plop.ogg",
+        "a_very_long_directory_name_that_does_not_fit_into_the_name_field_of_a_tar_header"
+    );
+    assert_eq!(
+        final_load_state("gnu", "gnu_long_names.tar", &path),
+        LoadState::Loaded
+    );
+    assert_eq!(
+        final_load_state("pax", "pax_long_names.tar", &path),
+        LoadState::Loaded
+    );
+}
+
+#[cfg(all(
+    feature = "archive",
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn fails_to_load_corrupted_zip_entries() {
+    assert_eq!(
+        final_load_state("corrupted", "corrupted.zip", "sounds/plop.ogg"),
+        LoadState::Failed
+    );
+}
+
+/// Load a single audio file from an archive and return its load state once it stops loading
+#[cfg(feature = "archive")]
+fn final_load_state(source: &'static str, archive: &str, path: &str) -> LoadState {
+    let mut app = App::new();
+    app.register_archive_asset_source(source, archive)
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ));
+    let handle: Handle<AudioSource> = app
+        .world
+        .resource::<AssetServer>()
+        .load(format!("{source}://{path}"));
+    let start = std::time::Instant::now();
+    loop {
+        app.update();
+        match app.world.resource::<AssetServer>().get_load_state(&handle) {
+            Some(LoadState::Loaded) => return LoadState::Loaded,
+            Some(LoadState::Failed) => return LoadState::Failed,
+            _ => {}
+        }
+        if start.elapsed() > std::time::Duration::from_secs(10) {
+            panic!("Failed to load '{path}' from the archive in 10 seconds");
+        }
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("Failed to load the assets from the archive in 10 seconds");
+    }
+}
+
+fn expect_zip_assets(
+    assets: Res<ZipAssets>,
+    sources: Res<Assets<AudioSource>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(
+        assets.plop.path().unwrap().to_string(),
+        "pack://sounds/plop.ogg"
+    );
+    let plop = sources
+        .get(&assets.plop)
+        .expect("The zip entry should be loaded");
+    assert_eq!(plop.bytes.len(), 4722);
+    assert_eq!(assets.sounds.len(), 2);
+    assert!(assets.sounds.iter().all(|sound| sources.contains(sound)));
+    exit.send(AppExit);
+}
+
+fn expect_tar_assets(
+    assets: Res<TarAssets>,
+    sources: Res<Assets<AudioSource>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let plop = sources
+        .get(&assets.plop)
+        .expect("The tar entry should be loaded");
+    assert_eq!(plop.bytes.len(), 4722);
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+#[asset_collection(source = "pack")]
+struct ZipAssets {
+    #[asset(path = "sounds/plop.ogg")]
+    plop: Handle<AudioSource>,
+    #[asset(path = "sounds", collection(typed))]
+    sounds: Vec<Handle<AudioSource>>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct TarAssets {
+    #[asset(path = "tar-pack://sounds/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,116 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+
+#[cfg(feature = "reflect")]
+#[test]
+fn asset_bindings() {
+    use bevy::reflect::Struct;
+    use bevy_asset_loader::asset_collection::{AssetBinding, AssetBindingKind};
+
+    let bindings = MyAssets::asset_bindings();
+    assert_eq!(bindings.collection, "MyAssets");
+    assert_eq!(
+        bindings.bindings,
+        vec![
+            AssetBinding {
+                variant: None,
+                field: "background".to_owned(),
+                kind: AssetBindingKind::File,
+                paths: vec!["audio/background.ogg".to_owned()],
+                key: None,
+            },
+            AssetBinding {
+                variant: None,
+                field: "files".to_owned(),
+                kind: AssetBindingKind::Files,
+                paths: vec!["images/tree.png".to_owned(), "images/player.png".to_owned()],
+                key: None,
+            },
+            AssetBinding {
+                variant: None,
+                field: "character".to_owned(),
+                kind: AssetBindingKind::OptionalDynamic,
+                paths: vec![],
+                key: Some("character".to_owned()),
+            },
+            AssetBinding {
+                variant: None,
+                field: "counter".to_owned(),
+                kind: AssetBindingKind::FromWorld,
+                paths: vec![],
+                key: None,
+            },
+        ]
+    );
+    assert_eq!(
+        bindings
+            .get_field::<String>("collection")
+            .expect("Bindings should be reflectable"),
+        "MyAssets"
+    );
+}
+
+#[cfg(feature = "reflect")]
+#[test]
+fn enum_asset_bindings_are_namespaced_by_variant() {
+    use bevy_asset_loader::asset_collection::{AssetBinding, AssetBindingKind};
+
+    let bindings = LevelAssets::asset_bindings();
+    assert_eq!(bindings.collection, "LevelAssets");
+    assert_eq!(
+        bindings.bindings,
+        vec![
+            AssetBinding {
+                variant: Some("Forest".to_owned()),
+                field: "music".to_owned(),
+                kind: AssetBindingKind::File,
+                paths: vec!["audio/background.ogg".to_owned()],
+                key: None,
+            },
+            AssetBinding {
+                variant: Some("Forest".to_owned()),
+                field: "counter".to_owned(),
+                kind: AssetBindingKind::FromWorld,
+                paths: vec![],
+                key: None,
+            },
+            AssetBinding {
+                variant: Some("Cave".to_owned()),
+                field: "music".to_owned(),
+                kind: AssetBindingKind::File,
+                paths: vec!["audio/plop.ogg".to_owned()],
+                key: None,
+            },
+        ]
+    );
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(paths("images/tree.png", "images/player.png"), collection(typed))]
+    files: Vec<Handle<Image>>,
+    #[asset(key = "character", optional)]
+    character: Option<Handle<Image>>,
+    counter: Counter,
+}
+
+#[derive(Default)]
+struct Counter(usize);
+
+#[derive(AssetCollection, Resource)]
+enum LevelAssets {
+    Forest {
+        #[asset(path = "audio/background.ogg")]
+        music: Handle<AudioSource>,
+        counter: Counter,
+    },
+    Cave {
+        #[asset(path = "audio/plop.ogg")]
+        music: Handle<AudioSource>,
+    },
+    Empty,
+}
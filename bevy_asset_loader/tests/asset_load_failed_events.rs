@@ -0,0 +1,78 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{AssetLoadFailed, LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn sends_event_once_per_failed_asset() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .init_resource::<FailedAssets>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, Audio>(MyStates::Load)
+        .add_systems(Update, (collect_failures, expect_failures))
+        .add_systems(OnEnter(MyStates::Next), fail)
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct FailedAssets(Vec<AssetLoadFailed>);
+
+fn collect_failures(
+    mut events: EventReader<AssetLoadFailed>,
+    mut failed_assets: ResMut<FailedAssets>,
+) {
+    failed_assets.0.extend(events.read().cloned());
+}
+
+fn expect_failures(
+    time: Res<Time>,
+    failed_assets: Res<FailedAssets>,
+    mut exit: EventWriter<AppExit>,
+) {
+    // keep the loading state running for a while to make sure events are not repeated
+    if time.elapsed_seconds_f64() < 1. {
+        return;
+    }
+    assert_eq!(
+        failed_assets.0,
+        vec![AssetLoadFailed {
+            collection: std::any::type_name::<Audio>(),
+            path: "audio/missing.ogg".to_owned(),
+        }]
+    );
+    exit.send(AppExit);
+}
+
+fn fail() {
+    panic!("The loading state should not finish with a failed asset");
+}
+
+#[derive(AssetCollection, Resource)]
+struct Audio {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+    #[asset(path = "audio/missing.ogg")]
+    missing: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
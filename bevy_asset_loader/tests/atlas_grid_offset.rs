@@ -0,0 +1,78 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy::render::texture::ImagePlugin;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+
+#[cfg(all(feature = "2d", not(feature = "progress_tracking")))]
+#[test]
+fn offsets_texture_atlas_grid() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+        ))
+        .init_asset::<TextureAtlas>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, Atlases>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+#[cfg(feature = "2d")]
+fn expect(
+    collection: Res<Atlases>,
+    atlases: Res<Assets<TextureAtlas>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let default_offset = atlases
+        .get(&collection.default_offset)
+        .expect("Atlas without offset should be added");
+    assert_eq!(default_offset.textures[0], Rect::new(0., 0., 32., 33.));
+    assert_eq!(default_offset.textures[1], Rect::new(32., 0., 64., 33.));
+
+    let offset = atlases
+        .get(&collection.offset)
+        .expect("Atlas with offset should be added");
+    assert_eq!(offset.textures[0], Rect::new(4., 2., 36., 35.));
+    assert_eq!(offset.textures[1], Rect::new(40., 2., 72., 35.));
+    exit.send(AppExit);
+}
+
+#[cfg(feature = "2d")]
+#[derive(AssetCollection, Resource)]
+struct Atlases {
+    #[asset(texture_atlas(tile_size_x = 32., tile_size_y = 33., columns = 2, rows = 1))]
+    #[asset(path = "images/female_adventurer_sheet.png")]
+    default_offset: Handle<TextureAtlas>,
+    #[asset(texture_atlas(
+        tile_size_x = 32.,
+        tile_size_y = 33.,
+        columns = 2,
+        rows = 1,
+        padding_x = 4.,
+        offset_x = 4.,
+        offset_y = 2.
+    ))]
+    #[asset(path = "images/female_adventurer_sheet.png")]
+    offset: Handle<TextureAtlas>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,80 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy::render::render_resource::TextureFormat;
+use bevy::render::texture::ImagePlugin;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+
+#[cfg(all(feature = "2d", not(feature = "progress_tracking")))]
+#[test]
+fn converts_atlas_image_format() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+        ))
+        .init_asset::<TextureAtlas>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, Atlases>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+#[cfg(feature = "2d")]
+fn expect(
+    collection: Res<Atlases>,
+    atlases: Res<Assets<TextureAtlas>>,
+    images: Res<Assets<Image>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let image_format = |atlas: &Handle<TextureAtlas>| {
+        let atlas = atlases.get(atlas).expect("Atlas should be added");
+        images
+            .get(&atlas.texture)
+            .expect("Atlas image should be loaded")
+            .texture_descriptor
+            .format
+    };
+    assert_eq!(
+        image_format(&collection.original),
+        TextureFormat::Rgba8UnormSrgb
+    );
+    assert_eq!(image_format(&collection.converted), TextureFormat::R8Unorm);
+    exit.send(AppExit);
+}
+
+#[cfg(feature = "2d")]
+#[derive(AssetCollection, Resource)]
+struct Atlases {
+    #[asset(texture_atlas(tile_size_x = 96., tile_size_y = 99., columns = 8, rows = 1))]
+    #[asset(path = "images/female_adventurer_sheet.png")]
+    original: Handle<TextureAtlas>,
+    #[asset(texture_atlas(
+        tile_size_x = 96.,
+        tile_size_y = 99.,
+        columns = 8,
+        rows = 1,
+        format = "r8unorm"
+    ))]
+    #[asset(path = "images/female_adventurer_sheet.png")]
+    converted: Handle<TextureAtlas>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,88 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy::render::texture::ImagePlugin;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+
+#[cfg(all(feature = "2d", not(feature = "progress_tracking")))]
+#[test]
+fn loads_texture_atlases_from_ron() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+        ))
+        .init_asset::<TextureAtlas>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .track_dependencies(),
+        )
+        .add_collection_to_loading_state::<_, Atlases>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+#[cfg(feature = "2d")]
+fn expect(
+    collection: Res<Atlases>,
+    atlases: Res<Assets<TextureAtlas>>,
+    images: Res<Assets<Image>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let grid = atlases
+        .get(&collection.grid)
+        .expect("Grid atlas should be added");
+    assert_eq!(grid.size, Vec2::new(768., 99.));
+    assert_eq!(grid.textures.len(), 8);
+    assert_eq!(grid.textures[0], Rect::new(0., 0., 96., 99.));
+    assert_eq!(grid.textures[7], Rect::new(672., 0., 768., 99.));
+    assert!(
+        images.contains(&grid.texture),
+        "The atlas image should be loaded as dependency"
+    );
+    assert_eq!(
+        grid.texture.path().map(ToString::to_string),
+        Some("images/female_adventurer_sheet.png".to_owned()),
+        "The atlas should share the image loaded from its own path"
+    );
+
+    let rects = atlases
+        .get(&collection.rects)
+        .expect("Rects atlas should be added");
+    assert_eq!(rects.size, Vec2::new(32., 32.));
+    assert_eq!(
+        rects.textures,
+        vec![Rect::new(0., 0., 16., 32.), Rect::new(16., 8., 32., 24.)]
+    );
+    assert!(images.contains(&rects.texture));
+    exit.send(AppExit);
+}
+
+#[cfg(feature = "2d")]
+#[derive(AssetCollection, Resource)]
+struct Atlases {
+    #[asset(path = "atlases/female_adventurer.atlas.ron")]
+    grid: Handle<TextureAtlas>,
+    #[asset(path = "atlases/trees.atlas.ron")]
+    rects: Handle<TextureAtlas>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,86 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy::render::texture::{ImagePlugin, ImageSampler, ImageSamplerDescriptor};
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+
+#[cfg(all(feature = "2d", not(feature = "progress_tracking")))]
+#[test]
+fn applies_atlas_sampler_defaults() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+        ))
+        .init_asset::<TextureAtlas>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, Atlases>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+#[cfg(feature = "2d")]
+fn expect(
+    collection: Res<Atlases>,
+    atlases: Res<Assets<TextureAtlas>>,
+    images: Res<Assets<Image>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let is_sampler = |atlas: &Handle<TextureAtlas>, expected: ImageSamplerDescriptor| {
+        let atlas = atlases.get(atlas).expect("Atlas should be added");
+        let image = images
+            .get(&atlas.texture)
+            .expect("Atlas image should be loaded");
+        if let ImageSampler::Descriptor(descriptor) = &image.sampler {
+            descriptor.as_wgpu() == expected.as_wgpu()
+        } else {
+            false
+        }
+    };
+    assert!(
+        is_sampler(&collection.default, ImageSamplerDescriptor::nearest()),
+        "The struct level atlas sampler should be applied"
+    );
+    assert!(
+        is_sampler(&collection.overridden, ImageSamplerDescriptor::linear()),
+        "The field level atlas sampler should win"
+    );
+    exit.send(AppExit);
+}
+
+#[cfg(feature = "2d")]
+#[derive(AssetCollection, Resource)]
+#[asset_collection(atlas_sampler = nearest)]
+struct Atlases {
+    #[asset(texture_atlas(tile_size_x = 96., tile_size_y = 99., columns = 8, rows = 1))]
+    #[asset(path = "images/female_adventurer_sheet.png")]
+    default: Handle<TextureAtlas>,
+    #[asset(texture_atlas(
+        tile_size_x = 32.,
+        tile_size_y = 32.,
+        columns = 1,
+        rows = 1,
+        sampler = linear
+    ))]
+    #[asset(path = "images/pixel_tree.png")]
+    overridden: Handle<TextureAtlas>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
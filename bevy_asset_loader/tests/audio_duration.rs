@@ -0,0 +1,100 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+use std::time::Duration;
+
+#[cfg(feature = "audio")]
+use bevy_asset_loader::audio::AudioWithDuration;
+
+#[cfg(all(feature = "audio", not(feature = "progress_tracking")))]
+#[test]
+fn stores_audio_duration() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, AudioAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_durations)
+        .run();
+}
+
+#[cfg(all(feature = "audio", not(target_arch = "wasm32")))]
+#[test]
+fn measures_audio_duration_before_blocking_init_returns() {
+    use bevy_asset_loader::asset_collection::AssetCollectionApp;
+
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+    ))
+    .init_collection_blocking::<AudioAssets>();
+    app.finish();
+    app.update();
+
+    let audio = app.world.resource::<AudioAssets>();
+    assert!(audio.yipee.is_measured());
+    assert!(audio.yipee.duration().is_some());
+    assert!(audio.plop.duration().is_some());
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("Failed to load the audio in 10 seconds");
+    }
+}
+
+#[cfg(feature = "audio")]
+fn expect_durations(
+    audio: Res<AudioAssets>,
+    sources: Res<Assets<AudioSource>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(sources.get(&audio.yipee.handle).is_some());
+    // 51200 samples at 44.1 kHz
+    let duration = audio
+        .yipee
+        .duration()
+        .expect("yipee.ogg should have a duration");
+    assert!(
+        duration.abs_diff(Duration::from_secs_f64(51200. / 44100.)) < Duration::from_millis(10),
+        "Unexpected duration {duration:?}"
+    );
+    // 4079 samples at 48 kHz
+    let duration = audio
+        .plop
+        .duration()
+        .expect("plop.ogg should have a duration");
+    assert!(
+        duration.abs_diff(Duration::from_secs_f64(4079. / 48000.)) < Duration::from_millis(10),
+        "Unexpected duration {duration:?}"
+    );
+    exit.send(AppExit);
+}
+
+#[cfg(feature = "audio")]
+#[derive(AssetCollection, Resource)]
+struct AudioAssets {
+    #[asset(path = "audio/yipee.ogg", duration)]
+    yipee: AudioWithDuration,
+    #[asset(path = "audio/plop.ogg", duration)]
+    plop: AudioWithDuration,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
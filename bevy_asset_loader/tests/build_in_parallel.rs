@@ -0,0 +1,75 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy::render::texture::ImagePlugin;
+use bevy::tasks::TaskPool;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+use std::sync::Arc;
+
+#[cfg(all(feature = "2d", not(feature = "progress_tracking")))]
+#[test]
+fn builds_texture_atlases_in_parallel() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+        ))
+        .init_asset::<TextureAtlas>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .build_in_parallel(2)
+                .build_in_task_pool(Arc::new(TaskPool::new())),
+        )
+        .add_collection_to_loading_state::<_, Atlases>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+#[cfg(feature = "2d")]
+fn expect(
+    collection: Res<Atlases>,
+    atlases: Res<Assets<TextureAtlas>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let player = atlases
+        .get(&collection.player)
+        .expect("Player atlas should be added");
+    assert_eq!(player.textures.len(), 8);
+    assert_eq!(player.textures[1], Rect::new(96., 0., 192., 99.));
+    let tree = atlases
+        .get(&collection.tree)
+        .expect("Tree atlas should be added");
+    assert_eq!(tree.textures.len(), 1);
+    exit.send(AppExit);
+}
+
+#[cfg(feature = "2d")]
+#[derive(AssetCollection, Resource)]
+struct Atlases {
+    #[asset(texture_atlas(tile_size_x = 96., tile_size_y = 99., columns = 8, rows = 1))]
+    #[asset(path = "images/female_adventurer_sheet.png")]
+    player: Handle<TextureAtlas>,
+    #[asset(texture_atlas(tile_size_x = 100., tile_size_y = 100., columns = 1, rows = 1))]
+    #[asset(path = "images/tree.png")]
+    tree: Handle<TextureAtlas>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,88 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::{AssetPlugin, UntypedHandle};
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn builds_collection_with_closure() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, Playlist>(MyStates::Load)
+        .build_with(|handles, world| Playlist {
+            tracks: handles
+                .into_iter()
+                .map(|handle| handle.typed::<AudioSource>())
+                .collect(),
+            loaded_sources: world.resource::<Assets<AudioSource>>().len(),
+        })
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_playlist)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("Failed to build the playlist in 10 seconds");
+    }
+}
+
+fn expect_playlist(
+    playlist: Res<Playlist>,
+    sources: Res<Assets<AudioSource>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(playlist.loaded_sources, 2);
+    assert_eq!(
+        playlist
+            .tracks
+            .iter()
+            .map(|track| track.path().unwrap().to_string())
+            .collect::<Vec<_>>(),
+        vec!["audio/plop.ogg", "audio/yipee.ogg"]
+    );
+    assert!(playlist.tracks.iter().all(|track| sources.contains(track)));
+    exit.send(AppExit);
+}
+
+/// Collection without a derive that is only constructed by the closure
+#[derive(Resource)]
+struct Playlist {
+    tracks: Vec<Handle<AudioSource>>,
+    loaded_sources: usize,
+}
+
+impl AssetCollection for Playlist {
+    fn create(_world: &mut World) -> Self {
+        panic!("The playlist should be built by the closure");
+    }
+
+    fn load(world: &mut World) -> Vec<UntypedHandle> {
+        let asset_server = world.resource::<AssetServer>();
+        vec![
+            asset_server.load_untyped("audio/plop.ogg").untyped(),
+            asset_server.load_untyped("audio/yipee.ogg").untyped(),
+        ]
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
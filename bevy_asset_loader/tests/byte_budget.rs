@@ -0,0 +1,131 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{DeferredAssets, LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn defers_assets_past_the_byte_budget() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .with_byte_budget(250)
+                .estimate_asset_size("audio/background.ogg", 100)
+                .estimate_asset_size("audio/plop.ogg", 100)
+                .estimate_asset_size("audio/yipee.ogg", 100),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout)
+        .add_systems(OnEnter(MyStates::Next), expect_deferred_asset)
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn assets_that_are_still_loaded_use_the_byte_budget() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .with_byte_budget(250)
+                .estimate_asset_size("audio/background.ogg", 100)
+                .estimate_asset_size("audio/plop.ogg", 100)
+                .estimate_asset_size("audio/yipee.ogg", 100),
+        )
+        .add_collection_to_loading_state::<_, SmallAssets>(MyStates::Load)
+        .add_systems(Startup, hold_yipee)
+        .add_systems(Update, timeout)
+        .add_systems(OnEnter(MyStates::Next), expect_plop_to_be_deferred)
+        .run();
+}
+
+fn hold_yipee(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(HeldAsset(asset_server.load("audio/yipee.ogg")));
+}
+
+fn expect_plop_to_be_deferred(deferred: Res<DeferredAssets>, mut exit: EventWriter<AppExit>) {
+    assert_eq!(
+        deferred.paths,
+        vec!["audio/plop.ogg".to_owned()],
+        "The held asset should still use the byte budget"
+    );
+    exit.send(AppExit);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The loading state did not continue in 10 seconds");
+    }
+}
+
+fn expect_deferred_asset(
+    deferred: Res<DeferredAssets>,
+    assets: Res<MyAssets>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(deferred.paths, vec!["audio/yipee.ogg".to_owned()]);
+    assert!(asset_server.is_loaded_with_dependencies(&assets.background));
+    assert!(asset_server.is_loaded_with_dependencies(&assets.plop));
+    assert_eq!(
+        asset_server
+            .get_path(&assets.yipee)
+            .map(|path| path.to_string()),
+        Some("audio/yipee.ogg".to_owned()),
+        "The deferred asset should still be part of the collection"
+    );
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+    #[asset(path = "audio/yipee.ogg")]
+    yipee: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct SmallAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Resource)]
+struct HeldAsset(Handle<AudioSource>);
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
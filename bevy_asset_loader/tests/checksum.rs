@@ -0,0 +1,203 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::{AssetCollection, AssetCollectionApp};
+use bevy_asset_loader::loading_state::{AssetLoadFailed, LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    feature = "checksums",
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn continues_with_matching_checksum() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error),
+        )
+        .add_collection_to_loading_state::<_, MatchingAssets>(MyStates::Load)
+        .add_systems(Update, timeout)
+        .add_systems(OnEnter(MyStates::Next), exit)
+        .add_systems(OnEnter(MyStates::Error), fail)
+        .run();
+}
+
+#[cfg(all(
+    feature = "checksums",
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn fails_with_mismatching_checksum() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error),
+        )
+        .add_collection_to_loading_state::<_, MismatchingAssets>(MyStates::Load)
+        .init_resource::<FailedPaths>()
+        .add_systems(
+            Update,
+            (
+                timeout,
+                (
+                    collect_failures,
+                    expect_failed_path.run_if(in_state(MyStates::Error)),
+                )
+                    .chain(),
+            ),
+        )
+        .add_systems(OnEnter(MyStates::Next), unexpected_success)
+        .run();
+}
+
+#[cfg(all(
+    feature = "checksums",
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn init_collection_reports_mismatching_checksum() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_event::<AssetLoadFailed>()
+        .init_collection::<MismatchingAssets>()
+        .init_resource::<FailedPaths>()
+        .add_systems(
+            Update,
+            (timeout, (collect_failures, exit_on_failure).chain()),
+        )
+        .run();
+}
+
+#[cfg(all(
+    feature = "checksums",
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+#[should_panic(expected = "does not match the expected SHA-256 digest")]
+fn init_collection_blocking_panics_on_mismatching_checksum() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+    ))
+    .init_collection_blocking::<MismatchingAssets>();
+    app.update();
+}
+
+#[cfg(all(
+    feature = "checksums",
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn init_collection_blocking_accepts_matching_checksum() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+    ))
+    .init_collection_blocking::<MatchingAssets>();
+    app.update();
+    assert!(app.world.contains_resource::<MatchingAssets>());
+}
+
+#[derive(Resource, Default)]
+struct FailedPaths(Vec<String>);
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The loading state did not finish in 10 seconds");
+    }
+}
+
+fn collect_failures(mut events: EventReader<AssetLoadFailed>, mut failed: ResMut<FailedPaths>) {
+    failed
+        .0
+        .extend(events.read().map(|event| event.path.clone()));
+}
+
+fn expect_failed_path(failed: Res<FailedPaths>, mut exit: EventWriter<AppExit>) {
+    assert_eq!(failed.0, vec!["audio/plop.ogg".to_owned()]);
+    exit.send(AppExit);
+}
+
+fn exit_on_failure(failed: Res<FailedPaths>, mut exit: EventWriter<AppExit>) {
+    if !failed.0.is_empty() {
+        assert_eq!(failed.0, vec!["audio/plop.ogg".to_owned()]);
+        exit.send(AppExit);
+    }
+}
+
+fn exit(mut exit: EventWriter<AppExit>) {
+    exit.send(AppExit);
+}
+
+fn fail() {
+    panic!("The checksum should match");
+}
+
+fn unexpected_success() {
+    panic!("A mismatching checksum should fail the loading state");
+}
+
+#[cfg(feature = "checksums")]
+#[derive(AssetCollection, Resource)]
+struct MatchingAssets {
+    #[asset(
+        path = "audio/plop.ogg",
+        sha256 = "e0ec9c57617b4a8383a497a35197d7125c651828922326eab59ff798bfb5a335"
+    )]
+    plop: Handle<AudioSource>,
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[cfg(feature = "checksums")]
+#[derive(AssetCollection, Resource)]
+struct MismatchingAssets {
+    #[asset(
+        path = "audio/plop.ogg",
+        sha256 = "0000000000000000000000000000000000000000000000000000000000000000"
+    )]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Error,
+    Next,
+}
@@ -0,0 +1,131 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt, LoadingStateSchedules};
+use std::time::{Duration, Instant};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn cleanup_after_loading_can_be_reentered() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .init_resource::<LoadCount>()
+        .add_loading_state(LoadingState::new(MyStates::Load).cleanup_after())
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout)
+        .add_systems(
+            Update,
+            leave_loading_state
+                .run_if(in_state(MyStates::Load).and_then(resource_exists::<MyAssets>())),
+        )
+        .add_systems(OnEnter(MyStates::Other), reenter_loading_state)
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn cleanup_after_stops_checking_the_loading_state() {
+    let mut app = App::new();
+    app.add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .init_resource::<ScheduleRuns>()
+        .add_loading_state(LoadingState::new(MyStates::Load).cleanup_after())
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load);
+    let schedule = app
+        .world
+        .resource::<LoadingStateSchedules<MyStates>>()
+        .schedules[&MyStates::Load];
+    app.add_systems(schedule, count_schedule_runs);
+
+    let start = Instant::now();
+    while !app.world.contains_resource::<MyAssets>() {
+        assert!(
+            start.elapsed() < Duration::from_secs(10),
+            "The asset loader did not insert the collection in 10 seconds"
+        );
+        app.update();
+    }
+    // let the loading state finish after inserting the collection
+    app.update();
+    app.update();
+    let runs = app.world.resource::<ScheduleRuns>().0;
+    assert!(runs > 0);
+
+    for _ in 0..5 {
+        app.update();
+    }
+    assert_eq!(
+        app.world.resource::<ScheduleRuns>().0,
+        runs,
+        "The loading state should not be checked after it finished"
+    );
+}
+
+#[derive(Resource, Default)]
+struct LoadCount(usize);
+
+#[derive(Resource, Default)]
+struct ScheduleRuns(usize);
+
+fn count_schedule_runs(mut runs: ResMut<ScheduleRuns>) {
+    runs.0 += 1;
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not insert the collection again in 10 seconds");
+    }
+}
+
+fn leave_loading_state(
+    mut commands: Commands,
+    mut count: ResMut<LoadCount>,
+    mut next_state: ResMut<NextState<MyStates>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    count.0 += 1;
+    if count.0 == 2 {
+        exit.send(AppExit);
+        return;
+    }
+    commands.remove_resource::<MyAssets>();
+    next_state.set(MyStates::Other);
+}
+
+fn reenter_loading_state(mut next_state: ResMut<NextState<MyStates>>) {
+    next_state.set(MyStates::Load);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Other,
+}
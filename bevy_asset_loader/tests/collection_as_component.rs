@@ -0,0 +1,130 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::{AssetCollection, HandleRegistry};
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn inserts_collection_as_component_on_every_entity() {
+    let mut app = App::new();
+    app.add_state::<MyStates>().add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+    ));
+    let first = app.world.spawn(Player(1)).id();
+    let second = app.world.spawn(Player(2)).id();
+    app.add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state_as_component::<_, PlayerAudio>(MyStates::Load, first)
+        .add_collection_to_loading_state_as_component::<_, PlayerAudio>(MyStates::Load, second)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn registers_handles_of_collections_inserted_as_components() {
+    let mut app = App::new();
+    app.add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .init_resource::<Registry>();
+    let player = app.world.spawn(Player(1)).id();
+    // the component is added first, so its system would run first without an explicit order
+    app.add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state_as_component::<_, PlayerAudio>(MyStates::Load, player)
+        .add_collection_to_registry::<_, PlayerAudio, Registry>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_registered)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+fn expect(
+    players: Query<(&Player, &PlayerAudio)>,
+    resource: Option<Res<PlayerAudio>>,
+    asset_server: Res<AssetServer>,
+    audio: Res<Assets<AudioSource>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(
+        resource.is_none(),
+        "The collection should not be inserted as a resource"
+    );
+    let players: Vec<_> = players.iter().collect();
+    assert_eq!(players.len(), 2, "Both entities should get the collection");
+    for (_, collection) in players {
+        assert!(audio.contains(&collection.plop));
+        assert_eq!(
+            asset_server
+                .get_path(collection.plop.id())
+                .map(|path| path.to_string()),
+            Some("audio/plop.ogg".to_owned())
+        );
+    }
+    exit.send(AppExit);
+}
+
+fn expect_registered(
+    players: Query<&PlayerAudio>,
+    resource: Option<Res<PlayerAudio>>,
+    registry: Res<Registry>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(
+        resource.is_none(),
+        "The collection should not be inserted as a resource"
+    );
+    let collection = players.single();
+    assert_eq!(
+        registry.0,
+        vec![("plop", collection.plop.clone().untyped())]
+    );
+    exit.send(AppExit);
+}
+
+#[derive(Resource, Default)]
+struct Registry(Vec<(&'static str, UntypedHandle)>);
+
+impl HandleRegistry for Registry {
+    fn register(&mut self, field: &'static str, handle: UntypedHandle) {
+        self.0.push((field, handle));
+    }
+}
+
+#[derive(Component)]
+struct Player(usize);
+
+#[derive(AssetCollection, Resource, Component, Clone)]
+struct PlayerAudio {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
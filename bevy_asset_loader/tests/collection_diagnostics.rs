@@ -0,0 +1,150 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::{AssetCollection, AssetCollectionApp};
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+use std::any::type_name;
+use std::panic::AssertUnwindSafe;
+
+#[cfg(all(
+    feature = "collection_diagnostics",
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn lists_collections_that_are_never_loaded() {
+    use bevy_asset_loader::collection_registry::CollectionRegistry;
+
+    let mut app = App::new();
+    app.add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .register_collection::<LoadedAssets>()
+        .register_collection::<InitializedAssets>()
+        .register_collection::<OrphanedAssets>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, LoadedAssets>(MyStates::Load)
+        .init_collection::<InitializedAssets>();
+    app.update();
+
+    assert_eq!(
+        app.world
+            .resource::<CollectionRegistry>()
+            .unloaded_collections(),
+        vec![type_name::<OrphanedAssets>()]
+    );
+}
+
+#[cfg(all(
+    feature = "collection_diagnostics",
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn registers_collections_of_loading_states() {
+    use bevy_asset_loader::collection_registry::CollectionRegistry;
+
+    let mut app = App::new();
+    app.add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, LoadedAssets>(MyStates::Load)
+        // there is no loading state for `MyStates::Next`
+        .add_collection_to_loading_state::<_, OrphanedAssets>(MyStates::Next);
+    app.update();
+
+    assert_eq!(
+        app.world
+            .resource::<CollectionRegistry>()
+            .unloaded_collections(),
+        vec![type_name::<OrphanedAssets>()]
+    );
+}
+
+#[cfg(all(
+    feature = "collection_diagnostics",
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn flags_paths_loaded_by_multiple_collections() {
+    use bevy_asset_loader::collection_registry::{DuplicateLoad, LoadRequests};
+
+    let mut app = App::new();
+    app.add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, LoadedAssets>(MyStates::Load)
+        .add_collection_to_loading_state::<_, DuplicateAssets>(MyStates::Load)
+        .init_collection::<InitializedAssets>();
+    // the loading state starts loading its collections after a few frames
+    for _ in 0..5 {
+        app.update();
+    }
+
+    let mut requests = app.world.resource_mut::<LoadRequests>();
+    assert_eq!(requests.count("audio/background.ogg"), 2);
+    assert_eq!(requests.count("audio/plop.ogg"), 1);
+    assert_eq!(
+        requests.duplicates(),
+        vec![DuplicateLoad {
+            path: "audio/background.ogg".to_owned(),
+            collections: vec![type_name::<DuplicateAssets>(), type_name::<LoadedAssets>()],
+        }]
+    );
+    let result = std::panic::catch_unwind(AssertUnwindSafe(|| requests.assert_no_duplicates()));
+    assert!(result.is_err(), "The duplicate load should be flagged");
+
+    requests.share("audio/background.ogg");
+    assert!(requests.duplicates().is_empty());
+    requests.assert_no_duplicates();
+}
+
+#[derive(AssetCollection, Resource)]
+struct LoadedAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct DuplicateAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(path = "audio/background.ogg")]
+    same_background: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct InitializedAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct OrphanedAssets {
+    #[asset(path = "audio/yipee.ogg")]
+    yipee: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
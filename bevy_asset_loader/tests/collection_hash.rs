@@ -0,0 +1,185 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::asset::io::file::FileAssetReader;
+use bevy::asset::io::{AssetReader, AssetReaderError, AssetSource, PathStream, Reader};
+use bevy::asset::{AssetApp, AssetPlugin};
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::utils::{BoxedFuture, HashSet};
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "checksums")]
+use bevy_asset_loader::checksum::CollectionHash;
+
+#[cfg(all(
+    feature = "checksums",
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn collection_hash_is_stable_across_loads() {
+    let first = load_hash::<Audio>();
+    let second = load_hash::<Audio>();
+    assert_eq!(first, second);
+    assert_eq!(first.len(), 64);
+
+    // the order of the fields does not change the hash
+    assert_eq!(first, load_hash::<ReorderedAudio>());
+    assert_ne!(first, load_hash::<OtherAudio>());
+}
+
+#[cfg(all(
+    feature = "checksums",
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn unreadable_files_skip_the_collection_hash() {
+    let mut app = App::new();
+    app.register_asset_source(
+        "once",
+        AssetSource::build().with_reader(|| {
+            Box::new(ReadOnceReader {
+                inner: FileAssetReader::new("assets"),
+                read: default(),
+            })
+        }),
+    );
+    let app = load(
+        app,
+        |state| state.hash_collections(),
+        |app| {
+            app.add_collection_to_loading_state::<_, ReadOnceAudio>(MyStates::Load);
+        },
+    );
+
+    assert!(app.world.contains_resource::<ReadOnceAudio>());
+    assert!(!app
+        .world
+        .contains_resource::<CollectionHash<ReadOnceAudio>>());
+}
+
+#[cfg(feature = "checksums")]
+fn load_hash<A: AssetCollection>() -> String {
+    let app = load(
+        App::new(),
+        |state| state.hash_collections(),
+        |app| {
+            app.add_collection_to_loading_state::<_, A>(MyStates::Load);
+        },
+    );
+    assert!(app.world.contains_resource::<A>());
+
+    app.world.resource::<CollectionHash<A>>().to_hex()
+}
+
+fn load(
+    mut app: App,
+    configure: impl FnOnce(LoadingState<MyStates>) -> LoadingState<MyStates>,
+    add_collection: impl FnOnce(&mut App),
+) -> App {
+    app.add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(configure(
+            LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next),
+        ));
+    add_collection(&mut app);
+
+    let start = Instant::now();
+    while app.world.resource::<State<MyStates>>().get() == &MyStates::Load {
+        assert!(
+            start.elapsed() < Duration::from_secs(10),
+            "The asset loader did not change the state in 10 seconds"
+        );
+        app.update();
+        std::thread::sleep(Duration::from_millis(5));
+    }
+
+    app
+}
+
+#[derive(AssetCollection, Resource)]
+struct Audio {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct ReorderedAudio {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct OtherAudio {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct ReadOnceAudio {
+    #[asset(path = "once://audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+/// Fails every read of an asset after the first one
+struct ReadOnceReader {
+    inner: FileAssetReader,
+    read: Mutex<HashSet<PathBuf>>,
+}
+
+impl AssetReader for ReadOnceReader {
+    fn read<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        Box::pin(async move {
+            if !self.read.lock().unwrap().insert(path.to_path_buf()) {
+                return Err(AssetReaderError::NotFound(path.to_path_buf()));
+            }
+            self.inner.read(path).await
+        })
+    }
+
+    fn read_meta<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        self.inner.read_meta(path)
+    }
+
+    fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<PathStream>, AssetReaderError>> {
+        self.inner.read_directory(path)
+    }
+
+    fn is_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<bool, AssetReaderError>> {
+        self.inner.is_directory(path)
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
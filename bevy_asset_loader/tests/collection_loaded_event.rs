@@ -0,0 +1,147 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::io::file::FileAssetReader;
+use bevy::asset::io::{AssetReader, AssetReaderError, AssetSource, PathStream, Reader};
+use bevy::asset::{AssetApp, AssetPlugin};
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::utils::BoxedFuture;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{
+    AssetCollectionLoaded, LoadingState, LoadingStateAppExt, LoadingStateSet,
+};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn sends_event_per_loaded_collection() {
+    App::new()
+        .register_asset_source(
+            "slow",
+            AssetSource::build().with_reader(|| {
+                Box::new(SlowReader {
+                    inner: FileAssetReader::new("assets"),
+                })
+            }),
+        )
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .init_resource::<LoadedCollections>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, LevelAssets>(MyStates::Load)
+        .add_collection_to_loading_state::<_, MenuAssets>(MyStates::Load)
+        .add_systems(
+            Update,
+            (
+                timeout,
+                collect_loaded_collections.after(LoadingStateSet(MyStates::Load)),
+            ),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct LoadedCollections(Vec<AssetCollectionLoaded>);
+
+fn collect_loaded_collections(
+    mut events: EventReader<AssetCollectionLoaded>,
+    mut loaded: ResMut<LoadedCollections>,
+    menu: Option<Res<MenuAssets>>,
+    level: Option<Res<LevelAssets>>,
+) {
+    for event in events.read() {
+        if event.is::<MenuAssets>() {
+            assert!(menu.is_some(), "The collection should be inserted");
+            assert!(level.is_none(), "The menu should be done before the level");
+        }
+        loaded.0.push(*event);
+    }
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+fn expect(loaded: Res<LoadedCollections>, mut exit: EventWriter<AppExit>) {
+    assert_eq!(loaded.0.len(), 2);
+    assert!(loaded.0[0].is::<MenuAssets>());
+    assert!(loaded.0[1].is::<LevelAssets>());
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MenuAssets {
+    #[asset(path = "audio/plop.ogg")]
+    music: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct LevelAssets {
+    #[asset(path = "slow://audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(path = "slow://audio/yipee.ogg")]
+    yipee: Handle<AudioSource>,
+    #[asset(path = "slow://audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+/// Reads take a while, so collections using this source finish after the others
+struct SlowReader {
+    inner: FileAssetReader,
+}
+
+impl AssetReader for SlowReader {
+    fn read<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        Box::pin(async move {
+            let start = Instant::now();
+            while start.elapsed() < Duration::from_millis(200) {
+                futures_lite::future::yield_now().await;
+            }
+            self.inner.read(path).await
+        })
+    }
+
+    fn read_meta<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        self.inner.read_meta(path)
+    }
+
+    fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<PathStream>, AssetReaderError>> {
+        self.inner.read_directory(path)
+    }
+
+    fn is_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<bool, AssetReaderError>> {
+        self.inner.is_directory(path)
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,69 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::tasks::block_on;
+use bevy_asset_loader::asset_collection::{AssetCollection, AssetCollectionApp};
+use bevy_asset_loader::collection_loader::CollectionLoader;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn awaits_collection_on_another_thread() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+    ))
+    .add_collection_loader();
+
+    let loader = app.world.resource::<CollectionLoader>().clone();
+    let awaited = AwaitedCollection::default();
+    let result = awaited.0.clone();
+    thread::spawn(move || {
+        let collection = block_on(loader.load::<MyAssets>());
+        *result.lock().unwrap() = Some(collection.is_ok());
+    });
+
+    app.insert_resource(awaited)
+        .add_systems(Update, (timeout, expect))
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct AwaitedCollection(Arc<Mutex<Option<bool>>>);
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The collection future did not resolve in 10 seconds");
+    }
+}
+
+fn expect(
+    awaited: Res<AwaitedCollection>,
+    collection: Option<Res<MyAssets>>,
+    audio: Res<Assets<AudioSource>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let Some(is_ok) = *awaited.0.lock().unwrap() else {
+        return;
+    };
+    assert!(is_ok, "The collection should load");
+    let collection = collection.expect("The collection should be inserted as a resource");
+    assert!(audio.contains(&collection.background));
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource, Clone)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
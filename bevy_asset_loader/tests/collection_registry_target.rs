@@ -0,0 +1,91 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::{AssetCollection, HandleRegistry};
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn merges_handles_into_registry() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_systems(Startup, setup_registry)
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_registry::<_, MyAssets, SoundRegistry>(MyStates::Load)
+        .add_systems(Update, timeout)
+        .add_systems(OnEnter(MyStates::Next), expect_registered_handles)
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct SoundRegistry {
+    entries: Vec<(&'static str, UntypedHandle)>,
+}
+
+impl HandleRegistry for SoundRegistry {
+    fn register(&mut self, field: &'static str, handle: UntypedHandle) {
+        self.entries.push((field, handle));
+    }
+}
+
+fn setup_registry(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(SoundRegistry {
+        entries: vec![(
+            "existing",
+            asset_server
+                .load::<AudioSource>("audio/yipee.ogg")
+                .untyped(),
+        )],
+    });
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The loading state did not continue in 10 seconds");
+    }
+}
+
+fn expect_registered_handles(
+    registry: Res<SoundRegistry>,
+    collection: Option<Res<MyAssets>>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(
+        collection.is_none(),
+        "The collection should be merged into the registry instead of being inserted"
+    );
+    let fields: Vec<&str> = registry.entries.iter().map(|(field, _)| *field).collect();
+    assert_eq!(fields, vec!["existing", "background", "sounds", "sounds"]);
+    for (_, handle) in registry.entries.iter().skip(1) {
+        assert!(asset_server.is_loaded_with_dependencies(handle.id()));
+    }
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(paths("audio/plop.ogg", "audio/yipee.ogg"), collection(typed))]
+    sounds: Vec<Handle<AudioSource>>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
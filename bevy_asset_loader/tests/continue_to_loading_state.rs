@@ -0,0 +1,21 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+
+#[test]
+#[should_panic(expected = "The loading state Load is configured to continue to itself")]
+fn continuing_to_the_loading_state_panics() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Load));
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -4,7 +4,7 @@ use bevy::app::AppExit;
 use bevy::asset::AssetPlugin;
 use bevy::prelude::*;
 use bevy_asset_loader::asset_collection::AssetCollection;
-use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+use bevy_asset_loader::loading_state::{FailedAssets, LoadingState, LoadingStateAppExt};
 
 #[cfg(all(
     not(feature = "2d"),
@@ -32,7 +32,8 @@ fn fail() {
     panic!("The library should have switched to the failure state");
 }
 
-fn exit(mut exit: EventWriter<AppExit>) {
+fn exit(failed: Res<FailedAssets>, mut exit: EventWriter<AppExit>) {
+    assert_eq!(failed.0, vec!["audio/plop.ogg".to_owned()]);
     exit.send(AppExit);
 }
 
@@ -0,0 +1,101 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::{AssetCollection, CustomReadiness};
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn custom_readiness_gates_completion() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .init_resource::<Pipeline>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, AudioAssets>(MyStates::Load)
+        .add_systems(
+            Update,
+            (timeout, compile_pipeline).run_if(in_state(MyStates::Load)),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect_compiled_pipeline)
+        .run();
+}
+
+/// Stands in for work that is not tracked by asset handles
+#[derive(Resource, Default)]
+struct Pipeline {
+    frames_after_load: u32,
+    compiled: bool,
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collection did not become ready in 10 seconds");
+    }
+}
+
+fn compile_pipeline(
+    mut pipeline: ResMut<Pipeline>,
+    asset_server: Res<AssetServer>,
+    audio: Option<Res<AudioAssets>>,
+) {
+    assert!(
+        pipeline.compiled || audio.is_none(),
+        "The collection was inserted before it was ready"
+    );
+    let loaded = asset_server
+        .get_handle_untyped("audio/plop.ogg")
+        .is_some_and(|handle| asset_server.is_loaded_with_dependencies(handle.id()));
+    if !loaded {
+        return;
+    }
+    if pipeline.compiled {
+        return;
+    }
+    pipeline.frames_after_load += 1;
+    if pipeline.frames_after_load == 10 {
+        pipeline.compiled = true;
+    }
+}
+
+fn expect_compiled_pipeline(
+    pipeline: Res<Pipeline>,
+    audio: Res<AudioAssets>,
+    sources: Res<Assets<AudioSource>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(pipeline.compiled);
+    assert!(sources.get(&audio.plop).is_some());
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+#[asset_collection(custom_ready)]
+struct AudioAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+impl CustomReadiness for AudioAssets {
+    fn custom_ready(&self, world: &World) -> bool {
+        world.resource::<Pipeline>().compiled
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
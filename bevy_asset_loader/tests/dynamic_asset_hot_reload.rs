@@ -0,0 +1,168 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::{AssetPlugin, LoadState};
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    feature = "standard_dynamic_assets",
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn changed_dynamic_asset_files_update_keyed_fields() {
+    app()
+        .add_systems(
+            OnEnter(MyStates::Next),
+            |audio_assets: Res<AudioAssets>,
+             other_assets: Res<OtherAudioAssets>,
+             asset_server: Res<AssetServer>,
+             collections: ResMut<Assets<StandardDynamicAssetCollection>>,
+             mut commands: Commands| {
+                assert_eq!(path(&asset_server, &audio_assets.sound), "audio/plop.ogg");
+                commands.insert_resource(OtherCreated(other_assets.last_changed()));
+                change_dynamic_asset_file(&asset_server, collections, "audio/background.ogg");
+            },
+        )
+        .add_systems(Update, expect.run_if(in_state(MyStates::Next)))
+        .run();
+}
+
+#[cfg(all(
+    feature = "standard_dynamic_assets",
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn keeps_collection_if_changed_dynamic_assets_fail_to_load() {
+    app()
+        .add_systems(
+            OnEnter(MyStates::Next),
+            |asset_server: Res<AssetServer>,
+             collections: ResMut<Assets<StandardDynamicAssetCollection>>| {
+                change_dynamic_asset_file(&asset_server, collections, "audio/does_not_exist.ogg");
+            },
+        )
+        .add_systems(Update, expect_kept.run_if(in_state(MyStates::Next)))
+        .run();
+}
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .with_hot_reload(),
+        )
+        .add_systems(Update, timeout);
+    #[cfg(feature = "standard_dynamic_assets")]
+    app.add_dynamic_collection_to_loading_state::<_, StandardDynamicAssetCollection>(
+        MyStates::Load,
+        "hot_reload.assets.ron",
+    )
+    .add_dynamic_collection_to_loading_state::<_, StandardDynamicAssetCollection>(
+        MyStates::Load,
+        "hot_reload_other.assets.ron",
+    )
+    .add_collection_to_loading_state::<_, AudioAssets>(MyStates::Load)
+    .add_collection_to_loading_state::<_, OtherAudioAssets>(MyStates::Load);
+
+    app
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The keyed field was not updated in 10 seconds");
+    }
+}
+
+#[cfg(feature = "standard_dynamic_assets")]
+fn change_dynamic_asset_file(
+    asset_server: &AssetServer,
+    mut collections: ResMut<Assets<StandardDynamicAssetCollection>>,
+    sound: &str,
+) {
+    // simulates a changed file; hot reloading it modifies the asset in the same way
+    let handle = asset_server
+        .get_handle::<StandardDynamicAssetCollection>("hot_reload.assets.ron")
+        .expect("The dynamic asset file should be kept alive");
+    collections.get_mut(&handle).unwrap().0.insert(
+        "sound".to_owned(),
+        StandardDynamicAsset::File {
+            path: sound.to_owned(),
+        },
+    );
+}
+
+fn expect(
+    audio_assets: Res<AudioAssets>,
+    other_assets: Res<OtherAudioAssets>,
+    other_created: Res<OtherCreated>,
+    asset_server: Res<AssetServer>,
+    sources: Res<Assets<AudioSource>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    if path(&asset_server, &audio_assets.sound) == "audio/background.ogg" {
+        assert!(audio_assets.is_changed());
+        assert!(sources.contains(&audio_assets.sound));
+        assert_eq!(
+            other_assets.last_changed(),
+            other_created.0,
+            "Collections without changed keys should not be created again"
+        );
+        exit.send(AppExit);
+    }
+}
+
+fn expect_kept(
+    audio_assets: Res<AudioAssets>,
+    asset_server: Res<AssetServer>,
+    mut missing: Local<Option<Handle<AudioSource>>>,
+    mut frames_after_failure: Local<u32>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(path(&asset_server, &audio_assets.sound), "audio/plop.ogg");
+    // the same handle as the one of the reloaded collection
+    let missing = missing.get_or_insert_with(|| asset_server.load("audio/does_not_exist.ogg"));
+    if asset_server.load_state(missing.id()) == LoadState::Failed {
+        *frames_after_failure += 1;
+    }
+    if *frames_after_failure > 5 {
+        exit.send(AppExit);
+    }
+}
+
+fn path(asset_server: &AssetServer, handle: &Handle<AudioSource>) -> String {
+    asset_server
+        .get_path(handle.id())
+        .expect("The handle should have a path")
+        .to_string()
+}
+
+#[derive(AssetCollection, Resource)]
+struct AudioAssets {
+    #[asset(key = "sound")]
+    sound: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct OtherAudioAssets {
+    #[asset(key = "other_sound")]
+    sound: Handle<AudioSource>,
+}
+
+#[derive(Resource)]
+struct OtherCreated(bevy::ecs::component::Tick);
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,186 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::dynamic_asset::{MissingDynamicAsset, MissingDynamicAssets};
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    feature = "standard_dynamic_assets",
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn resolves_keys_from_dynamic_assets_file() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error)
+                .with_dynamic_assets_file("dynamic_assets.assets.ron"),
+        )
+        .add_collection_to_loading_state::<_, Sounds>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_sounds)
+        .add_systems(
+            OnEnter(MyStates::Error),
+            fail("The keys of the file should resolve"),
+        )
+        .run();
+}
+
+#[cfg(all(
+    feature = "standard_dynamic_assets",
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn reports_key_missing_from_dynamic_assets_file() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error)
+                .with_dynamic_assets_file("dynamic_assets.assets.ron"),
+        )
+        .add_collection_to_loading_state::<_, MissingSound>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(
+            OnEnter(MyStates::Next),
+            fail("A collection with a missing key should not load"),
+        )
+        .add_systems(OnEnter(MyStates::Error), expect_missing_key)
+        .run();
+}
+
+#[cfg(all(
+    feature = "standard_dynamic_assets",
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn keys_registered_in_code_override_dynamic_assets_file() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error)
+                .with_dynamic_assets_file("dynamic_assets.assets.ron"),
+        )
+        .add_collection_to_loading_state::<_, Sounds>(MyStates::Load)
+        .add_systems(Startup, register_music_in_code)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_overridden_music)
+        .add_systems(
+            OnEnter(MyStates::Error),
+            fail("The keys of the file should resolve"),
+        )
+        .run();
+}
+
+#[cfg(feature = "standard_dynamic_assets")]
+fn register_music_in_code(mut dynamic_assets: ResMut<DynamicAssets>) {
+    dynamic_assets.register_asset(
+        "music",
+        Box::new(StandardDynamicAsset::File {
+            path: "audio/plop.ogg".to_owned(),
+        }),
+    );
+}
+
+fn expect_overridden_music(
+    sounds: Res<Sounds>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let path = |handle: &Handle<AudioSource>| {
+        asset_server
+            .get_path(handle.id())
+            .map(|path| path.to_string())
+    };
+    assert_eq!(path(&sounds.music), Some("audio/plop.ogg".to_owned()));
+    assert_eq!(path(&sounds.click), Some("audio/plop.ogg".to_owned()));
+    exit.send(AppExit);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+fn fail(message: &'static str) -> impl Fn() {
+    move || panic!("{message}")
+}
+
+fn expect_sounds(
+    sounds: Res<Sounds>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let path = |handle: &Handle<AudioSource>| {
+        asset_server
+            .get_path(handle.id())
+            .map(|path| path.to_string())
+    };
+    assert_eq!(path(&sounds.music), Some("audio/background.ogg".to_owned()));
+    assert_eq!(path(&sounds.click), Some("audio/plop.ogg".to_owned()));
+    exit.send(AppExit);
+}
+
+fn expect_missing_key(
+    missing: Res<MissingDynamicAssets>,
+    world_has_collection: Option<Res<MissingSound>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(world_has_collection.is_none());
+    assert_eq!(
+        missing.0,
+        vec![MissingDynamicAsset {
+            collection: std::any::type_name::<MissingSound>(),
+            field: "boss",
+            key: "boss_theme".to_owned(),
+        }]
+    );
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct Sounds {
+    #[asset(key = "music")]
+    music: Handle<AudioSource>,
+    #[asset(key = "click")]
+    click: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct MissingSound {
+    #[asset(key = "music")]
+    music: Handle<AudioSource>,
+    #[asset(key = "boss_theme")]
+    boss: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Error,
+    Next,
+}
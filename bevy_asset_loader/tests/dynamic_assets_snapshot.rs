@@ -0,0 +1,70 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    feature = "2d",
+    feature = "3d",
+    feature = "standard_dynamic_assets",
+    not(feature = "progress_tracking"),
+))]
+#[test]
+fn snapshot_round_trip() {
+    use bevy_asset_loader::standard_dynamic_asset::ImageSamplerType;
+
+    let mut dynamic_assets = DynamicAssets::default();
+    dynamic_assets.register_asset(
+        "single_file",
+        Box::new(StandardDynamicAsset::File {
+            path: "audio/background.ogg".to_owned(),
+        }),
+    );
+    dynamic_assets.register_asset(
+        "files",
+        Box::new(StandardDynamicAsset::Files {
+            paths: vec!["images/tree.png".to_owned(), "images/player.png".to_owned()],
+        }),
+    );
+    dynamic_assets.register_asset(
+        "pixel_tree",
+        Box::new(StandardDynamicAsset::Image {
+            path: "images/tree.png".to_owned(),
+            sampler: Some(ImageSamplerType::Linear),
+        }),
+    );
+    dynamic_assets.register_asset(
+        "texture_atlas",
+        Box::new(StandardDynamicAsset::TextureAtlas {
+            path: "images/female_adventurer_sheet.png".to_owned(),
+            tile_size_x: 96.,
+            tile_size_y: 99.,
+            columns: 8,
+            rows: 1,
+            padding_x: Some(2.),
+            padding_y: None,
+            offset_x: None,
+            offset_y: None,
+        }),
+    );
+
+    let serialized = ron::ser::to_string(&dynamic_assets.snapshot()).expect("Failed to serialize");
+    let snapshot: StandardDynamicAssetCollection =
+        ron::de::from_str(&serialized).expect("Failed to deserialize");
+
+    let mut restored = DynamicAssets::default();
+    restored.register_asset(
+        "single_file",
+        Box::new(StandardDynamicAsset::File {
+            path: "audio/plop.ogg".to_owned(),
+        }),
+    );
+    restored.restore(&snapshot);
+
+    assert_eq!(restored.iter_assets().count(), 4);
+    for (key, asset) in dynamic_assets.iter_assets() {
+        let restored_asset = restored
+            .get_asset(key)
+            .unwrap_or_else(|| panic!("Missing restored asset for key '{key}'"));
+        assert_eq!(format!("{asset:?}"), format!("{restored_asset:?}"));
+    }
+}
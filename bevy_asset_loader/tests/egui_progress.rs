@@ -0,0 +1,22 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::prelude::*;
+use bevy_asset_loader::loading_state::LoadingProgress;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking"),
+    feature = "egui",
+))]
+#[test]
+fn egui_progress_system_reads_loading_progress() {
+    use bevy_asset_loader::egui::egui_loading_progress;
+
+    let mut world = World::new();
+    let progress = world.init_resource::<LoadingProgress>();
+    let mut system = IntoSystem::into_system(egui_loading_progress);
+    system.initialize(&mut world);
+    assert!(system.component_access().has_read(progress));
+    assert!(!system.component_access().has_write(progress));
+}
@@ -0,0 +1,127 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::CollectionVariant;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loads_selected_struct_variant() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .insert_resource(CollectionVariant::<LevelAssets>::new(
+            LevelAssetsVariant::Forest,
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, LevelAssets>(MyStates::Load)
+        .add_systems(Update, timeout)
+        .add_systems(OnEnter(MyStates::Next), expect_forest)
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loads_selected_tuple_variant() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .insert_resource(CollectionVariant::<LevelAssets>::new(
+            LevelAssetsVariant::Cave,
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, LevelAssets>(MyStates::Load)
+        .add_systems(Update, timeout)
+        .add_systems(OnEnter(MyStates::Next), expect_cave)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+fn expect_forest(
+    level: Res<LevelAssets>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let LevelAssets::Forest { music, settings } = level.as_ref() else {
+        panic!("The forest variant should be loaded");
+    };
+    assert_eq!(settings.volume, 0.5);
+    assert_eq!(
+        asset_server.get_path(music.id()).unwrap().to_string(),
+        "audio/background.ogg"
+    );
+    assert_eq!(level.all_handles().len(), 1);
+    exit.send(AppExit);
+}
+
+fn expect_cave(
+    level: Res<LevelAssets>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let LevelAssets::Cave(music, effects) = level.as_ref() else {
+        panic!("The cave variant should be loaded");
+    };
+    assert_eq!(
+        asset_server.get_path(music.id()).unwrap().to_string(),
+        "audio/plop.ogg"
+    );
+    assert_eq!(effects.len(), 3);
+    assert_eq!(level.all_handles().len(), 4);
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+enum LevelAssets {
+    Forest {
+        #[asset(path = "audio/background.ogg")]
+        music: Handle<AudioSource>,
+        settings: LevelSettings,
+    },
+    Cave(
+        #[asset(path = "audio/plop.ogg")] Handle<AudioSource>,
+        #[asset(path = "audio", collection(typed))] Vec<Handle<AudioSource>>,
+    ),
+    Empty,
+}
+
+struct LevelSettings {
+    volume: f32,
+}
+
+impl FromWorld for LevelSettings {
+    fn from_world(_world: &mut World) -> Self {
+        LevelSettings { volume: 0.5 }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,123 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::io::file::FileAssetReader;
+use bevy::asset::io::{AssetReader, AssetReaderError, AssetSource, PathStream, Reader};
+use bevy::asset::{AssetApp, AssetPlugin};
+use bevy::prelude::*;
+use bevy::utils::BoxedFuture;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{FailedAssets, LoadingState, LoadingStateAppExt};
+use std::path::Path;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn fails_fast_at_first_failed_asset() {
+    App::new()
+        .register_asset_source(
+            "never",
+            AssetSource::build().with_reader(|| {
+                Box::new(NeverReader {
+                    inner: FileAssetReader::new("assets"),
+                })
+            }),
+        )
+        .add_state::<MyStates>()
+        .add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .fail_fast(MyStates::Error),
+        )
+        .add_collection_to_loading_state::<_, Audio>(MyStates::Load)
+        .add_collection_to_loading_state::<_, NeverLoading>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), fail)
+        .add_systems(OnEnter(MyStates::Error), exit)
+        .run();
+}
+
+fn fail() {
+    panic!("The library should have switched to the failure state");
+}
+
+fn exit(failed: Res<FailedAssets>, mut exit: EventWriter<AppExit>) {
+    assert_eq!(
+        failed.0.len(),
+        1,
+        "Only the first failure should be recorded"
+    );
+    assert!(
+        failed.0[0] == "audio/plop.ogg" || failed.0[0] == "audio/background.ogg",
+        "Unexpected failed asset '{}'",
+        failed.0[0]
+    );
+    exit.send(AppExit);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct Audio {
+    #[asset(path = "audio/plop.ogg")]
+    no_loader_for_ogg_files: Handle<AudioSource>,
+    #[asset(path = "audio/background.ogg")]
+    no_loader_for_background: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct NeverLoading {
+    #[asset(path = "never://images/player.png")]
+    never: Handle<Image>,
+}
+
+/// Reads never finish, so a loading state waiting for all assets would time out
+struct NeverReader {
+    inner: FileAssetReader,
+}
+
+impl AssetReader for NeverReader {
+    fn read<'a>(
+        &'a self,
+        _path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        Box::pin(futures_lite::future::pending())
+    }
+
+    fn read_meta<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        self.inner.read_meta(path)
+    }
+
+    fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<PathStream>, AssetReaderError>> {
+        self.inner.read_directory(path)
+    }
+
+    fn is_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<bool, AssetReaderError>> {
+        self.inner.is_directory(path)
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Error,
+    Next,
+}
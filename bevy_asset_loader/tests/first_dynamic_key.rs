@@ -0,0 +1,113 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    feature = "standard_dynamic_assets",
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loads_first_registered_key() {
+    app()
+        .add_systems(Startup, register_keys)
+        .add_systems(
+            OnEnter(MyStates::Next),
+            expect_paths("audio/yipee.ogg", "audio/plop.ogg"),
+        )
+        .run();
+}
+
+#[cfg(all(
+    feature = "standard_dynamic_assets",
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn falls_back_to_path_without_registered_keys() {
+    app()
+        .add_systems(
+            OnEnter(MyStates::Next),
+            expect_paths("audio/background.ogg", "audio/background.ogg"),
+        )
+        .run();
+}
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error),
+        )
+        .add_collection_to_loading_state::<_, Music>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Error), fail);
+
+    app
+}
+
+#[cfg(feature = "standard_dynamic_assets")]
+fn register_keys(mut dynamic_assets: ResMut<DynamicAssets>) {
+    dynamic_assets.register_asset(
+        "hd",
+        Box::new(StandardDynamicAsset::File {
+            path: "audio/yipee.ogg".to_owned(),
+        }),
+    );
+    dynamic_assets.register_asset(
+        "sd",
+        Box::new(StandardDynamicAsset::File {
+            path: "audio/plop.ogg".to_owned(),
+        }),
+    );
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("Failed to load the collection in 10 seconds");
+    }
+}
+
+fn fail() {
+    panic!("Failed to load the collection");
+}
+
+fn expect_paths(
+    theme: &'static str,
+    effect: &'static str,
+) -> impl Fn(Res<Music>, Res<AssetServer>, EventWriter<AppExit>) {
+    move |music, asset_server, mut exit| {
+        let path = |handle: &Handle<AudioSource>| {
+            asset_server
+                .get_path(handle.id())
+                .map(|path| path.to_string())
+        };
+        assert_eq!(path(&music.theme), Some(theme.to_owned()));
+        assert_eq!(path(&music.effect), Some(effect.to_owned()));
+        exit.send(AppExit);
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct Music {
+    #[asset(keys("hd", "sd"), path = "audio/background.ogg")]
+    theme: Handle<AudioSource>,
+    #[asset(keys("missing", "sd"), path = "audio/background.ogg")]
+    effect: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Error,
+    Next,
+}
@@ -0,0 +1,124 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::io::file::FileAssetReader;
+use bevy::asset::io::AssetSource;
+use bevy::asset::{AssetApp, AssetPlugin};
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{FolderContentsChanged, LoadingState, LoadingStateAppExt};
+use std::path::PathBuf;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn updates_folder_fields_when_files_change() {
+    let root = std::env::temp_dir().join(format!(
+        "bevy_asset_loader_folder_hot_reload_{}",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&root);
+    std::fs::create_dir_all(root.join("sounds")).unwrap();
+    std::fs::copy("assets/audio/plop.ogg", root.join("sounds/plop.ogg")).unwrap();
+
+    let reader_root = root.clone();
+    App::new()
+        .register_asset_source(
+            "hot",
+            AssetSource::build()
+                .with_reader(move || Box::new(FileAssetReader::new(reader_root.clone()))),
+        )
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .insert_resource(SoundsRoot(root.clone()))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .with_hot_reload(),
+        )
+        .add_collection_to_loading_state::<_, SoundAssets>(MyStates::Load)
+        .add_systems(Update, timeout)
+        .add_systems(OnEnter(MyStates::Next), change_folder)
+        .add_systems(Update, expect_update.run_if(in_state(MyStates::Next)))
+        .run();
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[derive(Resource)]
+struct SoundsRoot(PathBuf);
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The folder change was not picked up in 10 seconds");
+    }
+}
+
+fn change_folder(root: Res<SoundsRoot>, sounds: Res<SoundAssets>) {
+    assert_eq!(sounds.sounds.len(), 1);
+    assert!(sounds.mapped.contains_key("sounds/plop.ogg"));
+    std::fs::copy("assets/audio/yipee.ogg", root.0.join("sounds/yipee.ogg")).unwrap();
+    std::fs::remove_file(root.0.join("sounds/plop.ogg")).unwrap();
+}
+
+fn expect_update(
+    sounds: Res<SoundAssets>,
+    audio: Res<Assets<AudioSource>>,
+    mut changes: EventReader<FolderContentsChanged>,
+    mut added: Local<Vec<UntypedHandle>>,
+    mut removed: Local<Vec<UntypedHandle>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    for change in changes.read() {
+        assert_eq!(change.folder, "hot://sounds");
+        added.extend(change.added.iter().cloned());
+        // removed assets are still available while the event exists
+        for handle in &change.removed {
+            assert!(audio.get(handle.id().typed::<AudioSource>()).is_some());
+        }
+        removed.extend(change.removed.iter().cloned());
+    }
+    if added.is_empty() || removed.is_empty() {
+        return;
+    }
+
+    let path = |handle: &UntypedHandle| handle.path().unwrap().path().to_path_buf();
+    assert_eq!(
+        added.iter().map(path).collect::<Vec<_>>(),
+        vec![PathBuf::from("sounds/yipee.ogg")]
+    );
+    assert_eq!(
+        removed.iter().map(path).collect::<Vec<_>>(),
+        vec![PathBuf::from("sounds/plop.ogg")]
+    );
+    assert_eq!(sounds.sounds.len(), 1);
+    assert_eq!(sounds.sounds[0].id(), added[0].id().typed());
+    let keys: Vec<&String> = sounds.mapped.keys().collect();
+    assert_eq!(keys, vec!["sounds/yipee.ogg"]);
+    assert!(audio.get(&sounds.mapped["sounds/yipee.ogg"]).is_some());
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct SoundAssets {
+    #[asset(path = "hot://sounds", collection(typed))]
+    sounds: Vec<Handle<AudioSource>>,
+    #[asset(path = "hot://sounds", collection(typed, mapped))]
+    mapped: HashMap<String, Handle<AudioSource>>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
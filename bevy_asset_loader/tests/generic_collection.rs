@@ -0,0 +1,88 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::{Asset, AssetPlugin};
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+use std::marker::PhantomData;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loads_generic_collections() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error),
+        )
+        .add_collection_to_loading_state::<_, Sounds<AudioSource>>(MyStates::Load)
+        .add_collection_to_loading_state::<_, LevelSounds<AudioSource, Forest>>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .add_systems(OnEnter(MyStates::Error), fail)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+fn fail() {
+    panic!("The generic collections should load");
+}
+
+fn expect(
+    sounds: Res<Sounds<AudioSource>>,
+    level: Res<LevelSounds<AudioSource, Forest>>,
+    sources: Res<Assets<AudioSource>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(sources.contains(&sounds.click));
+    assert!(sources.contains(&level.music));
+    assert_eq!(level.variations.len(), 1);
+    assert!(sources.contains(&level.variations[0]));
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct Sounds<A: Asset> {
+    #[asset(path = "audio/plop.ogg")]
+    click: Handle<A>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct LevelSounds<A, L>
+where
+    A: Asset,
+    L: Send + Sync + 'static,
+{
+    #[asset(path = "audio/background.ogg")]
+    music: Handle<A>,
+    #[asset(paths("audio/plop.ogg"), collection(typed))]
+    variations: Vec<Handle<A>>,
+    level: PhantomData<L>,
+}
+
+struct Forest;
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Error,
+    Next,
+}
@@ -0,0 +1,132 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(feature = "2d", not(feature = "progress_tracking")))]
+use bevy_asset_loader::loading_state::GpuPrewarm;
+
+#[cfg(all(feature = "2d", not(feature = "progress_tracking")))]
+#[test]
+fn queues_collection_images_for_prewarming() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .prewarm_gpu(),
+        )
+        .add_collection_to_loading_state::<_, ImageAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_prewarm)
+        .run();
+}
+
+// Preparing a `GpuImage` needs a render device, so CI cannot cover images actually reaching the GPU.
+// This test drives the render side with a render app that never prepares any image and checks that the
+// loading state waits for it until the prewarm frame limit.
+#[cfg(all(feature = "2d", not(feature = "progress_tracking")))]
+#[test]
+fn waits_for_render_app_to_prepare_images() {
+    use bevy::app::SubApp;
+    use bevy::ecs::schedule::ScheduleLabel;
+    use bevy::render::render_asset::RenderAssets;
+    use bevy::render::{Render, RenderApp};
+
+    let mut app = App::new();
+    app.add_state::<MyStates>().add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        ImagePlugin::default(),
+    ));
+    let mut render_app = App::empty();
+    render_app.main_schedule_label = Render.intern();
+    render_app
+        .add_schedule(Render::base_schedule())
+        .init_resource::<RenderAssets<Image>>();
+    app.insert_sub_app(RenderApp, SubApp::new(render_app, |_, _| {}));
+    app.init_resource::<LoadingFrames>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .prewarm_gpu(),
+        )
+        .add_collection_to_loading_state::<_, ImageAssets>(MyStates::Load)
+        .add_systems(
+            Update,
+            (timeout, count_frames).run_if(in_state(MyStates::Load)),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect_prewarm_timeout);
+    // `ImagePlugin` expects a render device in the render app, so finish the plugins without it
+    let render_app = app.remove_sub_app(RenderApp).unwrap();
+    app.finish();
+    app.cleanup();
+    app.insert_sub_app(RenderApp, render_app);
+
+    while app
+        .world
+        .get_resource::<Events<AppExit>>()
+        .unwrap()
+        .is_empty()
+    {
+        app.update();
+    }
+}
+
+#[derive(Resource, Default)]
+struct LoadingFrames(u32);
+
+fn count_frames(mut frames: ResMut<LoadingFrames>) {
+    frames.0 += 1;
+}
+
+#[cfg(all(feature = "2d", not(feature = "progress_tracking")))]
+fn expect_prewarm_timeout(
+    prewarm: Res<GpuPrewarm>,
+    frames: Res<LoadingFrames>,
+    mut exit: EventWriter<AppExit>,
+) {
+    // the render app never prepares the images, so the loading state waits for the frame limit of 120
+    assert!(frames.0 >= 120, "Only waited {} frames", frames.0);
+    assert_eq!(prewarm.total(), 2);
+    assert_eq!(prewarm.prepared(), 0);
+    assert!(prewarm.is_done());
+    exit.send(AppExit);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+#[cfg(all(feature = "2d", not(feature = "progress_tracking")))]
+fn expect_prewarm(prewarm: Res<GpuPrewarm>, mut exit: EventWriter<AppExit>) {
+    // without a render app, images are queued but the loading state does not wait for them
+    assert_eq!(prewarm.total(), 2);
+    assert_eq!(prewarm.prepared(), 0);
+    assert!(prewarm.is_done());
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct ImageAssets {
+    #[asset(path = "images/player.png")]
+    player: Handle<Image>,
+    #[asset(path = "images/tree.png")]
+    tree: Handle<Image>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,101 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy::render::texture::ImagePlugin;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+
+#[cfg(all(feature = "2d", not(feature = "progress_tracking")))]
+#[test]
+fn rebuilds_texture_atlas_after_source_image_changed() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+        ))
+        .init_asset::<TextureAtlas>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .with_hot_reload(),
+        )
+        .add_collection_to_loading_state::<_, Atlases>(MyStates::Load)
+        .add_systems(Update, timeout)
+        .add_systems(
+            Update,
+            (modify_player, expect_rebuilt_atlas).run_if(in_state(MyStates::Next)),
+        )
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The texture atlas was not rebuilt in 10 seconds");
+    }
+}
+
+#[cfg(feature = "2d")]
+fn modify_player(
+    asset_server: Res<AssetServer>,
+    mut images: ResMut<Assets<Image>>,
+    mut modified: Local<bool>,
+) {
+    if *modified {
+        return;
+    }
+    if let Some(player) = images.get_mut(asset_server.load::<Image>("images/player.png")) {
+        player.data.fill(u8::MAX);
+        *modified = true;
+    }
+}
+
+#[cfg(feature = "2d")]
+fn expect_rebuilt_atlas(
+    collection: Res<Atlases>,
+    atlases: Res<Assets<TextureAtlas>>,
+    images: Res<Assets<Image>>,
+    asset_server: Res<AssetServer>,
+    mut first_atlas: Local<Option<Handle<TextureAtlas>>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let Some(first_atlas) = first_atlas.as_ref() else {
+        *first_atlas = Some(collection.sprites.clone());
+        return;
+    };
+    if &collection.sprites == first_atlas {
+        return;
+    }
+    let atlas = atlases
+        .get(&collection.sprites)
+        .expect("The rebuilt atlas should be added");
+    let player = atlas
+        .get_texture_index(asset_server.load::<Image>("images/player.png"))
+        .expect("The source images should stay loaded");
+    let texture = images
+        .get(&atlas.texture)
+        .expect("The packed image should be added");
+    let rect = atlas.textures[player];
+    let width = atlas.size.x as usize;
+    let (x, y) = (rect.min.x as usize, rect.min.y as usize);
+    let pixel = (y * width + x) * 4;
+    assert_eq!(texture.data[pixel..pixel + 4], [u8::MAX; 4]);
+    exit.send(AppExit);
+}
+
+#[cfg(feature = "2d")]
+#[derive(AssetCollection, Resource)]
+struct Atlases {
+    #[asset(texture_atlas(files(["images/player.png", "images/tree.png"])))]
+    sprites: Handle<TextureAtlas>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,97 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::utils::tracing::field::{Field, Visit};
+use bevy::utils::tracing::{span, subscriber, Event, Level, Metadata, Subscriber};
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn warns_if_hot_reloading_without_watching_for_changes() {
+    let warnings = Arc::new(Mutex::new(vec![]));
+    // Startup systems can run on other threads, so the recorder has to be the global default
+    subscriber::set_global_default(WarningRecorder(warnings.clone()))
+        .expect("Failed to record warnings");
+
+    let mut app = App::new();
+    app.add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .with_hot_reload(),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load);
+    app.update();
+
+    let warnings = warnings.lock().unwrap();
+    assert!(
+        warnings.iter().any(|warning| warning
+            .contains("requested hot reloading, but the asset server is not watching for changes")),
+        "Missing warning about hot reloading in {warnings:?}"
+    );
+}
+
+/// Records the messages of all warnings and errors
+struct WarningRecorder(Arc<Mutex<Vec<String>>>);
+
+impl Subscriber for WarningRecorder {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        *metadata.level() <= Level::WARN
+    }
+
+    fn new_span(&self, _span: &span::Attributes<'_>) -> span::Id {
+        span::Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut message = Message::default();
+        event.record(&mut message);
+        self.0.lock().unwrap().push(message.0);
+    }
+
+    fn enter(&self, _span: &span::Id) {}
+
+    fn exit(&self, _span: &span::Id) {}
+}
+
+#[derive(Default)]
+struct Message(String);
+
+impl Visit for Message {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            self.0 = format!("{value:?}");
+        }
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,101 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy::render::texture::{ImageAddressMode, ImagePlugin, ImageSampler, ImageSamplerDescriptor};
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+
+#[cfg(all(feature = "2d", not(feature = "progress_tracking")))]
+#[test]
+fn sets_address_mode_of_images() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, ImageAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+#[cfg(feature = "2d")]
+fn expect(
+    collection: Res<ImageAssets>,
+    images: Res<Assets<Image>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let descriptor = |handle: &Handle<Image>| {
+        let image = images.get(handle).expect("Image should be loaded");
+        let ImageSampler::Descriptor(descriptor) = &image.sampler else {
+            panic!("The image should have a sampler descriptor");
+        };
+        descriptor.clone()
+    };
+
+    let tiled = descriptor(&collection.tiled);
+    let mut expected = ImageSamplerDescriptor::nearest();
+    expected.address_mode_u = ImageAddressMode::Repeat;
+    expected.address_mode_v = ImageAddressMode::Repeat;
+    expected.address_mode_w = ImageAddressMode::Repeat;
+    assert_eq!(tiled.as_wgpu(), expected.as_wgpu());
+
+    let mirrored = descriptor(&collection.mirrored);
+    let mut expected = ImageSamplerDescriptor::linear();
+    expected.address_mode_u = ImageAddressMode::MirrorRepeat;
+    expected.address_mode_v = ImageAddressMode::MirrorRepeat;
+    expected.address_mode_w = ImageAddressMode::MirrorRepeat;
+    assert_eq!(mirrored.as_wgpu(), expected.as_wgpu());
+
+    let asymmetric = descriptor(&collection.asymmetric);
+    let mut expected = ImageSamplerDescriptor::linear();
+    expected.address_mode_u = ImageAddressMode::Repeat;
+    expected.address_mode_v = ImageAddressMode::ClampToEdge;
+    expected.address_mode_w = ImageAddressMode::Repeat;
+    assert_eq!(asymmetric.as_wgpu(), expected.as_wgpu());
+
+    // axes without a mode keep clamping to the edge
+    let single_axis = descriptor(&collection.single_axis);
+    let mut expected = ImageSamplerDescriptor::nearest();
+    expected.address_mode_u = ImageAddressMode::MirrorRepeat;
+    assert_eq!(single_axis.as_wgpu(), expected.as_wgpu());
+
+    // both fields load the same file, so the second one gets a copy with its own sampler
+    assert_ne!(collection.tiled, collection.mirrored);
+    exit.send(AppExit);
+}
+
+#[cfg(feature = "2d")]
+#[derive(AssetCollection, Resource)]
+struct ImageAssets {
+    #[asset(path = "images/tree.png")]
+    #[asset(image(sampler = nearest, address_mode = repeat))]
+    tiled: Handle<Image>,
+    #[asset(path = "images/tree.png")]
+    #[asset(image(address_mode = mirror))]
+    mirrored: Handle<Image>,
+    #[asset(path = "images/player.png")]
+    #[asset(image(sampler = linear, wrap = repeat, wrap_v = clamp))]
+    asymmetric: Handle<Image>,
+    #[asset(path = "images/zombie.png")]
+    #[asset(image(sampler = nearest, wrap_u = mirror))]
+    single_axis: Handle<Image>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
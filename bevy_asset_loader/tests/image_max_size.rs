@@ -0,0 +1,80 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy::render::texture::{ImagePlugin, ImageSampler, ImageSamplerDescriptor};
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+
+#[cfg(all(feature = "2d", not(feature = "progress_tracking")))]
+#[test]
+fn downscales_oversized_images() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, ImageAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+#[cfg(feature = "2d")]
+fn expect(
+    collection: Res<ImageAssets>,
+    images: Res<Assets<Image>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let size = |handle: &Handle<Image>| images.get(handle).expect("Image should be loaded").size();
+    assert_eq!(size(&collection.original), UVec2::new(1280, 720));
+    assert_eq!(size(&collection.capped), UVec2::new(256, 144));
+    assert_eq!(size(&collection.small), UVec2::new(64, 64));
+    assert_eq!(collection.small.path(), collection.player.path());
+
+    let capped = images.get(&collection.capped_nearest).unwrap();
+    assert_eq!(capped.size(), UVec2::new(256, 144));
+    let ImageSampler::Descriptor(descriptor) = &capped.sampler else {
+        panic!("The downscaled image should keep its sampler");
+    };
+    assert_eq!(
+        descriptor.as_wgpu(),
+        ImageSamplerDescriptor::nearest().as_wgpu()
+    );
+    exit.send(AppExit);
+}
+
+#[cfg(feature = "2d")]
+#[derive(AssetCollection, Resource)]
+struct ImageAssets {
+    #[asset(path = "images/background.png")]
+    original: Handle<Image>,
+    #[asset(path = "images/background.png")]
+    #[asset(image(max_size = 256))]
+    capped: Handle<Image>,
+    #[asset(path = "images/background.png")]
+    #[asset(image(sampler = nearest, max_size = 256))]
+    capped_nearest: Handle<Image>,
+    #[asset(path = "images/player.png")]
+    player: Handle<Image>,
+    #[asset(path = "images/player.png")]
+    #[asset(image(max_size = 256))]
+    small: Handle<Image>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,91 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy::render::texture::{ImageFilterMode, ImagePlugin, ImageSampler, ImageSamplerDescriptor};
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+
+#[cfg(all(feature = "2d", not(feature = "progress_tracking")))]
+#[test]
+fn sets_separate_sampler_filters_of_images() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, ImageAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+#[cfg(feature = "2d")]
+fn expect(
+    collection: Res<ImageAssets>,
+    images: Res<Assets<Image>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let descriptor = |handle: &Handle<Image>| {
+        let image = images.get(handle).expect("Image should be loaded");
+        let ImageSampler::Descriptor(descriptor) = &image.sampler else {
+            panic!("The image should have a sampler descriptor");
+        };
+        descriptor.clone()
+    };
+
+    let pixelated = descriptor(&collection.pixelated);
+    let expected = ImageSamplerDescriptor {
+        min_filter: ImageFilterMode::Linear,
+        mag_filter: ImageFilterMode::Nearest,
+        mipmap_filter: ImageFilterMode::Linear,
+        ..default()
+    };
+    assert_eq!(pixelated.as_wgpu(), expected.as_wgpu());
+
+    // filters that are not given default to linear
+    let partial = descriptor(&collection.partial);
+    let expected = ImageSamplerDescriptor {
+        mipmap_filter: ImageFilterMode::Nearest,
+        ..ImageSamplerDescriptor::linear()
+    };
+    assert_eq!(partial.as_wgpu(), expected.as_wgpu());
+
+    let shorthand = descriptor(&collection.shorthand);
+    assert_eq!(
+        shorthand.as_wgpu(),
+        ImageSamplerDescriptor::nearest().as_wgpu()
+    );
+    exit.send(AppExit);
+}
+
+#[cfg(feature = "2d")]
+#[derive(AssetCollection, Resource)]
+struct ImageAssets {
+    #[asset(path = "images/player.png")]
+    #[asset(image(sampler(min = linear, mag = nearest, mipmap = linear)))]
+    pixelated: Handle<Image>,
+    #[asset(path = "images/tree.png")]
+    #[asset(image(sampler(mipmap = nearest)))]
+    partial: Handle<Image>,
+    #[asset(path = "images/zombie.png")]
+    #[asset(image(sampler = nearest))]
+    shorthand: Handle<Image>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
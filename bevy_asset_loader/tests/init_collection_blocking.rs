@@ -0,0 +1,95 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::core::TaskPoolOptions;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::{AssetCollection, AssetCollectionApp};
+#[cfg(feature = "test_support")]
+use bevy_asset_loader::test_support::SteppedAssetSourceApp;
+use std::time::Duration;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn collection_is_loaded_before_startup() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .init_collection_blocking::<MyAssets>()
+        .add_systems(Startup, expect)
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn collection_is_loaded_with_a_single_thread() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins.set(TaskPoolPlugin {
+                task_pool_options: TaskPoolOptions::with_num_threads(1),
+            }),
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .init_collection_blocking::<MyAssets>()
+        .add_systems(Startup, expect)
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking"),
+    feature = "test_support",
+))]
+#[test]
+#[should_panic(expected = "did not finish loading within 100ms before startup")]
+fn panics_if_collection_is_not_loaded_in_time() {
+    App::new()
+        // the stepped asset source holds all reads until they are released
+        .register_stepped_asset_source("stepped")
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .init_collection_blocking_with_timeout::<HeldAssets>(Duration::from_millis(100))
+        .run();
+}
+
+fn expect(
+    collection: Option<Res<MyAssets>>,
+    audio: Res<Assets<AudioSource>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let collection = collection.expect("The collection should be inserted before startup");
+    assert!(
+        audio.contains(&collection.background),
+        "The asset should be loaded before startup"
+    );
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct HeldAssets {
+    #[asset(path = "stepped://audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
@@ -0,0 +1,108 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    feature = "standard_dynamic_assets",
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn collects_dynamic_keys_with_prefix_into_map() {
+    let mut dynamic_assets = DynamicAssets::default();
+    dynamic_assets.register_asset(
+        "enemy.orc",
+        Box::new(StandardDynamicAsset::File {
+            path: "audio/background.ogg".to_owned(),
+        }),
+    );
+    dynamic_assets.register_asset(
+        "enemy.goblin",
+        Box::new(StandardDynamicAsset::File {
+            path: "audio/plop.ogg".to_owned(),
+        }),
+    );
+    dynamic_assets.register_asset(
+        "friend.dog",
+        Box::new(StandardDynamicAsset::File {
+            path: "audio/plop.ogg".to_owned(),
+        }),
+    );
+
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .insert_resource(dynamic_assets)
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error),
+        )
+        .add_collection_to_loading_state::<_, EnemySounds>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .add_systems(OnEnter(MyStates::Error), fail)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+fn fail() {
+    panic!("The dynamic assets under the prefix should load");
+}
+
+fn expect(
+    sounds: Res<EnemySounds>,
+    asset_server: Res<AssetServer>,
+    sources: Res<Assets<AudioSource>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let mut paths: Vec<_> = sounds
+        .enemies
+        .iter()
+        .map(|(key, handle)| {
+            assert!(sources.contains(handle));
+            (
+                key.as_str(),
+                asset_server
+                    .get_path(handle.id())
+                    .map(|path| path.to_string()),
+            )
+        })
+        .collect();
+    paths.sort();
+    assert_eq!(
+        paths,
+        vec![
+            ("goblin", Some("audio/plop.ogg".to_owned())),
+            ("orc", Some("audio/background.ogg".to_owned())),
+        ]
+    );
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct EnemySounds {
+    #[asset(keys_into_map(prefix = "enemy."))]
+    enemies: HashMap<String, Handle<AudioSource>>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Error,
+    Next,
+}
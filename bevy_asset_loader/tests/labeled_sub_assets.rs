@@ -0,0 +1,138 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::gltf::GltfPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loads_labeled_sub_assets_of_gltf_files() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            GltfPlugin::default(),
+        ))
+        .init_asset::<Scene>()
+        .init_asset::<Mesh>()
+        .init_asset::<StandardMaterial>()
+        .init_asset::<Image>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error),
+        )
+        .add_collection_to_loading_state::<_, Model>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .add_systems(OnEnter(MyStates::Error), fail)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+fn fail() {
+    panic!("The labeled sub-assets should load");
+}
+
+fn expect(
+    model: Res<Model>,
+    asset_server: Res<AssetServer>,
+    scenes: Res<Assets<Scene>>,
+    meshes: Res<Assets<Mesh>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(scenes.contains(&model.scene));
+    let mesh = meshes
+        .get(&model.mesh)
+        .expect("The labeled mesh should be loaded");
+    assert_eq!(mesh.count_vertices(), 3);
+    assert_eq!(
+        asset_server
+            .get_path(model.mesh.id())
+            .map(|path| path.to_string()),
+        Some("models/triangle.gltf#Mesh0/Primitive0".to_owned())
+    );
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct Model {
+    #[asset(path = "models/triangle.gltf#Scene0")]
+    scene: Handle<Scene>,
+    #[asset(path = "models/triangle.gltf#Mesh0/Primitive0")]
+    mesh: Handle<Mesh>,
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loads_labeled_sub_assets_with_loader_settings() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            GltfPlugin::default(),
+        ))
+        .init_asset::<Scene>()
+        .init_asset::<Mesh>()
+        .init_asset::<StandardMaterial>()
+        .init_asset::<Image>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error),
+        )
+        .add_collection_to_loading_state::<_, ModelWithSettings>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_settings)
+        .add_systems(OnEnter(MyStates::Error), fail)
+        .run();
+}
+
+fn expect_settings(
+    model: Res<ModelWithSettings>,
+    meshes: Res<Assets<Mesh>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let mesh = meshes
+        .get(&model.mesh)
+        .expect("The labeled mesh should be loaded");
+    assert_eq!(mesh.count_vertices(), 3);
+    exit.send(AppExit);
+}
+
+fn gltf_settings(_settings: &mut ()) {}
+
+#[derive(AssetCollection, Resource)]
+struct ModelWithSettings {
+    #[asset(
+        path = "models/triangle.gltf#Mesh0/Primitive0",
+        settings(gltf_settings)
+    )]
+    mesh: Handle<Mesh>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Error,
+    Next,
+}
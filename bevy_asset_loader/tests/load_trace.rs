@@ -0,0 +1,149 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking"),
+    feature = "load_trace"
+))]
+#[test]
+fn traces_begin_and_end_of_every_asset() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, AudioAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking"),
+    feature = "load_trace"
+))]
+#[test]
+fn traces_assets_again_when_entering_the_loading_state_again() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, AudioAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), load_again)
+        .run();
+}
+
+#[cfg(feature = "load_trace")]
+fn load_again(
+    trace: Res<bevy_asset_loader::load_trace::LoadTrace>,
+    mut visits: Local<usize>,
+    mut next_state: ResMut<NextState<MyStates>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    use bevy_asset_loader::load_trace::TracePhase;
+
+    *visits += 1;
+    if *visits == 1 {
+        next_state.set(MyStates::Load);
+        return;
+    }
+    for path in ["audio/background.ogg", "audio/plop.ogg"] {
+        let events: Vec<_> = trace
+            .events()
+            .iter()
+            .filter(|event| event.name == path)
+            .collect();
+        let phases: Vec<_> = events.iter().map(|event| event.ph).collect();
+        assert_eq!(
+            phases,
+            vec![
+                TracePhase::Begin,
+                TracePhase::End,
+                TracePhase::Begin,
+                TracePhase::End
+            ],
+            "Expected a begin and end event for '{path}' in both visits"
+        );
+        assert_eq!(events[0].id, events[1].id);
+        assert_eq!(events[2].id, events[3].id);
+        assert_ne!(events[0].id, events[2].id);
+    }
+    exit.send(AppExit);
+}
+
+#[cfg(not(feature = "load_trace"))]
+fn load_again() {}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+#[cfg(feature = "load_trace")]
+fn expect(trace: Res<bevy_asset_loader::load_trace::LoadTrace>, mut exit: EventWriter<AppExit>) {
+    use bevy_asset_loader::load_trace::TracePhase;
+
+    for path in ["audio/background.ogg", "audio/plop.ogg"] {
+        let events: Vec<_> = trace
+            .events()
+            .iter()
+            .filter(|event| event.name == path)
+            .collect();
+        assert_eq!(
+            events.len(),
+            2,
+            "Expected a begin and end event for '{path}'"
+        );
+        assert_eq!(events[0].ph, TracePhase::Begin);
+        assert_eq!(events[1].ph, TracePhase::End);
+        assert_eq!(events[0].id, events[1].id);
+        assert!(events[0].ts <= events[1].ts);
+        assert_eq!(events[0].cat, std::any::type_name::<AudioAssets>());
+    }
+
+    let json: serde_json::Value =
+        serde_json::from_str(&trace.to_json()).expect("The trace should be valid JSON");
+    let trace_events = json["traceEvents"]
+        .as_array()
+        .expect("Trace events should be an array");
+    assert_eq!(trace_events.len(), 4);
+    assert_eq!(trace_events[0]["ph"], "b");
+    exit.send(AppExit);
+}
+
+#[cfg(not(feature = "load_trace"))]
+fn expect() {}
+
+#[derive(AssetCollection, Resource)]
+struct AudioAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
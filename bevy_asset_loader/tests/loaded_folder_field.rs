@@ -0,0 +1,73 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::{AssetPlugin, LoadedFolder};
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loaded_folder_and_typed_files_share_one_folder_load() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+    ))
+    .init_resource::<DynamicAssets>();
+
+    let handles = AudioCollection::load(&mut app.world);
+    assert_eq!(handles.len(), 1, "The folder should only be requested once");
+
+    app.add_state::<MyStates>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, AudioCollection>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+fn expect(
+    collection: Res<AudioCollection>,
+    folders: Res<Assets<LoadedFolder>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let folder = folders
+        .get(&collection.folder)
+        .expect("The folder should stay loaded while the collection holds its handle");
+    assert_eq!(folder.handles.len(), 3);
+    assert_eq!(collection.sounds.len(), 3);
+    for sound in &collection.sounds {
+        assert!(folder
+            .handles
+            .iter()
+            .any(|handle| handle.id() == sound.id().untyped()));
+    }
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct AudioCollection {
+    #[asset(path = "audio", folder)]
+    folder: Handle<LoadedFolder>,
+    #[asset(path = "audio", collection(typed))]
+    sounds: Vec<Handle<AudioSource>>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
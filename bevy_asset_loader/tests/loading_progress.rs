@@ -0,0 +1,82 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingProgress, LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn counts_loaded_assets_of_all_collections() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, Music>(MyStates::Load)
+        .add_collection_to_loading_state::<_, Effects>(MyStates::Load)
+        .add_systems(
+            Update,
+            (timeout, check_progress).run_if(in_state(MyStates::Load)),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .add_systems(Update, frozen.run_if(in_state(MyStates::Next)))
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+fn check_progress(progress: Res<LoadingProgress>) {
+    assert!(progress.loaded <= progress.total);
+    assert!(!progress.is_finished());
+    assert!((0. ..=1.).contains(&progress.fraction()));
+}
+
+fn expect(progress: Res<LoadingProgress>) {
+    // one background track and three files in the sounds folder
+    assert_eq!(progress.total, 4);
+    assert_eq!(progress.loaded, 4);
+    assert!(progress.is_finished());
+    assert_eq!(progress.fraction(), 1.);
+}
+
+fn frozen(progress: Res<LoadingProgress>, mut frames: Local<u32>, mut exit: EventWriter<AppExit>) {
+    assert_eq!(progress.fraction(), 1.);
+    assert_eq!(progress.total, 4);
+    *frames += 1;
+    if *frames > 3 {
+        exit.send(AppExit);
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+struct Music {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct Effects {
+    #[asset(path = "sounds", collection(typed))]
+    sounds: Vec<Handle<AudioSource>>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,155 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{
+    LoadingState, LoadingStateAppExt, LoadingStateConfig, LoadingStateRegistry,
+};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn builds_loading_state_from_config() {
+    let registry = LoadingStateRegistry::new()
+        .register_state("load", MyStates::Load)
+        .register_state("next", MyStates::Next)
+        .register_collection::<AudioAssets>("audio")
+        .register_collection::<MoreAudioAssets>("more_audio");
+    let config = LoadingStateConfig {
+        state: "load".to_owned(),
+        next_state: Some("next".to_owned()),
+        collections: vec!["audio".to_owned(), "more_audio".to_owned()],
+        ..default()
+    };
+
+    let mut app = App::new();
+    app.add_state::<MyStates>().add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+    ));
+    registry
+        .add_to_app(&mut app, &config)
+        .expect("The config should be valid");
+    app.add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn rejects_unknown_names() {
+    let registry = LoadingStateRegistry::new()
+        .register_state("load", MyStates::Load)
+        .register_collection::<AudioAssets>("audio");
+    let mut app = App::new();
+
+    let unknown_state = LoadingStateConfig {
+        state: "load".to_owned(),
+        next_state: Some("menu".to_owned()),
+        ..default()
+    };
+    let error = registry.add_to_app(&mut app, &unknown_state).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Unknown state 'menu' in loading state config"
+    );
+
+    let unknown_collection = LoadingStateConfig {
+        state: "load".to_owned(),
+        collections: vec!["audio".to_owned(), "images".to_owned()],
+        ..default()
+    };
+    let error = registry
+        .add_to_app(&mut app, &unknown_collection)
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Unknown asset collection 'images' in loading state config"
+    );
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn metadata_round_trips_through_registry() {
+    let registry = LoadingStateRegistry::new()
+        .register_state("load", MyStates::Load)
+        .register_state("next", MyStates::Next);
+    let config = LoadingStateConfig {
+        state: "load".to_owned(),
+        next_state: Some("next".to_owned()),
+        metadata: [("display_name".to_owned(), "Level one".to_owned())].into(),
+        ..default()
+    };
+
+    let mut app = App::new();
+    app.add_state::<MyStates>().add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+    ));
+    registry
+        .add_to_app(&mut app, &config)
+        .expect("The config should be valid");
+    app.add_loading_state(
+        LoadingState::new(MyStates::Load).with_metadata("description", "Assets of the first level"),
+    );
+
+    let metadata = registry
+        .metadata(&app.world, "load")
+        .expect("The loading state should have metadata");
+    assert_eq!(metadata.len(), 2);
+    assert_eq!(metadata["display_name"], "Level one");
+    assert_eq!(metadata["description"], "Assets of the first level");
+    assert_eq!(registry.metadata(&app.world, "next"), None);
+    assert_eq!(registry.metadata(&app.world, "unknown"), None);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The loading state did not continue in 10 seconds");
+    }
+}
+
+fn expect(
+    audio: Option<Res<AudioAssets>>,
+    more_audio: Option<Res<MoreAudioAssets>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(audio.is_some(), "AudioAssets should be loaded");
+    assert!(more_audio.is_some(), "MoreAudioAssets should be loaded");
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct AudioAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct MoreAudioAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
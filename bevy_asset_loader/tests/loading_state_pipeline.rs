@@ -0,0 +1,124 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt, LoadingStatePipeline};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn runs_two_stage_pipeline_to_completion() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state_pipeline(
+            LoadingStatePipeline::new()
+                .then(LoadingState::new(MyStates::LoadCore))
+                .load_collection::<CoreAssets>()
+                .then(LoadingState::new(MyStates::LoadMenu))
+                .load_collection::<MenuAssets>()
+                .continue_to_state(MyStates::Menu)
+                .on_failure_continue_to_state(MyStates::Error),
+        )
+        .add_systems(Update, timeout)
+        .add_systems(OnEnter(MyStates::LoadMenu), core_loaded)
+        .add_systems(OnEnter(MyStates::Menu), expect)
+        .add_systems(OnEnter(MyStates::Error), fail)
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn failing_stage_aborts_pipeline() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state_pipeline(
+            LoadingStatePipeline::new()
+                .then(LoadingState::new(MyStates::LoadCore))
+                .load_collection::<MissingAssets>()
+                .then(LoadingState::new(MyStates::LoadMenu))
+                .load_collection::<MenuAssets>()
+                .continue_to_state(MyStates::Menu)
+                .on_failure_continue_to_state(MyStates::Error),
+        )
+        .add_systems(Update, timeout)
+        .add_systems(OnEnter(MyStates::LoadMenu), fail)
+        .add_systems(OnEnter(MyStates::Error), expect_aborted)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The pipeline did not finish in 10 seconds");
+    }
+}
+
+fn fail() {
+    panic!("Entered an unexpected state");
+}
+
+fn core_loaded(core: Option<Res<CoreAssets>>, menu: Option<Res<MenuAssets>>) {
+    assert!(core.is_some());
+    assert!(menu.is_none());
+}
+
+fn expect(
+    core: Option<Res<CoreAssets>>,
+    menu: Option<Res<MenuAssets>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(core.is_some());
+    assert!(menu.is_some());
+    exit.send(AppExit);
+}
+
+fn expect_aborted(menu: Option<Res<MenuAssets>>, mut exit: EventWriter<AppExit>) {
+    assert!(menu.is_none());
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct CoreAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct MenuAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct MissingAssets {
+    #[asset(path = "audio/does_not_exist.ogg")]
+    missing: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    LoadCore,
+    LoadMenu,
+    Menu,
+    Error,
+}
@@ -0,0 +1,5 @@
+// Asset manifest in the format a build script would write to `OUT_DIR/assets.rs`
+pub const ASSET_MANIFEST: AssetManifest = &[
+    ("background_music", "audio/background.ogg"),
+    ("click_sound", "audio/plop.ogg"),
+];
@@ -0,0 +1,79 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::{AssetCollection, AssetManifest};
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+
+include!("manifest/assets.rs");
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loads_fields_from_manifest_keys() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error),
+        )
+        .add_collection_to_loading_state::<_, Sounds>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .add_systems(OnEnter(MyStates::Error), fail)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+fn fail() {
+    panic!("The assets from the manifest should load");
+}
+
+fn expect(
+    sounds: Res<Sounds>,
+    asset_server: Res<AssetServer>,
+    sources: Res<Assets<AudioSource>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let path = |handle: &Handle<AudioSource>| {
+        assert!(sources.contains(handle));
+        asset_server
+            .get_path(handle.id())
+            .map(|path| path.to_string())
+    };
+    assert_eq!(path(&sounds.music), Some("audio/background.ogg".to_owned()));
+    assert_eq!(path(&sounds.click), Some("audio/plop.ogg".to_owned()));
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct Sounds {
+    #[asset(manifest_key = "background_music")]
+    music: Handle<AudioSource>,
+    #[asset(manifest_key = "click_sound")]
+    click: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Error,
+    Next,
+}
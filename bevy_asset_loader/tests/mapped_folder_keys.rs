@@ -0,0 +1,117 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+use bevy_asset_loader::mapped::{BareFileStem, FileStem, RelativePath};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn mapped_folder_keys() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, SoundAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+fn expect(sounds: Res<SoundAssets>, mut exit: EventWriter<AppExit>) {
+    let mut full_paths: Vec<_> = sounds.full_paths.keys().map(String::as_str).collect();
+    full_paths.sort();
+    assert_eq!(
+        full_paths,
+        ["sounds/plop.oga", "sounds/plop.ogg", "sounds/ui/click.ogg"]
+    );
+
+    // 'plop.oga' and 'plop.ogg' share a file stem, the first in alphabetical order is kept
+    let mut names: Vec<_> = sounds.names.keys().map(|key| &**key).collect();
+    names.sort();
+    assert_eq!(names, ["click", "plop"]);
+    assert_eq!(
+        sounds.names["plop"].path().unwrap().to_string(),
+        "sounds/plop.oga"
+    );
+    assert_eq!(
+        sounds.names["click"].path().unwrap().to_string(),
+        "sounds/ui/click.ogg"
+    );
+
+    let mut files: Vec<_> = sounds.files.keys().map(|key| &**key).collect();
+    files.sort();
+    assert_eq!(files, ["click", "plop"]);
+    assert_eq!(
+        sounds.files["plop"].path().unwrap().to_string(),
+        "audio/plop.ogg"
+    );
+
+    let mut relative: Vec<_> = sounds.relative.keys().map(|key| &**key).collect();
+    relative.sort();
+    assert_eq!(relative, ["plop.oga", "plop.ogg", "ui/click.ogg"]);
+    assert_eq!(
+        sounds.relative["ui/click.ogg"].path().unwrap().to_string(),
+        "sounds/ui/click.ogg"
+    );
+
+    // 'plop.oga' and 'plop.ogg' share a stem, the first in alphabetical order is kept
+    assert_eq!(sounds.stems.len(), 2);
+    assert_eq!(
+        sounds.stems["plop"].path().unwrap().to_string(),
+        "sounds/plop.oga"
+    );
+    assert_eq!(
+        sounds.stems["ui/click"].path().unwrap().to_string(),
+        "sounds/ui/click.ogg"
+    );
+
+    let mut full_stems: Vec<_> = sounds.full_stems.keys().map(|key| &**key).collect();
+    full_stems.sort();
+    assert_eq!(full_stems, ["sounds/plop", "sounds/ui/click"]);
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct SoundAssets {
+    #[asset(path = "sounds", collection(typed, mapped))]
+    full_paths: HashMap<String, Handle<AudioSource>>,
+    #[asset(path = "sounds", collection(typed, mapped))]
+    names: HashMap<BareFileStem, Handle<AudioSource>>,
+    #[asset(
+        paths("audio/plop.ogg", "sounds/ui/click.ogg"),
+        collection(typed, mapped)
+    )]
+    files: HashMap<BareFileStem, Handle<AudioSource>>,
+    #[asset(path = "sounds", collection(typed, mapped))]
+    relative: HashMap<RelativePath, Handle<AudioSource>>,
+    #[asset(path = "sounds", collection(mapped))]
+    stems: HashMap<FileStem, UntypedHandle>,
+    #[asset(path = "sounds", collection(mapped(full_path)))]
+    full_stems: HashMap<FileStem, UntypedHandle>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
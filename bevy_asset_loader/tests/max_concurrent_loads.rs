@@ -0,0 +1,160 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::io::file::FileAssetReader;
+use bevy::asset::io::{AssetReader, AssetReaderError, AssetSource, PathStream, Reader};
+use bevy::asset::{AssetApp, AssetPlugin, LoadState};
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::utils::BoxedFuture;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+const PATHS: [&str; 3] = [
+    "slow://audio/background.ogg",
+    "slow://audio/plop.ogg",
+    "slow://audio/yipee.ogg",
+];
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn limits_concurrent_loads() {
+    let reads = Reads::default();
+    let reader_reads = reads.clone();
+    App::new()
+        .register_asset_source(
+            "slow",
+            AssetSource::build().with_reader(move || {
+                Box::new(SlowReader {
+                    inner: FileAssetReader::new("assets"),
+                    reads: reader_reads.clone(),
+                })
+            }),
+        )
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .insert_resource(reads)
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .max_concurrent_loads(1),
+        )
+        .add_collection_to_loading_state::<_, AudioAssets>(MyStates::Load)
+        .add_systems(
+            Update,
+            (timeout, count_loading_handles).run_if(in_state(MyStates::Load)),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect_sequential_loads)
+        .run();
+}
+
+/// Counts reads that are currently waiting in the slow asset source
+#[derive(Resource, Clone, Default)]
+struct Reads {
+    current: Arc<AtomicUsize>,
+    max: Arc<AtomicUsize>,
+    max_loading_handles: Arc<AtomicUsize>,
+}
+
+struct SlowReader {
+    inner: FileAssetReader,
+    reads: Reads,
+}
+
+impl AssetReader for SlowReader {
+    fn read<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        Box::pin(async move {
+            let current = self.reads.current.fetch_add(1, Ordering::SeqCst) + 1;
+            self.reads.max.fetch_max(current, Ordering::SeqCst);
+            std::thread::sleep(Duration::from_millis(100));
+            let reader = self.inner.read(path).await;
+            self.reads.current.fetch_sub(1, Ordering::SeqCst);
+
+            reader
+        })
+    }
+
+    fn read_meta<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        self.inner.read_meta(path)
+    }
+
+    fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<PathStream>, AssetReaderError>> {
+        self.inner.read_directory(path)
+    }
+
+    fn is_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<bool, AssetReaderError>> {
+        self.inner.is_directory(path)
+    }
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collection did not finish loading in 10 seconds");
+    }
+}
+
+fn count_loading_handles(asset_server: Res<AssetServer>, reads: Res<Reads>) {
+    let loading = PATHS
+        .iter()
+        .filter_map(|path| asset_server.get_handle_untyped(*path))
+        .filter(|handle| asset_server.get_load_state(handle.id()) == Some(LoadState::Loading))
+        .count();
+    reads
+        .max_loading_handles
+        .fetch_max(loading, Ordering::SeqCst);
+}
+
+fn expect_sequential_loads(
+    reads: Res<Reads>,
+    audio: Res<AudioAssets>,
+    sources: Res<Assets<AudioSource>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(reads.max.load(Ordering::SeqCst), 1);
+    assert!(reads.max_loading_handles.load(Ordering::SeqCst) <= 1);
+    assert!(sources.get(&audio.background).is_some());
+    assert!(sources.get(&audio.plop).is_some());
+    assert!(sources.get(&audio.yipee).is_some());
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct AudioAssets {
+    #[asset(path = "slow://audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(path = "slow://audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+    #[asset(path = "slow://audio/yipee.ogg")]
+    yipee: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,86 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingProgress, LoadingState, LoadingStateAppExt};
+#[cfg(feature = "test_support")]
+use bevy_asset_loader::test_support::{SteppedAssetSourceApp, SteppedAssets};
+use std::time::Duration;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking"),
+    feature = "test_support",
+))]
+#[test]
+fn limits_loads_started_per_frame() {
+    let mut app = App::new();
+    app.register_stepped_asset_source("stepped")
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .with_max_loads_per_frame(2),
+        )
+        .add_collection_to_loading_state::<_, AudioAssets>(MyStates::Load);
+
+    let mut frames = 0;
+    while pending_reads(&app) == 0 {
+        assert!(frames < 10, "No asset started loading");
+        app.update();
+        frames += 1;
+    }
+    assert_eq!(pending_reads(&app), 2);
+    assert_eq!(
+        app.world.resource::<LoadingProgress>().total,
+        4,
+        "Queued assets should count towards the total progress"
+    );
+
+    app.update();
+    assert_eq!(
+        pending_reads(&app),
+        4,
+        "The held reads should not keep the queued assets from starting"
+    );
+
+    while app.world.resource::<State<MyStates>>().get() != &MyStates::Next {
+        app.world.resource::<SteppedAssets>().release_next();
+        app.update();
+    }
+}
+
+/// Reads held by the stepped asset source, after giving started loads time to reach it
+#[cfg(feature = "test_support")]
+fn pending_reads(app: &App) -> usize {
+    std::thread::sleep(Duration::from_millis(50));
+    app.world.resource::<SteppedAssets>().pending().len()
+}
+
+#[derive(AssetCollection, Resource)]
+#[asset_collection(source = "stepped")]
+struct AudioAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+    #[asset(path = "audio/yipee.ogg")]
+    yipee: Handle<AudioSource>,
+    #[asset(path = "sounds/plop.ogg")]
+    other_plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,67 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{FailedAssets, LoadingState, LoadingStateAppExt};
+use std::time::{Duration, Instant};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn missing_file_continues_to_failure_state() {
+    let mut app = App::new();
+    app.add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error),
+        )
+        .add_collection_to_loading_state::<_, Audio>(MyStates::Load);
+
+    let start = Instant::now();
+    while app.world.resource::<State<MyStates>>().get() == &MyStates::Load {
+        assert!(
+            start.elapsed() < Duration::from_secs(10),
+            "The loading state did not continue to the failure state within 10 seconds"
+        );
+        app.update();
+        // give the IO task a moment to notice the missing file
+        std::thread::sleep(Duration::from_millis(5));
+    }
+
+    assert_eq!(
+        app.world.resource::<State<MyStates>>().get(),
+        &MyStates::Error
+    );
+    assert_eq!(
+        app.world.resource::<FailedAssets>().0,
+        vec!["audio/does_not_exist.ogg".to_owned()]
+    );
+    assert!(!app.world.contains_resource::<Audio>());
+}
+
+#[derive(AssetCollection, Resource)]
+struct Audio {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(path = "audio/does_not_exist.ogg")]
+    missing: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Error,
+    Next,
+}
@@ -0,0 +1,84 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    feature = "standard_dynamic_assets",
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn same_key_in_different_namespaces() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_namespaced_dynamic_collection_to_loading_state::<_, StandardDynamicAssetCollection>(
+            MyStates::Load,
+            "mod_a",
+            "mods/mod_a.assets.ron",
+        )
+        .add_namespaced_dynamic_collection_to_loading_state::<_, StandardDynamicAssetCollection>(
+            MyStates::Load,
+            "mod_b",
+            "mods/mod_b.assets.ron",
+        )
+        .add_collection_to_loading_state::<_, BossThemes>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_independent_keys)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("Failed to load the namespaced collections in 10 seconds");
+    }
+}
+
+fn expect_independent_keys(
+    themes: Res<BossThemes>,
+    dynamic_assets: Res<DynamicAssets>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(
+        themes.mod_a.path().map(|path| path.to_string()),
+        Some("audio/plop.ogg".to_owned())
+    );
+    assert_eq!(
+        themes.mod_b.path().map(|path| path.to_string()),
+        Some("audio/yipee.ogg".to_owned())
+    );
+    assert!(dynamic_assets.get_asset("boss_theme").is_none());
+    assert!(dynamic_assets
+        .get_namespaced_asset("mod_a", "boss_theme")
+        .is_some());
+    assert_eq!(
+        dynamic_assets
+            .iter_namespace("mod_b")
+            .map(|(key, _)| key)
+            .collect::<Vec<_>>(),
+        vec!["boss_theme"]
+    );
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct BossThemes {
+    #[asset(key = "mod_a:boss_theme")]
+    mod_a: Handle<AudioSource>,
+    #[asset(key = "mod_b:boss_theme")]
+    mod_b: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,130 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{AssetLoadFailed, LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn missing_optional_asset_does_not_fail_collection() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error),
+        )
+        .add_collection_to_loading_state::<_, VoiceOver>(MyStates::Load)
+        .add_systems(
+            Update,
+            (timeout, no_failure_events).run_if(in_state(MyStates::Load)),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .add_systems(OnEnter(MyStates::Error), fail)
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn optional_asset_is_required_by_other_collections() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error),
+        )
+        .add_collection_to_loading_state::<_, VoiceOver>(MyStates::Load)
+        .add_collection_to_loading_state::<_, RequiredVoiceOver>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), unexpected_success)
+        .add_systems(OnEnter(MyStates::Error), exit)
+        .run();
+}
+
+fn unexpected_success() {
+    panic!("A missing asset should fail the collection requiring it, even if another collection has it as optional");
+}
+
+fn exit(mut exit: EventWriter<AppExit>) {
+    exit.send(AppExit);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+fn no_failure_events(mut failed: EventReader<AssetLoadFailed>) {
+    if let Some(event) = failed.read().next() {
+        panic!("Optional assets should not be reported as failed: {event:?}");
+    }
+}
+
+fn fail() {
+    panic!("A missing optional asset should not fail the loading state");
+}
+
+fn expect(
+    voice_over: Res<VoiceOver>,
+    sources: Res<Assets<AudioSource>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(sources.contains(&voice_over.music));
+    assert_eq!(voice_over.missing, None);
+    assert_eq!(voice_over.premium, None);
+    let localized = voice_over
+        .localized
+        .as_ref()
+        .expect("The existing optional asset should be loaded");
+    assert!(sources.contains(localized));
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct VoiceOver {
+    #[asset(path = "audio/background.ogg")]
+    music: Handle<AudioSource>,
+    #[asset(path = "audio/plop.ogg")]
+    localized: Option<Handle<AudioSource>>,
+    #[asset(path = "audio/does_not_exist.ogg")]
+    missing: Option<Handle<AudioSource>>,
+    #[asset(path = "audio/premium_soundtrack.ogg", optional)]
+    premium: Option<Handle<AudioSource>>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct RequiredVoiceOver {
+    #[asset(path = "audio/does_not_exist.ogg")]
+    missing: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Error,
+    Next,
+}
@@ -0,0 +1,165 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::io::file::FileAssetReader;
+use bevy::asset::io::{AssetReader, AssetReaderError, AssetSource, PathStream, Reader};
+use bevy::asset::{AssetApp, AssetPlugin};
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::utils::BoxedFuture;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+use bevy_asset_loader::placeholder::PlaceholderReplaced;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn replaces_placeholder_once_loaded() {
+    let gate = Gate::default();
+    let reader_gate = gate.clone();
+    App::new()
+        .register_asset_source(
+            "gated",
+            AssetSource::build().with_reader(move || {
+                Box::new(GatedReader {
+                    inner: FileAssetReader::new("assets"),
+                    gate: reader_gate.clone(),
+                })
+            }),
+        )
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .insert_resource(gate)
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout)
+        .add_systems(OnEnter(MyStates::Next), expect_placeholder)
+        .add_systems(Update, expect_replaced.run_if(in_state(MyStates::Next)))
+        .run();
+}
+
+/// Keeps reads of the gated asset source waiting until it is opened
+#[derive(Resource, Clone, Default)]
+struct Gate(Arc<AtomicBool>);
+
+struct GatedReader {
+    inner: FileAssetReader,
+    gate: Gate,
+}
+
+impl AssetReader for GatedReader {
+    fn read<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        Box::pin(async move {
+            // yield instead of blocking, so other reads can continue on the same thread
+            while !self.gate.0.load(Ordering::SeqCst) {
+                futures_lite::future::yield_now().await;
+            }
+            self.inner.read(path).await
+        })
+    }
+
+    fn read_meta<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        self.inner.read_meta(path)
+    }
+
+    fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<PathStream>, AssetReaderError>> {
+        self.inner.read_directory(path)
+    }
+
+    fn is_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<bool, AssetReaderError>> {
+        self.inner.is_directory(path)
+    }
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The placeholder was not replaced in 10 seconds");
+    }
+}
+
+fn expect_placeholder(
+    mut commands: Commands,
+    collection: Res<MyAssets>,
+    sources: Res<Assets<AudioSource>>,
+    gate: Res<Gate>,
+) {
+    assert!(sources.contains(&collection.plop));
+    assert_eq!(
+        collection.background.path(),
+        None,
+        "The field should hold the placeholder while the asset is loading"
+    );
+    assert!(sources.contains(&collection.background));
+    commands.insert_resource(Placeholder(collection.background.clone()));
+    gate.0.store(true, Ordering::SeqCst);
+}
+
+fn expect_replaced(
+    mut replaced: EventReader<PlaceholderReplaced>,
+    collection: Res<MyAssets>,
+    placeholder: Res<Placeholder>,
+    sources: Res<Assets<AudioSource>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let Some(event) = replaced.read().next() else {
+        assert_eq!(collection.background, placeholder.0);
+        return;
+    };
+    assert_eq!(event.collection, std::any::type_name::<MyAssets>());
+    assert_eq!(event.field, "background");
+    assert_eq!(event.handle, collection.background.clone().untyped());
+    assert_eq!(
+        collection.background.path().unwrap().to_string(),
+        "gated://audio/background.ogg"
+    );
+    assert!(sources.contains(&collection.background));
+    exit.send(AppExit);
+}
+
+fn silence(world: &mut World) -> Handle<AudioSource> {
+    world
+        .resource_mut::<Assets<AudioSource>>()
+        .add(AudioSource {
+            bytes: Arc::new([]),
+        })
+}
+
+#[derive(Resource)]
+struct Placeholder(Handle<AudioSource>);
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+    #[asset(path = "gated://audio/background.ogg", placeholder = silence)]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,183 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::{AssetPlugin, LoadState};
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt, LoadingStateSet};
+use notes::{Note, Notebook, NotesPlugin};
+
+#[cfg(feature = "progress_tracking")]
+use iyes_progress::{ProgressCounter, ProgressPlugin};
+
+#[cfg(feature = "progress_tracking")]
+#[test]
+fn progress_waits_for_dependencies() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            NotesPlugin,
+            ProgressPlugin::new(MyStates::Load),
+        ))
+        .init_resource::<SeenLoadingDependencies>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .track_dependencies(),
+        )
+        .add_collection_to_loading_state::<_, NotebookAssets>(MyStates::Load)
+        .add_systems(
+            Update,
+            (timeout, check_progress)
+                .chain()
+                .run_if(in_state(MyStates::Load))
+                .after(LoadingStateSet(MyStates::Load)),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+/// Whether the notebook was loaded while its notes were still loading
+#[derive(Resource, Default)]
+struct SeenLoadingDependencies(bool);
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+#[cfg(feature = "progress_tracking")]
+fn check_progress(
+    counter: Res<ProgressCounter>,
+    asset_server: Res<AssetServer>,
+    notebooks: Res<Assets<Notebook>>,
+    mut seen: ResMut<SeenLoadingDependencies>,
+) {
+    let Some((id, notebook)) = notebooks.iter().next() else {
+        return;
+    };
+    let notes_loaded = notebook
+        .notes
+        .iter()
+        .all(|note| asset_server.get_load_state(note) == Some(LoadState::Loaded));
+    let progress = counter.progress();
+    if asset_server.get_load_state(id) == Some(LoadState::Loaded) && !notes_loaded {
+        seen.0 = true;
+        assert!(
+            progress.done < progress.total,
+            "The notebook should not count as loaded while its notes are loading"
+        );
+    }
+}
+
+#[cfg(not(feature = "progress_tracking"))]
+fn check_progress() {}
+
+fn expect(seen: Res<SeenLoadingDependencies>, mut exit: EventWriter<AppExit>) {
+    assert!(
+        seen.0,
+        "The notebook should finish loading before its notes"
+    );
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct NotebookAssets {
+    #[asset(path = "notebooks/chapters.notebook")]
+    notebook: Handle<Notebook>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
+
+/// A notebook asset loading its notes as dependencies
+mod notes {
+    use bevy::asset::io::Reader;
+    use bevy::asset::{AssetLoader, AsyncReadExt, LoadContext};
+    use bevy::prelude::*;
+    use bevy::utils::BoxedFuture;
+    use std::time::Duration;
+
+    pub struct NotesPlugin;
+
+    impl Plugin for NotesPlugin {
+        fn build(&self, app: &mut App) {
+            app.init_asset::<Note>()
+                .init_asset::<Notebook>()
+                .register_asset_loader(NoteLoader)
+                .register_asset_loader(NotebookLoader);
+        }
+    }
+
+    #[derive(Asset, TypePath)]
+    pub struct Note(pub String);
+
+    #[derive(Asset, TypePath)]
+    pub struct Notebook {
+        #[dependency]
+        pub notes: Vec<Handle<Note>>,
+    }
+
+    struct NoteLoader;
+
+    impl AssetLoader for NoteLoader {
+        type Asset = Note;
+        type Settings = ();
+        type Error = std::io::Error;
+
+        fn load<'a>(
+            &'a self,
+            reader: &'a mut Reader,
+            _settings: &'a (),
+            _load_context: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<Note, std::io::Error>> {
+            Box::pin(async move {
+                // slow enough for the notebook to finish loading before its notes
+                std::thread::sleep(Duration::from_millis(300));
+                let mut text = String::new();
+                reader.read_to_string(&mut text).await?;
+                Ok(Note(text))
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["note"]
+        }
+    }
+
+    struct NotebookLoader;
+
+    impl AssetLoader for NotebookLoader {
+        type Asset = Notebook;
+        type Settings = ();
+        type Error = std::io::Error;
+
+        fn load<'a>(
+            &'a self,
+            reader: &'a mut Reader,
+            _settings: &'a (),
+            load_context: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<Notebook, std::io::Error>> {
+            Box::pin(async move {
+                let mut text = String::new();
+                reader.read_to_string(&mut text).await?;
+                let notes = text
+                    .lines()
+                    .map(|path| load_context.load(path.to_owned()))
+                    .collect();
+                Ok(Notebook { notes })
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["notebook"]
+        }
+    }
+}
@@ -0,0 +1,105 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, AssetPlugin, AsyncReadExt, LoadContext};
+use bevy::prelude::*;
+use bevy::utils::BoxedFuture;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+use bevy_asset_loader::quality::{QualityAssetSourceApp, QualityLevel};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn reloads_collection_from_folder_of_new_quality() {
+    App::new()
+        .add_state::<MyStates>()
+        .register_quality_asset_source("quality", "quality", "high")
+        .add_plugins((MinimalPlugins, AssetPlugin::default()))
+        .init_asset::<Note>()
+        .register_asset_loader(NoteLoader)
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, SettingsAssets>(MyStates::Load)
+        .reload_on_quality_change::<SettingsAssets>()
+        .add_systems(Update, timeout)
+        .add_systems(OnEnter(MyStates::Next), lower_quality)
+        .add_systems(Update, expect_low_quality.run_if(in_state(MyStates::Next)))
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The collection was not reloaded in 10 seconds");
+    }
+}
+
+fn lower_quality(
+    collection: Res<SettingsAssets>,
+    notes: Res<Assets<Note>>,
+    mut quality: ResMut<QualityLevel>,
+) {
+    let note = notes
+        .get(&collection.settings)
+        .expect("The settings should be loaded");
+    assert_eq!(note.0.trim(), "Settings for high quality");
+    quality.set("low");
+}
+
+fn expect_low_quality(
+    collection: Res<SettingsAssets>,
+    notes: Res<Assets<Note>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let note = notes
+        .get(&collection.settings)
+        .expect("The settings should stay loaded while reloading");
+    if note.0.trim() == "Settings for low quality" {
+        exit.send(AppExit);
+    }
+}
+
+#[derive(AssetCollection, Resource)]
+#[asset_collection(source = "quality")]
+struct SettingsAssets {
+    #[asset(path = "settings.note")]
+    settings: Handle<Note>,
+}
+
+#[derive(Asset, TypePath)]
+struct Note(String);
+
+struct NoteLoader;
+
+impl AssetLoader for NoteLoader {
+    type Asset = Note;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Note, std::io::Error>> {
+        Box::pin(async move {
+            let mut text = String::new();
+            reader.read_to_string(&mut text).await?;
+            Ok(Note(text))
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["note"]
+    }
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,75 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::{AssetCollection, AssetCollectionApp};
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+use std::time::Duration;
+
+const RELEASE_AFTER: Duration = Duration::from_millis(200);
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn collection_is_removed_after_duration() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .release_collection_after::<MyAssets>(RELEASE_AFTER)
+        .add_systems(Update, timeout)
+        .add_systems(OnEnter(MyStates::Next), expect_collection)
+        .add_systems(Update, expect_release.run_if(in_state(MyStates::Next)))
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The collection was not released in 10 seconds");
+    }
+}
+
+fn expect_collection(collection: Option<Res<MyAssets>>) {
+    assert!(
+        collection.is_some(),
+        "The collection should exist after loading"
+    );
+}
+
+fn expect_release(
+    time: Res<Time>,
+    collection: Option<Res<MyAssets>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    if collection.is_some() {
+        return;
+    }
+    assert!(
+        time.elapsed() >= RELEASE_AFTER,
+        "The collection was released too early"
+    );
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,188 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{AssetLoadFailed, LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn field_is_skipped_if_its_dependency_failed() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error),
+        )
+        .add_collection_to_loading_state::<_, VoiceOver>(MyStates::Load)
+        .add_systems(
+            Update,
+            (timeout, no_failure_events).run_if(in_state(MyStates::Load)),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .add_systems(
+            OnEnter(MyStates::Error),
+            fail("Fields depending on a failed asset should not fail the loading state"),
+        )
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn field_is_required_if_its_dependency_loaded() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error),
+        )
+        .add_collection_to_loading_state::<_, MissingRequiredVoice>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(
+            OnEnter(MyStates::Next),
+            fail("A required field with a missing asset should fail the loading state"),
+        )
+        .add_systems(OnEnter(MyStates::Error), exit)
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn collections_with_the_same_conditional_field_are_independent() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error),
+        )
+        .add_collection_to_loading_state::<_, VoiceOver>(MyStates::Load)
+        .add_collection_to_loading_state::<_, OtherVoiceOver>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_both_skipped)
+        .add_systems(
+            OnEnter(MyStates::Error),
+            fail("Fields depending on a failed asset should not fail the loading state"),
+        )
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+fn no_failure_events(mut failed: EventReader<AssetLoadFailed>) {
+    if let Some(event) = failed.read().next() {
+        panic!("Skipped assets should not be reported as failed: {event:?}");
+    }
+}
+
+fn fail(message: &'static str) -> impl Fn() {
+    move || panic!("{message}")
+}
+
+fn exit(mut exit: EventWriter<AppExit>) {
+    exit.send(AppExit);
+}
+
+fn expect(
+    voice_over: Res<VoiceOver>,
+    sources: Res<Assets<AudioSource>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(voice_over.missing, None);
+    assert_eq!(voice_over.missing_subtitles, None);
+    let localized = voice_over
+        .localized
+        .as_ref()
+        .expect("The existing optional asset should be loaded");
+    assert!(sources.contains(localized));
+    let localized_subtitles = voice_over
+        .localized_subtitles
+        .as_ref()
+        .expect("The field depending on a loaded asset should be loaded");
+    assert!(sources.contains(localized_subtitles));
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct VoiceOver {
+    #[asset(path = "audio/does_not_exist.ogg")]
+    missing: Option<Handle<AudioSource>>,
+    #[asset(path = "audio/background.ogg", required_if = missing)]
+    missing_subtitles: Option<Handle<AudioSource>>,
+    #[asset(path = "audio/plop.ogg")]
+    localized: Option<Handle<AudioSource>>,
+    #[asset(path = "audio/background.ogg", required_if = localized)]
+    localized_subtitles: Option<Handle<AudioSource>>,
+}
+
+fn expect_both_skipped(
+    voice_over: Res<VoiceOver>,
+    other: Res<OtherVoiceOver>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(voice_over.missing_subtitles, None);
+    assert_eq!(
+        other.missing_subtitles, None,
+        "A field with the same path and condition in another collection should be skipped, too"
+    );
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct OtherVoiceOver {
+    #[asset(path = "audio/does_not_exist.ogg")]
+    missing: Option<Handle<AudioSource>>,
+    #[asset(path = "audio/background.ogg", required_if = missing)]
+    missing_subtitles: Option<Handle<AudioSource>>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct MissingRequiredVoice {
+    #[asset(path = "audio/plop.ogg")]
+    localized: Option<Handle<AudioSource>>,
+    #[asset(path = "audio/does_not_exist.ogg", required_if = localized)]
+    localized_subtitles: Option<Handle<AudioSource>>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Error,
+    Next,
+}
@@ -0,0 +1,167 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::io::file::FileAssetReader;
+use bevy::asset::io::{AssetReader, AssetReaderError, AssetSource, PathStream, Reader};
+use bevy::asset::{AssetApp, AssetPlugin};
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::utils::{BoxedFuture, HashMap};
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Every asset read from the flaky source fails this often before it succeeds
+const FAILED_READS: usize = 2;
+const RETRY_DELAY: Duration = Duration::from_millis(50);
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn retries_failed_loads() {
+    app(FAILED_READS as u32)
+        .add_systems(OnEnter(MyStates::Next), expect_loaded_assets)
+        .add_systems(OnEnter(MyStates::Error), fail)
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn fails_after_last_retry() {
+    app(FAILED_READS as u32 - 1)
+        .add_systems(OnEnter(MyStates::Next), fail)
+        .add_systems(OnEnter(MyStates::Error), exit)
+        .run();
+}
+
+fn app(retries: u32) -> App {
+    let mut app = App::new();
+    app.register_asset_source(
+        "flaky",
+        AssetSource::build().with_reader(|| {
+            Box::new(FlakyReader {
+                inner: FileAssetReader::new("assets"),
+                failed_reads: default(),
+            })
+        }),
+    )
+    .add_state::<MyStates>()
+    .add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+    ))
+    .add_loading_state(
+        LoadingState::new(MyStates::Load)
+            .continue_to_state(MyStates::Next)
+            .on_failure_continue_to_state(MyStates::Error)
+            .with_retries(retries)
+            .with_retry_delay(RETRY_DELAY),
+    )
+    .add_collection_to_loading_state::<_, AudioAssets>(MyStates::Load)
+    .add_systems(Update, timeout.run_if(in_state(MyStates::Load)));
+
+    app
+}
+
+/// Fails the first reads of every asset
+struct FlakyReader {
+    inner: FileAssetReader,
+    failed_reads: Arc<Mutex<HashMap<PathBuf, usize>>>,
+}
+
+impl AssetReader for FlakyReader {
+    fn read<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        Box::pin(async move {
+            {
+                let mut failed_reads = self.failed_reads.lock().unwrap();
+                let failed = failed_reads.entry(path.to_path_buf()).or_default();
+                if *failed < FAILED_READS {
+                    *failed += 1;
+                    return Err(AssetReaderError::NotFound(path.to_path_buf()));
+                }
+            }
+            self.inner.read(path).await
+        })
+    }
+
+    fn read_meta<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        self.inner.read_meta(path)
+    }
+
+    fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<PathStream>, AssetReaderError>> {
+        self.inner.read_directory(path)
+    }
+
+    fn is_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<bool, AssetReaderError>> {
+        self.inner.is_directory(path)
+    }
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collection did not finish loading in 10 seconds");
+    }
+}
+
+fn expect_loaded_assets(
+    time: Res<Time>,
+    audio: Res<AudioAssets>,
+    sources: Res<Assets<AudioSource>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(sources.get(&audio.background).is_some());
+    assert!(sources.get(&audio.plop).is_some());
+    // the delay doubles for the second retry
+    assert!(
+        time.elapsed() >= RETRY_DELAY * 3,
+        "Retried too early, loaded after {:?}",
+        time.elapsed()
+    );
+    exit.send(AppExit);
+}
+
+fn fail() {
+    panic!("The loading state ended up in the wrong state");
+}
+
+fn exit(mut exit: EventWriter<AppExit>) {
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct AudioAssets {
+    #[asset(path = "flaky://audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(path = "flaky://audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Error,
+    Next,
+}
@@ -0,0 +1,133 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::{AssetPlugin, LoadedFolder};
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+use bevy_asset_loader::runtime_collection::{
+    AssetCollectionBuilder, RuntimeAssetCollection, RuntimeAssetKind, RuntimeCollectionBuilders,
+};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loads_collection_declared_at_run_time() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_runtime_collection_to_loading_state(
+            MyStates::Load,
+            AssetCollectionBuilder::new().with_file("background", "audio/background.ogg"),
+        )
+        .add_systems(Startup, add_mod_assets)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect_collection)
+        .run();
+}
+
+fn add_mod_assets(mut builders: ResMut<RuntimeCollectionBuilders<MyStates>>) {
+    let builder = builders.get_mut(&MyStates::Load).unwrap();
+    builder.add_entry("plop", "audio/plop.ogg", RuntimeAssetKind::File);
+    builder.add_entry("audio", "audio", RuntimeAssetKind::Folder);
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loads_the_builder_of_each_loading_state() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_loading_state(LoadingState::new(MyStates::Next).continue_to_state(MyStates::Done))
+        .add_runtime_collection_to_loading_state(
+            MyStates::Load,
+            AssetCollectionBuilder::new().with_file("background", "audio/background.ogg"),
+        )
+        .add_runtime_collection_to_loading_state(
+            MyStates::Next,
+            AssetCollectionBuilder::new().with_file("plop", "audio/plop.ogg"),
+        )
+        .add_systems(Update, timeout.run_if(not(in_state(MyStates::Done))))
+        .add_systems(OnEnter(MyStates::Next), expect_first_builder)
+        .add_systems(OnEnter(MyStates::Done), expect_both_builders)
+        .run();
+}
+
+fn expect_first_builder(collection: Res<RuntimeAssetCollection>) {
+    assert!(collection.get("background").is_some());
+    assert!(
+        collection.get("plop").is_none(),
+        "The entries of the next loading state should not be loaded yet"
+    );
+}
+
+fn expect_both_builders(
+    collection: Res<RuntimeAssetCollection>,
+    audio: Res<Assets<AudioSource>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    for name in ["background", "plop"] {
+        let handle = collection
+            .get_typed::<AudioSource>(name)
+            .unwrap_or_else(|| panic!("'{name}' should be an audio source"));
+        assert!(audio.get(&handle).is_some());
+    }
+    exit.send(AppExit);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The loading state did not continue in 10 seconds");
+    }
+}
+
+fn expect_collection(
+    collection: Res<RuntimeAssetCollection>,
+    asset_server: Res<AssetServer>,
+    audio: Res<Assets<AudioSource>>,
+    folders: Res<Assets<LoadedFolder>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(collection.handles().len(), 3);
+    for name in ["background", "plop"] {
+        let handle = collection
+            .get_typed::<AudioSource>(name)
+            .unwrap_or_else(|| panic!("'{name}' should be an audio source"));
+        assert!(audio.get(&handle).is_some());
+    }
+    assert_eq!(
+        asset_server.get_path(collection.get("plop").unwrap().id()),
+        Some("audio/plop.ogg".into())
+    );
+    let folder = collection
+        .get_typed::<LoadedFolder>("audio")
+        .expect("'audio' should be a folder");
+    assert_eq!(folders.get(&folder).unwrap().handles.len(), 3);
+    assert!(collection.get_typed::<LoadedFolder>("plop").is_none());
+    exit.send(AppExit);
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+    Done,
+}
@@ -0,0 +1,163 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::io::file::FileAssetReader;
+use bevy::asset::io::{AssetReader, AssetReaderError, AssetSource, PathStream, Reader};
+use bevy::asset::{AssetApp, AssetPlugin};
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::utils::{BoxedFuture, HashMap};
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{DeferredAssets, LoadingState, LoadingStateAppExt};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn shared_paths_are_loaded_once() {
+    app(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next)).run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn shared_paths_count_once_for_budget_and_concurrent_loads() {
+    app(LoadingState::new(MyStates::Load)
+        .continue_to_state(MyStates::Next)
+        .with_byte_budget(15)
+        .estimate_asset_size("counted://audio/background.ogg", 10)
+        .estimate_asset_size("counted://audio/plop.ogg", 5)
+        .max_concurrent_loads(1))
+    .add_systems(OnEnter(MyStates::Next), expect_no_deferred_assets)
+    .run();
+}
+
+fn app(loading_state: LoadingState<MyStates>) -> App {
+    let reads = Reads::default();
+    let reader_reads = reads.clone();
+    let mut app = App::new();
+    app.register_asset_source(
+        "counted",
+        AssetSource::build().with_reader(move || {
+            Box::new(CountingReader {
+                inner: FileAssetReader::new("assets"),
+                reads: reader_reads.clone(),
+            })
+        }),
+    )
+    .add_state::<MyStates>()
+    .add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+    ))
+    .insert_resource(reads)
+    .add_loading_state(loading_state)
+    .add_collection_to_loading_state::<_, MusicAssets>(MyStates::Load)
+    .add_collection_to_loading_state::<_, EffectAssets>(MyStates::Load)
+    .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+    .add_systems(OnEnter(MyStates::Next), expect_single_reads);
+
+    app
+}
+
+/// Counts reads per asset path
+#[derive(Resource, Clone, Default)]
+struct Reads(Arc<Mutex<HashMap<PathBuf, usize>>>);
+
+struct CountingReader {
+    inner: FileAssetReader,
+    reads: Reads,
+}
+
+impl AssetReader for CountingReader {
+    fn read<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        *self
+            .reads
+            .0
+            .lock()
+            .unwrap()
+            .entry(path.to_path_buf())
+            .or_default() += 1;
+        self.inner.read(path)
+    }
+
+    fn read_meta<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        self.inner.read_meta(path)
+    }
+
+    fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<PathStream>, AssetReaderError>> {
+        self.inner.read_directory(path)
+    }
+
+    fn is_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<bool, AssetReaderError>> {
+        self.inner.is_directory(path)
+    }
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset collections did not finish loading in 10 seconds");
+    }
+}
+
+fn expect_single_reads(
+    reads: Res<Reads>,
+    music: Res<MusicAssets>,
+    effects: Res<EffectAssets>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(music.background, effects.background);
+    let reads = reads.0.lock().unwrap();
+    assert_eq!(reads.get(Path::new("audio/background.ogg")), Some(&1));
+    assert_eq!(reads.get(Path::new("audio/plop.ogg")), Some(&1));
+    exit.send(AppExit);
+}
+
+fn expect_no_deferred_assets(deferred: Res<DeferredAssets>) {
+    assert!(
+        deferred.paths.is_empty(),
+        "Shared paths should only use the byte budget once, but deferred {:?}",
+        deferred.paths
+    );
+}
+
+#[derive(AssetCollection, Resource)]
+struct MusicAssets {
+    #[asset(path = "counted://audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct EffectAssets {
+    #[asset(path = "counted://audio/background.ogg")]
+    background: Handle<AudioSource>,
+    #[asset(path = "counted://audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
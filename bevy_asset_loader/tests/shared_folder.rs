@@ -0,0 +1,61 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use bevy_asset_loader::prelude::*;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn shared_folder_is_loaded_once() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        AudioPlugin::default(),
+    ))
+    .init_resource::<DynamicAssets>();
+
+    let handles = AudioCollection::load(&mut app.world);
+    assert_eq!(handles.len(), 1, "The folder should only be requested once");
+
+    app.add_state::<MyStates>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, AudioCollection>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 60. {
+        panic!("The asset loader did not change the state in 60 seconds");
+    }
+}
+
+fn expect(collection: Res<AudioCollection>, mut exit: EventWriter<AppExit>) {
+    assert_eq!(collection.files.len(), 3);
+    assert_eq!(collection.mapped_files.len(), 3);
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct AudioCollection {
+    #[asset(path = "audio", collection(typed))]
+    files: Vec<Handle<AudioSource>>,
+    #[asset(path = "audio", collection(typed, mapped))]
+    mapped_files: HashMap<String, Handle<AudioSource>>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,144 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::io::file::FileAssetReader;
+use bevy::asset::io::{AssetReader, AssetReaderError, AssetSource, PathStream, Reader};
+use bevy::asset::{AssetApp, AssetPlugin};
+use bevy::audio::AudioPlugin;
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+use bevy::utils::BoxedFuture;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+use std::path::Path;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn skips_loading_state_on_input() {
+    App::new()
+        .register_asset_source(
+            "never",
+            AssetSource::build().with_reader(|| {
+                Box::new(NeverReader {
+                    inner: FileAssetReader::new("assets"),
+                })
+            }),
+        )
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .skip_on_input(),
+        )
+        .add_collection_to_loading_state::<_, LoadedAssets>(MyStates::Load)
+        .add_collection_to_loading_state::<_, StuckAssets>(MyStates::Load)
+        .add_systems(
+            Update,
+            (timeout, press_key_once_loaded).run_if(in_state(MyStates::Load)),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+/// Reads of this asset source never finish
+struct NeverReader {
+    inner: FileAssetReader,
+}
+
+impl AssetReader for NeverReader {
+    fn read<'a>(
+        &'a self,
+        _path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        Box::pin(futures_lite::future::pending())
+    }
+
+    fn read_meta<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<Reader<'a>>, AssetReaderError>> {
+        self.inner.read_meta(path)
+    }
+
+    fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<Box<PathStream>, AssetReaderError>> {
+        self.inner.read_directory(path)
+    }
+
+    fn is_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxedFuture<'a, Result<bool, AssetReaderError>> {
+        self.inner.is_directory(path)
+    }
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The loading state was not skipped in 10 seconds");
+    }
+}
+
+fn press_key_once_loaded(
+    loaded: Option<Res<LoadedAssets>>,
+    mut keyboard: EventWriter<KeyboardInput>,
+    mut pressed: Local<bool>,
+) {
+    if *pressed || loaded.is_none() {
+        return;
+    }
+    keyboard.send(KeyboardInput {
+        scan_code: 0,
+        key_code: Some(KeyCode::Space),
+        state: ButtonState::Pressed,
+        window: Entity::PLACEHOLDER,
+    });
+    *pressed = true;
+}
+
+fn expect(
+    loaded: Option<Res<LoadedAssets>>,
+    stuck: Option<Res<StuckAssets>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert!(
+        loaded.is_some(),
+        "Collections that finished loading should be inserted"
+    );
+    assert!(
+        stuck.is_none(),
+        "Collections that are still loading should be skipped"
+    );
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct LoadedAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct StuckAssets {
+    #[asset(path = "never://audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
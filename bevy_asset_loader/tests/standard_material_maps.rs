@@ -0,0 +1,119 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy::render::texture::ImagePlugin;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+
+#[cfg(all(feature = "3d", not(feature = "progress_tracking")))]
+#[test]
+fn creates_standard_materials_with_texture_maps() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+        ))
+        .init_asset::<StandardMaterial>()
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MaterialAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+#[cfg(feature = "3d")]
+fn expect(
+    collection: Res<MaterialAssets>,
+    materials: Res<Assets<StandardMaterial>>,
+    images: Res<Assets<Image>>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let path = |handle: &Option<Handle<Image>>| {
+        let handle = handle.as_ref().expect("The texture should be set");
+        assert!(images.contains(handle), "The texture should be loaded");
+        asset_server
+            .get_path(handle.id())
+            .map(|path| path.to_string())
+    };
+
+    let material = materials
+        .get(&collection.full)
+        .expect("Material should be created");
+    assert_eq!(
+        path(&material.base_color_texture),
+        Some("images/tree.png".to_owned())
+    );
+    assert_eq!(
+        path(&material.normal_map_texture),
+        Some("images/player.png".to_owned())
+    );
+    assert_eq!(
+        path(&material.metallic_roughness_texture),
+        Some("images/zombie.png".to_owned())
+    );
+
+    let material = materials
+        .get(&collection.normal_only)
+        .expect("Material should be created");
+    assert_eq!(
+        path(&material.base_color_texture),
+        Some("images/tree.png".to_owned())
+    );
+    assert_eq!(
+        path(&material.normal_map_texture),
+        Some("images/player.png".to_owned())
+    );
+    assert!(material.metallic_roughness_texture.is_none());
+
+    let material = materials
+        .get(&collection.shorthand)
+        .expect("Material should be created");
+    assert_eq!(
+        path(&material.base_color_texture),
+        Some("images/tree.png".to_owned())
+    );
+    assert!(material.normal_map_texture.is_none());
+    assert!(material.metallic_roughness_texture.is_none());
+
+    exit.send(AppExit);
+}
+
+#[cfg(not(feature = "3d"))]
+fn expect() {}
+
+#[cfg(feature = "3d")]
+#[derive(AssetCollection, Resource)]
+struct MaterialAssets {
+    #[asset(standard_material(
+        base_color = "images/tree.png",
+        normal = "images/player.png",
+        metallic_roughness = "images/zombie.png"
+    ))]
+    full: Handle<StandardMaterial>,
+    #[asset(standard_material(base_color = "images/tree.png", normal = "images/player.png"))]
+    normal_only: Handle<StandardMaterial>,
+    #[asset(path = "images/tree.png", standard_material)]
+    shorthand: Handle<StandardMaterial>,
+}
+
+#[cfg(not(feature = "3d"))]
+#[derive(AssetCollection, Resource)]
+struct MaterialAssets {}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
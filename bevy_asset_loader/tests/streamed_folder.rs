@@ -0,0 +1,59 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollectionApp;
+use bevy_asset_loader::streamed_folder::StreamedFolders;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn populates_mapped_folder_over_several_frames() {
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .stream_mapped_folder("audio", 1)
+        .add_systems(Update, (timeout, record_progress))
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The folder was not streamed in 10 seconds");
+    }
+}
+
+fn record_progress(
+    streamed_folders: Res<StreamedFolders>,
+    mut observed: Local<Vec<usize>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let folder = streamed_folders
+        .get("audio")
+        .expect("The streamed folder should exist from the start");
+    if observed.last() != Some(&folder.len()) {
+        observed.push(folder.len());
+    }
+    if !folder.is_complete() {
+        return;
+    }
+
+    assert_eq!(folder.total(), Some(3));
+    assert_eq!(*observed, vec![0, 1, 2, 3]);
+    let mut keys: Vec<&String> = folder.entries().keys().collect();
+    keys.sort();
+    assert_eq!(
+        keys,
+        vec!["audio/background.ogg", "audio/plop.ogg", "audio/yipee.ogg"]
+    );
+    assert!(streamed_folders.is_complete());
+    exit.send(AppExit);
+}
@@ -0,0 +1,91 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::math::{Rect, Vec2};
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+
+#[cfg(feature = "texture_packer")]
+#[test]
+fn texture_atlas_from_hash_format() {
+    use bevy_asset_loader::texture_packer::TexturePackerSheet;
+
+    let json = r#"{
+        "frames": {
+            "walk_2.png": { "frame": { "x": 32, "y": 0, "w": 32, "h": 16 }, "rotated": false },
+            "walk_1.png": { "frame": { "x": 0, "y": 0, "w": 32, "h": 16 }, "rotated": false }
+        },
+        "meta": { "size": { "w": 64, "h": 16 } }
+    }"#;
+    let sheet = TexturePackerSheet::from_json(json.as_bytes()).expect("Failed to parse sheet");
+    assert_eq!(sheet.index_of("walk_1.png"), Some(0));
+    assert_eq!(sheet.index_of("walk_2.png"), Some(1));
+    assert_eq!(sheet.index_of("jump.png"), None);
+
+    let atlas = sheet.texture_atlas(Handle::default());
+    assert_eq!(atlas.size, Vec2::new(64., 16.));
+    assert_eq!(
+        atlas.textures,
+        vec![Rect::new(0., 0., 32., 16.), Rect::new(32., 0., 64., 16.)]
+    );
+}
+
+#[cfg(feature = "texture_packer")]
+#[test]
+fn texture_atlas_from_array_format() {
+    use bevy_asset_loader::texture_packer::TexturePackerSheet;
+
+    let json = r#"{
+        "frames": [
+            { "filename": "walk_2.png", "frame": { "x": 0, "y": 16, "w": 16, "h": 16 } },
+            { "filename": "walk_1.png", "frame": { "x": 0, "y": 0, "w": 16, "h": 16 } }
+        ],
+        "meta": { "size": { "w": 16, "h": 32 } }
+    }"#;
+    let sheet = TexturePackerSheet::from_json(json.as_bytes()).expect("Failed to parse sheet");
+    assert_eq!(sheet.index_of("walk_2.png"), Some(0));
+    assert_eq!(sheet.index_of("walk_1.png"), Some(1));
+
+    let atlas = sheet.texture_atlas(Handle::default());
+    assert_eq!(atlas.size, Vec2::new(16., 32.));
+    assert_eq!(
+        atlas.textures,
+        vec![Rect::new(0., 16., 16., 32.), Rect::new(0., 0., 16., 16.)]
+    );
+}
+
+#[cfg(all(feature = "texture_packer", not(feature = "progress_tracking")))]
+#[test]
+fn loads_sprite_sheet_with_init_collection_blocking() {
+    use bevy::asset::AssetPlugin;
+    use bevy_asset_loader::asset_collection::AssetCollectionApp;
+
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        ImagePlugin::default(),
+    ))
+    .init_asset::<TextureAtlas>()
+    .init_collection_blocking::<SpriteSheetAssets>();
+    // the image loader is only registered when the plugins finish
+    app.finish();
+    app.update();
+
+    let assets = app.world.resource::<SpriteSheetAssets>();
+    let atlases = app.world.resource::<Assets<TextureAtlas>>();
+    let atlas = atlases
+        .get(&assets.player)
+        .expect("The texture atlas should be created");
+    assert_eq!(atlas.size, Vec2::new(64., 64.));
+    assert_eq!(
+        atlas.textures,
+        vec![Rect::new(0., 0., 32., 64.), Rect::new(32., 0., 64., 64.)]
+    );
+}
+
+#[cfg(feature = "texture_packer")]
+#[derive(AssetCollection, Resource)]
+struct SpriteSheetAssets {
+    #[asset(sprite_sheet(json = "images/player.tp.json", image = "images/player.png"))]
+    player: Handle<TextureAtlas>,
+}
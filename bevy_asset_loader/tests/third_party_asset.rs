@@ -0,0 +1,222 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+use third_party::{Note, Notebook, NotesPlugin};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn loads_assets_of_third_party_plugins() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((MinimalPlugins, AssetPlugin::default(), NotesPlugin))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .track_dependencies(),
+        )
+        .add_collection_to_loading_state::<_, NoteAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn missing_dependency_fails_the_collection() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((MinimalPlugins, AssetPlugin::default(), NotesPlugin))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error)
+                .track_dependencies(),
+        )
+        .add_collection_to_loading_state::<_, MissingChapterAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), || {
+            panic!("A notebook with a missing note should fail to load")
+        })
+        .add_systems(OnEnter(MyStates::Error), exit)
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn ignores_dependencies_without_tracking_them() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((MinimalPlugins, AssetPlugin::default(), NotesPlugin))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .on_failure_continue_to_state(MyStates::Error),
+        )
+        .add_collection_to_loading_state::<_, MissingChapterAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), exit)
+        .add_systems(OnEnter(MyStates::Error), || {
+            panic!("Failed dependencies should not fail a collection that does not track them")
+        })
+        .run();
+}
+
+fn exit(mut exit: EventWriter<AppExit>) {
+    exit.send(AppExit);
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+fn expect(
+    collection: Res<NoteAssets>,
+    notebooks: Res<Assets<Notebook>>,
+    notes: Res<Assets<Note>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    let note = notes
+        .get(&collection.note)
+        .expect("The note should be loaded");
+    assert_eq!(note.0.trim(), "Sounds for the typed folder tests");
+    assert_eq!(collection.notes.len(), 1);
+
+    let notebook = notebooks
+        .get(&collection.notebook)
+        .expect("The notebook should be loaded");
+    for note in &notebook.notes {
+        assert!(
+            notes.get(note).is_some(),
+            "Notes of the notebook should be loaded together with the notebook"
+        );
+    }
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct NoteAssets {
+    #[asset(path = "mixed/readme.note")]
+    note: Handle<Note>,
+    #[asset(paths("mixed/readme.note"), collection(typed))]
+    notes: Vec<Handle<Note>>,
+    #[asset(path = "notebooks/chapters.notebook")]
+    notebook: Handle<Notebook>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct MissingChapterAssets {
+    #[asset(path = "notebooks/missing_chapter.notebook")]
+    notebook: Handle<Notebook>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+    Error,
+}
+
+/// Assets and loaders like a third-party plugin would register them
+mod third_party {
+    use bevy::asset::io::Reader;
+    use bevy::asset::{AssetLoader, AsyncReadExt, LoadContext};
+    use bevy::prelude::*;
+    use bevy::utils::BoxedFuture;
+    use std::time::Duration;
+
+    pub struct NotesPlugin;
+
+    impl Plugin for NotesPlugin {
+        fn build(&self, app: &mut App) {
+            app.init_asset::<Note>()
+                .init_asset::<Notebook>()
+                .register_asset_loader(NoteLoader)
+                .register_asset_loader(NotebookLoader);
+        }
+    }
+
+    #[derive(Asset, TypePath)]
+    pub struct Note(pub String);
+
+    /// A list of notes loaded as dependencies
+    #[derive(Asset, TypePath)]
+    pub struct Notebook {
+        #[dependency]
+        pub notes: Vec<Handle<Note>>,
+    }
+
+    struct NoteLoader;
+
+    impl AssetLoader for NoteLoader {
+        type Asset = Note;
+        type Settings = ();
+        type Error = std::io::Error;
+
+        fn load<'a>(
+            &'a self,
+            reader: &'a mut Reader,
+            _settings: &'a (),
+            _load_context: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<Note, std::io::Error>> {
+            Box::pin(async move {
+                // slow enough for the notebook to finish loading before its notes
+                std::thread::sleep(Duration::from_millis(300));
+                let mut text = String::new();
+                reader.read_to_string(&mut text).await?;
+                Ok(Note(text))
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["note"]
+        }
+    }
+
+    struct NotebookLoader;
+
+    impl AssetLoader for NotebookLoader {
+        type Asset = Notebook;
+        type Settings = ();
+        type Error = std::io::Error;
+
+        fn load<'a>(
+            &'a self,
+            reader: &'a mut Reader,
+            _settings: &'a (),
+            load_context: &'a mut LoadContext,
+        ) -> BoxedFuture<'a, Result<Notebook, std::io::Error>> {
+            Box::pin(async move {
+                let mut text = String::new();
+                reader.read_to_string(&mut text).await?;
+                let notes = text
+                    .lines()
+                    .map(|path| load_context.load(path.to_owned()))
+                    .collect();
+                Ok(Notebook { notes })
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["notebook"]
+        }
+    }
+}
@@ -0,0 +1,97 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{
+    LoadingState, LoadingStateAppExt, LoadingStateSet, LoadingStateTransitionSet,
+};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn sets_next_state_at_end_of_frame() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .init_resource::<TransitionSeen>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .transition_at_end_of_frame(),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout)
+        .add_systems(
+            Update,
+            expect_no_transition_during_update
+                .after(LoadingStateSet(MyStates::Load))
+                .run_if(in_state(MyStates::Load)),
+        )
+        .add_systems(
+            Last,
+            (
+                expect_no_transition_before_set.before(LoadingStateTransitionSet(MyStates::Load)),
+                record_transition.after(LoadingStateTransitionSet(MyStates::Load)),
+            )
+                .run_if(in_state(MyStates::Load)),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect_transition_seen)
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct TransitionSeen(bool);
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The loading state did not continue in 10 seconds");
+    }
+}
+
+fn expect_no_transition_during_update(next_state: Res<NextState<MyStates>>) {
+    assert_eq!(
+        next_state.0, None,
+        "The next state should not be set before the end of the frame"
+    );
+}
+
+fn expect_no_transition_before_set(next_state: Res<NextState<MyStates>>) {
+    assert_eq!(next_state.0, None);
+}
+
+fn record_transition(next_state: Res<NextState<MyStates>>, mut seen: ResMut<TransitionSeen>) {
+    if next_state.0 == Some(MyStates::Next) {
+        seen.0 = true;
+    }
+}
+
+fn expect_transition_seen(seen: Res<TransitionSeen>, mut exit: EventWriter<AppExit>) {
+    assert!(
+        seen.0,
+        "The next state should be set in the loading state transition set"
+    );
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
@@ -0,0 +1,100 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, AssetPlugin, AsyncReadExt, LoadContext};
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy::utils::{BoxedFuture, HashMap};
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+use bevy_asset_loader::mapped::FileStem;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn typed_folder_skips_other_asset_types() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .init_asset::<Note>()
+        .register_asset_loader(NoteLoader)
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, MixedAssets>(MyStates::Load)
+        .add_systems(Update, timeout.run_if(in_state(MyStates::Load)))
+        .add_systems(OnEnter(MyStates::Next), expect)
+        .run();
+}
+
+#[derive(Asset, TypePath)]
+struct Note(String);
+
+struct NoteLoader;
+
+impl AssetLoader for NoteLoader {
+    type Asset = Note;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Note, std::io::Error>> {
+        Box::pin(async move {
+            let mut text = String::new();
+            reader.read_to_string(&mut text).await?;
+            Ok(Note(text))
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["note"]
+    }
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset loader did not change the state in 10 seconds");
+    }
+}
+
+fn expect(mixed: Res<MixedAssets>, notes: Res<Assets<Note>>, mut exit: EventWriter<AppExit>) {
+    assert_eq!(mixed.all.len(), 2);
+    assert_eq!(mixed.sounds.len(), 1);
+    assert_eq!(
+        mixed.sounds[0].path().unwrap().to_string(),
+        "mixed/plop.ogg"
+    );
+    assert_eq!(mixed.notes.len(), 1);
+    let note = notes
+        .get(&mixed.notes["readme"])
+        .expect("The note should be loaded");
+    assert_eq!(note.0.trim(), "Sounds for the typed folder tests");
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MixedAssets {
+    #[asset(path = "mixed", collection)]
+    all: Vec<UntypedHandle>,
+    #[asset(path = "mixed", collection(typed))]
+    sounds: Vec<Handle<AudioSource>>,
+    #[asset(path = "mixed", collection(typed, mapped))]
+    notes: HashMap<FileStem, Handle<Note>>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
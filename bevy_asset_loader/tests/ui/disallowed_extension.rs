@@ -0,0 +1,16 @@
+use bevy_asset_loader::prelude::*;
+use bevy::prelude::*;
+
+fn main() {}
+
+#[derive(AssetCollection, Resource)]
+struct Test {
+    #[asset(path = "audio/plop.ogg", extensions("png", "ktx2"))]
+    wrong_extension: Handle<AudioSource>,
+    #[asset(paths("audio/plop.ogg", "images/player.png"), collection, extensions("png"))]
+    one_wrong_extension: Vec<UntypedHandle>,
+    #[asset(path = "images", collection, extensions("png"))]
+    folder_is_rejected: Vec<UntypedHandle>,
+    #[asset(key = "image", extensions("png"))]
+    dynamic_is_rejected: Handle<Image>,
+}
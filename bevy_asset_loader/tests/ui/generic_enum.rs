@@ -0,0 +1,14 @@
+use bevy_asset_loader::prelude::*;
+use bevy::asset::Asset;
+use bevy::prelude::*;
+
+fn main() {}
+
+#[derive(AssetCollection, Resource)]
+enum Test<A: Asset> {
+    Single {
+        #[asset(path = "audio/plop.ogg")]
+        sound: Handle<A>,
+    },
+    Empty,
+}
@@ -0,0 +1,13 @@
+use bevy_asset_loader::prelude::*;
+use bevy_asset_loader::asset_collection::AssetManifest;
+use bevy::prelude::*;
+
+const ASSET_MANIFEST: AssetManifest = &[("player", "images/player.png")];
+
+fn main() {}
+
+#[derive(AssetCollection, Resource)]
+struct Test {
+    #[asset(manifest_key = "enemy")]
+    enemy: Handle<Image>,
+}
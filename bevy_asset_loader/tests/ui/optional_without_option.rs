@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+fn main() {}
+
+#[derive(AssetCollection, Resource)]
+struct Test {
+    #[asset(path = "audio/voice.ogg", optional)]
+    voice: Handle<AudioSource>,
+}
@@ -0,0 +1,12 @@
+use bevy_asset_loader::prelude::*;
+use bevy::prelude::*;
+
+fn main() {}
+
+#[derive(AssetCollection, Resource)]
+struct Test {
+    #[asset(path = "audio/voice.ogg")]
+    voice: Handle<AudioSource>,
+    #[asset(path = "audio/subtitles.ogg", required_if = voice)]
+    subtitles: Option<Handle<AudioSource>>,
+}
@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+fn main() {}
+
+fn gltf_settings(_settings: &mut ()) {}
+
+#[derive(AssetCollection, Resource)]
+struct Test {
+    #[asset(path = "models", collection(typed), settings(gltf_settings))]
+    models: Vec<Handle<Mesh>>,
+}
@@ -0,0 +1,12 @@
+use bevy_asset_loader::prelude::*;
+use bevy::prelude::*;
+
+fn main() {}
+
+#[derive(AssetCollection, Resource)]
+enum Test {
+    Level(
+        #[asset(path = "images/player.png")] Handle<Image>,
+        Handle<Image>,
+    ),
+}
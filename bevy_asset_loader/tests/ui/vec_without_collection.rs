@@ -0,0 +1,10 @@
+use bevy_asset_loader::prelude::*;
+use bevy::prelude::*;
+
+fn main() {}
+
+#[derive(AssetCollection, Resource)]
+struct Test {
+    #[asset(path = "images")]
+    images: Vec<Handle<Image>>,
+}
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+
+fn main() {
+    let _load = <ConfigAssets<Image> as AssetCollection>::load;
+    let _create = <ConfigAssets<Image> as AssetCollection>::create;
+}
+
+trait Config: Asset {}
+
+impl Config for Image {}
+
+#[derive(AssetCollection, Resource)]
+struct ConfigAssets<T: Config> {
+    #[asset(path = "images/player.png")]
+    config: Handle<T>,
+    #[asset(paths("images/tree.png"), collection(typed))]
+    variants: Vec<Handle<T>>,
+}
@@ -0,0 +1,21 @@
+use bevy::prelude::*;
+use bevy::render::texture::ImageLoaderSettings;
+use bevy_asset_loader::prelude::*;
+
+fn main() {}
+
+fn gltf_settings(_settings: &mut ()) {}
+
+fn linear_rgb(settings: &mut ImageLoaderSettings) {
+    settings.is_srgb = false;
+}
+
+#[derive(AssetCollection, Resource)]
+struct Test {
+    #[asset(path = "models/model.glb#Mesh0/Primitive0")]
+    primitive: Handle<Mesh>,
+    #[asset(path = "models/model.glb#Mesh0/Primitive0", settings(gltf_settings))]
+    primitive_with_settings: Handle<Mesh>,
+    #[asset(path = "images/normal_map.png", settings(linear_rgb))]
+    normal_map: Handle<Image>,
+}
@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+use std::sync::{Mutex, RwLock};
+
+fn main() {}
+
+#[derive(AssetCollection, Resource)]
+struct Test {
+    #[asset(path = "images/player.png", lock)]
+    player: Mutex<Handle<Image>>,
+    #[asset(paths("images/tree.png", "images/female_adventurer.png"), collection(typed), lock)]
+    images: RwLock<Vec<Handle<Image>>>,
+}
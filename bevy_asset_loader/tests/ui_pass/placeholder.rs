@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+use std::sync::Mutex;
+
+fn main() {}
+
+fn default_image(world: &mut World) -> Handle<Image> {
+    world.resource_mut::<Assets<Image>>().add(Image::default())
+}
+
+#[derive(AssetCollection, Resource)]
+struct Test {
+    #[asset(path = "images/player.png", placeholder = default_image)]
+    player: Handle<Image>,
+    #[asset(path = "images/tree.png", placeholder = default_image, lock)]
+    tree: Mutex<Handle<Image>>,
+}
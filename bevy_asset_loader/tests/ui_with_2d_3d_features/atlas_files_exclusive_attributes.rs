@@ -0,0 +1,40 @@
+use bevy_asset_loader::prelude::*;
+use bevy::prelude::*;
+
+fn main() {}
+
+#[derive(AssetCollection, Resource)]
+struct FilesAndGrid {
+    #[asset(texture_atlas(files(["a.png", "b.png"]), columns = 2))]
+    test: Handle<TextureAtlas>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct FilesAndManifestKey {
+    #[asset(texture_atlas(files(["a.png", "b.png"])), manifest_key = "atlas")]
+    test: Handle<TextureAtlas>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct FilesAndRuntimePath {
+    #[asset(texture_atlas(files(["a.png", "b.png"])), runtime_path = "atlas")]
+    test: Handle<TextureAtlas>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct FilesAndKeysIntoMap {
+    #[asset(texture_atlas(files(["a.png", "b.png"])), keys_into_map(prefix = "atlas."))]
+    test: Handle<TextureAtlas>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct FilesAndAlias {
+    #[asset(texture_atlas(files(["a.png", "b.png"])), alias_from = OtherAssets.atlases.atlas)]
+    test: Handle<TextureAtlas>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct FilesAndOptionalFolder {
+    #[asset(texture_atlas(files(["a.png", "b.png"])), optional, folder)]
+    test: Option<Handle<TextureAtlas>>,
+}
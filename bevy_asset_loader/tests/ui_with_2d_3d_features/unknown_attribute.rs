@@ -22,3 +22,10 @@ struct Test3 {
     #[asset(path = "test.png")]
     test: Handle<TextureAtlas>
 }
+
+#[derive(AssetCollection, Resource)]
+struct Test4 {
+    #[asset(path = "test.png")]
+    #[asset(image(wrap = AddressMode::Repeat))]
+    test: Handle<Image>
+}
@@ -0,0 +1,102 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::{AssetPlugin, UntypedAssetId};
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::{AssetCollection, AssetCollectionApp};
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn unloads_collection_when_leaving_state() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(LoadingState::new(MyStates::Load).continue_to_state(MyStates::InGame))
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .unload_on_exit::<MyAssets>(MyStates::InGame)
+        .unload_on_exit::<MyAssets>(MyStates::Paused)
+        // registering a state twice does not unload twice
+        .unload_on_exit::<MyAssets>(MyStates::Paused)
+        .add_systems(Update, timeout)
+        .add_systems(OnEnter(MyStates::InGame), pause)
+        .add_systems(OnEnter(MyStates::Paused), (expect_collection, leave))
+        .add_systems(OnEnter(MyStates::Menu), expect_no_collection)
+        .add_systems(
+            Update,
+            expect_unloaded_asset.run_if(in_state(MyStates::Menu)),
+        )
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The asset was not unloaded in 10 seconds");
+    }
+}
+
+fn pause(mut next_state: ResMut<NextState<MyStates>>) {
+    next_state.set(MyStates::Paused);
+}
+
+fn expect_collection(
+    mut commands: Commands,
+    collection: Option<Res<MyAssets>>,
+    state: Res<State<MyStates>>,
+) {
+    let collection = collection.unwrap_or_else(|| {
+        panic!(
+            "The collection should be kept when entering {:?}",
+            state.get()
+        )
+    });
+    commands.insert_resource(UnloadedAsset(collection.background.id().untyped()));
+}
+
+fn leave(mut next_state: ResMut<NextState<MyStates>>) {
+    next_state.set(MyStates::Menu);
+}
+
+fn expect_no_collection(collection: Option<Res<MyAssets>>) {
+    assert!(
+        collection.is_none(),
+        "The collection should be removed when leaving the state"
+    );
+}
+
+fn expect_unloaded_asset(
+    asset_server: Res<AssetServer>,
+    asset: Res<UnloadedAsset>,
+    mut exit: EventWriter<AppExit>,
+) {
+    if asset_server.get_load_state(asset.0).is_none() {
+        exit.send(AppExit);
+    }
+}
+
+#[derive(Resource)]
+struct UnloadedAsset(UntypedAssetId);
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    InGame,
+    Paused,
+    Menu,
+}
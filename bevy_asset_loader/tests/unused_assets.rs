@@ -0,0 +1,110 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt, UnusedAssets};
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn reports_unused_files() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .report_unused_assets(),
+        )
+        .add_collection_to_loading_state::<_, AudioAssets>(MyStates::Load)
+        .add_systems(Update, (timeout, expect_unused_background))
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn ignores_files_used_by_other_collections() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .report_unused_assets(),
+        )
+        .add_collection_to_loading_state::<_, PlopAssets>(MyStates::Load)
+        .add_collection_to_loading_state::<_, YipeeAssets>(MyStates::Load)
+        .add_systems(Update, (timeout, expect_shared_folder_reports))
+        .run();
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("Unused assets were not reported in 10 seconds");
+    }
+}
+
+fn expect_unused_background(unused_assets: Res<UnusedAssets>, mut exit: EventWriter<AppExit>) {
+    let Some(unused) = unused_assets.get::<AudioAssets>() else {
+        return;
+    };
+    assert_eq!(unused, ["audio/background.ogg".to_owned()]);
+    exit.send(AppExit);
+}
+
+fn expect_shared_folder_reports(unused_assets: Res<UnusedAssets>, mut exit: EventWriter<AppExit>) {
+    let (Some(plop), Some(yipee)) = (
+        unused_assets.get::<PlopAssets>(),
+        unused_assets.get::<YipeeAssets>(),
+    ) else {
+        return;
+    };
+    assert_eq!(plop, ["audio/background.ogg".to_owned()]);
+    assert_eq!(yipee, ["audio/background.ogg".to_owned()]);
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct AudioAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+    #[asset(path = "audio/yipee.ogg")]
+    yipee: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct PlopAssets {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct YipeeAssets {
+    #[asset(path = "audio/yipee.ogg")]
+    yipee: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
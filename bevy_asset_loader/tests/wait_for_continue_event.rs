@@ -0,0 +1,115 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::AssetPlugin;
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{ContinueLoading, LoadingState, LoadingStateAppExt};
+
+const WAIT_FRAMES: usize = 5;
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn waits_for_continue_event() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .init_resource::<FramesAfterLoading>()
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .wait_for_continue_event(),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Update, timeout)
+        .add_systems(
+            Update,
+            continue_after_some_frames
+                .run_if(in_state(MyStates::Load).and_then(resource_exists::<MyAssets>())),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect_waited)
+        .run();
+}
+
+#[cfg(all(
+    not(feature = "2d"),
+    not(feature = "3d"),
+    not(feature = "progress_tracking")
+))]
+#[test]
+fn remembers_continue_event_sent_while_loading() {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .add_loading_state(
+            LoadingState::new(MyStates::Load)
+                .continue_to_state(MyStates::Next)
+                .wait_for_continue_event(),
+        )
+        .add_collection_to_loading_state::<_, MyAssets>(MyStates::Load)
+        .add_systems(Startup, send_continue)
+        .add_systems(Update, timeout)
+        .add_systems(OnEnter(MyStates::Next), exit)
+        .run();
+}
+
+#[derive(Resource, Default)]
+struct FramesAfterLoading(usize);
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The loading state did not continue in 10 seconds");
+    }
+}
+
+fn continue_after_some_frames(
+    mut frames: ResMut<FramesAfterLoading>,
+    mut continue_loading: EventWriter<ContinueLoading>,
+) {
+    frames.0 += 1;
+    if frames.0 == WAIT_FRAMES {
+        continue_loading.send(ContinueLoading);
+    }
+}
+
+fn expect_waited(frames: Res<FramesAfterLoading>, mut exit: EventWriter<AppExit>) {
+    assert!(
+        frames.0 >= WAIT_FRAMES,
+        "The loading state should only continue after the event"
+    );
+    exit.send(AppExit);
+}
+
+fn send_continue(mut continue_loading: EventWriter<ContinueLoading>) {
+    continue_loading.send(ContinueLoading);
+}
+
+fn exit(mut exit: EventWriter<AppExit>) {
+    exit.send(AppExit);
+}
+
+#[derive(AssetCollection, Resource)]
+struct MyAssets {
+    #[asset(path = "audio/background.ogg")]
+    background: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Load,
+    Next,
+}
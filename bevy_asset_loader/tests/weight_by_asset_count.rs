@@ -0,0 +1,123 @@
+#![allow(dead_code, unused_imports)]
+
+use bevy::app::AppExit;
+use bevy::asset::{AssetPlugin, LoadedFolder};
+use bevy::audio::AudioPlugin;
+use bevy::prelude::*;
+use bevy_asset_loader::asset_collection::AssetCollection;
+use bevy_asset_loader::loading_state::{LoadingState, LoadingStateAppExt};
+
+#[cfg(feature = "progress_tracking")]
+use iyes_progress::{Progress, ProgressCounter};
+
+#[cfg(feature = "progress_tracking")]
+#[test]
+fn counts_each_folder_as_one_by_default() {
+    run(
+        LoadingState::new(MyStates::Load),
+        Progress { done: 2, total: 2 },
+    );
+}
+
+#[cfg(feature = "progress_tracking")]
+#[test]
+fn weights_collections_by_asset_count() {
+    run(
+        LoadingState::new(MyStates::Load).weight_by_asset_count(),
+        Progress { done: 4, total: 4 },
+    );
+}
+
+/// All assets are loaded before entering the loading state, so both collections
+/// finish in the same frame and only persist their final progress.
+#[cfg(feature = "progress_tracking")]
+fn run(loading_state: LoadingState<MyStates>, expected: Progress) {
+    App::new()
+        .add_state::<MyStates>()
+        .add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            AudioPlugin::default(),
+        ))
+        .init_resource::<ProgressCounter>()
+        .insert_resource(ExpectedProgress(expected))
+        .add_loading_state(loading_state.continue_to_state(MyStates::Next))
+        .add_collection_to_loading_state::<_, FolderAssets>(MyStates::Load)
+        .add_collection_to_loading_state::<_, SingleAsset>(MyStates::Load)
+        .add_systems(Startup, preload)
+        .add_systems(Update, timeout)
+        .add_systems(
+            Update,
+            enter_loading_state_when_preloaded.run_if(in_state(MyStates::Preload)),
+        )
+        .add_systems(OnEnter(MyStates::Next), expect_progress)
+        .run();
+}
+
+#[cfg(feature = "progress_tracking")]
+#[derive(Resource)]
+struct ExpectedProgress(Progress);
+
+#[derive(Resource)]
+struct Preloaded {
+    folder: Handle<LoadedFolder>,
+    single: UntypedHandle,
+}
+
+fn preload(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(Preloaded {
+        folder: asset_server.load_folder("audio"),
+        single: asset_server.load_untyped("audio/plop.ogg").untyped(),
+    });
+}
+
+fn enter_loading_state_when_preloaded(
+    preloaded: Res<Preloaded>,
+    asset_server: Res<AssetServer>,
+    mut next_state: ResMut<NextState<MyStates>>,
+) {
+    if asset_server.is_loaded_with_dependencies(&preloaded.folder)
+        && asset_server.is_loaded_with_dependencies(preloaded.single.id())
+    {
+        next_state.set(MyStates::Load);
+    }
+}
+
+fn timeout(time: Res<Time>) {
+    if time.elapsed_seconds_f64() > 10. {
+        panic!("The loading state did not continue in 10 seconds");
+    }
+}
+
+#[cfg(feature = "progress_tracking")]
+fn expect_progress(
+    counter: Res<ProgressCounter>,
+    expected: Res<ExpectedProgress>,
+    mut exit: EventWriter<AppExit>,
+) {
+    assert_eq!(counter.progress(), expected.0);
+    exit.send(AppExit);
+}
+
+#[cfg(not(feature = "progress_tracking"))]
+fn expect_progress() {}
+
+#[derive(AssetCollection, Resource)]
+struct FolderAssets {
+    #[asset(path = "audio", collection)]
+    files: Vec<UntypedHandle>,
+}
+
+#[derive(AssetCollection, Resource)]
+struct SingleAsset {
+    #[asset(path = "audio/plop.ogg")]
+    plop: Handle<AudioSource>,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Hash, Default, States)]
+enum MyStates {
+    #[default]
+    Preload,
+    Load,
+    Next,
+}
@@ -1,6 +1,12 @@
-use crate::{ParseFieldError, TextureAtlasAttribute};
+use crate::{
+    ImageAttribute, KeysIntoMapAttribute, ParseFieldError, SpriteSheetAttribute,
+    StandardMaterialAttribute, TextureAtlasAttribute, ALIAS_FROM_ATTRIBUTE, ARRAY_ATTRIBUTE,
+    COLLECTION_ATTRIBUTE, DURATION_ATTRIBUTE, FOLDER_ATTRIBUTE, FULL_PATH_ATTRIBUTE,
+    KEYS_ATTRIBUTE, KEY_ATTRIBUTE, MANIFEST_KEY_ATTRIBUTE, MAPPED_ATTRIBUTE, OPTIONAL_ATTRIBUTE,
+    PATHS_ATTRIBUTE, PATH_ATTRIBUTE, RUNTIME_PATH_ATTRIBUTE, TYPED_ATTRIBUTE,
+};
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{quote, ToTokens};
 
 #[derive(PartialEq, Debug)]
 pub(crate) struct TextureAtlasAssetField {
@@ -14,6 +20,31 @@ pub(crate) struct TextureAtlasAssetField {
     pub padding_y: f32,
     pub offset_x: f32,
     pub offset_y: f32,
+    pub format: Option<TextureFormatType>,
+    pub sampler: Option<SamplerType>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum TextureFormatType {
+    R8Unorm,
+    Rg8Unorm,
+    Rgba8Unorm,
+    Rgba8UnormSrgb,
+}
+
+impl TryFrom<String> for TextureFormatType {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "r8unorm" => Ok(Self::R8Unorm),
+            "rg8unorm" => Ok(Self::Rg8Unorm),
+            "rgba8unorm" => Ok(Self::Rgba8Unorm),
+            "rgba8unorm_srgb" => Ok(Self::Rgba8UnormSrgb),
+            _ => Err(
+                "Value must be one of `r8unorm`, `rg8unorm`, `rgba8unorm`, or `rgba8unorm_srgb`",
+            ),
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -33,11 +64,112 @@ impl TryFrom<String> for SamplerType {
     }
 }
 
+/// Min, mag, and mipmap filters of an image sampler
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) struct SamplerFilters {
+    pub min: SamplerType,
+    pub mag: SamplerType,
+    pub mipmap: SamplerType,
+}
+
+impl From<SamplerType> for SamplerFilters {
+    fn from(sampler: SamplerType) -> Self {
+        SamplerFilters {
+            min: sampler,
+            mag: sampler,
+            mipmap: sampler,
+        }
+    }
+}
+
+impl SamplerType {
+    fn filter_mode(self) -> TokenStream {
+        match self {
+            SamplerType::Linear => quote!(::bevy::render::texture::ImageFilterMode::Linear),
+            SamplerType::Nearest => quote!(::bevy::render::texture::ImageFilterMode::Nearest),
+        }
+    }
+}
+
+/// Address mode of an image sampler
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum AddressModeType {
+    Repeat,
+    Clamp,
+    Mirror,
+}
+
+impl TryFrom<String> for AddressModeType {
+    type Error = &'static str;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "repeat" => Ok(Self::Repeat),
+            "clamp" => Ok(Self::Clamp),
+            "mirror" => Ok(Self::Mirror),
+            _ => Err("Value must be either `repeat`, `clamp`, or `mirror`"),
+        }
+    }
+}
+
+impl AddressModeType {
+    fn address_mode(self) -> TokenStream {
+        match self {
+            AddressModeType::Repeat => quote!(::bevy::render::texture::ImageAddressMode::Repeat),
+            AddressModeType::Clamp => {
+                quote!(::bevy::render::texture::ImageAddressMode::ClampToEdge)
+            }
+            AddressModeType::Mirror => {
+                quote!(::bevy::render::texture::ImageAddressMode::MirrorRepeat)
+            }
+        }
+    }
+}
+
+/// Address modes of an image sampler per axis
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) struct AddressModes {
+    pub u: AddressModeType,
+    pub v: AddressModeType,
+    pub w: AddressModeType,
+}
+
+impl From<AddressModeType> for AddressModes {
+    fn from(address_mode: AddressModeType) -> Self {
+        AddressModes {
+            u: address_mode,
+            v: address_mode,
+            w: address_mode,
+        }
+    }
+}
+
 #[derive(PartialEq, Debug)]
 pub(crate) struct ImageAssetField {
     pub field_ident: Ident,
     pub asset_path: String,
-    pub sampler: SamplerType,
+    pub sampler: Option<SamplerFilters>,
+    pub address_mode: Option<AddressModes>,
+    pub max_size: Option<u32>,
+}
+
+/// A resource and the (nested) fields to access a handle in it
+#[derive(PartialEq, Debug, Clone)]
+pub(crate) struct AliasSource {
+    pub resource: syn::Path,
+    pub members: Vec<syn::Member>,
+}
+
+#[derive(PartialEq, Debug)]
+pub(crate) struct AliasAssetField {
+    pub field_ident: Ident,
+    pub source: AliasSource,
+}
+
+#[derive(PartialEq, Debug)]
+pub(crate) struct SpriteSheetAssetField {
+    pub field_ident: Ident,
+    pub json_path: String,
+    pub image_path: String,
 }
 
 #[derive(PartialEq, Debug)]
@@ -46,6 +178,30 @@ pub(crate) struct BasicAssetField {
     pub asset_path: String,
 }
 
+#[derive(PartialEq, Debug)]
+pub(crate) struct StandardMaterialAssetField {
+    pub field_ident: Ident,
+    pub base_color: String,
+    pub normal: Option<String>,
+    pub metallic_roughness: Option<String>,
+}
+
+impl StandardMaterialAssetField {
+    fn texture_paths(&self) -> impl Iterator<Item = &String> {
+        std::iter::once(&self.base_color)
+            .chain(self.normal.iter())
+            .chain(self.metallic_roughness.iter())
+    }
+}
+
+/// The optional field that has to load for a `required_if` field to be required
+#[derive(PartialEq, Debug)]
+pub(crate) struct RequiredIfCondition {
+    pub field_ident: Ident,
+    /// Path of the asset of the field, set once all fields of the collection are parsed
+    pub asset_path: String,
+}
+
 #[derive(PartialEq, Debug)]
 pub(crate) struct MultipleFilesField {
     pub field_ident: Ident,
@@ -58,21 +214,60 @@ pub(crate) struct DynamicAssetField {
     pub key: String,
 }
 
+/// Several dynamic asset keys tried in order, falling back to a single file
+#[derive(PartialEq, Debug)]
+pub(crate) struct DynamicKeysField {
+    pub field_ident: Ident,
+    pub keys: Vec<String>,
+    pub fallback_path: Option<String>,
+}
+
 /// Enum describing an asset field at compile-time
 ///
 /// Variants are created from derive attributes.
 #[derive(PartialEq, Debug)]
 pub(crate) enum AssetField {
     Basic(BasicAssetField),
+    /// A single file in an `Option<Handle<T>>` field that is `None` if the file fails to load
+    OptionalBasic(BasicAssetField),
+    /// A single file in an `Option<Handle<T>>` field that is only required if the asset of another optional field loaded
+    RequiredIf(BasicAssetField, RequiredIfCondition),
     Folder(BasicAssetField, Typed, Mapped),
+    FolderArray(BasicAssetField),
+    /// The `Handle<LoadedFolder>` of a folder
+    LoadedFolder(BasicAssetField),
+    /// An audio file together with its duration
+    AudioDuration(BasicAssetField),
     Files(MultipleFilesField, Typed, Mapped),
     TextureAtlas(TextureAtlasAssetField),
+    /// A texture atlas packed from individual image files
+    TextureAtlasFiles(MultipleFilesField),
+    SpriteSheet(SpriteSheetAssetField),
     Image(ImageAssetField),
-    StandardMaterial(BasicAssetField),
+    StandardMaterial(StandardMaterialAssetField),
     Dynamic(DynamicAssetField),
     OptionalDynamic(DynamicAssetField),
+    /// The first registered of several dynamic assets, or the fallback file if none of the keys is registered
+    FirstDynamic(DynamicKeysField),
     DynamicFileCollection(DynamicAssetField, Typed, Mapped),
     OptionalDynamicFileCollection(DynamicAssetField, Typed, Mapped),
+    Alias(AliasAssetField),
+    /// A single file whose path is looked up by key in the `RuntimePaths` resource
+    RuntimePath(DynamicAssetField),
+    /// A single file whose path is looked up by key in the `ASSET_MANIFEST` constant at compile time
+    ManifestKey(DynamicAssetField),
+    /// All dynamic assets with keys starting with a prefix, mapped by the rest of their keys
+    KeysIntoMap(DynamicAssetField),
+    /// An asset embedded into the binary; the path is relative to the crate's `assets` directory
+    Embedded(Box<AssetField>, String),
+    /// An asset with an expected SHA-256 checksum of its file; holds the asset path and the hex digest
+    Checksum(Box<AssetField>, String, String),
+    /// An asset stored behind a lock of the standard library for interior mutability
+    Locked(Box<AssetField>),
+    /// An asset that is not awaited while loading; holds the path of the function creating its placeholder
+    Placeholder(Box<AssetField>, syn::Path),
+    /// An asset loaded with custom loader settings; holds the path of the function changing the settings
+    Settings(Box<AssetField>, syn::Path),
 }
 
 #[derive(PartialEq, Debug)]
@@ -92,17 +287,25 @@ impl From<bool> for Typed {
 
 #[derive(PartialEq, Debug)]
 pub(crate) enum Mapped {
+    /// Keys are created from the path of a file in its folder
     Yes,
+    /// Keys are created from the full asset path of a file
+    FullPath,
     No,
 }
 
-impl From<bool> for Mapped {
-    fn from(flag: bool) -> Self {
-        match flag {
-            true => Mapped::Yes,
-            false => Mapped::No,
+impl Mapped {
+    fn new(is_mapped: bool, is_full_path: bool) -> Self {
+        match (is_mapped, is_full_path) {
+            (false, _) => Mapped::No,
+            (true, false) => Mapped::Yes,
+            (true, true) => Mapped::FullPath,
         }
     }
+
+    fn full_path(&self) -> bool {
+        self == &Mapped::FullPath
+    }
 }
 
 impl AssetField {
@@ -112,6 +315,47 @@ impl AssetField {
         name: String,
     ) -> TokenStream {
         match self {
+            AssetField::Embedded(asset, _)
+            | AssetField::Checksum(asset, _, _)
+            | AssetField::Settings(asset, _) => {
+                asset.attach_token_stream_for_creation(token_stream, name)
+            }
+            AssetField::Locked(asset) => {
+                // the creation of the wrapped field has the form `field_ident : expression,`
+                let field_creation: Vec<_> = asset
+                    .attach_token_stream_for_creation(quote!(), name)
+                    .into_iter()
+                    .collect();
+                let field_ident = asset.field_ident();
+                let expression = &field_creation[2..field_creation.len() - 1];
+                quote!(#token_stream #field_ident : ::std::convert::From::from(#(#expression)*),)
+            }
+            AssetField::Placeholder(asset, placeholder) => {
+                let AssetField::Basic(basic) = asset.as_ref() else {
+                    unreachable!("Placeholders are only supported for basic assets");
+                };
+                let field_ident = basic.field_ident.clone();
+                let field = field_ident.to_string();
+                let asset_path = basic.asset_path.clone();
+                quote!(#token_stream #field_ident : ::bevy_asset_loader::placeholder::PendingPlaceholders::loaded_or_placeholder(
+                    world,
+                    #asset_path,
+                    #field,
+                    #placeholder,
+                    |collection: &mut Self| &mut collection.#field_ident,
+                ),)
+            }
+            AssetField::OptionalBasic(basic) => {
+                let field_ident = basic.field_ident.clone();
+                let asset_path = basic.asset_path.clone();
+                quote!(#token_stream #field_ident : ::bevy_asset_loader::asset_collection::OptionalAssets::get::<Self, _>(world, #asset_path),)
+            }
+            AssetField::RequiredIf(basic, condition) => {
+                let field_ident = basic.field_ident.clone();
+                let asset_path = basic.asset_path.clone();
+                let condition_path = condition.asset_path.clone();
+                quote!(#token_stream #field_ident : ::bevy_asset_loader::asset_collection::OptionalAssets::get_required_if::<Self, _>(world, #asset_path, #condition_path),)
+            }
             AssetField::Basic(basic) => {
                 let field_ident = basic.field_ident.clone();
                 let asset_path = basic.asset_path.clone();
@@ -123,13 +367,58 @@ impl AssetField {
             AssetField::Image(image) => {
                 let field_ident = image.field_ident.clone();
                 let asset_path = image.asset_path.clone();
-                let sampler = match image.sampler {
-                    SamplerType::Linear => quote!(ImageSampler::linear()),
-                    SamplerType::Nearest => quote!(ImageSampler::nearest()),
+                // without a sampler, the address mode is combined with linear filtering like Bevy's default sampler
+                let filters = image.sampler.unwrap_or_else(|| SamplerType::Linear.into());
+                let min_filter = filters.min.filter_mode();
+                let mag_filter = filters.mag.filter_mode();
+                let mipmap_filter = filters.mipmap.filter_mode();
+                let base_descriptor = quote!(ImageSamplerDescriptor {
+                    min_filter: #min_filter,
+                    mag_filter: #mag_filter,
+                    mipmap_filter: #mipmap_filter,
+                    ..ImageSamplerDescriptor::default()
+                });
+                let set_address_mode = match image.address_mode {
+                    Some(address_modes) => {
+                        let u = address_modes.u.address_mode();
+                        let v = address_modes.v.address_mode();
+                        let w = address_modes.w.address_mode();
+                        quote!(
+                            descriptor.address_mode_u = #u;
+                            descriptor.address_mode_v = #v;
+                            descriptor.address_mode_w = #w;
+                        )
+                    }
+                    None => quote!(),
+                };
+                let apply_sampler = if image.sampler.is_some() || image.address_mode.is_some() {
+                    quote!(
+                        let mut image = images.get_mut(&handle).expect("Only asset collection fields holding an `Image` handle can be annotated with `image`");
+                        let mut descriptor = #base_descriptor;
+                        #set_address_mode
+
+                        let is_different_sampler = if let ImageSampler::Descriptor(current) = &image.sampler {
+                            !current.as_wgpu().eq(&descriptor.as_wgpu())
+                        } else {
+                            false
+                        };
+
+                        if is_different_sampler {
+                            let mut cloned_image = image.clone();
+                            cloned_image.sampler = ImageSampler::Descriptor(descriptor);
+                            handle = images.add(cloned_image);
+                        } else {
+                            image.sampler = ImageSampler::Descriptor(descriptor);
+                        }
+                    )
+                } else {
+                    quote!()
                 };
-                let descriptor = match image.sampler {
-                    SamplerType::Linear => quote!(ImageSamplerDescriptor::linear()),
-                    SamplerType::Nearest => quote!(ImageSamplerDescriptor::nearest()),
+                let limit_size = match image.max_size {
+                    Some(max_size) => quote!(
+                        handle = ::bevy_asset_loader::asset_collection::limit_image_size(&mut images, handle, #max_size);
+                    ),
+                    None => quote!(),
                 };
 
                 quote!(#token_stream #field_ident : {
@@ -139,21 +428,8 @@ impl AssetField {
                     let mut images = cell.get_resource_mut::<Assets<Image>>().expect("Cannot get resource Assets<Image>");
 
                     let mut handle = asset_server.load(#asset_path);
-                    let mut image = images.get_mut(&handle).expect("Only asset collection fields holding an `Image` handle can be annotated with `image`");
-
-                    let is_different_sampler = if let ImageSampler::Descriptor(descriptor) = &image.sampler {
-                        !descriptor.as_wgpu().eq(&#descriptor.as_wgpu())
-                    } else {
-                        false
-                    };
-
-                    if is_different_sampler {
-                        let mut cloned_image = image.clone();
-                        cloned_image.sampler = #sampler;
-                        handle = images.add(cloned_image);
-                    } else {
-                        image.sampler = #sampler;
-                    }
+                    #apply_sampler
+                    #limit_size
 
                     handle
                 },)
@@ -174,26 +450,19 @@ impl AssetField {
                                         .unwrap()
                                         .handles
                                         .iter()
-                                        .map(|handle| handle.clone().typed())
+                                        .filter_map(::bevy_asset_loader::asset_collection::try_typed)
                                         .collect()
                                 },)
                         }
-                        Mapped::Yes => {
+                        Mapped::Yes | Mapped::FullPath => {
+                            let full_path = mapped.full_path();
                             quote!(#token_stream #field_ident : {
                                     let cell = world.cell();
                                     let asset_server = cell.get_resource::<::bevy::asset::AssetServer>().expect("Cannot get AssetServer");
-                                    let mut folder_map = ::bevy::utils::HashMap::default();
                                     let folders = cell.get_resource::<::bevy::asset::Assets<::bevy::asset::LoadedFolder>>().expect("Cannot get Assets<LoadedFolder>");
                                     let handle = asset_server.get_handle(#asset_path).unwrap_or_else(|| panic!("Folders are only supported when using a loading state. Consider using 'paths' for {}.{}.", #name, #field));
                                     let folder = &folders.get(handle).unwrap().handles;
-                                    for handle in folder {
-                                        let path = handle.path().unwrap().path();
-                                        let key: String = ::bevy_asset_loader::path_slash::PathExt::to_slash(path)
-                                                .expect("Path should be valid UTF-8")
-                                                .into();
-                                        folder_map.insert(key, handle.clone().typed());
-                                    }
-                                    folder_map
+                                    ::bevy_asset_loader::mapped::map_folder(#asset_path, #full_path, folder, ::bevy_asset_loader::asset_collection::try_typed)
                                 },)
                         }
                     },
@@ -207,66 +476,161 @@ impl AssetField {
                                     folders.get(handle).expect("test").handles.iter().cloned().collect()
                                 },)
                         }
-                        Mapped::Yes => {
+                        Mapped::Yes | Mapped::FullPath => {
+                            let full_path = mapped.full_path();
                             quote!(#token_stream #field_ident : {
                                     let cell = world.cell();
                                     let asset_server = cell.get_resource::<::bevy::asset::AssetServer>().expect("Cannot get AssetServer");
-                                    let mut folder_map = ::bevy::utils::HashMap::default();
                                     let folders = cell.get_resource::<::bevy::asset::Assets<::bevy::asset::LoadedFolder>>().expect("Cannot get Assets<LoadedFolder>");
                                     let handle = asset_server.get_handle(#asset_path).unwrap_or_else(|| panic!("Folders are only supported when using a loading state. Consider using 'paths' for {}.{}.", #name, #field));
                                     let folder = &folders.get(handle).unwrap().handles;
-                                    for handle in folder {
-                                        let path = handle.path().unwrap().path();
-                                        let key: String = ::bevy_asset_loader::path_slash::PathExt::to_slash(path)
-                                                .expect("Path should be valid UTF-8")
-                                                .into();
-                                        folder_map.insert(key, handle.clone());
-                                    }
-                                    folder_map
+                                    ::bevy_asset_loader::mapped::map_folder(#asset_path, #full_path, folder, |handle| Some(handle.clone()))
                                 },)
                         }
                     },
                 }
             }
-            AssetField::StandardMaterial(basic) => {
+            AssetField::Alias(alias) => {
+                let field_ident = alias.field_ident.clone();
+                let field = field_ident.to_string();
+                let resource = &alias.source.resource;
+                let resource_name = resource.to_token_stream().to_string().replace(' ', "");
+                let members = &alias.source.members;
+                quote!(#token_stream #field_ident : world
+                    .get_resource::<#resource>()
+                    .unwrap_or_else(|| panic!("The resource {} aliased by {}.{} does not exist", #resource_name, #name, #field))
+                    #(.#members)*
+                    .clone(),
+                )
+            }
+            AssetField::RuntimePath(runtime_path) => {
+                let field_ident = runtime_path.field_ident.clone();
+                let field = field_ident.to_string();
+                let key = runtime_path.key.clone();
+                quote!(#token_stream #field_ident : {
+                    let path = ::bevy_asset_loader::asset_collection::RuntimePaths::resolve(world.get_resource(), #key)
+                        .unwrap_or_else(|| panic!("The runtime path '{}' of the field '{}' was removed from RuntimePaths while {} was loading", #key, #field, #name));
+                    let asset_server = world.get_resource::<::bevy::asset::AssetServer>().expect("Cannot get AssetServer");
+                    asset_server.load(path)
+                },)
+            }
+            AssetField::ManifestKey(manifest_key) => {
+                let field_ident = manifest_key.field_ident.clone();
+                let key = manifest_key.key.clone();
+                quote!(#token_stream #field_ident : {
+                    const PATH: &str = ::bevy_asset_loader::asset_collection::manifest_path(ASSET_MANIFEST, #key);
+                    let asset_server = world.get_resource::<::bevy::asset::AssetServer>().expect("Cannot get AssetServer");
+                    asset_server.load(PATH)
+                },)
+            }
+            AssetField::AudioDuration(basic) => {
+                let field_ident = basic.field_ident.clone();
+                let asset_path = basic.asset_path.clone();
+                quote!(#token_stream #field_ident : {
+                    let asset_server = world.get_resource::<::bevy::asset::AssetServer>().expect("Cannot get AssetServer");
+                    let handle = asset_server.load::<::bevy::audio::AudioSource>(#asset_path);
+                    ::bevy_asset_loader::audio::AudioWithDuration::new(world, handle)
+                },)
+            }
+            AssetField::LoadedFolder(basic) => {
+                let field_ident = basic.field_ident.clone();
+                let field = field_ident.to_string();
+                let asset_path = basic.asset_path.clone();
+                quote!(#token_stream #field_ident : {
+                    let asset_server = world.get_resource::<::bevy::asset::AssetServer>().expect("Cannot get AssetServer");
+                    asset_server.get_handle(#asset_path).unwrap_or_else(|| panic!("Folders are only supported when using a loading state. Consider using 'paths' for {}.{}.", #name, #field))
+                },)
+            }
+            AssetField::FolderArray(basic) => {
                 let field_ident = basic.field_ident.clone();
+                let field = field_ident.to_string();
                 let asset_path = basic.asset_path.clone();
+                quote!(#token_stream #field_ident : {
+                    let cell = world.cell();
+                    let asset_server = cell.get_resource::<::bevy::asset::AssetServer>().expect("Cannot get AssetServer");
+                    let folders = cell.get_resource::<::bevy::asset::Assets<::bevy::asset::LoadedFolder>>().expect("Cannot get Assets<LoadedFolder>");
+                    let handle = asset_server.get_handle(#asset_path).unwrap_or_else(|| panic!("Folders are only supported when using a loading state. Consider using 'paths' for {}.{}.", #name, #field));
+                    let mut handles = folders.get(handle).unwrap().handles.clone();
+                    handles.sort_by(|a, b| a.path().map(|path| path.path()).cmp(&b.path().map(|path| path.path())));
+                    handles
+                        .into_iter()
+                        .map(|handle| handle.typed())
+                        .collect::<Vec<_>>()
+                        .try_into()
+                        .unwrap_or_else(|handles: Vec<_>| panic!("The folder '{}' contains {} assets, which does not match the length of the array {}.{}", #asset_path, handles.len(), #name, #field))
+                },)
+            }
+            AssetField::StandardMaterial(material) => {
+                let field_ident = material.field_ident.clone();
+                let base_color = material.base_color.clone();
+                let normal = material.normal.iter();
+                let metallic_roughness = material.metallic_roughness.iter();
                 quote!(#token_stream #field_ident : {
                     let cell = world.cell();
                     let asset_server = cell.get_resource::<::bevy::asset::AssetServer>().expect("Cannot get AssetServer");
                     let mut materials = cell
                         .get_resource_mut::<::bevy::asset::Assets<StandardMaterial>>()
                         .expect("Cannot get resource Assets<StandardMaterial>");
-                    materials.add(asset_server.load::<::bevy::render::texture::Image>(#asset_path).into())
+                    #[allow(unused_mut)]
+                    let mut material: StandardMaterial = asset_server.load::<::bevy::render::texture::Image>(#base_color).into();
+                    #(material.normal_map_texture = Some(asset_server.load::<::bevy::render::texture::Image>(#normal));)*
+                    #(material.metallic_roughness_texture = Some(asset_server.load::<::bevy::render::texture::Image>(#metallic_roughness));)*
+                    materials.add(material)
                 },)
             }
             AssetField::TextureAtlas(texture_atlas) => {
                 let field_ident = texture_atlas.field_ident.clone();
-                let asset_path = texture_atlas.asset_path.clone();
-                let tile_size_x = texture_atlas.tile_size_x;
-                let tile_size_y = texture_atlas.tile_size_y;
-                let columns = texture_atlas.columns;
-                let rows = texture_atlas.rows;
-                let padding_x = texture_atlas.padding_x;
-                let padding_y = texture_atlas.padding_y;
-                let offset_x = texture_atlas.offset_x;
-                let offset_y = texture_atlas.offset_y;
+                quote!(#token_stream #field_ident : texture_atlases.next().expect("Missing texture atlas"),)
+            }
+            AssetField::TextureAtlasFiles(files) => {
+                let field_ident = files.field_ident.clone();
+                let field = field_ident.to_string();
+                let asset_paths = files.asset_paths.clone();
                 quote!(#token_stream #field_ident : {
                     let cell = world.cell();
                     let asset_server = cell
                         .get_resource::<::bevy::asset::AssetServer>()
                         .expect("Cannot get AssetServer");
+                    let mut images = cell
+                        .get_resource_mut::<::bevy::asset::Assets<::bevy::render::texture::Image>>()
+                        .expect("Cannot get resource Assets<Image>");
                     let mut atlases = cell
-                        .get_resource_mut::<::bevy::asset::Assets<TextureAtlas>>()
+                        .get_resource_mut::<::bevy::asset::Assets<::bevy::sprite::TextureAtlas>>()
                         .expect("Cannot get resource Assets<TextureAtlas>");
-                    atlases.add(TextureAtlas::from_grid(
-                        asset_server.load(#asset_path),
-                        Vec2::new(#tile_size_x, #tile_size_y),
-                        #columns,
-                        #rows,
-                        Some(Vec2::new(#padding_x, #padding_y)),
-                        Some(Vec2::new(#offset_x, #offset_y)),
-                    ))
+                    let mut builder = ::bevy::sprite::TextureAtlasBuilder::default();
+                    for path in [#(#asset_paths),*] {
+                        let handle = asset_server.load::<::bevy::render::texture::Image>(path);
+                        let image = images
+                            .get(&handle)
+                            .unwrap_or_else(|| panic!("Texture atlases from files are only supported when using a loading state. The image '{}' for {}.{} is not loaded.", path, #name, #field));
+                        builder.add_texture(handle.id(), image);
+                    }
+                    let atlas = builder
+                        .finish(&mut images)
+                        .unwrap_or_else(|error| panic!("Failed to pack the texture atlas {}.{}: {:?}", #name, #field, error));
+                    atlases.add(atlas)
+                },)
+            }
+            AssetField::SpriteSheet(sprite_sheet) => {
+                let field_ident = sprite_sheet.field_ident.clone();
+                let field = field_ident.to_string();
+                let json_path = sprite_sheet.json_path.clone();
+                let image_path = sprite_sheet.image_path.clone();
+                quote!(#token_stream #field_ident : {
+                    let cell = world.cell();
+                    let asset_server = cell
+                        .get_resource::<::bevy::asset::AssetServer>()
+                        .expect("Cannot get AssetServer");
+                    let sheets = cell
+                        .get_resource::<::bevy::asset::Assets<::bevy_asset_loader::texture_packer::TexturePackerSheet>>()
+                        .expect("Cannot get resource Assets<TexturePackerSheet>");
+                    let mut atlases = cell
+                        .get_resource_mut::<::bevy::asset::Assets<::bevy::sprite::TextureAtlas>>()
+                        .expect("Cannot get resource Assets<TextureAtlas>");
+                    let sheet = sheets
+                        .get(asset_server.load::<::bevy_asset_loader::texture_packer::TexturePackerSheet>(#json_path))
+                        .unwrap_or_else(|| panic!("Sprite sheets are only supported when using a loading state or `init_collection_blocking`. The sprite sheet for {}.{} is not loaded.", #name, #field));
+                    atlases.add(sheet.texture_atlas(asset_server.load(#image_path)))
                 },)
             }
             AssetField::Files(files, typed, mapped) => {
@@ -278,38 +642,81 @@ impl AssetField {
                                 let asset_server = world.get_resource::<::bevy::asset::AssetServer>().expect("Cannot get AssetServer");
                                 vec![#(asset_server.load(#asset_paths)),*]
                             },),
-                        Mapped::Yes => quote!(#token_stream #field_ident : {
+                        Mapped::Yes | Mapped::FullPath => {
+                            let full_path = mapped.full_path();
+                            quote!(#token_stream #field_ident : {
                                 let asset_server = world.get_resource::<::bevy::asset::AssetServer>().expect("Cannot get AssetServer");
-                                let mut folder_map = ::bevy::utils::HashMap::default();
-                                #(folder_map.insert(#asset_paths.to_owned(), asset_server.load(#asset_paths)));*;
-                                folder_map
-                            },),
+                                ::bevy_asset_loader::mapped::map_paths(#full_path, [#((#asset_paths, asset_server.load(#asset_paths))),*])
+                            },)
+                        }
                     },
                     Typed::No => match mapped {
                         Mapped::No => quote!(#token_stream #field_ident : {
                                 let asset_server = world.get_resource::<::bevy::asset::AssetServer>().expect("Cannot get AssetServer");
                                 vec![#(asset_server.get_handle_untyped(#asset_paths).unwrap()),*]
                             },),
-                        Mapped::Yes => quote!(#token_stream #field_ident : {
+                        Mapped::Yes | Mapped::FullPath => {
+                            let full_path = mapped.full_path();
+                            quote!(#token_stream #field_ident : {
                                 let asset_server = world.get_resource::<::bevy::asset::AssetServer>().expect("Cannot get AssetServer");
-                                let mut folder_map = ::bevy::utils::HashMap::default();
-                                #(folder_map.insert(#asset_paths.to_owned(), asset_server.get_handle_untyped(#asset_paths).unwrap()));*;
-                                folder_map
-                            },),
+                                ::bevy_asset_loader::mapped::map_paths(#full_path, [#((#asset_paths, asset_server.get_handle_untyped(#asset_paths).unwrap())),*])
+                            },)
+                        }
                     },
                 }
             }
             AssetField::Dynamic(dynamic) => {
                 let field_ident = dynamic.field_ident.clone();
                 let asset_key = dynamic.key.clone();
+                let field = field_ident.to_string();
                 quote!(#token_stream #field_ident : {
-                    let asset = asset_keys.get_asset(#asset_key.into()).unwrap_or_else(|| panic!("Failed to get asset for key '{}'", #asset_key));
+                    let asset = asset_keys.get_asset(#asset_key.into()).unwrap_or_else(|| panic!("The field '{}' of the asset collection {} requires the dynamic asset '{}', but no dynamic asset is registered for this key", #field, #name, #asset_key));
                     match asset.build(world).unwrap_or_else(|_| panic!("Error building the dynamic asset {:?} with the key {}", asset, #asset_key)) {
                         ::bevy_asset_loader::prelude::DynamicAssetType::Single(handle) => handle.typed(),
                         result => panic!("The dynamic asset '{}' cannot be created. The asset collection {} expected it to resolve to `Single(handle)`, but {asset:?} resolves to {result:?}", #asset_key, #name)
                     }
                 },)
             }
+            AssetField::FirstDynamic(dynamic) => {
+                let field_ident = dynamic.field_ident.clone();
+                let keys = dynamic.keys.clone();
+                let field = field_ident.to_string();
+                let fallback = match &dynamic.fallback_path {
+                    Some(asset_path) => quote!({
+                        let asset_server = world.get_resource::<::bevy::asset::AssetServer>().expect("Cannot get AssetServer");
+                        asset_server.load(#asset_path)
+                    }),
+                    None => {
+                        let keys = keys.join("', '");
+                        quote!(
+                            panic!("The field '{}' of the asset collection {} requires one of the dynamic assets '{}', but no dynamic asset is registered for these keys", #field, #name, #keys)
+                        )
+                    }
+                };
+                quote!(#token_stream #field_ident : match [#(#keys),*].into_iter().find_map(|asset_key| asset_keys.get_asset(asset_key).map(|asset| (asset_key, asset))) {
+                    Some((asset_key, asset)) => match asset.build(world).unwrap_or_else(|_| panic!("Error building the dynamic asset {:?} with the key {}", asset, asset_key)) {
+                        ::bevy_asset_loader::prelude::DynamicAssetType::Single(handle) => handle.typed(),
+                        result => panic!("The dynamic asset '{}' cannot be created. The asset collection {} expected it to resolve to `Single(handle)`, but {asset:?} resolves to {result:?}", asset_key, #name)
+                    },
+                    None => #fallback,
+                },)
+            }
+            AssetField::KeysIntoMap(keys) => {
+                let field_ident = keys.field_ident.clone();
+                let prefix = keys.key.clone();
+                quote!(#token_stream #field_ident : asset_keys
+                    .iter_assets()
+                    .filter_map(|(asset_key, asset)| {
+                        let suffix = asset_key.strip_prefix(#prefix)?;
+                        let handle = match asset.build(world).unwrap_or_else(|_| panic!("Error building the dynamic asset {:?} with the key {}", asset, asset_key)) {
+                            ::bevy_asset_loader::prelude::DynamicAssetType::Single(handle) => handle.typed(),
+                            result => panic!("The dynamic asset '{}' cannot be created. The asset collection {} expected it to resolve to `Single(handle)`, but {asset:?} resolves to {result:?}", asset_key, #name)
+                        };
+                        Some((suffix.to_owned(), handle))
+                    })
+                    .collect(),
+                )
+            }
             AssetField::OptionalDynamic(dynamic) => {
                 let field_ident = dynamic.field_ident.clone();
                 let asset_key = dynamic.key.clone();
@@ -332,8 +739,8 @@ impl AssetField {
                                 ::bevy_asset_loader::prelude::DynamicAssetType::Collection(mut handles) => handles.drain(..).map(|handle| handle.typed()).collect(),
                                 result => panic!("The dynamic asset '{}' cannot be created. The asset collection {} expected it to resolve to `Collection(handle)`, but {asset:?} resolves to {result:?}", #asset_key, #name),
                             }),
-                            Mapped::Yes => {
-                                let build_collection = Self::build_mapped_dynamic_file_collection(Typed::Yes, &asset_key, name);
+                            Mapped::Yes | Mapped::FullPath => {
+                                let build_collection = Self::build_mapped_dynamic_file_collection(Typed::Yes, mapped.full_path(), &asset_key, name.clone());
                                 quote!(match asset.build(world).unwrap_or_else(|_| panic!("Error building the dynamic asset {:?} with the key {}", asset, #asset_key)) {
                                     #build_collection
                                 })
@@ -347,8 +754,8 @@ impl AssetField {
                                     ::bevy_asset_loader::prelude::DynamicAssetType::Collection(handles) => handles,
                                     result => panic!("The dynamic asset '{}' cannot be created. The asset collection {} expected it to resolve to `Collection(handle)`, but {asset:?} resolves to {result:?}", #asset_key, #name),
                                 }),
-                            Mapped::Yes => {
-                                let build_collection = Self::build_mapped_dynamic_file_collection(Typed::No, &asset_key, name);
+                            Mapped::Yes | Mapped::FullPath => {
+                                let build_collection = Self::build_mapped_dynamic_file_collection(Typed::No, mapped.full_path(), &asset_key, name.clone());
                                 quote!(match asset.build(world).unwrap_or_else(|_| panic!("Error building the dynamic asset {:?} with the key {}", asset, #asset_key)) {
                                     #build_collection
                                 })
@@ -356,8 +763,9 @@ impl AssetField {
                         }
                     }
                 };
+                let field = field_ident.to_string();
                 quote!(#token_stream #field_ident : {
-                    let asset = asset_keys.get_asset(#asset_key.into()).unwrap_or_else(|| panic!("Failed to get asset for key '{}'", #asset_key));
+                    let asset = asset_keys.get_asset(#asset_key.into()).unwrap_or_else(|| panic!("The field '{}' of the asset collection {} requires the dynamic asset '{}', but no dynamic asset is registered for this key", #field, #name, #asset_key));
                     #load
                 },)
             }
@@ -373,8 +781,8 @@ impl AssetField {
                                     result => panic!("The dynamic asset '{}' cannot be created. The asset collection {} expected it to resolve to `Collection(handle)`, but {asset:?} resolves to {result:?}", #asset_key, #name),
                                 })
                             ),
-                            Mapped::Yes => {
-                                let build_collection = Self::build_mapped_dynamic_file_collection(Typed::Yes, &asset_key, name);
+                            Mapped::Yes | Mapped::FullPath => {
+                                let build_collection = Self::build_mapped_dynamic_file_collection(Typed::Yes, mapped.full_path(), &asset_key, name);
                                 quote!(
                                     asset.map(|asset| match asset.build(world).unwrap_or_else(|_| panic!("Error building the dynamic asset {:?} with the key {}", asset, #asset_key)) {
                                         #build_collection
@@ -391,8 +799,8 @@ impl AssetField {
                                     result => panic!("The dynamic asset '{}' cannot be created. The asset collection {} expected it to resolve to `Collection(handle)`, but {asset:?} resolves to {result:?}", #asset_key, #name),
                                 })
                             ),
-                            Mapped::Yes => {
-                                let build_collection = Self::build_mapped_dynamic_file_collection(Typed::No, &asset_key, name);
+                            Mapped::Yes | Mapped::FullPath => {
+                                let build_collection = Self::build_mapped_dynamic_file_collection(Typed::No, mapped.full_path(), &asset_key, name);
                                 quote!(
                                     asset.map(|asset| match asset.build(world).unwrap_or_else(|_| panic!("Error building the dynamic asset {:?} with the key {}", asset, #asset_key)) {
                                         #build_collection
@@ -412,150 +820,896 @@ impl AssetField {
 
     fn build_mapped_dynamic_file_collection(
         typed: Typed,
+        full_path: bool,
         asset_key: &String,
         name: String,
     ) -> TokenStream {
         let handle = match typed {
-            Typed::Yes => quote!(handle.typed()),
-            Typed::No => quote!(handle),
+            Typed::Yes => quote!(handle.clone().typed()),
+            Typed::No => quote!(handle.clone()),
         };
         quote!(
-            ::bevy_asset_loader::prelude::DynamicAssetType::Collection(mut handles) => {
-                let asset_server = world.get_resource::<::bevy::asset::AssetServer>().expect("Cannot get AssetServer");
-                let mut folder_map = ::bevy::utils::HashMap::default();
-                for handle in handles {
-                    let path = handle.path().unwrap().path();
-                    let key: String = ::bevy_asset_loader::path_slash::PathExt::to_slash(path)
-                        .expect("Path should be valid UTF-8")
-                        .into();
-                    folder_map.insert(key, #handle);
-                }
-                folder_map
+            ::bevy_asset_loader::prelude::DynamicAssetType::Collection(handles) => {
+                ::bevy_asset_loader::mapped::map_folder("", #full_path, &handles, |handle| Some(#handle))
             },
             result => panic!("The dynamic asset '{}' cannot be created. The asset collection {} expected it to resolve to `Collection(handle)`, but {asset:?} resolves to {result:?}", #asset_key, #name),
         )
     }
 
-    pub(crate) fn attach_token_stream_for_loading(&self, token_stream: TokenStream) -> TokenStream {
-        match self {
-            AssetField::Basic(asset) => {
-                let asset_path = asset.asset_path.clone();
-                quote!(#token_stream handles.push(asset_server.load_untyped(#asset_path).untyped());)
+    #[cfg(feature = "reflect")]
+    pub(crate) fn binding(&self, variant: &TokenStream) -> TokenStream {
+        let (field_ident, kind, paths, key) = match self {
+            AssetField::Embedded(asset, _)
+            | AssetField::Checksum(asset, _, _)
+            | AssetField::Locked(asset)
+            | AssetField::Placeholder(asset, _)
+            | AssetField::Settings(asset, _) => return asset.binding(variant),
+            AssetField::Basic(basic)
+            | AssetField::OptionalBasic(basic)
+            | AssetField::RequiredIf(basic, _)
+            | AssetField::AudioDuration(basic) => (
+                &basic.field_ident,
+                quote!(File),
+                vec![basic.asset_path.clone()],
+                None,
+            ),
+            AssetField::Alias(alias) => (&alias.field_ident, quote!(Alias), vec![], None),
+            AssetField::RuntimePath(runtime_path) => (
+                &runtime_path.field_ident,
+                quote!(RuntimePath),
+                vec![],
+                Some(runtime_path.key.clone()),
+            ),
+            AssetField::ManifestKey(manifest_key) => (
+                &manifest_key.field_ident,
+                quote!(ManifestKey),
+                vec![],
+                Some(manifest_key.key.clone()),
+            ),
+            AssetField::FirstDynamic(dynamic) => (
+                &dynamic.field_ident,
+                quote!(FirstDynamic),
+                dynamic.fallback_path.iter().cloned().collect(),
+                Some(dynamic.keys.join(", ")),
+            ),
+            AssetField::KeysIntoMap(keys) => (
+                &keys.field_ident,
+                quote!(KeysIntoMap),
+                vec![],
+                Some(keys.key.clone()),
+            ),
+            AssetField::Folder(basic, _, _)
+            | AssetField::FolderArray(basic)
+            | AssetField::LoadedFolder(basic) => (
+                &basic.field_ident,
+                quote!(Folder),
+                vec![basic.asset_path.clone()],
+                None,
+            ),
+            AssetField::Files(files, _, _) => (
+                &files.field_ident,
+                quote!(Files),
+                files.asset_paths.clone(),
+                None,
+            ),
+            AssetField::TextureAtlas(texture_atlas) => (
+                &texture_atlas.field_ident,
+                quote!(TextureAtlas),
+                vec![texture_atlas.asset_path.clone()],
+                None,
+            ),
+            AssetField::TextureAtlasFiles(files) => (
+                &files.field_ident,
+                quote!(TextureAtlas),
+                files.asset_paths.clone(),
+                None,
+            ),
+            AssetField::SpriteSheet(sprite_sheet) => (
+                &sprite_sheet.field_ident,
+                quote!(SpriteSheet),
+                vec![
+                    sprite_sheet.json_path.clone(),
+                    sprite_sheet.image_path.clone(),
+                ],
+                None,
+            ),
+            AssetField::Image(image) => (
+                &image.field_ident,
+                quote!(Image),
+                vec![image.asset_path.clone()],
+                None,
+            ),
+            AssetField::StandardMaterial(material) => (
+                &material.field_ident,
+                quote!(StandardMaterial),
+                material.texture_paths().cloned().collect(),
+                None,
+            ),
+            AssetField::Dynamic(dynamic) => (
+                &dynamic.field_ident,
+                quote!(Dynamic),
+                vec![],
+                Some(dynamic.key.clone()),
+            ),
+            AssetField::OptionalDynamic(dynamic) => (
+                &dynamic.field_ident,
+                quote!(OptionalDynamic),
+                vec![],
+                Some(dynamic.key.clone()),
+            ),
+            AssetField::DynamicFileCollection(dynamic, _, _) => (
+                &dynamic.field_ident,
+                quote!(DynamicCollection),
+                vec![],
+                Some(dynamic.key.clone()),
+            ),
+            AssetField::OptionalDynamicFileCollection(dynamic, _, _) => (
+                &dynamic.field_ident,
+                quote!(OptionalDynamicCollection),
+                vec![],
+                Some(dynamic.key.clone()),
+            ),
+        };
+        let field = field_ident.to_string();
+        let key = match key {
+            Some(key) => quote!(Some(#key.to_owned())),
+            None => quote!(None),
+        };
+        quote!(::bevy_asset_loader::asset_collection::AssetBinding {
+            variant: #variant,
+            field: #field.to_owned(),
+            kind: ::bevy_asset_loader::asset_collection::AssetBindingKind::#kind,
+            paths: vec![#(#paths.to_owned()),*],
+            key: #key,
+        })
+    }
+
+    /// Grid layout of a texture atlas field, built together with all other atlases of the collection
+    pub(crate) fn texture_atlas_grid(&self) -> Option<TokenStream> {
+        let texture_atlas = self.texture_atlas()?;
+        let asset_path = texture_atlas.asset_path.clone();
+        let tile_size_x = texture_atlas.tile_size_x;
+        let tile_size_y = texture_atlas.tile_size_y;
+        let columns = texture_atlas.columns;
+        let rows = texture_atlas.rows;
+        let padding_x = texture_atlas.padding_x;
+        let padding_y = texture_atlas.padding_y;
+        let offset_x = texture_atlas.offset_x;
+        let offset_y = texture_atlas.offset_y;
+        let format = match texture_atlas.format {
+            Some(TextureFormatType::R8Unorm) => {
+                quote!(Some(
+                    ::bevy::render::render_resource::TextureFormat::R8Unorm
+                ))
             }
-            AssetField::Folder(asset, _, _) => {
-                let asset_path = asset.asset_path.clone();
-                quote!(#token_stream handles.push(asset_server.load_folder(#asset_path).untyped());)
+            Some(TextureFormatType::Rg8Unorm) => {
+                quote!(Some(
+                    ::bevy::render::render_resource::TextureFormat::Rg8Unorm
+                ))
             }
-            AssetField::OptionalDynamic(dynamic)
-            | AssetField::OptionalDynamicFileCollection(dynamic, _, _) => {
-                let asset_key = dynamic.key.clone();
-                quote!(
-                    #token_stream {
-                        let dynamic_asset = asset_keys.get_asset(#asset_key.into());
-                        if let Some(dynamic_asset) = dynamic_asset {
-                            handles.extend(dynamic_asset.load(&asset_server));
-                        }
-                    }
-                )
+            Some(TextureFormatType::Rgba8Unorm) => {
+                quote!(Some(
+                    ::bevy::render::render_resource::TextureFormat::Rgba8Unorm
+                ))
             }
-            AssetField::Dynamic(dynamic) | AssetField::DynamicFileCollection(dynamic, _, _) => {
-                let asset_key = dynamic.key.clone();
-                quote!(
-                    #token_stream {
-                        let dynamic_asset = asset_keys.get_asset(#asset_key.into()).unwrap_or_else(|| panic!("Failed to get asset for key '{}'", #asset_key));
-                        handles.extend(dynamic_asset.load(&asset_server));
-                    }
-                )
+            Some(TextureFormatType::Rgba8UnormSrgb) => {
+                quote!(Some(
+                    ::bevy::render::render_resource::TextureFormat::Rgba8UnormSrgb
+                ))
             }
-            AssetField::StandardMaterial(BasicAssetField { asset_path, .. })
-            | AssetField::TextureAtlas(TextureAtlasAssetField { asset_path, .. })
-            | AssetField::Image(ImageAssetField { asset_path, .. }) => {
-                let asset_path = asset_path.clone();
-                quote!(#token_stream handles.push(asset_server.load::<::bevy::render::texture::Image>(#asset_path).untyped());)
+            None => quote!(None),
+        };
+        let sampler = match texture_atlas.sampler {
+            Some(SamplerType::Linear) => {
+                quote!(Some(::bevy::render::texture::ImageSampler::linear()))
             }
-            AssetField::Files(assets, _, _) => {
-                let asset_paths = assets.asset_paths.clone();
-                quote!(#token_stream #(handles.push(asset_server.load_untyped(#asset_paths).untyped()));*;)
+            Some(SamplerType::Nearest) => {
+                quote!(Some(::bevy::render::texture::ImageSampler::nearest()))
+            }
+            None => quote!(None),
+        };
+        Some(
+            quote!(::bevy_asset_loader::asset_collection::TextureAtlasGrid {
+                image: asset_server.load(#asset_path),
+                tile_size: ::bevy::math::Vec2::new(#tile_size_x, #tile_size_y),
+                columns: #columns,
+                rows: #rows,
+                padding: ::bevy::math::Vec2::new(#padding_x, #padding_y),
+                offset: ::bevy::math::Vec2::new(#offset_x, #offset_y),
+                format: #format,
+                sampler: #sampler,
+            }),
+        )
+    }
+
+    pub(crate) fn field_ident(&self) -> &Ident {
+        match self {
+            AssetField::Basic(asset)
+            | AssetField::OptionalBasic(asset)
+            | AssetField::RequiredIf(asset, _)
+            | AssetField::Folder(asset, _, _)
+            | AssetField::FolderArray(asset)
+            | AssetField::LoadedFolder(asset)
+            | AssetField::AudioDuration(asset) => &asset.field_ident,
+            AssetField::StandardMaterial(material) => &material.field_ident,
+            AssetField::Files(files, _, _) | AssetField::TextureAtlasFiles(files) => {
+                &files.field_ident
             }
+            AssetField::TextureAtlas(texture_atlas) => &texture_atlas.field_ident,
+            AssetField::SpriteSheet(sprite_sheet) => &sprite_sheet.field_ident,
+            AssetField::Image(image) => &image.field_ident,
+            AssetField::Dynamic(dynamic)
+            | AssetField::OptionalDynamic(dynamic)
+            | AssetField::DynamicFileCollection(dynamic, _, _)
+            | AssetField::OptionalDynamicFileCollection(dynamic, _, _)
+            | AssetField::RuntimePath(dynamic)
+            | AssetField::ManifestKey(dynamic)
+            | AssetField::KeysIntoMap(dynamic) => &dynamic.field_ident,
+            AssetField::FirstDynamic(dynamic) => &dynamic.field_ident,
+            AssetField::Alias(alias) => &alias.field_ident,
+            AssetField::Embedded(asset, _)
+            | AssetField::Checksum(asset, _, _)
+            | AssetField::Locked(asset)
+            | AssetField::Placeholder(asset, _)
+            | AssetField::Settings(asset, _) => asset.field_ident(),
         }
     }
-}
 
-#[derive(Default, Debug)]
-pub(crate) struct AssetBuilder {
-    pub field_ident: Option<Ident>,
-    pub asset_path: Option<String>,
-    pub asset_paths: Option<Vec<String>>,
-    pub is_standard_material: bool,
-    pub is_optional: bool,
-    pub is_collection: bool,
-    pub is_typed: bool,
-    pub is_mapped: bool,
-    pub key: Option<String>,
-    pub tile_size_x: Option<f32>,
-    pub tile_size_y: Option<f32>,
-    pub columns: Option<usize>,
-    pub rows: Option<usize>,
-    pub padding_x: Option<f32>,
-    pub padding_y: Option<f32>,
-    pub offset_x: Option<f32>,
-    pub offset_y: Option<f32>,
-    pub sampler: Option<SamplerType>,
-}
+    /// Whether this field can be `None` because an asset failed to load
+    pub(crate) fn is_optional_file(&self) -> bool {
+        matches!(self, AssetField::RequiredIf(_, _)) || self.optional_file_path().is_some()
+    }
 
-impl AssetBuilder {
-    pub(crate) fn build(self) -> Result<AssetField, Vec<ParseFieldError>> {
-        let mut missing_fields = vec![];
-        if self.tile_size_x.is_none() {
-            missing_fields.push(format!(
-                "{}/{}",
-                TextureAtlasAttribute::ATTRIBUTE_NAME,
-                TextureAtlasAttribute::TILE_SIZE_X
-            ));
+    /// Path of the asset of an optional single file field that is `None` if the file fails to load
+    pub(crate) fn optional_file_path(&self) -> Option<&str> {
+        match self {
+            AssetField::OptionalBasic(asset) => Some(&asset.asset_path),
+            AssetField::Embedded(asset, _)
+            | AssetField::Checksum(asset, _, _)
+            | AssetField::Locked(asset) => asset.optional_file_path(),
+            _ => None,
         }
-        if self.tile_size_y.is_none() {
-            missing_fields.push(format!(
-                "{}/{}",
-                TextureAtlasAttribute::ATTRIBUTE_NAME,
-                TextureAtlasAttribute::TILE_SIZE_Y
-            ));
+    }
+
+    /// The condition of a `required_if` field
+    pub(crate) fn required_if_mut(&mut self) -> Option<&mut RequiredIfCondition> {
+        match self {
+            AssetField::RequiredIf(_, condition) => Some(condition),
+            _ => None,
         }
-        if self.columns.is_none() {
-            missing_fields.push(format!(
-                "{}/{}",
-                TextureAtlasAttribute::ATTRIBUTE_NAME,
-                TextureAtlasAttribute::COLUMNS
-            ));
+    }
+
+    /// Whether this field holds a placeholder while its asset is loading
+    pub(crate) fn has_placeholder(&self) -> bool {
+        match self {
+            AssetField::Placeholder(_, _) => true,
+            AssetField::Locked(asset) => asset.has_placeholder(),
+            _ => false,
         }
-        if self.rows.is_none() {
-            missing_fields.push(format!(
-                "{}/{}",
-                TextureAtlasAttribute::ATTRIBUTE_NAME,
-                TextureAtlasAttribute::ROWS
+    }
+
+    /// Paths of the images this field is processed from when the collection is created
+    pub(crate) fn processed_images(&self) -> Vec<&String> {
+        match self {
+            AssetField::TextureAtlas(texture_atlas) => vec![&texture_atlas.asset_path],
+            AssetField::TextureAtlasFiles(files) => files.asset_paths.iter().collect(),
+            AssetField::Image(image) => vec![&image.asset_path],
+            AssetField::StandardMaterial(material) => material.texture_paths().collect(),
+            AssetField::Embedded(asset, _)
+            | AssetField::Checksum(asset, _, _)
+            | AssetField::Locked(asset)
+            | AssetField::Placeholder(asset, _)
+            | AssetField::Settings(asset, _) => asset.processed_images(),
+            _ => vec![],
+        }
+    }
+
+    /// The folder loaded into this field, if it holds the contents of a single folder
+    pub(crate) fn folder(&self) -> Option<(&BasicAssetField, &Mapped)> {
+        match self {
+            AssetField::Folder(basic, _, mapped) => Some((basic, mapped)),
+            AssetField::Locked(asset) => asset.folder(),
+            _ => None,
+        }
+    }
+
+    /// The path of the folder this field loads, if any
+    ///
+    /// Fields loading the same folder share a single folder load.
+    pub(crate) fn loaded_folder(&self) -> Option<&str> {
+        match self {
+            AssetField::Folder(basic, _, _)
+            | AssetField::FolderArray(basic)
+            | AssetField::LoadedFolder(basic) => Some(&basic.asset_path),
+            AssetField::Checksum(asset, _, _) | AssetField::Locked(asset) => asset.loaded_folder(),
+            _ => None,
+        }
+    }
+
+    /// Load the relative paths of this field from the given asset source
+    ///
+    /// Paths that already name a source (like `https://...` or embedded assets) are kept.
+    pub(crate) fn set_source(&mut self, source: &str) {
+        let set_source = |path: &mut String| {
+            if !path.contains("://") {
+                *path = format!("{source}://{path}");
+            }
+        };
+        match self {
+            AssetField::Basic(basic)
+            | AssetField::OptionalBasic(basic)
+            | AssetField::RequiredIf(basic, _)
+            | AssetField::Folder(basic, _, _)
+            | AssetField::FolderArray(basic)
+            | AssetField::LoadedFolder(basic)
+            | AssetField::AudioDuration(basic) => set_source(&mut basic.asset_path),
+            AssetField::FirstDynamic(dynamic) => {
+                dynamic.fallback_path.iter_mut().for_each(set_source)
+            }
+            AssetField::StandardMaterial(material) => {
+                set_source(&mut material.base_color);
+                material.normal.iter_mut().for_each(set_source);
+                material.metallic_roughness.iter_mut().for_each(set_source);
+            }
+            AssetField::Files(files, _, _) | AssetField::TextureAtlasFiles(files) => {
+                files.asset_paths.iter_mut().for_each(set_source)
+            }
+            AssetField::TextureAtlas(texture_atlas) => set_source(&mut texture_atlas.asset_path),
+            AssetField::Image(image) => set_source(&mut image.asset_path),
+            AssetField::SpriteSheet(sprite_sheet) => {
+                set_source(&mut sprite_sheet.json_path);
+                set_source(&mut sprite_sheet.image_path);
+            }
+            AssetField::Checksum(asset, asset_path, _) => {
+                set_source(asset_path);
+                asset.set_source(source);
+            }
+            AssetField::Locked(asset)
+            | AssetField::Placeholder(asset, _)
+            | AssetField::Settings(asset, _) => asset.set_source(source),
+            AssetField::Embedded(_, _)
+            | AssetField::Dynamic(_)
+            | AssetField::OptionalDynamic(_)
+            | AssetField::DynamicFileCollection(_, _, _)
+            | AssetField::OptionalDynamicFileCollection(_, _, _)
+            | AssetField::RuntimePath(_)
+            | AssetField::ManifestKey(_)
+            | AssetField::KeysIntoMap(_)
+            | AssetField::Alias(_) => {}
+        }
+    }
+
+    /// Whether the paths of this field are only known at run time, like the files of a folder or dynamic assets
+    pub(crate) fn has_runtime_paths(&self) -> bool {
+        match self {
+            AssetField::Folder(_, _, _)
+            | AssetField::FolderArray(_)
+            | AssetField::LoadedFolder(_)
+            | AssetField::Dynamic(_)
+            | AssetField::OptionalDynamic(_)
+            | AssetField::FirstDynamic(_)
+            | AssetField::DynamicFileCollection(_, _, _)
+            | AssetField::OptionalDynamicFileCollection(_, _, _)
+            | AssetField::Alias(_)
+            | AssetField::RuntimePath(_)
+            | AssetField::ManifestKey(_)
+            | AssetField::KeysIntoMap(_) => true,
+            AssetField::Embedded(asset, _)
+            | AssetField::Checksum(asset, _, _)
+            | AssetField::Locked(asset)
+            | AssetField::Placeholder(asset, _)
+            | AssetField::Settings(asset, _) => asset.has_runtime_paths(),
+            AssetField::Basic(_)
+            | AssetField::OptionalBasic(_)
+            | AssetField::RequiredIf(_, _)
+            | AssetField::AudioDuration(_)
+            | AssetField::Files(_, _, _)
+            | AssetField::TextureAtlas(_)
+            | AssetField::TextureAtlasFiles(_)
+            | AssetField::SpriteSheet(_)
+            | AssetField::Image(_)
+            | AssetField::StandardMaterial(_) => false,
+        }
+    }
+
+    /// The texture atlas definition of this field, if any
+    pub(crate) fn texture_atlas(&self) -> Option<&TextureAtlasAssetField> {
+        match self {
+            AssetField::TextureAtlas(texture_atlas) => Some(texture_atlas),
+            AssetField::Embedded(asset, _)
+            | AssetField::Checksum(asset, _, _)
+            | AssetField::Locked(asset) => asset.texture_atlas(),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn texture_atlas_mut(&mut self) -> Option<&mut TextureAtlasAssetField> {
+        match self {
+            AssetField::TextureAtlas(texture_atlas) => Some(texture_atlas),
+            AssetField::Embedded(asset, _)
+            | AssetField::Checksum(asset, _, _)
+            | AssetField::Locked(asset) => asset.texture_atlas_mut(),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn attach_token_stream_for_loading(&self, token_stream: TokenStream) -> TokenStream {
+        match self {
+            AssetField::Embedded(asset, embedded_path) => {
+                let token_stream = quote!(#token_stream {
+                    let embedded = cell.get_resource::<::bevy::asset::io::embedded::EmbeddedAssetRegistry>().expect("Cannot get EmbeddedAssetRegistry");
+                    embedded.insert_asset(
+                        ::std::path::PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/", #embedded_path)),
+                        ::std::path::Path::new(#embedded_path),
+                        include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/", #embedded_path)).as_slice(),
+                    );
+                });
+                asset.attach_token_stream_for_loading(token_stream)
+            }
+            AssetField::Locked(asset) => asset.attach_token_stream_for_loading(token_stream),
+            AssetField::Placeholder(asset, _) => {
+                let AssetField::Basic(basic) = asset.as_ref() else {
+                    unreachable!("Placeholders are only supported for basic assets");
+                };
+                let asset_path = basic.asset_path.clone();
+                quote!(#token_stream
+                    if let Some(mut placeholders) = cell.get_resource_mut::<::bevy_asset_loader::placeholder::PendingPlaceholders>() {
+                        placeholders.load(&asset_server, #asset_path);
+                    }
+                )
+            }
+            AssetField::Settings(asset, settings) => {
+                let AssetField::Basic(basic) = asset.as_ref() else {
+                    unreachable!("Loader settings are only supported for basic assets");
+                };
+                let field_ident = basic.field_ident.clone();
+                let asset_path = basic.asset_path.clone();
+                quote!(#token_stream
+                    handles.push(::bevy_asset_loader::asset_collection::load_field_with_settings(
+                        &asset_server,
+                        #asset_path,
+                        #settings,
+                        |collection: &Self| &collection.#field_ident,
+                    ));
+                )
+            }
+            AssetField::Checksum(asset, asset_path, sha256) => {
+                let token_stream = quote!(#token_stream
+                    if let Some(mut checksums) = cell.get_resource_mut::<bevy_asset_loader::checksum::PendingChecksums>() {
+                        checksums.verify(&asset_server, #asset_path, #sha256);
+                    }
+                );
+                asset.attach_token_stream_for_loading(token_stream)
+            }
+            AssetField::Basic(asset) => {
+                let asset_path = asset.asset_path.clone();
+                quote!(#token_stream
+                    let admitted = match cell.get_resource_mut::<bevy_asset_loader::asset_collection::AssetByteBudget>() {
+                        Some(mut budget) => budget.admit(#asset_path),
+                        None => true,
+                    };
+                    if admitted {
+                        match cell.get_resource_mut::<bevy_asset_loader::asset_collection::AssetLoadThrottle>() {
+                            Some(mut throttle) => throttle.queue(#asset_path),
+                            None => handles.push(asset_server.load_untyped(#asset_path).untyped()),
+                        }
+                    }
+                )
+            }
+            AssetField::OptionalBasic(asset) => {
+                let asset_path = asset.asset_path.clone();
+                quote!(#token_stream
+                    if let Some(mut optional) = cell.get_resource_mut::<::bevy_asset_loader::asset_collection::OptionalAssets>() {
+                        handles.push(optional.load::<Self>(&asset_server, #asset_path));
+                    }
+                )
+            }
+            AssetField::RequiredIf(asset, condition) => {
+                let asset_path = asset.asset_path.clone();
+                let condition_path = condition.asset_path.clone();
+                quote!(#token_stream
+                    if let Some(mut optional) = cell.get_resource_mut::<::bevy_asset_loader::asset_collection::OptionalAssets>() {
+                        handles.push(optional.load_required_if::<Self>(&asset_server, #asset_path, #condition_path));
+                    }
+                )
+            }
+            AssetField::Folder(asset, _, _)
+            | AssetField::FolderArray(asset)
+            | AssetField::LoadedFolder(asset) => {
+                let asset_path = asset.asset_path.clone();
+                quote!(#token_stream handles.push(asset_server.load_folder(#asset_path).untyped());)
+            }
+            AssetField::AudioDuration(asset) => {
+                let asset_path = asset.asset_path.clone();
+                quote!(#token_stream handles.push(asset_server.load::<::bevy::audio::AudioSource>(#asset_path).untyped());)
+            }
+            AssetField::Alias(_) => token_stream,
+            AssetField::RuntimePath(runtime_path) => {
+                let field = runtime_path.field_ident.to_string();
+                let key = runtime_path.key.clone();
+                quote!(#token_stream
+                    match ::bevy_asset_loader::asset_collection::RuntimePaths::resolve(cell.get_resource().as_deref(), #key) {
+                        Some(path) => handles.push(asset_server.load_untyped(path).untyped()),
+                        None => ::bevy_asset_loader::asset_collection::report_missing_runtime_path(&cell, ::std::any::type_name::<Self>(), #field, #key),
+                    }
+                )
+            }
+            AssetField::ManifestKey(manifest_key) => {
+                let key = manifest_key.key.clone();
+                quote!(#token_stream {
+                    const PATH: &str = ::bevy_asset_loader::asset_collection::manifest_path(ASSET_MANIFEST, #key);
+                    handles.push(asset_server.load_untyped(PATH).untyped());
+                })
+            }
+            AssetField::KeysIntoMap(keys) => {
+                let prefix = keys.key.clone();
+                quote!(
+                    #token_stream for (_, dynamic_asset) in asset_keys.iter_assets().filter(|(asset_key, _)| asset_key.starts_with(#prefix)) {
+                        handles.extend(dynamic_asset.load(&asset_server));
+                    }
+                )
+            }
+            AssetField::OptionalDynamic(dynamic)
+            | AssetField::OptionalDynamicFileCollection(dynamic, _, _) => {
+                let asset_key = dynamic.key.clone();
+                quote!(
+                    #token_stream {
+                        let dynamic_asset = asset_keys.get_asset(#asset_key.into());
+                        if let Some(dynamic_asset) = dynamic_asset {
+                            handles.extend(dynamic_asset.load(&asset_server));
+                        }
+                    }
+                )
+            }
+            AssetField::FirstDynamic(dynamic) => {
+                let keys = dynamic.keys.clone();
+                let fallback = match &dynamic.fallback_path {
+                    Some(asset_path) => {
+                        quote!(handles.push(asset_server.load_untyped(#asset_path).untyped()))
+                    }
+                    None => {
+                        let field = dynamic.field_ident.to_string();
+                        let keys = keys.join("', '");
+                        quote!(::bevy_asset_loader::dynamic_asset::report_missing_dynamic_asset(&cell, ::std::any::type_name::<Self>(), #field, #keys))
+                    }
+                };
+                quote!(
+                    #token_stream match [#(#keys),*].into_iter().find_map(|asset_key| asset_keys.get_asset(asset_key)) {
+                        Some(dynamic_asset) => handles.extend(dynamic_asset.load(&asset_server)),
+                        None => #fallback,
+                    }
+                )
+            }
+            AssetField::Dynamic(dynamic) | AssetField::DynamicFileCollection(dynamic, _, _) => {
+                let asset_key = dynamic.key.clone();
+                let field = dynamic.field_ident.to_string();
+                quote!(
+                    #token_stream match asset_keys.get_asset(#asset_key.into()) {
+                        Some(dynamic_asset) => handles.extend(dynamic_asset.load(&asset_server)),
+                        None => ::bevy_asset_loader::dynamic_asset::report_missing_dynamic_asset(&cell, ::std::any::type_name::<Self>(), #field, #asset_key),
+                    }
+                )
+            }
+            AssetField::StandardMaterial(material) => {
+                let texture_paths = material.texture_paths();
+                quote!(#token_stream #(handles.push(asset_server.load::<::bevy::render::texture::Image>(#texture_paths).untyped());)*)
+            }
+            AssetField::TextureAtlas(TextureAtlasAssetField { asset_path, .. })
+            | AssetField::Image(ImageAssetField { asset_path, .. }) => {
+                let asset_path = asset_path.clone();
+                quote!(#token_stream handles.push(asset_server.load::<::bevy::render::texture::Image>(#asset_path).untyped());)
+            }
+            AssetField::TextureAtlasFiles(files) => {
+                let asset_paths = files.asset_paths.clone();
+                quote!(#token_stream #(handles.push(asset_server.load::<::bevy::render::texture::Image>(#asset_paths).untyped());)*)
+            }
+            AssetField::SpriteSheet(sprite_sheet) => {
+                let json_path = sprite_sheet.json_path.clone();
+                let image_path = sprite_sheet.image_path.clone();
+                quote!(#token_stream
+                    handles.push(asset_server.load::<::bevy::render::texture::Image>(#image_path).untyped());
+                    handles.push(asset_server.load::<::bevy_asset_loader::texture_packer::TexturePackerSheet>(#json_path).untyped());
+                )
+            }
+            AssetField::Files(assets, _, _) => {
+                let asset_paths = assets.asset_paths.clone();
+                quote!(#token_stream #(handles.push(asset_server.load_untyped(#asset_paths).untyped()));*;)
+            }
+        }
+    }
+}
+
+/// Textures of a `standard_material` field
+///
+/// Without a `base_color`, the base color texture is loaded from the `path` of the field.
+#[derive(Default, Debug)]
+pub(crate) struct StandardMaterialTextures {
+    pub base_color: Option<String>,
+    pub normal: Option<String>,
+    pub metallic_roughness: Option<String>,
+}
+
+fn atlas_files_attribute() -> String {
+    format!(
+        "{}/{}",
+        TextureAtlasAttribute::ATTRIBUTE_NAME,
+        TextureAtlasAttribute::FILES
+    )
+}
+
+#[derive(Default, Debug)]
+pub(crate) struct AssetBuilder {
+    pub field_ident: Option<Ident>,
+    pub asset_path: Option<String>,
+    pub asset_paths: Option<Vec<String>>,
+    pub standard_material: Option<StandardMaterialTextures>,
+    pub is_optional: bool,
+    pub is_collection: bool,
+    pub is_typed: bool,
+    pub is_mapped: bool,
+    pub is_full_path: bool,
+    pub is_array: bool,
+    pub is_folder: bool,
+    pub with_duration: bool,
+    pub key: Option<String>,
+    pub keys: Option<Vec<String>>,
+    pub tile_size_x: Option<f32>,
+    pub tile_size_y: Option<f32>,
+    pub columns: Option<usize>,
+    pub rows: Option<usize>,
+    pub padding_x: Option<f32>,
+    pub padding_y: Option<f32>,
+    pub offset_x: Option<f32>,
+    pub offset_y: Option<f32>,
+    pub sampler: Option<SamplerFilters>,
+    pub image_max_size: Option<u32>,
+    pub image_address_mode: Option<AddressModes>,
+    pub atlas_sampler: Option<SamplerType>,
+    pub format: Option<TextureFormatType>,
+    pub alias_from: Option<AliasSource>,
+    pub runtime_path: Option<String>,
+    pub manifest_key: Option<String>,
+    pub keys_into_map: Option<String>,
+    pub sprite_sheet_json: Option<String>,
+    pub sprite_sheet_image: Option<String>,
+    pub texture_atlas_files: Option<Vec<String>>,
+    pub embedded: Option<String>,
+    pub sha256: Option<String>,
+    pub extensions: Option<Vec<String>>,
+    pub is_locked: bool,
+    pub placeholder: Option<syn::Path>,
+    pub settings: Option<syn::Path>,
+    pub required_if: Option<Ident>,
+    pub is_option_type: bool,
+    pub is_option_handle_type: bool,
+    pub is_vec_or_map_type: bool,
+}
+
+impl AssetBuilder {
+    pub(crate) fn build(mut self) -> Result<AssetField, Vec<ParseFieldError>> {
+        if let Some(required_if) = self.required_if.take() {
+            let AssetField::OptionalBasic(asset) = self.build()? else {
+                return Err(vec![ParseFieldError::RequiredIfNeedsOptionalPath]);
+            };
+            return Ok(AssetField::RequiredIf(
+                asset,
+                RequiredIfCondition {
+                    field_ident: required_if,
+                    asset_path: String::new(),
+                },
             ));
         }
+        if self.is_locked {
+            if self.settings.is_some() {
+                return Err(vec![ParseFieldError::SettingsNeedsSinglePath]);
+            }
+            self.is_locked = false;
+            let asset = self.build()?;
+            return Ok(AssetField::Locked(Box::new(asset)));
+        }
+        if self.extensions.take().is_some() {
+            // literal paths are checked while parsing the field
+            let asset = self.build()?;
+            if asset.has_runtime_paths() {
+                return Err(vec![ParseFieldError::ExtensionsNeedLiteralPaths]);
+            }
+            return Ok(asset);
+        }
+        if let Some(placeholder) = self.placeholder.take() {
+            let asset = self.build()?;
+            if !matches!(asset, AssetField::Basic(_)) {
+                return Err(vec![ParseFieldError::PlaceholderNeedsSinglePath]);
+            }
+            return Ok(AssetField::Placeholder(Box::new(asset), placeholder));
+        }
+        if let Some(settings) = self.settings.take() {
+            let asset = self.build()?;
+            if !matches!(asset, AssetField::Basic(_)) {
+                return Err(vec![ParseFieldError::SettingsNeedsSinglePath]);
+            }
+            return Ok(AssetField::Settings(Box::new(asset), settings));
+        }
+        if let Some(embedded_path) = self.embedded.take() {
+            if self.asset_path.is_some()
+                || self.asset_paths.is_some()
+                || self.key.is_some()
+                || self.is_collection
+                || self.alias_from.is_some()
+                || self.sprite_sheet_json.is_some()
+                || self.sprite_sheet_image.is_some()
+            {
+                return Err(vec![ParseFieldError::EmbeddedReplacesPath]);
+            }
+            self.asset_path = Some(format!("embedded://{embedded_path}"));
+            let asset = self.build()?;
+            return Ok(AssetField::Embedded(Box::new(asset), embedded_path));
+        }
+        if let Some(base_color) = self
+            .standard_material
+            .as_mut()
+            .and_then(|textures| textures.base_color.take())
+        {
+            if self.asset_path.is_some() {
+                return Err(vec![ParseFieldError::BaseColorReplacesPath]);
+            }
+            self.asset_path = Some(base_color);
+        }
+        if let Some(sha256) = self.sha256.take() {
+            let Some(asset_path) = self.asset_path.clone() else {
+                return Err(vec![ParseFieldError::ChecksumNeedsSinglePath]);
+            };
+            if self.asset_paths.is_some()
+                || self.key.is_some()
+                || self.is_collection
+                || self.alias_from.is_some()
+                || self.sprite_sheet_json.is_some()
+                || self.sprite_sheet_image.is_some()
+            {
+                return Err(vec![ParseFieldError::ChecksumNeedsSinglePath]);
+            }
+            let asset = self.build()?;
+            return Ok(AssetField::Checksum(Box::new(asset), asset_path, sha256));
+        }
+        self.check_exclusive_attributes()?;
+        let texture_atlas = self.has_texture_atlas_grid();
+        if self.sprite_sheet_json.is_some() || self.sprite_sheet_image.is_some() {
+            return self.build_sprite_sheet();
+        }
+        if let Some(asset_paths) = self.texture_atlas_files {
+            return Ok(AssetField::TextureAtlasFiles(MultipleFilesField {
+                field_ident: self.field_ident.unwrap(),
+                asset_paths,
+            }));
+        }
+        if let Some(source) = self.alias_from {
+            return Ok(AssetField::Alias(AliasAssetField {
+                field_ident: self.field_ident.unwrap(),
+                source,
+            }));
+        }
+        if let Some(keys) = self.keys {
+            return Ok(AssetField::FirstDynamic(DynamicKeysField {
+                field_ident: self.field_ident.unwrap(),
+                keys,
+                fallback_path: self.asset_path,
+            }));
+        }
+        if let Some(prefix) = self.keys_into_map {
+            return Ok(AssetField::KeysIntoMap(DynamicAssetField {
+                field_ident: self.field_ident.unwrap(),
+                key: prefix,
+            }));
+        }
+        if let Some(key) = self.runtime_path {
+            return Ok(AssetField::RuntimePath(DynamicAssetField {
+                field_ident: self.field_ident.unwrap(),
+                key,
+            }));
+        }
+        if let Some(key) = self.manifest_key {
+            return Ok(AssetField::ManifestKey(DynamicAssetField {
+                field_ident: self.field_ident.unwrap(),
+                key,
+            }));
+        }
         if self.asset_path.is_none() && self.asset_paths.is_none() && self.key.is_none() {
             return Err(vec![ParseFieldError::NoAttributes]);
         }
         if self.key.is_some()
             && (self.asset_path.is_some()
                 || self.asset_paths.is_some()
-                || missing_fields.len() < 4
+                || texture_atlas
                 || self.padding_x.is_some()
                 || self.padding_y.is_some()
                 || self.offset_x.is_some()
                 || self.offset_y.is_some()
-                || self.is_standard_material)
+                || self.format.is_some()
+                || self.atlas_sampler.is_some()
+                || self.image_max_size.is_some()
+                || self.image_address_mode.is_some()
+                || self.standard_material.is_some())
         {
             return Err(vec![ParseFieldError::KeyAttributeStandsAlone]);
         }
-        if self.is_optional && self.key.is_none() {
-            return Err(vec![ParseFieldError::OnlyDynamicCanBeOptional]);
+        if self.is_optional
+            && self.key.is_none()
+            && (self.asset_path.is_none()
+                || self.is_collection
+                || self.is_folder
+                || self.is_array
+                || self.with_duration
+                || texture_atlas
+                || self.sampler.is_some()
+                || self.image_max_size.is_some()
+                || self.image_address_mode.is_some()
+                || self.standard_material.is_some())
+        {
+            return Err(vec![ParseFieldError::OptionalNeedsKeyOrSinglePath]);
         }
         if self.asset_path.is_some() && self.asset_paths.is_some() {
             return Err(vec![ParseFieldError::PathAndPathsAreExclusive]);
         }
-        if missing_fields.len() == 4 {
+        if self.is_array
+            && (self.asset_path.is_none() || self.key.is_some() || self.is_mapped || texture_atlas)
+        {
+            return Err(vec![ParseFieldError::ArrayOnlyForFolders]);
+        }
+        if self.with_duration {
+            if self.asset_path.is_none()
+                || self.key.is_some()
+                || self.is_collection
+                || self.is_folder
+                || texture_atlas
+                || self.format.is_some()
+                || self.sampler.is_some()
+                || self.image_max_size.is_some()
+                || self.image_address_mode.is_some()
+                || self.atlas_sampler.is_some()
+                || self.standard_material.is_some()
+            {
+                return Err(vec![ParseFieldError::DurationNeedsSinglePath]);
+            }
+            return Ok(AssetField::AudioDuration(BasicAssetField {
+                field_ident: self.field_ident.unwrap(),
+                asset_path: self.asset_path.unwrap(),
+            }));
+        }
+        if self.is_folder {
+            if self.asset_path.is_none()
+                || self.key.is_some()
+                || self.is_collection
+                || texture_atlas
+                || self.format.is_some()
+                || self.sampler.is_some()
+                || self.image_max_size.is_some()
+                || self.image_address_mode.is_some()
+                || self.atlas_sampler.is_some()
+                || self.standard_material.is_some()
+            {
+                return Err(vec![ParseFieldError::FolderNeedsSinglePath]);
+            }
+            return Ok(AssetField::LoadedFolder(BasicAssetField {
+                field_ident: self.field_ident.unwrap(),
+                asset_path: self.asset_path.unwrap(),
+            }));
+        }
+        if !texture_atlas {
+            if self.format.is_some()
+                || self.atlas_sampler.is_some()
+                || self.padding_x.is_some()
+                || self.padding_y.is_some()
+                || self.offset_x.is_some()
+                || self.offset_y.is_some()
+            {
+                return Err(vec![ParseFieldError::MissingAttributes(
+                    self.missing_texture_atlas_attributes(),
+                )]);
+            }
+            if self.is_optional
+                && !(self.is_option_handle_type || self.is_collection && self.is_option_type)
+            {
+                return Err(vec![ParseFieldError::OptionalNeedsOptionType]);
+            }
             if self.key.is_some() {
                 return if self.is_optional {
                     if self.is_collection {
@@ -565,7 +1719,7 @@ impl AssetBuilder {
                                 key: self.key.unwrap(),
                             },
                             self.is_typed.into(),
-                            self.is_mapped.into(),
+                            Mapped::new(self.is_mapped, self.is_full_path),
                         ))
                     } else {
                         Ok(AssetField::OptionalDynamic(DynamicAssetField {
@@ -580,8 +1734,10 @@ impl AssetBuilder {
                             key: self.key.unwrap(),
                         },
                         self.is_typed.into(),
-                        self.is_mapped.into(),
+                        Mapped::new(self.is_mapped, self.is_full_path),
                     ))
+                } else if self.is_vec_or_map_type {
+                    Err(vec![ParseFieldError::VecOrMapNeedsCollection])
                 } else {
                     Ok(AssetField::Dynamic(DynamicAssetField {
                         field_ident: self.field_ident.unwrap(),
@@ -596,9 +1752,15 @@ impl AssetBuilder {
                         asset_paths: self.asset_paths.unwrap(),
                     },
                     self.is_typed.into(),
-                    self.is_mapped.into(),
+                    Mapped::new(self.is_mapped, self.is_full_path),
                 ));
             }
+            if self.is_array {
+                return Ok(AssetField::FolderArray(BasicAssetField {
+                    field_ident: self.field_ident.unwrap(),
+                    asset_path: self.asset_path.unwrap(),
+                }));
+            }
             if self.is_collection {
                 return Ok(AssetField::Folder(
                     BasicAssetField {
@@ -606,25 +1768,42 @@ impl AssetBuilder {
                         asset_path: self.asset_path.unwrap(),
                     },
                     self.is_typed.into(),
-                    self.is_mapped.into(),
+                    Mapped::new(self.is_mapped, self.is_full_path),
                 ));
             }
-            if self.sampler.is_some() {
+            if self.sampler.is_some()
+                || self.image_address_mode.is_some()
+                || self.image_max_size.is_some()
+            {
                 return Ok(AssetField::Image(ImageAssetField {
                     field_ident: self.field_ident.unwrap(),
                     asset_path: self.asset_path.unwrap(),
-                    sampler: self.sampler.unwrap(),
+                    sampler: self.sampler,
+                    address_mode: self.image_address_mode,
+                    max_size: self.image_max_size,
                 }));
             }
+            if self.is_vec_or_map_type {
+                return Err(vec![ParseFieldError::VecOrMapNeedsCollection]);
+            }
             let asset = BasicAssetField {
                 field_ident: self.field_ident.unwrap(),
                 asset_path: self.asset_path.unwrap(),
             };
-            if self.is_standard_material {
-                return Ok(AssetField::StandardMaterial(asset));
+            if let Some(textures) = self.standard_material {
+                return Ok(AssetField::StandardMaterial(StandardMaterialAssetField {
+                    field_ident: asset.field_ident,
+                    base_color: asset.asset_path,
+                    normal: textures.normal,
+                    metallic_roughness: textures.metallic_roughness,
+                }));
+            }
+            if self.is_optional || self.is_option_type {
+                return Ok(AssetField::OptionalBasic(asset));
             }
             return Ok(AssetField::Basic(asset));
         }
+        let missing_fields = self.missing_texture_atlas_attributes();
         if missing_fields.is_empty() {
             return Ok(AssetField::TextureAtlas(TextureAtlasAssetField {
                 field_ident: self.field_ident.unwrap(),
@@ -637,292 +1816,1703 @@ impl AssetBuilder {
                 padding_y: self.padding_y.unwrap_or_default(),
                 offset_x: self.offset_x.unwrap_or_default(),
                 offset_y: self.offset_y.unwrap_or_default(),
+                format: self.format,
+                sampler: self.atlas_sampler,
             }));
         }
         Err(vec![ParseFieldError::MissingAttributes(missing_fields)])
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use proc_macro2::Span;
+    /// Any of the grid attributes selects the texture atlas variant
+    fn has_texture_atlas_grid(&self) -> bool {
+        self.tile_size_x.is_some()
+            || self.tile_size_y.is_some()
+            || self.columns.is_some()
+            || self.rows.is_some()
+    }
+
+    /// Names of all attributes defining the asset of this field
+    fn attributes(&self) -> Vec<String> {
+        let texture_atlas =
+            |attribute| format!("{}/{attribute}", TextureAtlasAttribute::ATTRIBUTE_NAME);
+        let collection = |attribute| format!("{COLLECTION_ATTRIBUTE}/{attribute}");
+        [
+            (self.asset_path.is_some(), PATH_ATTRIBUTE.to_owned()),
+            (self.asset_paths.is_some(), PATHS_ATTRIBUTE.to_owned()),
+            (self.key.is_some(), KEY_ATTRIBUTE.to_owned()),
+            (self.keys.is_some(), KEYS_ATTRIBUTE.to_owned()),
+            (
+                self.keys_into_map.is_some(),
+                KeysIntoMapAttribute::ATTRIBUTE_NAME.to_owned(),
+            ),
+            (self.alias_from.is_some(), ALIAS_FROM_ATTRIBUTE.to_owned()),
+            (
+                self.runtime_path.is_some(),
+                RUNTIME_PATH_ATTRIBUTE.to_owned(),
+            ),
+            (
+                self.manifest_key.is_some(),
+                MANIFEST_KEY_ATTRIBUTE.to_owned(),
+            ),
+            (self.is_optional, OPTIONAL_ATTRIBUTE.to_owned()),
+            (self.is_folder, FOLDER_ATTRIBUTE.to_owned()),
+            (self.with_duration, DURATION_ATTRIBUTE.to_owned()),
+            (self.is_collection, COLLECTION_ATTRIBUTE.to_owned()),
+            (self.is_typed, collection(TYPED_ATTRIBUTE)),
+            (self.is_mapped, collection(MAPPED_ATTRIBUTE)),
+            (self.is_full_path, collection(FULL_PATH_ATTRIBUTE)),
+            (self.is_array, collection(ARRAY_ATTRIBUTE)),
+            (self.texture_atlas_files.is_some(), atlas_files_attribute()),
+            (
+                self.tile_size_x.is_some(),
+                texture_atlas(TextureAtlasAttribute::TILE_SIZE_X),
+            ),
+            (
+                self.tile_size_y.is_some(),
+                texture_atlas(TextureAtlasAttribute::TILE_SIZE_Y),
+            ),
+            (
+                self.columns.is_some(),
+                texture_atlas(TextureAtlasAttribute::COLUMNS),
+            ),
+            (
+                self.rows.is_some(),
+                texture_atlas(TextureAtlasAttribute::ROWS),
+            ),
+            (
+                self.padding_x.is_some(),
+                texture_atlas(TextureAtlasAttribute::PADDING_X),
+            ),
+            (
+                self.padding_y.is_some(),
+                texture_atlas(TextureAtlasAttribute::PADDING_Y),
+            ),
+            (
+                self.offset_x.is_some(),
+                texture_atlas(TextureAtlasAttribute::OFFSET_X),
+            ),
+            (
+                self.offset_y.is_some(),
+                texture_atlas(TextureAtlasAttribute::OFFSET_Y),
+            ),
+            (
+                self.format.is_some(),
+                texture_atlas(TextureAtlasAttribute::FORMAT),
+            ),
+            (
+                self.atlas_sampler.is_some(),
+                texture_atlas(TextureAtlasAttribute::SAMPLER),
+            ),
+            (
+                self.sampler.is_some(),
+                format!(
+                    "{}/{}",
+                    ImageAttribute::ATTRIBUTE_NAME,
+                    ImageAttribute::SAMPLER
+                ),
+            ),
+            (
+                self.image_max_size.is_some(),
+                format!(
+                    "{}/{}",
+                    ImageAttribute::ATTRIBUTE_NAME,
+                    ImageAttribute::MAX_SIZE
+                ),
+            ),
+            (
+                self.image_address_mode.is_some(),
+                format!(
+                    "{}/{}",
+                    ImageAttribute::ATTRIBUTE_NAME,
+                    ImageAttribute::ADDRESS_MODE
+                ),
+            ),
+            (
+                self.standard_material.is_some(),
+                StandardMaterialAttribute::ATTRIBUTE_NAME.to_owned(),
+            ),
+            (
+                self.sprite_sheet_json.is_some() || self.sprite_sheet_image.is_some(),
+                SpriteSheetAttribute::ATTRIBUTE_NAME.to_owned(),
+            ),
+        ]
+        .into_iter()
+        .filter(|(set, _)| *set)
+        .map(|(_, attribute)| attribute)
+        .collect()
+    }
+
+    /// Attributes that define the asset of a field on their own
+    ///
+    /// Each entry lists the attributes it can be combined with and whether the field can be an `Option`.
+    fn exclusive_attributes() -> [(String, &'static [&'static str], bool); 6] {
+        [
+            (atlas_files_attribute(), &[], true),
+            (ALIAS_FROM_ATTRIBUTE.to_owned(), &[], true),
+            (KEYS_ATTRIBUTE.to_owned(), &[PATH_ATTRIBUTE], false),
+            (KeysIntoMapAttribute::ATTRIBUTE_NAME.to_owned(), &[], false),
+            (RUNTIME_PATH_ATTRIBUTE.to_owned(), &[], false),
+            (MANIFEST_KEY_ATTRIBUTE.to_owned(), &[], false),
+        ]
+    }
+
+    /// Report every attribute combined with the first exclusive attribute of the field that it does not allow
+    fn check_exclusive_attributes(&self) -> Result<(), Vec<ParseFieldError>> {
+        let attributes = self.attributes();
+        let Some((attribute, allowed, option_type)) = Self::exclusive_attributes()
+            .into_iter()
+            .find(|(attribute, _, _)| attributes.contains(attribute))
+        else {
+            return Ok(());
+        };
+        let mut errors: Vec<_> = attributes
+            .into_iter()
+            .filter(|other| other != &attribute && !allowed.contains(&other.as_str()))
+            .map(|other| ParseFieldError::ExclusiveAttributes(attribute.clone(), other))
+            .collect();
+        if self.is_option_type && !option_type {
+            errors.push(ParseFieldError::NoOptionType(attribute));
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn missing_texture_atlas_attributes(&self) -> Vec<String> {
+        [
+            (
+                self.tile_size_x.is_none(),
+                TextureAtlasAttribute::TILE_SIZE_X,
+            ),
+            (
+                self.tile_size_y.is_none(),
+                TextureAtlasAttribute::TILE_SIZE_Y,
+            ),
+            (self.columns.is_none(), TextureAtlasAttribute::COLUMNS),
+            (self.rows.is_none(), TextureAtlasAttribute::ROWS),
+        ]
+        .into_iter()
+        .filter(|(missing, _)| *missing)
+        .map(|(_, attribute)| format!("{}/{attribute}", TextureAtlasAttribute::ATTRIBUTE_NAME))
+        .collect()
+    }
+
+    fn build_sprite_sheet(self) -> Result<AssetField, Vec<ParseFieldError>> {
+        if self.asset_path.is_some()
+            || self.asset_paths.is_some()
+            || self.key.is_some()
+            || self.has_texture_atlas_grid()
+            || self.padding_x.is_some()
+            || self.padding_y.is_some()
+            || self.offset_x.is_some()
+            || self.offset_y.is_some()
+            || self.format.is_some()
+            || self.standard_material.is_some()
+            || self.is_collection
+            || self.sampler.is_some()
+            || self.image_max_size.is_some()
+            || self.image_address_mode.is_some()
+            || self.atlas_sampler.is_some()
+        {
+            return Err(vec![ParseFieldError::SpriteSheetStandsAlone]);
+        }
+        let mut missing_fields = vec![];
+        if self.sprite_sheet_json.is_none() {
+            missing_fields.push(format!(
+                "{}/{}",
+                SpriteSheetAttribute::ATTRIBUTE_NAME,
+                SpriteSheetAttribute::JSON
+            ));
+        }
+        if self.sprite_sheet_image.is_none() {
+            missing_fields.push(format!(
+                "{}/{}",
+                SpriteSheetAttribute::ATTRIBUTE_NAME,
+                SpriteSheetAttribute::IMAGE
+            ));
+        }
+        if !missing_fields.is_empty() {
+            return Err(vec![ParseFieldError::MissingAttributes(missing_fields)]);
+        }
+
+        Ok(AssetField::SpriteSheet(SpriteSheetAssetField {
+            field_ident: self.field_ident.unwrap(),
+            json_path: self.sprite_sheet_json.unwrap(),
+            image_path: self.sprite_sheet_image.unwrap(),
+        }))
+    }
+}
+
+/// Paths containing `://` are URLs loaded from a named asset source (e.g. a web asset source for `https`).
+///
+/// The scheme has to be a valid asset source name and something has to follow the separator.
+/// Bevy's asset path parser rejects any further `:`, so URLs cannot contain ports.
+pub(crate) fn is_valid_asset_path(path: &str) -> bool {
+    let Some((scheme, rest)) = path.split_once("://") else {
+        return true;
+    };
+    let mut chars = scheme.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        && !rest.is_empty()
+        && !rest.contains(':')
+}
+
+/// Whether the file of the given asset path has one of the extensions (case insensitive)
+///
+/// Labels are ignored. Extensions can contain dots, e.g. `tar.gz`.
+pub(crate) fn has_allowed_extension(path: &str, extensions: &[String]) -> bool {
+    let file = path.split('#').next().unwrap_or_default().to_lowercase();
+    extensions
+        .iter()
+        .any(|extension| file.ends_with(&format!(".{}", extension.to_lowercase())))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proc_macro2::Span;
+
+    #[test]
+    fn basic_asset() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/image.png".to_owned()),
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid BasicAsset");
+        assert_eq!(
+            asset,
+            AssetField::Basic(BasicAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                asset_path: "some/image.png".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn standard_material() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/image.png".to_owned()),
+            standard_material: Some(StandardMaterialTextures::default()),
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid BasicAsset");
+        assert_eq!(
+            asset,
+            AssetField::StandardMaterial(StandardMaterialAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                base_color: "some/image.png".to_owned(),
+                normal: None,
+                metallic_roughness: None,
+            })
+        );
+    }
+
+    #[test]
+    fn standard_material_with_maps() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            standard_material: Some(StandardMaterialTextures {
+                base_color: Some("textures/albedo.png".to_owned()),
+                normal: Some("textures/normal.png".to_owned()),
+                metallic_roughness: Some("textures/mr.png".to_owned()),
+            }),
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid StandardMaterialAsset");
+        assert_eq!(
+            asset,
+            AssetField::StandardMaterial(StandardMaterialAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                base_color: "textures/albedo.png".to_owned(),
+                normal: Some("textures/normal.png".to_owned()),
+                metallic_roughness: Some("textures/mr.png".to_owned()),
+            })
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            standard_material: Some(StandardMaterialTextures {
+                normal: Some("textures/normal.png".to_owned()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("textures/albedo.png".to_owned()),
+            standard_material: Some(StandardMaterialTextures {
+                base_color: Some("textures/albedo.png".to_owned()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn folder() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/folder".to_owned()),
+            is_collection: true,
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid BasicAsset");
+        assert_eq!(
+            asset,
+            AssetField::Folder(
+                BasicAssetField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    asset_path: "some/folder".to_owned()
+                },
+                Typed::No,
+                Mapped::No
+            )
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/folder".to_owned()),
+            is_collection: true,
+            is_typed: true,
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid BasicAsset");
+        assert_eq!(
+            asset,
+            AssetField::Folder(
+                BasicAssetField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    asset_path: "some/folder".to_owned()
+                },
+                Typed::Yes,
+                Mapped::No
+            )
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/folder".to_owned()),
+            is_collection: true,
+            is_mapped: true,
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid BasicAsset");
+        assert_eq!(
+            asset,
+            AssetField::Folder(
+                BasicAssetField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    asset_path: "some/folder".to_owned()
+                },
+                Typed::No,
+                Mapped::Yes
+            )
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/folder".to_owned()),
+            is_collection: true,
+            is_typed: true,
+            is_mapped: true,
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid BasicAsset");
+        assert_eq!(
+            asset,
+            AssetField::Folder(
+                BasicAssetField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    asset_path: "some/folder".to_owned()
+                },
+                Typed::Yes,
+                Mapped::Yes
+            )
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/folder".to_owned()),
+            is_collection: true,
+            is_typed: true,
+            is_mapped: true,
+            is_full_path: true,
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid BasicAsset");
+        assert_eq!(
+            asset,
+            AssetField::Folder(
+                BasicAssetField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    asset_path: "some/folder".to_owned()
+                },
+                Typed::Yes,
+                Mapped::FullPath
+            )
+        );
+    }
+
+    #[test]
+    fn dynamic_asset() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            key: Some("some.asset.key".to_owned()),
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid DynamicAsset");
+        assert_eq!(
+            asset,
+            AssetField::Dynamic(DynamicAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                key: "some.asset.key".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn first_dynamic_asset() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            keys: Some(vec!["hd".to_owned(), "sd".to_owned()]),
+            asset_path: Some("images/default.png".to_owned()),
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid FirstDynamic asset");
+        assert_eq!(
+            asset,
+            AssetField::FirstDynamic(DynamicKeysField {
+                field_ident: Ident::new("test", Span::call_site()),
+                keys: vec!["hd".to_owned(), "sd".to_owned()],
+                fallback_path: Some("images/default.png".to_owned()),
+            })
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            keys: Some(vec!["hd".to_owned()]),
+            key: Some("sd".to_owned()),
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            keys: Some(vec!["hd".to_owned()]),
+            asset_paths: Some(vec!["images/default.png".to_owned()]),
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn paths_and_path_exclusive() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some.asset".to_owned()),
+            asset_paths: Some(vec!["some.asset".to_owned()]),
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect_err("Should be pasing error");
+        assert!(variant_eq(
+            asset.get(0).unwrap(),
+            &ParseFieldError::PathAndPathsAreExclusive
+        ));
+    }
+
+    #[test]
+    fn multiple_files() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_paths: Some(vec!["some.asset".to_owned()]),
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid Files asset");
+        assert_eq!(
+            asset,
+            AssetField::Files(
+                MultipleFilesField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    asset_paths: vec!["some.asset".to_owned()]
+                },
+                Typed::No,
+                Mapped::No
+            )
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_paths: Some(vec!["some.asset".to_owned()]),
+            is_typed: true,
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid Files asset");
+        assert_eq!(
+            asset,
+            AssetField::Files(
+                MultipleFilesField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    asset_paths: vec!["some.asset".to_owned()]
+                },
+                Typed::Yes,
+                Mapped::No
+            )
+        );
+    }
+
+    #[test]
+    fn texture_atlas() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/folder".to_owned()),
+            tile_size_x: Some(100.),
+            tile_size_y: Some(50.),
+            columns: Some(10),
+            rows: Some(5),
+            padding_x: Some(2.),
+            offset_y: Some(3.),
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid TextureAtlasAsset");
+        assert_eq!(
+            asset,
+            AssetField::TextureAtlas(TextureAtlasAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                asset_path: "some/folder".to_owned(),
+                tile_size_x: 100.0,
+                tile_size_y: 50.0,
+                columns: 10,
+                rows: 5,
+                padding_x: 2.0,
+                padding_y: 0.0,
+                offset_x: 0.0,
+                offset_y: 3.0,
+                format: None,
+                sampler: None,
+            })
+        );
+    }
+
+    #[test]
+    fn image_asset() {
+        let builder_linear = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/image.png".to_owned()),
+            sampler: Some(SamplerType::Linear.into()),
+            ..Default::default()
+        };
+
+        let builder_nearest = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/image.png".to_owned()),
+            sampler: Some(SamplerType::Nearest.into()),
+            ..Default::default()
+        };
+
+        let asset_linear = builder_linear
+            .build()
+            .expect("This should be a valid ImageAsset");
+        let asset_nearest = builder_nearest
+            .build()
+            .expect("This should be a valid ImageAsset");
+
+        assert_eq!(
+            asset_linear,
+            AssetField::Image(ImageAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                asset_path: "some/image.png".to_owned(),
+                sampler: Some(SamplerType::Linear.into()),
+                address_mode: None,
+                max_size: None
+            })
+        );
+        assert_eq!(
+            asset_nearest,
+            AssetField::Image(ImageAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                asset_path: "some/image.png".to_owned(),
+                sampler: Some(SamplerType::Nearest.into()),
+                address_mode: None,
+                max_size: None
+            })
+        );
+    }
+
+    #[test]
+    fn image_max_size() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/image.png".to_owned()),
+            image_max_size: Some(1024),
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid ImageAsset");
+        assert_eq!(
+            asset,
+            AssetField::Image(ImageAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                asset_path: "some/image.png".to_owned(),
+                sampler: None,
+                address_mode: None,
+                max_size: Some(1024)
+            })
+        );
+    }
+
+    #[test]
+    fn image_address_mode() {
+        assert_eq!(
+            AddressModeType::try_from("mirror".to_owned()),
+            Ok(AddressModeType::Mirror)
+        );
+        assert!(AddressModeType::try_from("wrap".to_owned()).is_err());
+        assert_eq!(
+            AddressModes::from(AddressModeType::Clamp),
+            AddressModes {
+                u: AddressModeType::Clamp,
+                v: AddressModeType::Clamp,
+                w: AddressModeType::Clamp,
+            }
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/image.png".to_owned()),
+            sampler: Some(SamplerType::Nearest.into()),
+            image_address_mode: Some(AddressModeType::Repeat.into()),
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid ImageAsset");
+        assert_eq!(
+            asset,
+            AssetField::Image(ImageAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                asset_path: "some/image.png".to_owned(),
+                sampler: Some(SamplerType::Nearest.into()),
+                address_mode: Some(AddressModeType::Repeat.into()),
+                max_size: None
+            })
+        );
+    }
+
+    #[test]
+    fn image_sampler_filters() {
+        let filters = SamplerFilters {
+            min: SamplerType::Linear,
+            mag: SamplerType::Nearest,
+            mipmap: SamplerType::Linear,
+        };
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/image.png".to_owned()),
+            sampler: Some(filters),
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid ImageAsset");
+        assert_eq!(
+            asset,
+            AssetField::Image(ImageAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                asset_path: "some/image.png".to_owned(),
+                sampler: Some(filters),
+                address_mode: None,
+                max_size: None
+            })
+        );
+        assert_eq!(
+            SamplerFilters::from(SamplerType::Nearest),
+            SamplerFilters {
+                min: SamplerType::Nearest,
+                mag: SamplerType::Nearest,
+                mipmap: SamplerType::Nearest,
+            }
+        );
+    }
+
+    #[test]
+    fn texture_atlas_format_requires_grid() {
+        assert_eq!(
+            TextureFormatType::try_from("r8unorm".to_owned()),
+            Ok(TextureFormatType::R8Unorm)
+        );
+        assert!(TextureFormatType::try_from("bc7_rgba_unorm".to_owned()).is_err());
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/image.png".to_owned()),
+            format: Some(TextureFormatType::R8Unorm),
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn texture_atlas_reports_missing_grid_attributes() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/image.png".to_owned()),
+            tile_size_x: Some(16.),
+            rows: Some(2),
+            ..Default::default()
+        };
+        let Err(errors) = builder.build() else {
+            panic!("Expected an error for an incomplete grid");
+        };
+        assert!(matches!(
+            errors.as_slice(),
+            [ParseFieldError::MissingAttributes(missing)]
+                if missing == &["texture_atlas/tile_size_y", "texture_atlas/columns"]
+        ));
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/image.png".to_owned()),
+            padding_x: Some(2.),
+            ..Default::default()
+        };
+        let Err(errors) = builder.build() else {
+            panic!("Expected an error for padding without a grid");
+        };
+        assert!(matches!(
+            errors.as_slice(),
+            [ParseFieldError::MissingAttributes(missing)] if missing.len() == 4
+        ));
+    }
+
+    #[test]
+    fn optional_needs_option_type() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("audio/voice.ogg".to_owned()),
+            is_optional: true,
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build(),
+            Err(errors) if matches!(errors[..], [ParseFieldError::OptionalNeedsOptionType])
+        ));
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            key: Some("voices".to_owned()),
+            is_collection: true,
+            is_optional: true,
+            is_option_type: true,
+            is_option_handle_type: true,
+            ..Default::default()
+        };
+        assert!(builder.build().is_ok());
+    }
+
+    #[test]
+    fn texture_atlas_files() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            texture_atlas_files: Some(vec!["a.png".to_owned(), "b.png".to_owned()]),
+            ..Default::default()
+        };
+        assert_eq!(
+            builder
+                .build()
+                .expect("This should be a valid TextureAtlasFiles asset"),
+            AssetField::TextureAtlasFiles(MultipleFilesField {
+                field_ident: Ident::new("test", Span::call_site()),
+                asset_paths: vec!["a.png".to_owned(), "b.png".to_owned()]
+            })
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            texture_atlas_files: Some(vec!["a.png".to_owned()]),
+            columns: Some(2),
+            rows: Some(1),
+            ..Default::default()
+        };
+        let errors = builder
+            .build()
+            .expect_err("Files cannot be combined with a grid");
+        assert_eq!(
+            conflicting_attributes(errors, "texture_atlas/files"),
+            vec!["texture_atlas/columns", "texture_atlas/rows"]
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            texture_atlas_files: Some(vec!["a.png".to_owned()]),
+            manifest_key: Some("atlas".to_owned()),
+            is_optional: true,
+            with_duration: true,
+            ..Default::default()
+        };
+        let errors = builder
+            .build()
+            .expect_err("Files cannot be combined with other asset defining attributes");
+        assert_eq!(
+            conflicting_attributes(errors, "texture_atlas/files"),
+            vec!["manifest_key", "optional", "duration"]
+        );
+    }
+
+    #[test]
+    fn exclusive_attributes() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            alias_from: Some(AliasSource {
+                resource: syn::parse_quote!(MyResource),
+                members: vec![syn::parse_quote!(handle)],
+            }),
+            is_folder: true,
+            with_duration: true,
+            ..Default::default()
+        };
+        let errors = builder
+            .build()
+            .expect_err("An alias cannot be combined with other asset defining attributes");
+        assert_eq!(
+            conflicting_attributes(errors, "alias_from"),
+            vec!["folder", "duration"]
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            keys: Some(vec!["a".to_owned(), "b".to_owned()]),
+            asset_path: Some("fallback.png".to_owned()),
+            padding_x: Some(2.),
+            ..Default::default()
+        };
+        let errors = builder
+            .build()
+            .expect_err("Keys can only be combined with a fallback path");
+        assert_eq!(
+            conflicting_attributes(errors, "keys"),
+            vec!["texture_atlas/padding_x"]
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            runtime_path: Some("model".to_owned()),
+            manifest_key: Some("model".to_owned()),
+            is_typed: true,
+            ..Default::default()
+        };
+        let errors = builder
+            .build()
+            .expect_err("A runtime path cannot be combined with other asset defining attributes");
+        assert_eq!(
+            conflicting_attributes(errors, "runtime_path"),
+            vec!["manifest_key", "collection/typed"]
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            manifest_key: Some("model".to_owned()),
+            is_option_type: true,
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build().unwrap_err().as_slice(),
+            [ParseFieldError::NoOptionType(attribute)] if attribute == "manifest_key"
+        ));
+    }
+
+    fn conflicting_attributes(errors: Vec<ParseFieldError>, expected: &str) -> Vec<String> {
+        errors
+            .into_iter()
+            .map(|error| match error {
+                ParseFieldError::ExclusiveAttributes(attribute, other) => {
+                    assert_eq!(attribute, expected);
+                    other
+                }
+                error => panic!("Expected conflicting attributes, got {error:?}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn processed_images() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            texture_atlas_files: Some(vec!["a.png".to_owned(), "b.png".to_owned()]),
+            ..Default::default()
+        };
+        let asset = builder.build().expect("This should be a valid asset");
+        assert_eq!(asset.processed_images(), vec!["a.png", "b.png"]);
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("a.png".to_owned()),
+            ..Default::default()
+        };
+        let asset = builder.build().expect("This should be a valid asset");
+        assert!(asset.processed_images().is_empty());
+    }
+
+    #[test]
+    fn texture_atlas_sampler() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/image.png".to_owned()),
+            tile_size_x: Some(100.),
+            tile_size_y: Some(50.),
+            columns: Some(10),
+            rows: Some(5),
+            atlas_sampler: Some(SamplerType::Nearest),
+            ..Default::default()
+        };
+        let Ok(AssetField::TextureAtlas(atlas)) = builder.build() else {
+            panic!("This should be a valid TextureAtlasAsset");
+        };
+        assert_eq!(atlas.sampler, Some(SamplerType::Nearest));
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/image.png".to_owned()),
+            atlas_sampler: Some(SamplerType::Nearest),
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn embedded_asset() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            embedded: Some("images/player.png".to_owned()),
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid embedded asset");
+        assert_eq!(
+            asset,
+            AssetField::Embedded(
+                Box::new(AssetField::Basic(BasicAssetField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    asset_path: "embedded://images/player.png".to_owned(),
+                })),
+                "images/player.png".to_owned()
+            )
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("images/player.png".to_owned()),
+            embedded: Some("images/player.png".to_owned()),
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn checksum() {
+        let sha256 = "e0ec9c57617b4a8383a497a35197d7125c651828922326eab59ff798bfb5a335";
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("audio/plop.ogg".to_owned()),
+            sha256: Some(sha256.to_owned()),
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid asset with checksum");
+        assert_eq!(
+            asset,
+            AssetField::Checksum(
+                Box::new(AssetField::Basic(BasicAssetField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    asset_path: "audio/plop.ogg".to_owned(),
+                })),
+                "audio/plop.ogg".to_owned(),
+                sha256.to_owned()
+            )
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("audio".to_owned()),
+            is_collection: true,
+            sha256: Some(sha256.to_owned()),
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn extensions() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("images/player.png".to_owned()),
+            extensions: Some(vec!["png".to_owned(), "ktx2".to_owned()]),
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid asset with extensions");
+        assert_eq!(
+            asset,
+            AssetField::Basic(BasicAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                asset_path: "images/player.png".to_owned(),
+            })
+        );
+
+        for builder in [
+            AssetBuilder {
+                field_ident: Some(Ident::new("test", Span::call_site())),
+                key: Some("image".to_owned()),
+                extensions: Some(vec!["png".to_owned()]),
+                ..Default::default()
+            },
+            AssetBuilder {
+                field_ident: Some(Ident::new("test", Span::call_site())),
+                asset_path: Some("images".to_owned()),
+                is_collection: true,
+                extensions: Some(vec!["png".to_owned()]),
+                ..Default::default()
+            },
+        ] {
+            assert!(matches!(
+                builder.build(),
+                Err(errors) if matches!(errors[..], [ParseFieldError::ExtensionsNeedLiteralPaths])
+            ));
+        }
+
+        let extensions = vec!["png".to_owned(), "tar.gz".to_owned()];
+        assert!(has_allowed_extension("images/player.png", &extensions));
+        assert!(has_allowed_extension("images/PLAYER.PNG", &extensions));
+        assert!(has_allowed_extension(
+            "https://example.com/a.png",
+            &extensions
+        ));
+        assert!(has_allowed_extension("levels/one.tar.gz", &extensions));
+        assert!(!has_allowed_extension(
+            "models/tree.gltf#Scene0",
+            &extensions
+        ));
+        assert!(!has_allowed_extension("images/png", &extensions));
+        assert!(!has_allowed_extension("images/player.jpg", &extensions));
+    }
+
+    #[test]
+    fn source() {
+        let mut asset = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_paths: Some(vec![
+                "images/player.png".to_owned(),
+                "https://example.com/tree.png".to_owned(),
+            ]),
+            ..Default::default()
+        }
+        .build()
+        .expect("This should be a valid multiple files asset");
+        asset.set_source("pack");
+
+        assert_eq!(
+            asset,
+            AssetField::Files(
+                MultipleFilesField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    asset_paths: vec![
+                        "pack://images/player.png".to_owned(),
+                        "https://example.com/tree.png".to_owned()
+                    ],
+                },
+                Typed::No,
+                Mapped::No
+            )
+        );
+    }
+
+    #[test]
+    fn labeled_asset_path() {
+        let mut asset = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("models/player.glb#Mesh0/Primitive0".to_owned()),
+            ..Default::default()
+        }
+        .build()
+        .expect("This should be a valid BasicAsset");
+        asset.set_source("pack");
+
+        assert_eq!(
+            asset,
+            AssetField::Basic(BasicAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                asset_path: "pack://models/player.glb#Mesh0/Primitive0".to_owned(),
+            })
+        );
+        assert!(is_valid_asset_path("pack://models/player.glb#Scene0"));
+    }
+
+    #[test]
+    fn optional_file() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("audio/voice.ogg".to_owned()),
+            is_option_type: true,
+            is_option_handle_type: true,
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid optional BasicAsset");
+        assert_eq!(
+            asset,
+            AssetField::OptionalBasic(BasicAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                asset_path: "audio/voice.ogg".to_owned()
+            })
+        );
+        assert!(asset.is_optional_file());
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("audio/voice.ogg".to_owned()),
+            is_optional: true,
+            is_option_type: true,
+            is_option_handle_type: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            builder
+                .build()
+                .expect("This should be a valid optional BasicAsset"),
+            AssetField::OptionalBasic(BasicAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                asset_path: "audio/voice.ogg".to_owned()
+            })
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("audio".to_owned()),
+            is_collection: true,
+            is_optional: true,
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build(),
+            Err(errors) if matches!(errors[..], [ParseFieldError::OptionalNeedsKeyOrSinglePath])
+        ));
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            key: Some("voice".to_owned()),
+            is_optional: true,
+            is_option_type: true,
+            is_option_handle_type: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            builder
+                .build()
+                .expect("This should be a valid optional dynamic asset"),
+            AssetField::OptionalDynamic(DynamicAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                key: "voice".to_owned()
+            })
+        );
+    }
+
+    #[test]
+    fn required_if() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("audio/voice.ogg".to_owned()),
+            required_if: Some(Ident::new("base", Span::call_site())),
+            is_option_type: true,
+            is_option_handle_type: true,
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid required_if asset");
+        assert_eq!(
+            asset,
+            AssetField::RequiredIf(
+                BasicAssetField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    asset_path: "audio/voice.ogg".to_owned()
+                },
+                RequiredIfCondition {
+                    field_ident: Ident::new("base", Span::call_site()),
+                    asset_path: String::new()
+                }
+            )
+        );
+        assert!(asset.is_optional_file());
+        assert_eq!(asset.optional_file_path(), None);
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("audio/voice.ogg".to_owned()),
+            required_if: Some(Ident::new("base", Span::call_site())),
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build(),
+            Err(errors) if matches!(errors[..], [ParseFieldError::RequiredIfNeedsOptionalPath])
+        ));
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            key: Some("voice".to_owned()),
+            required_if: Some(Ident::new("base", Span::call_site())),
+            is_optional: true,
+            is_option_type: true,
+            is_option_handle_type: true,
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build(),
+            Err(errors) if matches!(errors[..], [ParseFieldError::RequiredIfNeedsOptionalPath])
+        ));
+    }
+
+    #[test]
+    fn vec_or_map_needs_collection() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("enemies".to_owned()),
+            is_vec_or_map_type: true,
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build(),
+            Err(errors) if matches!(errors[..], [ParseFieldError::VecOrMapNeedsCollection])
+        ));
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            key: Some("enemies".to_owned()),
+            is_vec_or_map_type: true,
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build(),
+            Err(errors) if matches!(errors[..], [ParseFieldError::VecOrMapNeedsCollection])
+        ));
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("enemies".to_owned()),
+            is_collection: true,
+            is_typed: true,
+            is_vec_or_map_type: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            builder
+                .build()
+                .expect("This should be a valid typed folder"),
+            AssetField::Folder(
+                BasicAssetField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    asset_path: "enemies".to_owned(),
+                },
+                Typed::Yes,
+                Mapped::No
+            )
+        );
+    }
+
+    #[test]
+    fn placeholder() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("audio/music.ogg".to_owned()),
+            placeholder: Some(syn::parse_quote!(silence)),
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid BasicAsset with placeholder");
+        assert_eq!(
+            asset,
+            AssetField::Placeholder(
+                Box::new(AssetField::Basic(BasicAssetField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    asset_path: "audio/music.ogg".to_owned()
+                })),
+                syn::parse_quote!(silence)
+            )
+        );
+        assert!(asset.has_placeholder());
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("audio".to_owned()),
+            is_collection: true,
+            placeholder: Some(syn::parse_quote!(silence)),
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build(),
+            Err(errors) if matches!(errors[..], [ParseFieldError::PlaceholderNeedsSinglePath])
+        ));
+    }
+
+    #[test]
+    fn settings() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("models/model.glb#Mesh0/Primitive0".to_owned()),
+            settings: Some(syn::parse_quote!(gltf_settings)),
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("This should be a valid BasicAsset with loader settings");
+        assert_eq!(
+            asset,
+            AssetField::Settings(
+                Box::new(AssetField::Basic(BasicAssetField {
+                    field_ident: Ident::new("test", Span::call_site()),
+                    asset_path: "models/model.glb#Mesh0/Primitive0".to_owned()
+                })),
+                syn::parse_quote!(gltf_settings)
+            )
+        );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("models".to_owned()),
+            is_collection: true,
+            settings: Some(syn::parse_quote!(gltf_settings)),
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build(),
+            Err(errors) if matches!(errors[..], [ParseFieldError::SettingsNeedsSinglePath])
+        ));
+    }
 
     #[test]
-    fn basic_asset() {
+    fn locked() {
         let builder = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
             asset_path: Some("some/image.png".to_owned()),
+            is_locked: true,
             ..Default::default()
         };
 
-        let asset = builder.build().expect("This should be a valid BasicAsset");
+        let asset = builder
+            .build()
+            .expect("This should be a valid locked BasicAsset");
         assert_eq!(
             asset,
-            AssetField::Basic(BasicAssetField {
+            AssetField::Locked(Box::new(AssetField::Basic(BasicAssetField {
                 field_ident: Ident::new("test", Span::call_site()),
                 asset_path: "some/image.png".to_owned()
-            })
+            })))
         );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            is_locked: true,
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
     }
 
     #[test]
-    fn standard_material() {
+    fn alias() {
+        let source = AliasSource {
+            resource: syn::parse_quote!(MyResource),
+            members: vec![syn::parse_quote!(handle)],
+        };
         let builder = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some/image.png".to_owned()),
-            is_standard_material: true,
+            alias_from: Some(source.clone()),
             ..Default::default()
         };
 
-        let asset = builder.build().expect("This should be a valid BasicAsset");
+        let asset = builder.build().expect("This should be a valid Alias");
         assert_eq!(
             asset,
-            AssetField::StandardMaterial(BasicAssetField {
+            AssetField::Alias(AliasAssetField {
                 field_ident: Ident::new("test", Span::call_site()),
-                asset_path: "some/image.png".to_owned()
+                source: source.clone(),
             })
         );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("some/image.png".to_owned()),
+            alias_from: Some(source),
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
     }
 
     #[test]
-    fn folder() {
+    fn runtime_path() {
         let builder = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some/folder".to_owned()),
-            is_collection: true,
+            runtime_path: Some("input_model".to_owned()),
             ..Default::default()
         };
 
-        let asset = builder.build().expect("This should be a valid BasicAsset");
+        let asset = builder.build().expect("This should be a valid RuntimePath");
         assert_eq!(
             asset,
-            AssetField::Folder(
-                BasicAssetField {
-                    field_ident: Ident::new("test", Span::call_site()),
-                    asset_path: "some/folder".to_owned()
-                },
-                Typed::No,
-                Mapped::No
-            )
+            AssetField::RuntimePath(DynamicAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                key: "input_model".to_owned(),
+            })
         );
 
         let builder = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some/folder".to_owned()),
-            is_collection: true,
-            is_typed: true,
+            asset_path: Some("some/model.gltf".to_owned()),
+            runtime_path: Some("input_model".to_owned()),
             ..Default::default()
         };
+        assert!(builder.build().is_err());
+    }
 
-        let asset = builder.build().expect("This should be a valid BasicAsset");
-        assert_eq!(
-            asset,
-            AssetField::Folder(
-                BasicAssetField {
-                    field_ident: Ident::new("test", Span::call_site()),
-                    asset_path: "some/folder".to_owned()
-                },
-                Typed::Yes,
-                Mapped::No
-            )
-        );
-
+    #[test]
+    fn manifest_key() {
         let builder = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some/folder".to_owned()),
-            is_collection: true,
-            is_mapped: true,
+            manifest_key: Some("player_sprite".to_owned()),
             ..Default::default()
         };
 
-        let asset = builder.build().expect("This should be a valid BasicAsset");
+        let asset = builder.build().expect("This should be a valid ManifestKey");
         assert_eq!(
             asset,
-            AssetField::Folder(
-                BasicAssetField {
-                    field_ident: Ident::new("test", Span::call_site()),
-                    asset_path: "some/folder".to_owned()
-                },
-                Typed::No,
-                Mapped::Yes
-            )
+            AssetField::ManifestKey(DynamicAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                key: "player_sprite".to_owned(),
+            })
         );
 
         let builder = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some/folder".to_owned()),
-            is_collection: true,
-            is_typed: true,
-            is_mapped: true,
+            manifest_key: Some("player_sprite".to_owned()),
+            runtime_path: Some("player_sprite".to_owned()),
             ..Default::default()
         };
+        assert!(builder.build().is_err());
+    }
 
-        let asset = builder.build().expect("This should be a valid BasicAsset");
+    #[test]
+    fn keys_into_map() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            keys_into_map: Some("enemy.".to_owned()),
+            ..Default::default()
+        };
+
+        let asset = builder.build().expect("This should be a valid KeysIntoMap");
         assert_eq!(
             asset,
-            AssetField::Folder(
-                BasicAssetField {
-                    field_ident: Ident::new("test", Span::call_site()),
-                    asset_path: "some/folder".to_owned()
-                },
-                Typed::Yes,
-                Mapped::Yes
-            )
+            AssetField::KeysIntoMap(DynamicAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                key: "enemy.".to_owned(),
+            })
         );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            keys_into_map: Some("enemy.".to_owned()),
+            key: Some("enemy.orc".to_owned()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            builder.build().unwrap_err().as_slice(),
+            [ParseFieldError::ExclusiveAttributes(attribute, other)]
+                if attribute == "keys_into_map" && other == "key"
+        ));
     }
 
     #[test]
-    fn dynamic_asset() {
+    fn loaded_folder() {
         let builder = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
-            key: Some("some.asset.key".to_owned()),
+            asset_path: Some("some/folder".to_owned()),
+            is_folder: true,
             ..Default::default()
         };
 
         let asset = builder
             .build()
-            .expect("This should be a valid DynamicAsset");
+            .expect("This should be a valid LoadedFolder");
         assert_eq!(
             asset,
-            AssetField::Dynamic(DynamicAssetField {
+            AssetField::LoadedFolder(BasicAssetField {
                 field_ident: Ident::new("test", Span::call_site()),
-                key: "some.asset.key".to_owned()
+                asset_path: "some/folder".to_owned()
             })
         );
-    }
+        assert_eq!(asset.loaded_folder(), Some("some/folder"));
 
-    #[test]
-    fn paths_and_path_exclusive() {
         let builder = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some.asset".to_owned()),
-            asset_paths: Some(vec!["some.asset".to_owned()]),
+            asset_path: Some("some/folder".to_owned()),
+            is_folder: true,
+            is_collection: true,
             ..Default::default()
         };
+        assert!(builder.build().is_err());
 
-        let asset = builder.build().expect_err("Should be pasing error");
-        assert!(variant_eq(
-            asset.get(0).unwrap(),
-            &ParseFieldError::PathAndPathsAreExclusive
-        ));
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            key: Some("folder".to_owned()),
+            is_folder: true,
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
     }
 
     #[test]
-    fn multiple_files() {
+    fn audio_duration() {
         let builder = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_paths: Some(vec!["some.asset".to_owned()]),
+            asset_path: Some("audio/plop.ogg".to_owned()),
+            with_duration: true,
             ..Default::default()
         };
 
-        let asset = builder.build().expect("This should be a valid Files asset");
+        let asset = builder
+            .build()
+            .expect("This should be a valid AudioDuration");
         assert_eq!(
             asset,
-            AssetField::Files(
-                MultipleFilesField {
-                    field_ident: Ident::new("test", Span::call_site()),
-                    asset_paths: vec!["some.asset".to_owned()]
-                },
-                Typed::No,
-                Mapped::No
-            )
+            AssetField::AudioDuration(BasicAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                asset_path: "audio/plop.ogg".to_owned()
+            })
         );
 
         let builder = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_paths: Some(vec!["some.asset".to_owned()]),
-            is_typed: true,
+            asset_path: Some("audio".to_owned()),
+            with_duration: true,
+            is_folder: true,
             ..Default::default()
         };
-
-        let asset = builder.build().expect("This should be a valid Files asset");
-        assert_eq!(
-            asset,
-            AssetField::Files(
-                MultipleFilesField {
-                    field_ident: Ident::new("test", Span::call_site()),
-                    asset_paths: vec!["some.asset".to_owned()]
-                },
-                Typed::Yes,
-                Mapped::No
-            )
-        );
+        assert!(builder.build().is_err());
     }
 
     #[test]
-    fn texture_atlas() {
+    fn folder_array() {
         let builder = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
             asset_path: Some("some/folder".to_owned()),
-            tile_size_x: Some(100.),
-            tile_size_y: Some(50.),
-            columns: Some(10),
-            rows: Some(5),
-            padding_x: Some(2.),
-            offset_y: Some(3.),
+            is_collection: true,
+            is_array: true,
             ..Default::default()
         };
 
-        let asset = builder
-            .build()
-            .expect("This should be a valid TextureAtlasAsset");
+        let asset = builder.build().expect("This should be a valid FolderArray");
         assert_eq!(
             asset,
-            AssetField::TextureAtlas(TextureAtlasAssetField {
+            AssetField::FolderArray(BasicAssetField {
                 field_ident: Ident::new("test", Span::call_site()),
-                asset_path: "some/folder".to_owned(),
-                tile_size_x: 100.0,
-                tile_size_y: 50.0,
-                columns: 10,
-                rows: 5,
-                padding_x: 2.0,
-                padding_y: 0.0,
-                offset_x: 0.0,
-                offset_y: 3.0,
+                asset_path: "some/folder".to_owned()
             })
         );
-    }
 
-    #[test]
-    fn image_asset() {
-        let builder_linear = AssetBuilder {
+        let builder = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some/image.png".to_owned()),
-            sampler: Some(SamplerType::Linear),
+            asset_paths: Some(vec!["some/file.png".to_owned()]),
+            is_collection: true,
+            is_array: true,
             ..Default::default()
         };
+        assert!(builder.build().is_err());
 
-        let builder_nearest = AssetBuilder {
+        let builder = AssetBuilder {
             field_ident: Some(Ident::new("test", Span::call_site())),
-            asset_path: Some("some/image.png".to_owned()),
-            sampler: Some(SamplerType::Nearest),
+            asset_path: Some("some/folder".to_owned()),
+            is_collection: true,
+            is_array: true,
+            is_mapped: true,
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn sprite_sheet() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            sprite_sheet_json: Some("sheet.json".to_owned()),
+            sprite_sheet_image: Some("sheet.png".to_owned()),
             ..Default::default()
         };
 
-        let asset_linear = builder_linear
-            .build()
-            .expect("This should be a valid ImageAsset");
-        let asset_nearest = builder_nearest
+        let asset = builder
             .build()
-            .expect("This should be a valid ImageAsset");
-
-        assert_eq!(
-            asset_linear,
-            AssetField::Image(ImageAssetField {
-                field_ident: Ident::new("test", Span::call_site()),
-                asset_path: "some/image.png".to_owned(),
-                sampler: SamplerType::Linear
-            })
-        );
+            .expect("This should be a valid SpriteSheetAsset");
         assert_eq!(
-            asset_nearest,
-            AssetField::Image(ImageAssetField {
+            asset,
+            AssetField::SpriteSheet(SpriteSheetAssetField {
                 field_ident: Ident::new("test", Span::call_site()),
-                asset_path: "some/image.png".to_owned(),
-                sampler: SamplerType::Nearest
+                json_path: "sheet.json".to_owned(),
+                image_path: "sheet.png".to_owned(),
             })
         );
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            sprite_sheet_json: Some("sheet.json".to_owned()),
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
+
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("sheet.png".to_owned()),
+            sprite_sheet_json: Some("sheet.json".to_owned()),
+            sprite_sheet_image: Some("sheet.png".to_owned()),
+            ..Default::default()
+        };
+        assert!(builder.build().is_err());
     }
 
     #[test]
@@ -932,7 +3522,7 @@ mod test {
         assert!(builder.build().is_err());
 
         let mut builder = asset_builder_dynamic();
-        builder.is_standard_material = true;
+        builder.standard_material = Some(StandardMaterialTextures::default());
         assert!(builder.build().is_err());
 
         // Required texture atlas field
@@ -941,6 +3531,10 @@ mod test {
         assert!(builder.build().is_err());
 
         // Optional texture atlas field
+        let mut builder = asset_builder_dynamic();
+        builder.padding_x = Some(5.0);
+        assert!(builder.build().is_err());
+
         let mut builder = asset_builder_dynamic();
         builder.padding_y = Some(5.0);
         assert!(builder.build().is_err());
@@ -949,8 +3543,13 @@ mod test {
         builder.offset_x = Some(2.5);
         assert!(builder.build().is_err());
 
+        let mut builder = asset_builder_dynamic();
+        builder.offset_y = Some(2.5);
+        assert!(builder.build().is_err());
+
         let mut builder = asset_builder_dynamic();
         builder.is_optional = true;
+        builder.is_option_handle_type = true;
         let asset = builder.build().expect("This should be a valid asset");
         assert_eq!(
             asset,
@@ -1006,4 +3605,46 @@ mod test {
     fn variant_eq<T>(a: &T, b: &T) -> bool {
         std::mem::discriminant(a) == std::mem::discriminant(b)
     }
+
+    #[test]
+    fn labeled_asset() {
+        let builder = AssetBuilder {
+            field_ident: Some(Ident::new("test", Span::call_site())),
+            asset_path: Some("models/fox.gltf#Mesh0/Primitive0".to_owned()),
+            extensions: Some(vec!["gltf".to_owned()]),
+            ..Default::default()
+        };
+
+        let asset = builder
+            .build()
+            .expect("Labels should be part of the asset path");
+        assert_eq!(
+            asset,
+            AssetField::Basic(BasicAssetField {
+                field_ident: Ident::new("test", Span::call_site()),
+                asset_path: "models/fox.gltf#Mesh0/Primitive0".to_owned()
+            })
+        );
+        assert!(has_allowed_extension(
+            "models/fox.gltf#Mesh0/Primitive0",
+            &["gltf".to_owned()]
+        ));
+    }
+
+    #[test]
+    fn asset_paths_and_urls() {
+        assert!(is_valid_asset_path("images/player.png"));
+        assert!(is_valid_asset_path("https://example.com/images/player.png"));
+        assert!(is_valid_asset_path("http://127.0.0.1/player.png"));
+        assert!(is_valid_asset_path("models/fox.gltf#Scene0"));
+        assert!(is_valid_asset_path("models/fox.gltf#Mesh0/Primitive0"));
+        assert!(is_valid_asset_path("https://example.com/fox.gltf#Scene0"));
+        assert!(!is_valid_asset_path("://example.com/player.png"));
+        assert!(!is_valid_asset_path("https://"));
+        assert!(!is_valid_asset_path("1http://example.com/player.png"));
+        assert!(!is_valid_asset_path("ht tp://example.com/player.png"));
+        assert!(!is_valid_asset_path(
+            "https://https://example.com/player.png"
+        ));
+    }
 }
@@ -11,22 +11,26 @@ extern crate proc_macro;
 mod assets;
 
 use proc_macro::TokenStream;
+use std::collections::HashSet;
 use std::option::Option::Some;
 use std::result::Result::{Err, Ok};
 
 use crate::assets::*;
 use proc_macro2::Ident;
-use quote::{quote, quote_spanned, ToTokens, TokenStreamExt};
+use quote::{format_ident, quote, quote_spanned, ToTokens, TokenStreamExt};
 use syn::punctuated::Punctuated;
-#[cfg(any(feature = "2d", feature = "3d"))]
-use syn::ExprPath;
-use syn::{Data, Expr, ExprLit, Field, Fields, Index, Lit, LitStr, Meta, Token};
+use syn::spanned::Spanned;
+use syn::{
+    Data, DataEnum, Expr, ExprLit, ExprPath, Field, Fields, GenericArgument, Generics, Index, Lit,
+    LitStr, Meta, PathArguments, Token, Type, TypePath, Visibility,
+};
 
 /// Derive macro for [`AssetCollection`]
 ///
 /// The helper attribute ``asset`` can be used to define the path to the asset file
-/// and other asset options.
-#[proc_macro_derive(AssetCollection, attributes(asset))]
+/// and other asset options. Defaults for all fields can be set with the struct level
+/// helper attribute ``asset_collection``.
+#[proc_macro_derive(AssetCollection, attributes(asset, asset_collection))]
 pub fn asset_collection_derive(input: TokenStream) -> TokenStream {
     let ast = syn::parse(input).unwrap();
     impl_asset_collection(ast)
@@ -35,9 +39,24 @@ pub fn asset_collection_derive(input: TokenStream) -> TokenStream {
 }
 
 pub(crate) const ASSET_ATTRIBUTE: &str = "asset";
+pub(crate) const ASSET_COLLECTION_ATTRIBUTE: &str = "asset_collection";
+pub(crate) const ATLAS_SAMPLER_ATTRIBUTE: &str = "atlas_sampler";
+pub(crate) const CUSTOM_READY_ATTRIBUTE: &str = "custom_ready";
+pub(crate) const SOURCE_ATTRIBUTE: &str = "source";
 pub(crate) const PATH_ATTRIBUTE: &str = "path";
 pub(crate) const KEY_ATTRIBUTE: &str = "key";
+pub(crate) const KEYS_ATTRIBUTE: &str = "keys";
 pub(crate) const OPTIONAL_ATTRIBUTE: &str = "optional";
+pub(crate) const ALIAS_FROM_ATTRIBUTE: &str = "alias_from";
+pub(crate) const RUNTIME_PATH_ATTRIBUTE: &str = "runtime_path";
+pub(crate) const MANIFEST_KEY_ATTRIBUTE: &str = "manifest_key";
+pub(crate) const EMBEDDED_ATTRIBUTE: &str = "embedded";
+pub(crate) const SHA256_ATTRIBUTE: &str = "sha256";
+pub(crate) const EXTENSIONS_ATTRIBUTE: &str = "extensions";
+pub(crate) const PLACEHOLDER_ATTRIBUTE: &str = "placeholder";
+pub(crate) const SETTINGS_ATTRIBUTE: &str = "settings";
+pub(crate) const LOCK_ATTRIBUTE: &str = "lock";
+pub(crate) const REQUIRED_IF_ATTRIBUTE: &str = "required_if";
 
 pub(crate) struct TextureAtlasAttribute;
 impl TextureAtlasAttribute {
@@ -54,6 +73,12 @@ impl TextureAtlasAttribute {
     pub const OFFSET_X: &'static str = "offset_x";
     #[allow(dead_code)]
     pub const OFFSET_Y: &'static str = "offset_y";
+    #[allow(dead_code)]
+    pub const FORMAT: &'static str = "format";
+    #[allow(dead_code)]
+    pub const SAMPLER: &'static str = "sampler";
+    #[allow(dead_code)]
+    pub const FILES: &'static str = "files";
 }
 
 pub(crate) struct ImageAttribute;
@@ -61,122 +86,107 @@ impl ImageAttribute {
     pub const ATTRIBUTE_NAME: &'static str = "image";
     #[allow(dead_code)]
     pub const SAMPLER: &'static str = "sampler";
+    #[allow(dead_code)]
+    pub const MIN_FILTER: &'static str = "min";
+    #[allow(dead_code)]
+    pub const MAG_FILTER: &'static str = "mag";
+    #[allow(dead_code)]
+    pub const MIPMAP_FILTER: &'static str = "mipmap";
+    #[allow(dead_code)]
+    pub const MAX_SIZE: &'static str = "max_size";
+    #[allow(dead_code)]
+    pub const ADDRESS_MODE: &'static str = "address_mode";
+    #[allow(dead_code)]
+    pub const WRAP: &'static str = "wrap";
+    #[allow(dead_code)]
+    pub const WRAP_U: &'static str = "wrap_u";
+    #[allow(dead_code)]
+    pub const WRAP_V: &'static str = "wrap_v";
+    #[allow(dead_code)]
+    pub const WRAP_W: &'static str = "wrap_w";
+}
+
+pub(crate) struct SpriteSheetAttribute;
+impl SpriteSheetAttribute {
+    pub const ATTRIBUTE_NAME: &'static str = "sprite_sheet";
+    #[allow(dead_code)]
+    pub const JSON: &'static str = "json";
+    #[allow(dead_code)]
+    pub const IMAGE: &'static str = "image";
+}
+
+pub(crate) struct StandardMaterialAttribute;
+impl StandardMaterialAttribute {
+    pub const ATTRIBUTE_NAME: &'static str = "standard_material";
+    #[allow(dead_code)]
+    pub const BASE_COLOR: &'static str = "base_color";
+    #[allow(dead_code)]
+    pub const NORMAL: &'static str = "normal";
+    #[allow(dead_code)]
+    pub const METALLIC_ROUGHNESS: &'static str = "metallic_roughness";
+}
+
+pub(crate) struct KeysIntoMapAttribute;
+impl KeysIntoMapAttribute {
+    pub const ATTRIBUTE_NAME: &'static str = "keys_into_map";
+    pub const PREFIX: &'static str = "prefix";
 }
 
 pub(crate) const COLLECTION_ATTRIBUTE: &str = "collection";
 pub(crate) const PATHS_ATTRIBUTE: &str = "paths";
 pub(crate) const TYPED_ATTRIBUTE: &str = "typed";
 pub(crate) const MAPPED_ATTRIBUTE: &str = "mapped";
-pub(crate) const STANDARD_MATERIAL_ATTRIBUTE: &str = "standard_material";
+pub(crate) const FULL_PATH_ATTRIBUTE: &str = "full_path";
+pub(crate) const ARRAY_ATTRIBUTE: &str = "array";
+pub(crate) const FOLDER_ATTRIBUTE: &str = "folder";
+pub(crate) const DURATION_ATTRIBUTE: &str = "duration";
 
 fn impl_asset_collection(
     ast: syn::DeriveInput,
 ) -> Result<proc_macro2::TokenStream, Vec<syn::Error>> {
     let name = &ast.ident;
+    let collection_attributes = parse_collection_attributes(&ast.attrs)?;
+    let generics = with_asset_bounds(&ast.generics, &ast.data);
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
 
-    let mut from_world_fields: Vec<Ident> = vec![];
-    let mut assets: Vec<AssetField> = vec![];
-    if let Data::Struct(ref data_struct) = ast.data {
-        if let Fields::Named(ref named_fields) = data_struct.fields {
-            let mut compile_errors = vec![];
-            for field in named_fields.named.iter() {
-                match parse_field(field) {
-                    Ok(asset) => assets.push(asset),
-                    Err(errors) => {
-                        for error in errors {
-                            match error {
-                                ParseFieldError::NoAttributes => {
-                                    from_world_fields.push(field.clone().ident.unwrap())
-                                }
-                                ParseFieldError::KeyAttributeStandsAlone => {
-                                    compile_errors.push(syn::Error::new_spanned(
-                                        field.into_token_stream(),
-                                        "The 'key' attribute cannot be combined with any other asset defining attributes",
-                                    ));
-                                }
-                                ParseFieldError::OnlyDynamicCanBeOptional => {
-                                    compile_errors.push(syn::Error::new_spanned(
-                                        field.into_token_stream(),
-                                        "Only a dynamic asset (with 'key' attribute) can be optional",
-                                    ));
-                                }
-                                ParseFieldError::MissingAttributes(missing_attributes) => {
-                                    compile_errors.push(syn::Error::new_spanned(
-                                        field.into_token_stream(),
-                                        format!(
-                                            "Field is missing asset attributes: {}",
-                                            missing_attributes.join(", ")
-                                        ),
-                                    ));
-                                }
-                                ParseFieldError::WrongAttributeType(token_stream, expected) => {
-                                    compile_errors.push(syn::Error::new_spanned(
-                                        token_stream,
-                                        format!("Wrong attribute type. Expected '{expected}'"),
-                                    ));
-                                }
-                                ParseFieldError::UnknownAttributeType(token_stream) => {
-                                    compile_errors.push(syn::Error::new_spanned(
-                                        token_stream,
-                                        "Unknown attribute type",
-                                    ));
-                                }
-                                ParseFieldError::UnknownAttribute(token_stream) => {
-                                    compile_errors.push(syn::Error::new_spanned(
-                                        token_stream,
-                                        "Unknown attribute",
-                                    ));
-                                }
-                                ParseFieldError::Missing2dFeature(token_stream) => {
-                                    compile_errors.push(syn::Error::new_spanned(
-                                        token_stream,
-                                        "This attribute requires the '2d' feature",
-                                    ));
-                                }
-                                ParseFieldError::Missing3dFeature(token_stream) => {
-                                    compile_errors.push(syn::Error::new_spanned(
-                                        token_stream,
-                                        "This attribute requires the '3d' feature",
-                                    ));
-                                }
-                                ParseFieldError::Missing2dOr3dFeature(token_stream) => {
-                                    compile_errors.push(syn::Error::new_spanned(
-                                        token_stream,
-                                        "This attribute requires the '3d' or '2d' feature",
-                                    ));
-                                }
-                                ParseFieldError::PathAndPathsAreExclusive => {
-                                    compile_errors.push(syn::Error::new_spanned(
-                                        field.into_token_stream(),
-                                        "Either specify 'path' OR 'paths'",
-                                    ));
-                                }
-                            }
-                        }
-                    }
-                }
+    let (assets, from_world_fields) = match ast.data {
+        Data::Struct(ref data_struct) => {
+            if let Fields::Named(ref named_fields) = data_struct.fields {
+                parse_fields(named_fields.named.iter(), &collection_attributes)?
+            } else {
+                return Err(vec![syn::Error::new_spanned(
+                    data_struct.fields.clone().into_token_stream(),
+                    "only named fields are supported to derive AssetCollection",
+                )]);
             }
-            if !compile_errors.is_empty() {
-                return Err(compile_errors);
+        }
+        Data::Enum(ref data_enum) => {
+            if !ast.generics.params.is_empty() {
+                return Err(vec![syn::Error::new_spanned(
+                    &ast.generics,
+                    "AssetCollection cannot be derived for generic enums",
+                )]);
             }
-        } else {
+            return impl_asset_collection_for_enum(
+                name,
+                &ast.vis,
+                &collection_attributes,
+                data_enum,
+            );
+        }
+        Data::Union(_) => {
             return Err(vec![syn::Error::new_spanned(
-                data_struct.fields.clone().into_token_stream(),
-                "only named fields are supported to derive AssetCollection",
+                &ast.into_token_stream(),
+                "AssetCollection can only be derived for a struct or an enum",
             )]);
         }
-    } else {
-        return Err(vec![syn::Error::new_spanned(
-            &ast.into_token_stream(),
-            "AssetCollection can only be derived for a struct",
-        )]);
-    }
+    };
 
-    let asset_loading = assets.iter().fold(quote!(), |token_stream, asset| {
-        asset.attach_token_stream_for_loading(token_stream)
-    });
+    let asset_loading = load_assets(&assets);
+    let prepare_loading = prepare_loading(assets.iter());
     let load_function = quote! {
             fn load(world: &mut ::bevy::ecs::world::World) -> Vec<::bevy::prelude::UntypedHandle> {
+                #prepare_loading
                 let cell = world.cell();
                 let asset_server = cell.get_resource::<::bevy::prelude::AssetServer>().expect("Cannot get AssetServer");
                 let asset_keys = cell.get_resource::<bevy_asset_loader::prelude::DynamicAssets>().expect("Cannot get bevy_asset_loader::prelude::DynamicAssets");
@@ -186,13 +196,819 @@ fn impl_asset_collection(
             }
     };
 
+    let collection_creation = create_collection(
+        &quote!(#name),
+        &name.to_string(),
+        &assets,
+        &from_world_fields,
+    );
+    let create_function = quote! {
+        fn create(world: &mut ::bevy::ecs::world::World) -> Self {
+            #collection_creation
+        }
+    };
+
+    let handle_fields: Vec<_> = assets.iter().map(AssetField::field_ident).collect();
+    let all_handles_function = quote! {
+        fn all_handles(&self) -> Vec<::bevy::asset::UntypedHandle> {
+            let mut handles = vec![];
+            #(::bevy_asset_loader::asset_collection::CollectionHandles::extend_handles(&self.#handle_fields, &mut handles);)*
+            handles
+        }
+    };
+    let register_handles_function = if handle_fields.is_empty() {
+        quote!()
+    } else {
+        let field_names = handle_fields.iter().map(|ident| ident.to_string());
+        quote! {
+            fn register_handles(&self, registry: &mut dyn ::bevy_asset_loader::asset_collection::HandleRegistry) {
+                #({
+                    let mut handles = vec![];
+                    ::bevy_asset_loader::asset_collection::CollectionHandles::extend_handles(&self.#handle_fields, &mut handles);
+                    for handle in handles {
+                        registry.register(#field_names, handle);
+                    }
+                })*
+            }
+        }
+    };
+
+    let folders: Vec<_> = assets.iter().filter_map(AssetField::folder).collect();
+    let folder_functions = if folders.is_empty() {
+        quote!()
+    } else {
+        let folder_fields: Vec<_> = folders
+            .iter()
+            .map(|(folder, _)| &folder.field_ident)
+            .collect();
+        let folder_paths: Vec<_> = folders
+            .iter()
+            .map(|(folder, _)| &folder.asset_path)
+            .collect();
+        let full_paths: Vec<_> = folders
+            .iter()
+            .map(|(_, mapped)| mapped == &&Mapped::FullPath)
+            .collect();
+        quote! {
+            fn folders(&self) -> Vec<(&'static str, Vec<::bevy::asset::UntypedHandle>)> {
+                vec![#({
+                    let mut handles = vec![];
+                    ::bevy_asset_loader::asset_collection::CollectionHandles::extend_handles(&self.#folder_fields, &mut handles);
+                    (#folder_paths, handles)
+                }),*]
+            }
+
+            fn update_folder(&mut self, folder: &str, handles: &[::bevy::asset::UntypedHandle]) {
+                #(if folder == #folder_paths {
+                    ::bevy_asset_loader::asset_collection::FolderHandles::replace_folder_handles(&mut self.#folder_fields, folder, #full_paths, handles);
+                })*
+            }
+        }
+    };
+
+    let ready_function = ready_function(&collection_attributes);
+    let processed_images_function = processed_images_function(assets.iter());
+
+    #[cfg(feature = "reflect")]
+    let asset_bindings_function =
+        asset_bindings_function(name, [(None, &assets[..], &from_world_fields[..])]);
+    #[cfg(not(feature = "reflect"))]
+    let asset_bindings_function = quote!();
+
+    let impl_asset_collection = quote! {
+        #[automatically_derived]
+        #[allow(unused_variables)]
+        impl #impl_generics AssetCollection for #name #type_generics #where_clause {
+            #create_function
+
+            #load_function
+
+            #all_handles_function
+
+            #register_handles_function
+
+            #folder_functions
+
+            #processed_images_function
+
+            #ready_function
+
+            #asset_bindings_function
+        }
+    };
+    Ok(impl_asset_collection)
+}
+
+/// A variant of an enum deriving `AssetCollection`
+///
+/// Fields of tuple variants are named `_0`, `_1`, ... in the generated code.
+struct CollectionVariant {
+    ident: Ident,
+    style: VariantStyle,
+    fields: Vec<(Ident, Type)>,
+    assets: Vec<AssetField>,
+    from_world_fields: Vec<Ident>,
+}
+
+#[derive(PartialEq)]
+enum VariantStyle {
+    Named,
+    Tuple,
+    Unit,
+}
+
+impl CollectionVariant {
+    /// Pattern matching the variant and binding all its asset fields
+    fn pattern(&self, name: &Ident) -> proc_macro2::TokenStream {
+        let variant = &self.ident;
+        let asset_fields: Vec<_> = self.assets.iter().map(AssetField::field_ident).collect();
+        match self.style {
+            VariantStyle::Named => quote!(#name::#variant { #(#asset_fields,)* .. }),
+            VariantStyle::Tuple if asset_fields.is_empty() => quote!(#name::#variant(..)),
+            VariantStyle::Tuple => quote!(#name::#variant(#(#asset_fields),*)),
+            VariantStyle::Unit => quote!(#name::#variant),
+        }
+    }
+
+    fn create(&self, name: &Ident) -> proc_macro2::TokenStream {
+        let variant = &self.ident;
+        let (field_idents, field_types): (Vec<_>, Vec<_>) = self.fields.iter().cloned().unzip();
+        let collection_creation = create_collection(
+            &quote!(VariantFields),
+            &format!("{name}::{variant}"),
+            &self.assets,
+            &self.from_world_fields,
+        );
+        let construction = match self.style {
+            VariantStyle::Named => quote!(#name::#variant { #(#field_idents),* }),
+            VariantStyle::Tuple => quote!(#name::#variant(#(#field_idents),*)),
+            VariantStyle::Unit => quote!(#name::#variant),
+        };
+        quote! {{
+            // the fields are created like the fields of a struct collection
+            struct VariantFields {
+                #(#field_idents: #field_types),*
+            }
+            let VariantFields { #(#field_idents),* } = #collection_creation;
+            #construction
+        }}
+    }
+}
+
+/// Add an `Asset` bound for every type parameter that is used as the asset type of a handle in a field
+fn with_asset_bounds(generics: &Generics, data: &Data) -> Generics {
+    let mut generics = generics.clone();
+    let Data::Struct(data_struct) = data else {
+        return generics;
+    };
+    let params: Vec<Ident> = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+    let mut asset_params = vec![];
+    for field in &data_struct.fields {
+        collect_handle_params(&field.ty, &params, &mut asset_params);
+    }
+    let where_clause = generics.make_where_clause();
+    for param in asset_params {
+        where_clause
+            .predicates
+            .push(syn::parse_quote!(#param: ::bevy::asset::Asset));
+    }
+
+    generics
+}
+
+/// Find type parameters used as `A` in `Handle<A>`, also nested in types like `Option<Handle<A>>`
+fn collect_handle_params(ty: &Type, params: &[Ident], found: &mut Vec<Ident>) {
+    match ty {
+        Type::Path(TypePath { path, .. }) => {
+            for segment in &path.segments {
+                let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+                    continue;
+                };
+                for argument in &arguments.args {
+                    let GenericArgument::Type(argument) = argument else {
+                        continue;
+                    };
+                    if segment.ident == "Handle" {
+                        if let Type::Path(TypePath { path, qself: None }) = argument {
+                            if let Some(param) = path.get_ident() {
+                                if params.contains(param) && !found.contains(param) {
+                                    found.push(param.clone());
+                                }
+                            }
+                        }
+                    }
+                    collect_handle_params(argument, params, found);
+                }
+            }
+        }
+        Type::Array(array) => collect_handle_params(&array.elem, params, found),
+        Type::Paren(paren) => collect_handle_params(&paren.elem, params, found),
+        Type::Tuple(tuple) => tuple
+            .elems
+            .iter()
+            .for_each(|elem| collect_handle_params(elem, params, found)),
+        _ => {}
+    }
+}
+
+fn impl_asset_collection_for_enum(
+    name: &Ident,
+    vis: &Visibility,
+    collection_attributes: &CollectionAttributes,
+    data_enum: &DataEnum,
+) -> Result<proc_macro2::TokenStream, Vec<syn::Error>> {
+    if data_enum.variants.is_empty() {
+        return Err(vec![syn::Error::new_spanned(
+            name,
+            "AssetCollection cannot be derived for an enum without variants",
+        )]);
+    }
+    let mut variants = vec![];
+    let mut compile_errors = vec![];
+    for variant in &data_enum.variants {
+        if let Some(attribute) = variant
+            .attrs
+            .iter()
+            .find(|attribute| attribute.path().is_ident(ASSET_ATTRIBUTE))
+        {
+            compile_errors.push(syn::Error::new_spanned(
+                attribute,
+                "Asset attributes belong on the fields of a variant, not on the variant itself",
+            ));
+            continue;
+        }
+        let style = match variant.fields {
+            Fields::Named(_) => VariantStyle::Named,
+            Fields::Unnamed(_) => VariantStyle::Tuple,
+            Fields::Unit => VariantStyle::Unit,
+        };
+        let fields: Vec<Field> = variant
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let mut field = field.clone();
+                if field.ident.is_none() {
+                    field.ident = Some(format_ident!("_{}", index, span = field.span()));
+                }
+                field
+            })
+            .collect();
+        let (assets, from_world_fields) = match parse_fields(fields.iter(), collection_attributes) {
+            Ok(parsed) => parsed,
+            Err(errors) => {
+                compile_errors.extend(errors);
+                continue;
+            }
+        };
+        if style == VariantStyle::Tuple && !assets.is_empty() && !from_world_fields.is_empty() {
+            compile_errors.push(syn::Error::new_spanned(
+                variant.into_token_stream(),
+                "Either all or none of the fields of a tuple variant need asset attributes. Use named fields to combine assets with fields created from the world",
+            ));
+            continue;
+        }
+        variants.push(CollectionVariant {
+            ident: variant.ident.clone(),
+            style,
+            fields: fields
+                .into_iter()
+                .map(|field| (field.ident.unwrap(), field.ty))
+                .collect(),
+            assets,
+            from_world_fields,
+        });
+    }
+    if !compile_errors.is_empty() {
+        return Err(compile_errors);
+    }
+
+    let variant_enum = format_ident!("{}Variant", name);
+    let variant_idents: Vec<_> = variants.iter().map(|variant| &variant.ident).collect();
+    let variant_enum_doc = format!("Variants of the asset collection [`{name}`]");
+    let variant_enum_definition = quote! {
+        #[doc = #variant_enum_doc]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        #vis enum #variant_enum {
+            #(#variant_idents),*
+        }
+
+        #[automatically_derived]
+        impl ::bevy_asset_loader::asset_collection::EnumCollection for #name {
+            type Variant = #variant_enum;
+        }
+    };
+    let select_variant = quote! {
+        let variant = ::bevy_asset_loader::asset_collection::collection_variant::<Self>(world);
+    };
+
+    let all_assets = variants.iter().flat_map(|variant| variant.assets.iter());
+    let prepare_loading = prepare_loading(all_assets.clone());
+    let asset_loading = variants.iter().map(|variant| load_assets(&variant.assets));
+    let load_function = quote! {
+        fn load(world: &mut ::bevy::ecs::world::World) -> Vec<::bevy::prelude::UntypedHandle> {
+            #select_variant
+            #prepare_loading
+            let cell = world.cell();
+            let asset_server = cell.get_resource::<::bevy::prelude::AssetServer>().expect("Cannot get AssetServer");
+            let asset_keys = cell.get_resource::<bevy_asset_loader::prelude::DynamicAssets>().expect("Cannot get bevy_asset_loader::prelude::DynamicAssets");
+            let mut handles = vec![];
+            match variant {
+                #(#variant_enum::#variant_idents => { #asset_loading })*
+            }
+            handles
+        }
+    };
+
+    let variant_creation = variants.iter().map(|variant| variant.create(name));
+    let create_function = quote! {
+        fn create(world: &mut ::bevy::ecs::world::World) -> Self {
+            #select_variant
+            match variant {
+                #(#variant_enum::#variant_idents => #variant_creation,)*
+            }
+        }
+    };
+
+    let patterns: Vec<_> = variants
+        .iter()
+        .map(|variant| variant.pattern(name))
+        .collect();
+    let handle_fields: Vec<Vec<_>> = variants
+        .iter()
+        .map(|variant| variant.assets.iter().map(AssetField::field_ident).collect())
+        .collect();
+    let all_handles_function = quote! {
+        fn all_handles(&self) -> Vec<::bevy::asset::UntypedHandle> {
+            let mut handles = vec![];
+            match self {
+                #(#patterns => {
+                    #(::bevy_asset_loader::asset_collection::CollectionHandles::extend_handles(#handle_fields, &mut handles);)*
+                })*
+            }
+            handles
+        }
+    };
+    let field_names: Vec<Vec<_>> = handle_fields
+        .iter()
+        .map(|fields| fields.iter().map(|ident| ident.to_string()).collect())
+        .collect();
+    let register_handles_function = quote! {
+        fn register_handles(&self, registry: &mut dyn ::bevy_asset_loader::asset_collection::HandleRegistry) {
+            match self {
+                #(#patterns => {
+                    #({
+                        let mut handles = vec![];
+                        ::bevy_asset_loader::asset_collection::CollectionHandles::extend_handles(#handle_fields, &mut handles);
+                        for handle in handles {
+                            registry.register(#field_names, handle);
+                        }
+                    })*
+                })*
+            }
+        }
+    };
+
+    let folders: Vec<Vec<_>> = variants
+        .iter()
+        .map(|variant| {
+            variant
+                .assets
+                .iter()
+                .filter_map(AssetField::folder)
+                .collect()
+        })
+        .collect();
+    let folder_functions = if folders.iter().all(Vec::is_empty) {
+        quote!()
+    } else {
+        let folder_fields = folders.iter().map(|folders| {
+            folders
+                .iter()
+                .map(|(folder, _)| &folder.field_ident)
+                .collect::<Vec<_>>()
+        });
+        let folder_paths = folders.iter().map(|folders| {
+            folders
+                .iter()
+                .map(|(folder, _)| &folder.asset_path)
+                .collect::<Vec<_>>()
+        });
+        let full_paths = folders.iter().map(|folders| {
+            folders
+                .iter()
+                .map(|(_, mapped)| mapped == &&Mapped::FullPath)
+                .collect::<Vec<_>>()
+        });
+        let folder_list = folder_fields
+            .clone()
+            .zip(folder_paths.clone())
+            .map(|(folder_fields, folder_paths)| {
+                quote! {
+                    vec![#({
+                        let mut handles = vec![];
+                        ::bevy_asset_loader::asset_collection::CollectionHandles::extend_handles(#folder_fields, &mut handles);
+                        (#folder_paths, handles)
+                    }),*]
+                }
+            });
+        let folder_updates = folder_fields.zip(folder_paths).zip(full_paths).map(
+            |((folder_fields, folder_paths), full_paths)| {
+                quote! {
+                    #(if folder == #folder_paths {
+                        ::bevy_asset_loader::asset_collection::FolderHandles::replace_folder_handles(#folder_fields, folder, #full_paths, handles);
+                    })*
+                }
+            },
+        );
+        quote! {
+            fn folders(&self) -> Vec<(&'static str, Vec<::bevy::asset::UntypedHandle>)> {
+                match self {
+                    #(#patterns => #folder_list,)*
+                }
+            }
+
+            fn update_folder(&mut self, folder: &str, handles: &[::bevy::asset::UntypedHandle]) {
+                match self {
+                    #(#patterns => { #folder_updates })*
+                }
+            }
+        }
+    };
+
+    let ready_function = ready_function(collection_attributes);
+    let processed_images_function = processed_images_function(all_assets.clone());
+
+    #[cfg(feature = "reflect")]
+    let asset_bindings_function = asset_bindings_function(
+        name,
+        variants.iter().map(|variant| {
+            (
+                Some(variant.ident.to_string()),
+                &variant.assets[..],
+                &variant.from_world_fields[..],
+            )
+        }),
+    );
+    #[cfg(not(feature = "reflect"))]
+    let asset_bindings_function = quote!();
+
+    Ok(quote! {
+        #variant_enum_definition
+
+        #[automatically_derived]
+        #[allow(unused_variables)]
+        impl AssetCollection for #name {
+            #create_function
+
+            #load_function
+
+            #all_handles_function
+
+            #register_handles_function
+
+            #folder_functions
+
+            #processed_images_function
+
+            #ready_function
+
+            #asset_bindings_function
+        }
+    })
+}
+
+/// Parse the fields of a collection into asset fields and fields created from the world
+fn parse_fields<'a>(
+    fields: impl Iterator<Item = &'a Field>,
+    collection_attributes: &CollectionAttributes,
+) -> Result<(Vec<AssetField>, Vec<Ident>), Vec<syn::Error>> {
+    let mut from_world_fields: Vec<Ident> = vec![];
+    let mut assets: Vec<AssetField> = vec![];
+    let mut compile_errors = vec![];
+    for field in fields {
+        match parse_field(field) {
+            Ok(asset) => assets.push(asset),
+            Err(errors) => {
+                for error in errors {
+                    match error {
+                        ParseFieldError::NoAttributes => {
+                            from_world_fields.push(field.clone().ident.unwrap())
+                        }
+                        ParseFieldError::KeyAttributeStandsAlone => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                field.into_token_stream(),
+                                "The 'key' attribute cannot be combined with any other asset defining attributes",
+                            ));
+                        }
+                        ParseFieldError::OptionalNeedsKeyOrSinglePath => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                field.into_token_stream(),
+                                "Only a dynamic asset (with 'key' attribute) or a single file (with 'path' attribute) can be optional",
+                            ));
+                        }
+                        ParseFieldError::MissingAttributes(missing_attributes) => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                field.into_token_stream(),
+                                format!(
+                                    "Field is missing asset attributes: {}",
+                                    missing_attributes.join(", ")
+                                ),
+                            ));
+                        }
+                        ParseFieldError::WrongAttributeType(token_stream, expected) => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                token_stream,
+                                format!("Wrong attribute type. Expected '{expected}'"),
+                            ));
+                        }
+                        ParseFieldError::UnknownAttributeType(token_stream) => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                token_stream,
+                                "Unknown attribute type",
+                            ));
+                        }
+                        ParseFieldError::UnknownAttribute(token_stream) => {
+                            compile_errors
+                                .push(syn::Error::new_spanned(token_stream, "Unknown attribute"));
+                        }
+                        ParseFieldError::Missing2dFeature(token_stream) => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                token_stream,
+                                "This attribute requires the '2d' feature",
+                            ));
+                        }
+                        ParseFieldError::Missing3dFeature(token_stream) => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                token_stream,
+                                "This attribute requires the '3d' feature",
+                            ));
+                        }
+                        ParseFieldError::Missing2dOr3dFeature(token_stream) => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                token_stream,
+                                "This attribute requires the '3d' or '2d' feature",
+                            ));
+                        }
+                        ParseFieldError::MissingTexturePackerFeature(token_stream) => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                token_stream,
+                                "This attribute requires the 'texture_packer' feature",
+                            ));
+                        }
+                        ParseFieldError::SpriteSheetStandsAlone => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                field.into_token_stream(),
+                                "The 'sprite_sheet' attribute cannot be combined with any other asset defining attributes",
+                            ));
+                        }
+                        ParseFieldError::ExclusiveAttributes(attribute, other) => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                field.into_token_stream(),
+                                format!(
+                                    "The '{attribute}' attribute cannot be combined with '{other}'"
+                                ),
+                            ));
+                        }
+                        ParseFieldError::ArrayOnlyForFolders => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                field.into_token_stream(),
+                                "'collection(array)' is only supported for folders loaded with 'path' and cannot be 'mapped'",
+                            ));
+                        }
+                        ParseFieldError::FolderNeedsSinglePath => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                field.into_token_stream(),
+                                "The 'folder' attribute requires a single folder loaded with 'path' and cannot be combined with 'collection', 'key', or image attributes",
+                            ));
+                        }
+                        ParseFieldError::MissingAudioFeature(token_stream) => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                token_stream,
+                                "This attribute requires the 'audio' feature",
+                            ));
+                        }
+                        ParseFieldError::DurationNeedsSinglePath => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                field.into_token_stream(),
+                                "The 'duration' attribute requires a single audio file loaded with 'path' or 'embedded' and cannot be combined with 'collection', 'folder', 'key', or image attributes",
+                            ));
+                        }
+                        ParseFieldError::MalformedNamespacedKey(token_stream) => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                token_stream,
+                                "Namespaced keys need a namespace and a key separated by ':', like 'mod_a:boss_theme'",
+                            ));
+                        }
+                        ParseFieldError::NoOptionType(attribute) => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                field.into_token_stream(),
+                                format!(
+                                    "Fields with the '{attribute}' attribute cannot be an Option"
+                                ),
+                            ));
+                        }
+                        ParseFieldError::BaseColorReplacesPath => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                field.into_token_stream(),
+                                "The 'base_color' texture of a standard material replaces 'path' and cannot be combined with it",
+                            ));
+                        }
+                        ParseFieldError::EmbeddedReplacesPath => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                field.into_token_stream(),
+                                "The 'embedded' attribute replaces 'path' and cannot be combined with 'path', 'paths', 'key', 'collection', 'alias_from', or 'sprite_sheet'",
+                            ));
+                        }
+                        ParseFieldError::MissingChecksumsFeature(token_stream) => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                token_stream,
+                                "This attribute requires the 'checksums' feature",
+                            ));
+                        }
+                        ParseFieldError::ChecksumNeedsSinglePath => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                field.into_token_stream(),
+                                "The 'sha256' attribute requires a single file loaded with 'path' or 'embedded'",
+                            ));
+                        }
+                        ParseFieldError::PlaceholderNeedsSinglePath => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                field.into_token_stream(),
+                                "The 'placeholder' attribute requires a single file loaded with 'path' and cannot be combined with 'key', 'collection', 'sha256', or image attributes",
+                            ));
+                        }
+                        ParseFieldError::SettingsNeedsSinglePath => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                field.into_token_stream(),
+                                "The 'settings' attribute requires a single file loaded with 'path' and cannot be combined with 'key', 'collection', 'sha256', 'placeholder', locks, or image attributes",
+                            ));
+                        }
+                        ParseFieldError::RequiredIfNeedsOptionalPath => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                field.into_token_stream(),
+                                "The 'required_if' attribute requires an 'Option<Handle<T>>' field loading a single file with 'path' and cannot be combined with other attributes changing the asset",
+                            ));
+                        }
+                        ParseFieldError::OptionalNeedsOptionType => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                field.ty.clone().into_token_stream(),
+                                "Fields with the 'optional' attribute are 'None' if their asset fails to load. Single files need the type 'Option<Handle<T>>', collections the type 'Option<...>'",
+                            ));
+                        }
+                        ParseFieldError::VecOrMapNeedsCollection => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                field.into_token_stream(),
+                                "A field of type 'Vec' or 'HashMap' needs the 'collection' attribute to load a folder or multiple files, e.g. #[asset(path = \"enemies\", collection(typed))]",
+                            ));
+                        }
+                        ParseFieldError::InvalidAssetUrl(token_stream) => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                token_stream,
+                                "Invalid asset URL. Expected '<scheme>://<path>' with a scheme like 'https' and no further ':' (asset paths cannot contain ports)",
+                            ));
+                        }
+                        ParseFieldError::DisallowedExtension(token_stream, allowed) => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                token_stream,
+                                format!("The asset path does not have one of the allowed extensions: {allowed}"),
+                            ));
+                        }
+                        ParseFieldError::ExtensionsNeedLiteralPaths => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                field.into_token_stream(),
+                                "The 'extensions' attribute is checked at compile time and requires literal paths. It cannot be used for folders or dynamic assets",
+                            ));
+                        }
+                        ParseFieldError::PathAndPathsAreExclusive => {
+                            compile_errors.push(syn::Error::new_spanned(
+                                field.into_token_stream(),
+                                "Either specify 'path' OR 'paths'",
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    if !compile_errors.is_empty() {
+        return Err(compile_errors);
+    }
+    if let Some(atlas_sampler) = collection_attributes.atlas_sampler {
+        for texture_atlas in assets.iter_mut().filter_map(AssetField::texture_atlas_mut) {
+            texture_atlas.sampler.get_or_insert(atlas_sampler);
+        }
+    }
+    if let Some(source) = &collection_attributes.source {
+        for asset in assets.iter_mut() {
+            asset.set_source(source);
+        }
+    }
+    resolve_required_if(&mut assets)?;
+
+    Ok((assets, from_world_fields))
+}
+
+/// Set the asset paths of the fields that `required_if` fields depend on
+///
+/// A `required_if` field can only depend on an optional field loading a single file.
+fn resolve_required_if(assets: &mut [AssetField]) -> Result<(), Vec<syn::Error>> {
+    let optional_files: Vec<(Ident, String)> = assets
+        .iter()
+        .filter_map(|asset| {
+            asset
+                .optional_file_path()
+                .map(|path| (asset.field_ident().clone(), path.to_owned()))
+        })
+        .collect();
+    let mut errors = vec![];
+    for condition in assets.iter_mut().filter_map(AssetField::required_if_mut) {
+        match optional_files
+            .iter()
+            .find(|(field, _)| field == &condition.field_ident)
+        {
+            Some((_, path)) => condition.asset_path = path.clone(),
+            None => errors.push(syn::Error::new_spanned(
+                &condition.field_ident,
+                "'required_if' has to name an optional field of the collection loading a single file",
+            )),
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Resources needed while loading the given assets
+fn prepare_loading<'a>(
+    mut assets: impl Iterator<Item = &'a AssetField> + Clone,
+) -> proc_macro2::TokenStream {
+    let prepare_placeholders = if assets.clone().any(AssetField::has_placeholder) {
+        quote!(world.init_resource::<::bevy_asset_loader::placeholder::PendingPlaceholders>();)
+    } else {
+        quote!()
+    };
+    let prepare_optional_assets = if assets.any(AssetField::is_optional_file) {
+        quote!(world.init_resource::<::bevy_asset_loader::asset_collection::OptionalAssets>();)
+    } else {
+        quote!()
+    };
+
+    quote! {
+        #prepare_placeholders
+        #prepare_optional_assets
+    }
+}
+
+fn load_assets(assets: &[AssetField]) -> proc_macro2::TokenStream {
+    // Fields loading the same folder share a single folder handle
+    let mut loaded_folders = HashSet::new();
+    assets.iter().fold(quote!(), |token_stream, asset| {
+        if let Some(folder) = asset.loaded_folder() {
+            if !loaded_folders.insert(folder) {
+                return token_stream;
+            }
+        }
+        asset.attach_token_stream_for_loading(token_stream)
+    })
+}
+
+/// Expression creating the struct at `path` from loaded assets and the world
+fn create_collection(
+    path: &proc_macro2::TokenStream,
+    collection: &str,
+    assets: &[AssetField],
+    from_world_fields: &[Ident],
+) -> proc_macro2::TokenStream {
     let prepare_from_world = from_world_fields.iter().fold(
         quote!(),
         |es, ident| quote_spanned! {ident.span() => #es ::bevy::ecs::world::FromWorld::from_world(world),},
     );
 
+    let texture_atlas_grids: Vec<_> = assets
+        .iter()
+        .filter_map(AssetField::texture_atlas_grid)
+        .collect();
+    let prepare_texture_atlases = if texture_atlas_grids.is_empty() {
+        quote!()
+    } else {
+        quote! {
+            let asset_server = world.resource::<::bevy::asset::AssetServer>().clone();
+            let mut texture_atlases = ::bevy_asset_loader::asset_collection::build_texture_atlases(
+                world,
+                vec![#(#texture_atlas_grids),*],
+            )
+            .into_iter();
+        }
+    };
+
     let mut asset_creation = assets.iter().fold(quote!(), |token_stream, asset| {
-        asset.attach_token_stream_for_creation(token_stream, name.to_string())
+        asset.attach_token_stream_for_creation(token_stream, collection.to_owned())
     });
     let mut index = 0;
     asset_creation.append_all(from_world_fields.iter().fold(quote!(), |es, ident| {
@@ -201,36 +1017,87 @@ fn impl_asset_collection(
         index += 1;
         tokens
     }));
-    let create_function = quote! {
-        fn create(world: &mut ::bevy::ecs::world::World) -> Self {
-            let from_world_fields = (#prepare_from_world);
-            world.resource_scope(
-                |world, asset_keys: ::bevy::prelude::Mut<::bevy_asset_loader::dynamic_asset::DynamicAssets>| {
-                    #name {
-                        #asset_creation
-                    }
-                },
-            )
+    quote! {{
+        let from_world_fields = (#prepare_from_world);
+        world.resource_scope(
+            |world, asset_keys: ::bevy::prelude::Mut<::bevy_asset_loader::dynamic_asset::DynamicAssets>| {
+                #prepare_texture_atlases
+                #path {
+                    #asset_creation
+                }
+            },
+        )
+    }}
+}
+
+fn ready_function(collection_attributes: &CollectionAttributes) -> proc_macro2::TokenStream {
+    if collection_attributes.custom_ready {
+        quote! {
+            fn ready(&self, world: &::bevy::ecs::world::World) -> bool {
+                ::bevy_asset_loader::asset_collection::CustomReadiness::custom_ready(self, world)
+            }
         }
-    };
+    } else {
+        quote!()
+    }
+}
 
-    let impl_asset_collection = quote! {
-        #[automatically_derived]
-        #[allow(unused_variables)]
-        impl AssetCollection for #name {
-            #create_function
+fn processed_images_function<'a>(
+    assets: impl Iterator<Item = &'a AssetField>,
+) -> proc_macro2::TokenStream {
+    let mut paths: Vec<_> = assets.flat_map(AssetField::processed_images).collect();
+    paths.sort();
+    paths.dedup();
+    if paths.is_empty() {
+        return quote!();
+    }
+    quote! {
+        fn processed_images() -> Vec<&'static str> {
+            vec![#(#paths),*]
+        }
+    }
+}
 
-            #load_function
+#[cfg(feature = "reflect")]
+fn asset_bindings_function<'a>(
+    name: &Ident,
+    variants: impl IntoIterator<Item = (Option<String>, &'a [AssetField], &'a [Ident])>,
+) -> proc_macro2::TokenStream {
+    let collection = name.to_string();
+    let mut bindings = vec![];
+    // fields of enum collections are namespaced by their variant
+    for (variant, assets, from_world_fields) in variants {
+        let variant = match variant {
+            Some(variant) => quote!(Some(#variant.to_owned())),
+            None => quote!(None),
+        };
+        bindings.extend(assets.iter().map(|asset| asset.binding(&variant)));
+        bindings.extend(from_world_fields.iter().map(|ident| {
+            let field = ident.to_string();
+            quote!(::bevy_asset_loader::asset_collection::AssetBinding {
+                variant: #variant,
+                field: #field.to_owned(),
+                kind: ::bevy_asset_loader::asset_collection::AssetBindingKind::FromWorld,
+                paths: vec![],
+                key: None,
+            })
+        }));
+    }
+    quote! {
+        fn asset_bindings() -> ::bevy_asset_loader::asset_collection::AssetCollectionBindings {
+            ::bevy_asset_loader::asset_collection::AssetCollectionBindings {
+                collection: #collection.to_owned(),
+                bindings: vec![#(#bindings),*],
+            }
         }
-    };
-    Ok(impl_asset_collection)
+    }
 }
 
 #[derive(Debug)]
 enum ParseFieldError {
     NoAttributes,
     KeyAttributeStandsAlone,
-    OnlyDynamicCanBeOptional,
+    OptionalNeedsKeyOrSinglePath,
     PathAndPathsAreExclusive,
     WrongAttributeType(proc_macro2::TokenStream, &'static str),
     UnknownAttributeType(proc_macro2::TokenStream),
@@ -242,11 +1109,199 @@ enum ParseFieldError {
     Missing3dFeature(proc_macro2::TokenStream),
     #[allow(dead_code)]
     Missing2dOr3dFeature(proc_macro2::TokenStream),
+    #[allow(dead_code)]
+    MissingTexturePackerFeature(proc_macro2::TokenStream),
+    SpriteSheetStandsAlone,
+    ExclusiveAttributes(String, String),
+    ArrayOnlyForFolders,
+    FolderNeedsSinglePath,
+    #[allow(dead_code)]
+    MissingAudioFeature(proc_macro2::TokenStream),
+    DurationNeedsSinglePath,
+    MalformedNamespacedKey(proc_macro2::TokenStream),
+    NoOptionType(String),
+    EmbeddedReplacesPath,
+    BaseColorReplacesPath,
+    #[allow(dead_code)]
+    MissingChecksumsFeature(proc_macro2::TokenStream),
+    ChecksumNeedsSinglePath,
+    PlaceholderNeedsSinglePath,
+    SettingsNeedsSinglePath,
+    RequiredIfNeedsOptionalPath,
+    OptionalNeedsOptionType,
+    VecOrMapNeedsCollection,
+    InvalidAssetUrl(proc_macro2::TokenStream),
+    DisallowedExtension(proc_macro2::TokenStream, String),
+    ExtensionsNeedLiteralPaths,
+}
+
+/// Options of a collection set with `#[asset_collection(...)]` on the struct
+#[derive(Default)]
+struct CollectionAttributes {
+    atlas_sampler: Option<SamplerType>,
+    custom_ready: bool,
+    source: Option<String>,
+}
+
+fn parse_collection_attributes(
+    attrs: &[syn::Attribute],
+) -> Result<CollectionAttributes, Vec<syn::Error>> {
+    let mut collection_attributes = CollectionAttributes::default();
+    let mut errors = vec![];
+    for attr in attrs
+        .iter()
+        .filter(|attribute| attribute.path().is_ident(ASSET_COLLECTION_ATTRIBUTE))
+    {
+        let meta_list = attr
+            .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+            .map_err(|error| vec![error])?;
+        for attribute in meta_list {
+            match attribute {
+                Meta::NameValue(named_value)
+                    if named_value.path.is_ident(ATLAS_SAMPLER_ATTRIBUTE) =>
+                {
+                    if cfg!(not(feature = "2d")) {
+                        errors.push(syn::Error::new_spanned(
+                            named_value.into_token_stream(),
+                            "This attribute requires the '2d' feature",
+                        ));
+                        continue;
+                    }
+                    let sampler = match &named_value.value {
+                        Expr::Path(ExprPath { path, .. }) => path
+                            .get_ident()
+                            .and_then(|ident| SamplerType::try_from(ident.to_string()).ok()),
+                        _ => None,
+                    };
+                    if let Some(sampler) = sampler {
+                        collection_attributes.atlas_sampler = Some(sampler);
+                    } else {
+                        errors.push(syn::Error::new_spanned(
+                            named_value.value.into_token_stream(),
+                            "Value must be either `linear` or `nearest`",
+                        ));
+                    }
+                }
+                Meta::Path(path) if path.is_ident(CUSTOM_READY_ATTRIBUTE) => {
+                    collection_attributes.custom_ready = true;
+                }
+                Meta::NameValue(named_value) if named_value.path.is_ident(SOURCE_ATTRIBUTE) => {
+                    match &named_value.value {
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(source),
+                            ..
+                        }) if is_valid_asset_path(&format!("{}://file", source.value())) => {
+                            collection_attributes.source = Some(source.value());
+                        }
+                        _ => errors.push(syn::Error::new_spanned(
+                            named_value.value.into_token_stream(),
+                            "Value must be the name of an asset source, like \"pack\"",
+                        )),
+                    }
+                }
+                _ => errors.push(syn::Error::new_spanned(
+                    attribute.into_token_stream(),
+                    "Unknown attribute",
+                )),
+            }
+        }
+    }
+    if errors.is_empty() {
+        Ok(collection_attributes)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Parse an expression like `MyResource.field.handle` into the resource path and the accessed fields
+fn parse_resource_field_access(mut expr: &Expr) -> Option<AliasSource> {
+    let mut members = vec![];
+    loop {
+        match expr {
+            Expr::Field(field) => {
+                members.push(field.member.clone());
+                expr = &field.base;
+            }
+            Expr::Path(ExprPath { path, .. }) if !members.is_empty() => {
+                members.reverse();
+                return Some(AliasSource {
+                    resource: path.clone(),
+                    members,
+                });
+            }
+            _ => return None,
+        }
+    }
+}
+
+/// Whether the field type is an `Option`, like `Option<Handle<AudioSource>>`
+/// Parses the image paths of `files("a.png", "b.png")` or `files(["a.png", "b.png"])`
+#[cfg(feature = "2d")]
+fn parse_atlas_files(meta_list: &syn::MetaList) -> Option<Vec<LitStr>> {
+    let files = meta_list
+        .parse_args_with(Punctuated::<LitStr, Token![,]>::parse_terminated)
+        .or_else(|_| {
+            meta_list.parse_args_with(|input: syn::parse::ParseStream| {
+                let content;
+                syn::bracketed!(content in input);
+                Punctuated::<LitStr, Token![,]>::parse_terminated(&content)
+            })
+        })
+        .ok()?;
+    Some(files.into_iter().collect())
+}
+
+fn field_is_option(ty: &Type) -> bool {
+    let Type::Path(TypePath { path, .. }) = ty else {
+        return false;
+    };
+    path.segments.last().is_some_and(|segment| {
+        segment.ident == "Option" && matches!(segment.arguments, PathArguments::AngleBracketed(_))
+    })
+}
+
+/// Whether the type is `Option<Handle<T>>`
+fn field_is_option_handle(ty: &Type) -> bool {
+    field_is_option(ty)
+        && first_type_argument(ty).is_some_and(|inner| {
+            let Type::Path(TypePath { path, .. }) = inner else {
+                return false;
+            };
+            path.segments
+                .last()
+                .is_some_and(|segment| segment.ident == "Handle")
+        })
+}
+
+/// The first generic type argument of a type like `Mutex<Handle<Image>>`
+fn first_type_argument(ty: &Type) -> Option<&Type> {
+    let Type::Path(TypePath { path, .. }) = ty else {
+        return None;
+    };
+    let PathArguments::AngleBracketed(arguments) = &path.segments.last()?.arguments else {
+        return None;
+    };
+    arguments.args.iter().find_map(|argument| match argument {
+        syn::GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    })
+}
+
+fn field_is_vec_or_map(ty: &Type) -> bool {
+    let Type::Path(TypePath { path, .. }) = ty else {
+        return false;
+    };
+    path.segments.last().is_some_and(|segment| {
+        (segment.ident == "Vec" || segment.ident == "HashMap")
+            && matches!(segment.arguments, PathArguments::AngleBracketed(_))
+    })
 }
 
 fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
     let mut builder = AssetBuilder::default();
     let mut errors = vec![];
+    // literal paths with a flag for single paths, which might be a folder
+    let mut literal_paths: Vec<(LitStr, bool)> = vec![];
     for attr in field
         .attrs
         .iter()
@@ -356,35 +1411,151 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
                                         } else {
                                             errors.push(ParseFieldError::WrongAttributeType(
                                                 named_value.into_token_stream(),
-                                                "float",
+                                                "float",
+                                            ));
+                                        }
+                                    } else if path == TextureAtlasAttribute::OFFSET_X {
+                                        if let Expr::Lit(ExprLit {
+                                            lit: Lit::Float(offset_x),
+                                            ..
+                                        }) = &named_value.value
+                                        {
+                                            builder.offset_x =
+                                                Some(offset_x.base10_parse::<f32>().unwrap());
+                                        } else {
+                                            errors.push(ParseFieldError::WrongAttributeType(
+                                                named_value.into_token_stream(),
+                                                "float",
+                                            ));
+                                        }
+                                    } else if path == TextureAtlasAttribute::OFFSET_Y {
+                                        if let Expr::Lit(ExprLit {
+                                            lit: Lit::Float(offset_y),
+                                            ..
+                                        }) = &named_value.value
+                                        {
+                                            builder.offset_y =
+                                                Some(offset_y.base10_parse::<f32>().unwrap());
+                                        } else {
+                                            errors.push(ParseFieldError::WrongAttributeType(
+                                                named_value.into_token_stream(),
+                                                "float",
+                                            ));
+                                        }
+                                    } else if path == TextureAtlasAttribute::SAMPLER {
+                                        if let Expr::Path(ExprPath { path, .. }) =
+                                            &named_value.value
+                                        {
+                                            let sampler_result = SamplerType::try_from(
+                                                path.get_ident().unwrap().to_string(),
+                                            );
+
+                                            if let Ok(sampler) = sampler_result {
+                                                builder.atlas_sampler = Some(sampler);
+                                            } else {
+                                                errors.push(ParseFieldError::UnknownAttribute(
+                                                    named_value.value.into_token_stream(),
+                                                ));
+                                            }
+                                        } else {
+                                            errors.push(ParseFieldError::WrongAttributeType(
+                                                named_value.into_token_stream(),
+                                                "path",
                                             ));
                                         }
-                                    } else if path == TextureAtlasAttribute::OFFSET_X {
+                                    } else if path == TextureAtlasAttribute::FORMAT {
                                         if let Expr::Lit(ExprLit {
-                                            lit: Lit::Float(offset_x),
+                                            lit: Lit::Str(format),
                                             ..
                                         }) = &named_value.value
                                         {
-                                            builder.offset_x =
-                                                Some(offset_x.base10_parse::<f32>().unwrap());
+                                            if let Ok(format) =
+                                                TextureFormatType::try_from(format.value())
+                                            {
+                                                builder.format = Some(format);
+                                            } else {
+                                                errors.push(ParseFieldError::UnknownAttribute(
+                                                    named_value.value.into_token_stream(),
+                                                ));
+                                            }
                                         } else {
                                             errors.push(ParseFieldError::WrongAttributeType(
                                                 named_value.into_token_stream(),
-                                                "float",
+                                                "str",
                                             ));
                                         }
-                                    } else if path == TextureAtlasAttribute::OFFSET_Y {
+                                    } else {
+                                        errors.push(ParseFieldError::UnknownAttribute(
+                                            named_value.into_token_stream(),
+                                        ));
+                                    }
+                                }
+                                Meta::List(files_list)
+                                    if files_list.path.is_ident(TextureAtlasAttribute::FILES) =>
+                                {
+                                    match parse_atlas_files(&files_list) {
+                                        Some(files) if !files.is_empty() => {
+                                            let mut paths = vec![];
+                                            for file in files {
+                                                if !is_valid_asset_path(&file.value()) {
+                                                    errors.push(ParseFieldError::InvalidAssetUrl(
+                                                        file.to_token_stream(),
+                                                    ));
+                                                }
+                                                paths.push(file.value());
+                                                literal_paths.push((file, false));
+                                            }
+                                            builder.texture_atlas_files = Some(paths);
+                                        }
+                                        _ => errors.push(ParseFieldError::WrongAttributeType(
+                                            files_list.into_token_stream(),
+                                            "list of image paths",
+                                        )),
+                                    }
+                                }
+                                _ => {
+                                    errors.push(ParseFieldError::UnknownAttributeType(
+                                        attribute.into_token_stream(),
+                                    ));
+                                }
+                            }
+                        }
+                    }
+                }
+                Meta::List(meta_list)
+                    if meta_list
+                        .path
+                        .is_ident(SpriteSheetAttribute::ATTRIBUTE_NAME) =>
+                {
+                    #[cfg(not(feature = "texture_packer"))]
+                    errors.push(ParseFieldError::MissingTexturePackerFeature(
+                        meta_list.into_token_stream(),
+                    ));
+                    #[cfg(feature = "texture_packer")]
+                    {
+                        let sprite_sheet_meta_list = meta_list
+                            .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated);
+                        for attribute in sprite_sheet_meta_list.unwrap() {
+                            match attribute {
+                                Meta::NameValue(named_value) => {
+                                    let path = named_value.path.get_ident().unwrap().clone();
+                                    if path == SpriteSheetAttribute::JSON
+                                        || path == SpriteSheetAttribute::IMAGE
+                                    {
                                         if let Expr::Lit(ExprLit {
-                                            lit: Lit::Float(offset_y),
+                                            lit: Lit::Str(file),
                                             ..
                                         }) = &named_value.value
                                         {
-                                            builder.offset_y =
-                                                Some(offset_y.base10_parse::<f32>().unwrap());
+                                            if path == SpriteSheetAttribute::JSON {
+                                                builder.sprite_sheet_json = Some(file.value());
+                                            } else {
+                                                builder.sprite_sheet_image = Some(file.value());
+                                            }
                                         } else {
                                             errors.push(ParseFieldError::WrongAttributeType(
                                                 named_value.into_token_stream(),
-                                                "float",
+                                                "str",
                                             ));
                                         }
                                     } else {
@@ -415,12 +1586,34 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
                                     builder.is_typed = true;
                                 } else if path == MAPPED_ATTRIBUTE {
                                     builder.is_mapped = true;
+                                } else if path == ARRAY_ATTRIBUTE {
+                                    builder.is_array = true;
                                 } else {
                                     errors.push(ParseFieldError::UnknownAttribute(
                                         meta_path.into_token_stream(),
                                     ))
                                 }
                             }
+                            Meta::List(mapped_meta_list)
+                                if mapped_meta_list.path.is_ident(MAPPED_ATTRIBUTE) =>
+                            {
+                                builder.is_mapped = true;
+                                let key_meta_list = mapped_meta_list.parse_args_with(
+                                    Punctuated::<Meta, Token![,]>::parse_terminated,
+                                );
+                                for key_attribute in key_meta_list.unwrap() {
+                                    match key_attribute {
+                                        Meta::Path(key_path)
+                                            if key_path.is_ident(FULL_PATH_ATTRIBUTE) =>
+                                        {
+                                            builder.is_full_path = true;
+                                        }
+                                        _ => errors.push(ParseFieldError::UnknownAttribute(
+                                            key_attribute.into_token_stream(),
+                                        )),
+                                    }
+                                }
+                            }
                             _ => {
                                 errors.push(ParseFieldError::UnknownAttributeType(
                                     attribute.into_token_stream(),
@@ -435,7 +1628,11 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
 
                     let mut paths = vec![];
                     for path in paths_meta_list.unwrap() {
+                        if !is_valid_asset_path(&path.value()) {
+                            errors.push(ParseFieldError::InvalidAssetUrl(path.to_token_stream()));
+                        }
                         paths.push(path.value());
+                        literal_paths.push((path, false));
                     }
                     builder.asset_paths = Some(paths);
                 }
@@ -450,6 +1647,9 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
                     {
                         let image_meta_list = meta_list
                             .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated);
+                        // per axis modes override the mode for all axes
+                        let mut address_mode = None;
+                        let mut axis_address_modes = [None; 3];
                         for attribute in image_meta_list.unwrap() {
                             match attribute {
                                 Meta::NameValue(named_value) => {
@@ -463,7 +1663,106 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
                                             );
 
                                             if let Ok(sampler) = sampler_result {
-                                                builder.sampler = Some(sampler);
+                                                builder.sampler = Some(sampler.into());
+                                            } else {
+                                                errors.push(ParseFieldError::UnknownAttribute(
+                                                    named_value.value.into_token_stream(),
+                                                ));
+                                            }
+                                        } else {
+                                            errors.push(ParseFieldError::WrongAttributeType(
+                                                named_value.into_token_stream(),
+                                                "path",
+                                            ));
+                                        }
+                                    } else if path == ImageAttribute::ADDRESS_MODE
+                                        || path == ImageAttribute::WRAP
+                                        || path == ImageAttribute::WRAP_U
+                                        || path == ImageAttribute::WRAP_V
+                                        || path == ImageAttribute::WRAP_W
+                                    {
+                                        let target = if path == ImageAttribute::WRAP_U {
+                                            &mut axis_address_modes[0]
+                                        } else if path == ImageAttribute::WRAP_V {
+                                            &mut axis_address_modes[1]
+                                        } else if path == ImageAttribute::WRAP_W {
+                                            &mut axis_address_modes[2]
+                                        } else {
+                                            &mut address_mode
+                                        };
+                                        if let Expr::Path(ExprPath { path, .. }) =
+                                            &named_value.value
+                                        {
+                                            match path.get_ident().map(|ident| {
+                                                AddressModeType::try_from(ident.to_string())
+                                            }) {
+                                                Some(Ok(mode)) => *target = Some(mode),
+                                                _ => {
+                                                    errors.push(ParseFieldError::UnknownAttribute(
+                                                        named_value.value.into_token_stream(),
+                                                    ))
+                                                }
+                                            }
+                                        } else {
+                                            errors.push(ParseFieldError::WrongAttributeType(
+                                                named_value.into_token_stream(),
+                                                "path",
+                                            ));
+                                        }
+                                    } else if path == ImageAttribute::MAX_SIZE {
+                                        match &named_value.value {
+                                            Expr::Lit(ExprLit {
+                                                lit: Lit::Int(max_size),
+                                                ..
+                                            }) if max_size
+                                                .base10_parse::<u32>()
+                                                .is_ok_and(|size| size > 0) =>
+                                            {
+                                                builder.image_max_size =
+                                                    Some(max_size.base10_parse::<u32>().unwrap());
+                                            }
+                                            _ => errors.push(ParseFieldError::WrongAttributeType(
+                                                named_value.into_token_stream(),
+                                                "positive integer",
+                                            )),
+                                        }
+                                    }
+                                }
+                                Meta::List(sampler_list)
+                                    if sampler_list.path.is_ident(ImageAttribute::SAMPLER) =>
+                                {
+                                    // filters that are not given default to linear
+                                    let mut filters = SamplerFilters::from(SamplerType::Linear);
+                                    let filter_meta_list = sampler_list.parse_args_with(
+                                        Punctuated::<Meta, Token![,]>::parse_terminated,
+                                    );
+                                    for filter in filter_meta_list.unwrap() {
+                                        let Meta::NameValue(named_value) = filter else {
+                                            errors.push(ParseFieldError::UnknownAttributeType(
+                                                filter.into_token_stream(),
+                                            ));
+                                            continue;
+                                        };
+                                        let path = named_value.path.get_ident().unwrap().clone();
+                                        let filter = if path == ImageAttribute::MIN_FILTER {
+                                            &mut filters.min
+                                        } else if path == ImageAttribute::MAG_FILTER {
+                                            &mut filters.mag
+                                        } else if path == ImageAttribute::MIPMAP_FILTER {
+                                            &mut filters.mipmap
+                                        } else {
+                                            errors.push(ParseFieldError::UnknownAttribute(
+                                                named_value.into_token_stream(),
+                                            ));
+                                            continue;
+                                        };
+                                        if let Expr::Path(ExprPath { path, .. }) =
+                                            &named_value.value
+                                        {
+                                            if let Ok(sampler) = SamplerType::try_from(
+                                                path.get_ident().unwrap().to_string(),
+                                            ) {
+                                                *filter = sampler;
                                             } else {
                                                 errors.push(ParseFieldError::UnknownAttribute(
                                                     named_value.value.into_token_stream(),
@@ -476,6 +1775,117 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
                                             ));
                                         }
                                     }
+                                    builder.sampler = Some(filters);
+                                }
+                                _ => {
+                                    errors.push(ParseFieldError::UnknownAttributeType(
+                                        attribute.into_token_stream(),
+                                    ));
+                                }
+                            }
+                        }
+                        if address_mode.is_some() || axis_address_modes.iter().any(Option::is_some)
+                        {
+                            let [u, v, w] = axis_address_modes.map(|axis| {
+                                axis.or(address_mode).unwrap_or(AddressModeType::Clamp)
+                            });
+                            builder.image_address_mode = Some(AddressModes { u, v, w });
+                        }
+                    }
+                }
+                Meta::List(meta_list) if meta_list.path.is_ident(KEYS_ATTRIBUTE) => match meta_list
+                    .parse_args_with(Punctuated::<LitStr, Token![,]>::parse_terminated)
+                {
+                    Ok(keys) if !keys.is_empty() => {
+                        for key in &keys {
+                            if let Some((namespace, namespaced_key)) = key.value().split_once(':') {
+                                if namespace.is_empty() || namespaced_key.is_empty() {
+                                    errors.push(ParseFieldError::MalformedNamespacedKey(
+                                        key.to_token_stream(),
+                                    ));
+                                }
+                            }
+                        }
+                        builder.keys = Some(keys.iter().map(LitStr::value).collect());
+                    }
+                    _ => errors.push(ParseFieldError::WrongAttributeType(
+                        meta_list.into_token_stream(),
+                        "list of dynamic asset keys",
+                    )),
+                },
+                Meta::List(meta_list) if meta_list.path.is_ident(EXTENSIONS_ATTRIBUTE) => {
+                    match meta_list
+                        .parse_args_with(Punctuated::<LitStr, Token![,]>::parse_terminated)
+                    {
+                        Ok(extensions) if !extensions.is_empty() => {
+                            builder.extensions = Some(
+                                extensions
+                                    .iter()
+                                    .map(|extension| {
+                                        extension.value().trim_start_matches('.').to_owned()
+                                    })
+                                    .collect(),
+                            );
+                        }
+                        _ => errors.push(ParseFieldError::WrongAttributeType(
+                            meta_list.into_token_stream(),
+                            "list of file extensions",
+                        )),
+                    }
+                }
+                Meta::List(meta_list)
+                    if meta_list
+                        .path
+                        .is_ident(StandardMaterialAttribute::ATTRIBUTE_NAME) =>
+                {
+                    #[cfg(not(feature = "3d"))]
+                    errors.push(ParseFieldError::Missing3dFeature(
+                        meta_list.into_token_stream(),
+                    ));
+                    #[cfg(feature = "3d")]
+                    {
+                        let textures = builder
+                            .standard_material
+                            .get_or_insert_with(StandardMaterialTextures::default);
+                        let material_meta_list = meta_list
+                            .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated);
+                        for attribute in material_meta_list.unwrap() {
+                            match attribute {
+                                Meta::NameValue(named_value) => {
+                                    let path = named_value.path.get_ident().unwrap().clone();
+                                    if path == StandardMaterialAttribute::BASE_COLOR
+                                        || path == StandardMaterialAttribute::NORMAL
+                                        || path == StandardMaterialAttribute::METALLIC_ROUGHNESS
+                                    {
+                                        if let Expr::Lit(ExprLit {
+                                            lit: Lit::Str(texture),
+                                            ..
+                                        }) = &named_value.value
+                                        {
+                                            if !is_valid_asset_path(&texture.value()) {
+                                                errors.push(ParseFieldError::InvalidAssetUrl(
+                                                    texture.to_token_stream(),
+                                                ));
+                                            }
+                                            literal_paths.push((texture.clone(), false));
+                                            if path == StandardMaterialAttribute::BASE_COLOR {
+                                                textures.base_color = Some(texture.value());
+                                            } else if path == StandardMaterialAttribute::NORMAL {
+                                                textures.normal = Some(texture.value());
+                                            } else {
+                                                textures.metallic_roughness = Some(texture.value());
+                                            }
+                                        } else {
+                                            errors.push(ParseFieldError::WrongAttributeType(
+                                                named_value.into_token_stream(),
+                                                "str",
+                                            ));
+                                        }
+                                    } else {
+                                        errors.push(ParseFieldError::UnknownAttribute(
+                                            named_value.into_token_stream(),
+                                        ));
+                                    }
                                 }
                                 _ => {
                                     errors.push(ParseFieldError::UnknownAttributeType(
@@ -486,6 +1896,62 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
                         }
                     }
                 }
+                Meta::List(meta_list)
+                    if meta_list
+                        .path
+                        .is_ident(KeysIntoMapAttribute::ATTRIBUTE_NAME) =>
+                {
+                    let keys_meta_list =
+                        meta_list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated);
+                    let mut prefix = None;
+                    for attribute in keys_meta_list.unwrap() {
+                        match attribute {
+                            Meta::NameValue(named_value)
+                                if named_value.path.is_ident(KeysIntoMapAttribute::PREFIX) =>
+                            {
+                                if let Expr::Lit(ExprLit {
+                                    lit: Lit::Str(key_prefix),
+                                    ..
+                                }) = &named_value.value
+                                {
+                                    prefix = Some(key_prefix.value());
+                                } else {
+                                    errors.push(ParseFieldError::WrongAttributeType(
+                                        named_value.into_token_stream(),
+                                        "str",
+                                    ));
+                                }
+                            }
+                            Meta::NameValue(named_value) => {
+                                errors.push(ParseFieldError::UnknownAttribute(
+                                    named_value.into_token_stream(),
+                                ));
+                            }
+                            _ => {
+                                errors.push(ParseFieldError::UnknownAttributeType(
+                                    attribute.into_token_stream(),
+                                ));
+                            }
+                        }
+                    }
+                    if prefix.is_none() {
+                        errors.push(ParseFieldError::MissingAttributes(vec![format!(
+                            "{}/{}",
+                            KeysIntoMapAttribute::ATTRIBUTE_NAME,
+                            KeysIntoMapAttribute::PREFIX
+                        )]));
+                    }
+                    builder.keys_into_map = prefix;
+                }
+                Meta::List(meta_list) if meta_list.path.is_ident(SETTINGS_ATTRIBUTE) => {
+                    match meta_list.parse_args::<syn::Path>() {
+                        Ok(settings) => builder.settings = Some(settings),
+                        Err(_) => errors.push(ParseFieldError::WrongAttributeType(
+                            meta_list.into_token_stream(),
+                            "path to a function like 'linear_rgb'",
+                        )),
+                    }
+                }
                 Meta::List(meta_list) => errors.push(ParseFieldError::UnknownAttribute(
                     meta_list.into_token_stream(),
                 )),
@@ -495,7 +1961,11 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
                         ..
                     }) = &named_value.value
                     {
+                        if !is_valid_asset_path(&path.value()) {
+                            errors.push(ParseFieldError::InvalidAssetUrl(path.to_token_stream()));
+                        }
                         builder.asset_path = Some(path.value());
+                        literal_paths.push((path.clone(), true));
                     } else {
                         errors.push(ParseFieldError::WrongAttributeType(
                             named_value.into_token_stream(),
@@ -508,7 +1978,91 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
                         lit: Lit::Str(key), ..
                     }) = &named_value.value
                     {
-                        builder.key = Some(key.value());
+                        let key = key.value();
+                        if let Some((namespace, namespaced_key)) = key.split_once(':') {
+                            if namespace.is_empty() || namespaced_key.is_empty() {
+                                errors.push(ParseFieldError::MalformedNamespacedKey(
+                                    named_value.to_token_stream(),
+                                ));
+                            }
+                        }
+                        builder.key = Some(key);
+                    } else {
+                        errors.push(ParseFieldError::WrongAttributeType(
+                            named_value.into_token_stream(),
+                            "str",
+                        ));
+                    }
+                }
+                Meta::NameValue(named_value) if named_value.path.is_ident(EMBEDDED_ATTRIBUTE) => {
+                    if let Expr::Lit(ExprLit {
+                        lit: Lit::Str(path),
+                        ..
+                    }) = &named_value.value
+                    {
+                        builder.embedded = Some(path.value());
+                        literal_paths.push((path.clone(), false));
+                    } else {
+                        errors.push(ParseFieldError::WrongAttributeType(
+                            named_value.into_token_stream(),
+                            "str",
+                        ));
+                    }
+                }
+                Meta::NameValue(named_value) if named_value.path.is_ident(SHA256_ATTRIBUTE) => {
+                    #[cfg(not(feature = "checksums"))]
+                    errors.push(ParseFieldError::MissingChecksumsFeature(
+                        named_value.into_token_stream(),
+                    ));
+                    #[cfg(feature = "checksums")]
+                    match &named_value.value {
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(sha256),
+                            ..
+                        }) if sha256.value().len() == 64
+                            && sha256.value().chars().all(|c| c.is_ascii_hexdigit()) =>
+                        {
+                            builder.sha256 = Some(sha256.value().to_ascii_lowercase());
+                        }
+                        _ => errors.push(ParseFieldError::WrongAttributeType(
+                            named_value.into_token_stream(),
+                            "hex encoded SHA-256 digest",
+                        )),
+                    }
+                }
+                Meta::NameValue(named_value) if named_value.path.is_ident(ALIAS_FROM_ATTRIBUTE) => {
+                    if let Some(alias) = parse_resource_field_access(&named_value.value) {
+                        builder.alias_from = Some(alias);
+                    } else {
+                        errors.push(ParseFieldError::WrongAttributeType(
+                            named_value.into_token_stream(),
+                            "resource field access like 'MyResource.handle'",
+                        ));
+                    }
+                }
+                Meta::NameValue(named_value)
+                    if named_value.path.is_ident(RUNTIME_PATH_ATTRIBUTE) =>
+                {
+                    if let Expr::Lit(ExprLit {
+                        lit: Lit::Str(key), ..
+                    }) = &named_value.value
+                    {
+                        builder.runtime_path = Some(key.value());
+                    } else {
+                        errors.push(ParseFieldError::WrongAttributeType(
+                            named_value.into_token_stream(),
+                            "str",
+                        ));
+                    }
+                }
+                Meta::NameValue(named_value)
+                    if named_value.path.is_ident(MANIFEST_KEY_ATTRIBUTE) =>
+                {
+                    if let Expr::Lit(ExprLit {
+                        lit: Lit::Str(key), ..
+                    }) = &named_value.value
+                    {
+                        builder.manifest_key = Some(key.value());
                     } else {
                         errors.push(ParseFieldError::WrongAttributeType(
                             named_value.into_token_stream(),
@@ -516,17 +2070,48 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
                         ));
                     }
                 }
+                Meta::NameValue(named_value)
+                    if named_value.path.is_ident(PLACEHOLDER_ATTRIBUTE) =>
+                {
+                    if let Expr::Path(ExprPath { path, .. }) = &named_value.value {
+                        builder.placeholder = Some(path.clone());
+                    } else {
+                        errors.push(ParseFieldError::WrongAttributeType(
+                            named_value.into_token_stream(),
+                            "path to a function like 'default_image'",
+                        ));
+                    }
+                }
+                Meta::NameValue(named_value)
+                    if named_value.path.is_ident(REQUIRED_IF_ATTRIBUTE) =>
+                {
+                    if let Some(field) = match &named_value.value {
+                        Expr::Path(ExprPath { path, .. }) => path.get_ident(),
+                        _ => None,
+                    } {
+                        builder.required_if = Some(field.clone());
+                    } else {
+                        errors.push(ParseFieldError::WrongAttributeType(
+                            named_value.into_token_stream(),
+                            "name of a field like 'base_texture'",
+                        ));
+                    }
+                }
                 Meta::NameValue(named_value) => errors.push(ParseFieldError::UnknownAttribute(
                     named_value.into_token_stream(),
                 )),
-                Meta::Path(meta_path) if meta_path.is_ident(STANDARD_MATERIAL_ATTRIBUTE) => {
+                Meta::Path(meta_path)
+                    if meta_path.is_ident(StandardMaterialAttribute::ATTRIBUTE_NAME) =>
+                {
                     #[cfg(not(feature = "3d"))]
                     errors.push(ParseFieldError::Missing3dFeature(
                         meta_path.into_token_stream(),
                     ));
                     #[cfg(feature = "3d")]
                     {
-                        builder.is_standard_material = true;
+                        builder
+                            .standard_material
+                            .get_or_insert_with(StandardMaterialTextures::default);
                     }
                 }
                 Meta::Path(meta_path) if meta_path.is_ident(OPTIONAL_ATTRIBUTE) => {
@@ -535,6 +2120,22 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
                 Meta::Path(meta_path) if meta_path.is_ident(COLLECTION_ATTRIBUTE) => {
                     builder.is_collection = true;
                 }
+                Meta::Path(meta_path) if meta_path.is_ident(FOLDER_ATTRIBUTE) => {
+                    builder.is_folder = true;
+                }
+                Meta::Path(meta_path) if meta_path.is_ident(LOCK_ATTRIBUTE) => {
+                    builder.is_locked = true;
+                }
+                Meta::Path(meta_path) if meta_path.is_ident(DURATION_ATTRIBUTE) => {
+                    #[cfg(not(feature = "audio"))]
+                    errors.push(ParseFieldError::MissingAudioFeature(
+                        meta_path.into_token_stream(),
+                    ));
+                    #[cfg(feature = "audio")]
+                    {
+                        builder.with_duration = true;
+                    }
+                }
                 Meta::Path(meta_path) if meta_path.is_ident(TYPED_ATTRIBUTE) => {
                     builder.is_typed = true;
                 }
@@ -544,6 +2145,29 @@ fn parse_field(field: &Field) -> Result<AssetField, Vec<ParseFieldError>> {
             }
         }
     }
+    // the derive puts locked fields into their lock, so the type of the asset is inside of it
+    let ty = if builder.is_locked {
+        first_type_argument(&field.ty).unwrap_or(&field.ty)
+    } else {
+        &field.ty
+    };
+    builder.is_option_type = field_is_option(ty);
+    builder.is_option_handle_type = field_is_option_handle(ty);
+    builder.is_vec_or_map_type = field_is_vec_or_map(ty);
+    if let Some(extensions) = &builder.extensions {
+        // a single path of a collection is a folder, which is rejected when building the field
+        for (path, _) in literal_paths
+            .iter()
+            .filter(|(_, single)| !(*single && builder.is_collection))
+        {
+            if !has_allowed_extension(&path.value(), extensions) {
+                errors.push(ParseFieldError::DisallowedExtension(
+                    path.to_token_stream(),
+                    extensions.join(", "),
+                ));
+            }
+        }
+    }
     if !errors.is_empty() {
         return Err(errors);
     }